@@ -871,6 +871,10 @@ mod tests {
                 }
                 d => unreachable!("{d}")
             }
+
+            let array = new_empty_array(&data_type);
+            array.to_data().validate_full().unwrap();
+            assert_eq!(array.len(), 0);
         }
     }
 
@@ -84,6 +84,17 @@ mod private {
         Offset(FixedOffset),
     }
 
+    impl Tz {
+        /// Returns the offset of this timezone if it is a fixed offset, e.g. `+08:00`,
+        /// as opposed to a named timezone whose offset may vary
+        pub fn fixed_offset(&self) -> Option<FixedOffset> {
+            match self.0 {
+                TzInner::Timezone(_) => None,
+                TzInner::Offset(offset) => Some(offset),
+            }
+        }
+    }
+
     impl FromStr for Tz {
         type Err = ArrowError;
 
@@ -261,6 +272,14 @@ mod private {
     #[derive(Debug, Copy, Clone)]
     pub struct Tz(FixedOffset);
 
+    impl Tz {
+        /// Returns the offset of this timezone if it is a fixed offset, e.g. `+08:00`,
+        /// as opposed to a named timezone whose offset may vary
+        pub fn fixed_offset(&self) -> Option<FixedOffset> {
+            Some(self.0)
+        }
+    }
+
     impl FromStr for Tz {
         type Err = ArrowError;
 
@@ -0,0 +1,211 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_array::builder::{
+    Decimal256Builder, Float64Builder, Int64Builder, TimestampNanosecondBuilder,
+};
+use arrow_array::types::Int32Type;
+use arrow_array::{ArrayRef, DictionaryArray, StringArray};
+use arrow_buffer::i256;
+use arrow_cast::{cast_with_options, CastOptions};
+use arrow_schema::{DataType, TimeUnit};
+use criterion::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::Arc;
+
+const SIZE: usize = 8192;
+
+/// Returns an index in `0..n` skewed towards zero, such that a small number of
+/// indices account for most of the samples, as is typical of real world data
+fn skewed_index(rng: &mut StdRng, n: usize) -> usize {
+    let u: f64 = rng.gen();
+    ((u * u * u) * n as f64) as usize
+}
+
+/// Returns `size` values generated by `f`, of which around `null_density` are null
+fn skewed_values<T>(
+    size: usize,
+    null_density: f64,
+    mut f: impl FnMut(&mut StdRng) -> T,
+) -> Vec<Option<T>> {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..size)
+        .map(|_| match rng.gen_bool(null_density) {
+            true => None,
+            false => Some(f(&mut rng)),
+        })
+        .collect()
+}
+
+/// Timestamps clustered around recent dates, mostly formatted as RFC 3339 in UTC,
+/// with occasional fractional seconds, offsets and space separators
+fn build_utf8_timestamp_array() -> ArrayRef {
+    let values = skewed_values(SIZE, 0.1, |rng| {
+        let secs = 1_700_000_000 - 86_400 * skewed_index(rng, 10_000) as i64;
+        let datetime = chrono::NaiveDateTime::from_timestamp_opt(secs, 0).unwrap();
+        match skewed_index(rng, 4) {
+            0 => datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            1 => format!(
+                "{}.{:06}Z",
+                datetime.format("%Y-%m-%dT%H:%M:%S"),
+                secs % 1000
+            ),
+            2 => datetime.format("%Y-%m-%dT%H:%M:%S+05:30").to_string(),
+            _ => datetime.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    });
+    Arc::new(StringArray::from(values))
+}
+
+/// Decimal strings with mostly small magnitudes and few fractional digits
+fn build_utf8_decimal_array() -> ArrayRef {
+    let values = skewed_values(SIZE, 0.1, |rng| {
+        let integer = skewed_index(rng, 1_000_000_000) as i64;
+        let integer = match rng.gen_bool(0.2) {
+            true => -integer,
+            false => integer,
+        };
+        match skewed_index(rng, 4) {
+            0 => integer.to_string(),
+            digits => format!(
+                "{integer}.{:0digits$}",
+                rng.gen_range(0..10_i64.pow(digits as u32 * 2))
+            ),
+        }
+    });
+    Arc::new(StringArray::from(values))
+}
+
+/// Decimal256 values that fit in a Decimal128 of the same scale
+fn build_decimal256_array() -> ArrayRef {
+    let mut builder = Decimal256Builder::with_capacity(SIZE);
+    for v in skewed_values(SIZE, 0.1, |rng| {
+        i256::from_i128(skewed_index(rng, usize::MAX) as i128 * 1000)
+    }) {
+        builder.append_option(v);
+    }
+    Arc::new(builder.finish().with_precision_and_scale(50, 10).unwrap())
+}
+
+/// Nanosecond timestamps of recent dates, without a timezone
+fn build_timestamp_array() -> ArrayRef {
+    let mut builder = TimestampNanosecondBuilder::with_capacity(SIZE);
+    for v in skewed_values(SIZE, 0.1, |rng| {
+        let secs = 1_700_000_000 - skewed_index(rng, 100_000_000) as i64;
+        secs * 1_000_000_000
+    }) {
+        builder.append_option(v);
+    }
+    Arc::new(builder.finish())
+}
+
+fn build_int64_array() -> ArrayRef {
+    let mut builder = Int64Builder::with_capacity(SIZE);
+    for v in skewed_values(SIZE, 0.1, |rng| skewed_index(rng, i32::MAX as usize) as i64) {
+        builder.append_option(v);
+    }
+    Arc::new(builder.finish())
+}
+
+fn build_float64_array() -> ArrayRef {
+    let mut builder = Float64Builder::with_capacity(SIZE);
+    for v in skewed_values(SIZE, 0.1, |rng| skewed_index(rng, 1 << 40) as f64 / 7.0) {
+        builder.append_option(v);
+    }
+    Arc::new(builder.finish())
+}
+
+/// A dictionary of strings where a handful of values make up most of the keys
+fn build_string_dictionary_array() -> ArrayRef {
+    let values = skewed_values(SIZE, 0.1, |rng| {
+        format!("category_{}", skewed_index(rng, 1000))
+    });
+    let array: DictionaryArray<Int32Type> = values.iter().map(|v| v.as_deref()).collect();
+    Arc::new(array)
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let utf8_timestamp = build_utf8_timestamp_array();
+    let utf8_decimal = build_utf8_decimal_array();
+    let decimal256 = build_decimal256_array();
+    let timestamp = build_timestamp_array();
+    let int64 = build_int64_array();
+    let float64 = build_float64_array();
+    let dictionary = build_string_dictionary_array();
+
+    let cases = [
+        (
+            "utf8 to timestamp(ns)",
+            &utf8_timestamp,
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+        ),
+        (
+            "utf8 to timestamp(ms, +00:00)",
+            &utf8_timestamp,
+            DataType::Timestamp(TimeUnit::Millisecond, Some("+00:00".into())),
+        ),
+        (
+            "utf8 to decimal128(38, 10)",
+            &utf8_decimal,
+            DataType::Decimal128(38, 10),
+        ),
+        (
+            "decimal256(50, 10) to decimal128(38, 10)",
+            &decimal256,
+            DataType::Decimal128(38, 10),
+        ),
+        (
+            "decimal256(50, 10) to decimal128(38, 6)",
+            &decimal256,
+            DataType::Decimal128(38, 6),
+        ),
+        (
+            "decimal256(50, 10) to decimal256(60, 20)",
+            &decimal256,
+            DataType::Decimal256(60, 20),
+        ),
+        ("timestamp(ns) to date32", &timestamp, DataType::Date32),
+        ("utf8 to large_utf8", &utf8_timestamp, DataType::LargeUtf8),
+        ("int64 to int32", &int64, DataType::Int32),
+        ("int64 to float64", &int64, DataType::Float64),
+        ("float64 to int64", &float64, DataType::Int64),
+        ("float64 to float32", &float64, DataType::Float32),
+        (
+            "dictionary(int32, utf8) to utf8",
+            &dictionary,
+            DataType::Utf8,
+        ),
+        (
+            "dictionary(int32, utf8) to large_utf8",
+            &dictionary,
+            DataType::LargeUtf8,
+        ),
+    ];
+
+    for (name, array, to_type) in &cases {
+        for safe in [true, false] {
+            let options = CastOptions::new().with_safe(safe);
+            c.bench_function(&format!("cast {name} safe={safe} {SIZE}"), |b| {
+                b.iter(|| black_box(cast_with_options(array, to_type, &options).unwrap()))
+            });
+        }
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,226 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels converting binary arrays to and from base64 encoded strings
+//!
+//! These are also used by [`cast_with_options`](crate::cast_with_options) to cast
+//! between binary and string arrays if [`CastOptions::base64`] is set
+//!
+//! ```
+//! # use arrow_array::{BinaryArray, StringArray};
+//! # use arrow_cast::base64::*;
+//! # use arrow_cast::DEFAULT_CAST_OPTIONS;
+//! let array = BinaryArray::from_iter_values([b"arrow".as_slice(), &[0xfb, 0xff]]);
+//! let encoded = binary_to_base64_string(&array, Base64Alphabet::Standard).unwrap();
+//! assert_eq!(encoded, StringArray::from(vec!["YXJyb3c=", "+/8="]));
+//!
+//! let encoded = binary_to_base64_string(&array, Base64Alphabet::UrlSafe).unwrap();
+//! assert_eq!(encoded.value(1), "-_8=");
+//!
+//! let decoded =
+//!     base64_string_to_binary(&encoded, Base64Alphabet::UrlSafe, &DEFAULT_CAST_OPTIONS)
+//!         .unwrap();
+//! assert_eq!(decoded, array);
+//! ```
+
+use crate::CastOptions;
+use arrow_array::builder::{GenericBinaryBuilder, GenericStringBuilder};
+use arrow_array::{Array, GenericBinaryArray, GenericStringArray, OffsetSizeTrait};
+use arrow_schema::ArrowError;
+use base64::alphabet::{Alphabet, STANDARD, URL_SAFE};
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+
+/// The alphabet of base64 encoded strings, as defined by
+/// [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648)
+///
+/// Strings are encoded with `=` padding, and decoded with or without it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Base64Alphabet {
+    /// The standard alphabet, with `+` and `/` as the last two characters
+    #[default]
+    Standard,
+    /// The URL and filename safe alphabet, with `-` and `_` as the last two characters
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    fn engine(self) -> GeneralPurpose {
+        const CONFIG: GeneralPurposeConfig = GeneralPurposeConfig::new()
+            .with_encode_padding(true)
+            .with_decode_padding_mode(DecodePaddingMode::Indifferent);
+
+        let alphabet: &Alphabet = match self {
+            Self::Standard => &STANDARD,
+            Self::UrlSafe => &URL_SAFE,
+        };
+        GeneralPurpose::new(alphabet, CONFIG)
+    }
+}
+
+/// Base64 encodes each value of `array` with `alphabet`
+///
+/// Returns an error if the encoded values are too large for the offsets of `O`
+pub fn binary_to_base64_string<O: OffsetSizeTrait>(
+    array: &GenericBinaryArray<O>,
+    alphabet: Base64Alphabet,
+) -> Result<GenericStringArray<O>, ArrowError> {
+    let too_large = || {
+        ArrowError::ComputeError(format!(
+            "{:?} array too large to base64 encode",
+            array.data_type()
+        ))
+    };
+    let data_capacity = array
+        .iter()
+        .flatten()
+        .try_fold(0_usize, |acc, v| {
+            base64::encoded_len(v.len(), true).and_then(|len| acc.checked_add(len))
+        })
+        .filter(|len| O::from_usize(*len).is_some())
+        .ok_or_else(too_large)?;
+
+    let engine = alphabet.engine();
+    let mut builder =
+        GenericStringBuilder::<O>::with_capacity(array.len(), data_capacity);
+    let mut encoded = String::new();
+    for v in array.iter() {
+        match v {
+            Some(v) => {
+                encoded.clear();
+                engine.encode_string(v, &mut encoded);
+                builder.append_value(&encoded);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Decodes each base64 encoded value of `array` with `alphabet`
+///
+/// Values that are not valid base64 are returned as null, or as an error if
+/// [`CastOptions::safe`] is false
+pub fn base64_string_to_binary<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    alphabet: Base64Alphabet,
+    cast_options: &CastOptions,
+) -> Result<GenericBinaryArray<O>, ArrowError> {
+    let offsets = array.value_offsets();
+    let bytes = offsets[array.len()].as_usize() - offsets[0].as_usize();
+
+    let engine = alphabet.engine();
+    // Decoding never increases the length of a value
+    let mut builder = GenericBinaryBuilder::<O>::with_capacity(array.len(), bytes);
+    let mut decoded = Vec::new();
+    for v in array.iter() {
+        match v {
+            Some(v) => {
+                decoded.clear();
+                match engine.decode_vec(v, &mut decoded) {
+                    Ok(()) => builder.append_value(&decoded),
+                    Err(_) if cast_options.safe => builder.append_null(),
+                    Err(e) => {
+                        return Err(ArrowError::CastError(format!(
+                            "Cannot decode base64 string '{v}': {e}"
+                        )))
+                    }
+                }
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_CAST_OPTIONS;
+    use arrow_array::{LargeBinaryArray, LargeStringArray, StringArray};
+
+    #[test]
+    fn test_base64_roundtrip() {
+        let values: Vec<Option<&[u8]>> = vec![
+            Some(b""),
+            Some(b"f"),
+            Some(b"fo"),
+            Some(b"foo"),
+            None,
+            Some(&[0, 0xfb, 0xef, 0xff]),
+        ];
+        let array = LargeBinaryArray::from(values);
+
+        let encoded = binary_to_base64_string(&array, Base64Alphabet::Standard).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some(""),
+            Some("Zg=="),
+            Some("Zm8="),
+            Some("Zm9v"),
+            None,
+            Some("APvv/w=="),
+        ]);
+        assert_eq!(encoded, expected);
+        let decoded = base64_string_to_binary(
+            &encoded,
+            Base64Alphabet::Standard,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap();
+        assert_eq!(decoded, array);
+
+        let encoded = binary_to_base64_string(&array, Base64Alphabet::UrlSafe).unwrap();
+        assert_eq!(encoded.value(5), "APvv_w==");
+        let decoded = base64_string_to_binary(
+            &encoded,
+            Base64Alphabet::UrlSafe,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap();
+        assert_eq!(decoded, array);
+    }
+
+    #[test]
+    fn test_base64_decode_invalid() {
+        let array = StringArray::from(vec![
+            Some("Zm8"),
+            Some("Zm8="),
+            Some("Zm 8"),
+            Some("_w"),
+            None,
+        ]);
+        let decoded = base64_string_to_binary(
+            &array,
+            Base64Alphabet::Standard,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap();
+        let values: Vec<_> = decoded.iter().collect();
+        let foo: &[u8] = b"fo";
+        assert_eq!(values, vec![Some(foo), Some(foo), None, None, None]);
+
+        let options = CastOptions::new().with_safe(false);
+        let err = base64_string_to_binary(&array, Base64Alphabet::Standard, &options)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot decode base64 string 'Zm 8': Invalid byte 32, offset 2."
+        );
+    }
+}
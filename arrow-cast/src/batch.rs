@@ -0,0 +1,465 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels casting the columns of a [`RecordBatch`] to a schema, or to and from
+//! dictionary encoding
+
+use std::sync::Arc;
+
+use crate::cast::{
+    cast, cast_with_options, check_non_nullable, child_cast_options, default_column,
+    with_error_context, CastOptions,
+};
+use arrow_array::{ArrayRef, RecordBatch, RecordBatchOptions};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+
+/// Cast `batch` to `schema`, matching columns by name
+///
+/// Columns of `batch` that are not present in `schema` are dropped, and fields of
+/// `schema` that are not present in `batch` are filled with the value registered for
+/// them in [`CastOptions::defaults`], or nulls if there is none. The children of struct
+/// columns are matched in the same way, see [`cast_with_options`].
+///
+/// Columns of [`DataType::Null`] cast to non-nullable fields are treated as absent.
+/// Returns an error if an absent field is not nullable and has no default, or if a
+/// column, or a child of a struct column, contains nulls for a non-nullable field.
+/// Errors casting a column are prefixed with the names of the column and children
+/// that caused them, e.g. `column 's': field 'a': Cannot cast string 'x' ...`
+pub fn cast_record_batch(
+    batch: &RecordBatch,
+    schema: &SchemaRef,
+    cast_options: &CastOptions,
+) -> Result<RecordBatch, ArrowError> {
+    let num_rows = batch.num_rows();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| match batch.column_by_name(field.name()) {
+            // A null column cannot populate a non-nullable field
+            Some(column)
+                if column.data_type() == &DataType::Null && !field.is_nullable() =>
+            {
+                default_column(&[field.name()], field, num_rows, cast_options)
+            }
+            Some(column) => {
+                let options = child_cast_options(cast_options, field.name());
+                let column = cast_with_options(column, field.data_type(), &options)
+                    .map_err(|e| with_error_context("column", field.name(), e))?;
+                check_non_nullable(field, column.as_ref(), None)?;
+                Ok(column)
+            }
+            None => default_column(&[field.name()], field, num_rows, cast_options),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    RecordBatch::try_new_with_options(schema.clone(), columns, &options)
+}
+
+/// Dictionary encodes the columns of `batch` at `indices`, with keys of `key_type`
+///
+/// The fields of the encoded columns keep their name, nullability and metadata, and are
+/// assigned dictionary ids distinct from those of the other fields of the schema, as
+/// required by IPC writers. Columns that are already dictionary encoded have their keys
+/// cast to `key_type`.
+///
+/// Returns an error if an index is out of bounds, `key_type` is not an integer type, or
+/// a column has more distinct values than `key_type` can index
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_cast::{decode_columns_dictionary, encode_columns_dictionary};
+/// # use arrow_schema::DataType;
+/// let batch = RecordBatch::try_from_iter([
+///     ("id", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+///     ("city", Arc::new(StringArray::from(vec!["Paris", "Oslo", "Paris"]))),
+/// ])
+/// .unwrap();
+///
+/// let encoded = encode_columns_dictionary(&batch, &[1], &DataType::Int8).unwrap();
+/// let expected = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+/// assert_eq!(encoded.schema().field(1).data_type(), &expected);
+///
+/// let decoded = decode_columns_dictionary(&encoded, &[1]).unwrap();
+/// assert_eq!(decoded, batch);
+/// ```
+pub fn encode_columns_dictionary(
+    batch: &RecordBatch,
+    indices: &[usize],
+    key_type: &DataType,
+) -> Result<RecordBatch, ArrowError> {
+    if !key_type.is_dictionary_key_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot dictionary encode columns with keys of type {key_type:?}"
+        )));
+    }
+    let schema = batch.schema();
+    let mut next_dict_id = schema
+        .all_fields()
+        .iter()
+        .filter_map(|f| f.dict_id())
+        .max()
+        .map_or(0, |id| id + 1);
+
+    map_columns(batch, indices, |field, column| {
+        let value_type = match field.data_type() {
+            DataType::Dictionary(_, value_type) => value_type.as_ref().clone(),
+            data_type => data_type.clone(),
+        };
+        let to_type =
+            DataType::Dictionary(Box::new(key_type.clone()), Box::new(value_type));
+        let options = CastOptions::new().with_safe(false);
+        let column = cast_with_options(column, &to_type, &options)?;
+        let field = Field::new_dict(
+            field.name(),
+            to_type,
+            field.is_nullable(),
+            next_dict_id,
+            field.dict_is_ordered().unwrap_or_default(),
+        )
+        .with_metadata(field.metadata().clone());
+        next_dict_id += 1;
+        Ok((field, column))
+    })
+}
+
+/// Decodes the dictionary encoded columns of `batch` at `indices` to their value type,
+/// the inverse of [`encode_columns_dictionary`]
+///
+/// Columns at `indices` that are not dictionary encoded are left unchanged. Returns an
+/// error if an index is out of bounds
+pub fn decode_columns_dictionary(
+    batch: &RecordBatch,
+    indices: &[usize],
+) -> Result<RecordBatch, ArrowError> {
+    map_columns(batch, indices, |field, column| match field.data_type() {
+        DataType::Dictionary(_, value_type) => {
+            let column = cast(column, value_type)?;
+            let field = field.clone().with_data_type(value_type.as_ref().clone());
+            Ok((field, column))
+        }
+        _ => Ok((field.clone(), column.clone())),
+    })
+}
+
+/// Returns `batch` with the fields and columns at `indices` replaced by the result of `f`
+fn map_columns<F>(
+    batch: &RecordBatch,
+    indices: &[usize],
+    mut f: F,
+) -> Result<RecordBatch, ArrowError>
+where
+    F: FnMut(&Field, &ArrayRef) -> Result<(Field, ArrayRef), ArrowError>,
+{
+    let schema = batch.schema();
+    if let Some(i) = indices.iter().find(|i| **i >= batch.num_columns()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Column index {i} out of bounds for batch with {} columns",
+            batch.num_columns()
+        )));
+    }
+
+    let mut fields = Vec::with_capacity(batch.num_columns());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    for (i, (field, column)) in schema.fields().iter().zip(batch.columns()).enumerate() {
+        let (field, column) = match indices.contains(&i) {
+            true => f(field, column)?,
+            false => (field.as_ref().clone(), column.clone()),
+        };
+        fields.push(field);
+        columns.push(column);
+    }
+
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    RecordBatch::try_new_with_options(Arc::new(schema), columns, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    use crate::cast::{CastDefaults, DEFAULT_CAST_OPTIONS};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int64Type, UInt8Type};
+    use arrow_array::{
+        DictionaryArray, Int32Array, NullArray, StringArray, StructArray, UInt8Array,
+    };
+    use arrow_buffer::{BooleanBuffer, NullBuffer};
+    use arrow_schema::Fields;
+
+    #[test]
+    fn test_cast_record_batch() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            (
+                "b",
+                Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("b", DataType::Utf8, true),
+            Field::new("a", DataType::Int64, true),
+            Field::new("c", DataType::Float64, true),
+            Field::new("d", DataType::Utf8, false),
+        ]));
+
+        let defaults = CastDefaults::new()
+            .with_default(&["d"], Arc::new(StringArray::from(vec!["unknown"])))
+            .unwrap();
+        let options = CastOptions {
+            defaults: Some(Arc::new(defaults)),
+            ..DEFAULT_CAST_OPTIONS
+        };
+
+        let out = cast_record_batch(&batch, &schema, &options).unwrap();
+        assert_eq!(out.schema(), schema);
+        assert_eq!(out.num_rows(), 3);
+        assert_eq!(
+            out.column(0).as_string::<i32>(),
+            batch.column(1).as_string()
+        );
+        assert_eq!(
+            out.column(1).as_primitive::<Int64Type>().values(),
+            &[1, 2, 3]
+        );
+        assert_eq!(out.column(2).null_count(), 3);
+        let d = out.column(3).as_string::<i32>();
+        assert_eq!(d, &StringArray::from(vec!["unknown"; 3]));
+
+        // Without a default the non-nullable field cannot be filled
+        let err = cast_record_batch(&batch, &schema, &DEFAULT_CAST_OPTIONS).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot fill non-nullable field 'd' with nulls, no default value was provided"
+        );
+    }
+
+    #[test]
+    fn test_cast_record_batch_nested() {
+        let s = StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Int32, true)),
+            Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+        )]);
+        let batch = RecordBatch::try_from_iter([("s", Arc::new(s) as ArrayRef)]).unwrap();
+
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(fields),
+            true,
+        )]));
+        let defaults = CastDefaults::new()
+            .with_default(&["s", "b"], Arc::new(StringArray::from(vec!["x"])))
+            .unwrap();
+        let options = CastOptions::new().with_defaults(Some(Arc::new(defaults)));
+
+        let out = cast_record_batch(&batch, &schema, &options).unwrap();
+        let s = out.column(0).as_struct();
+        assert_eq!(s.column(0).as_primitive::<Int64Type>().values(), &[1, 2]);
+        assert_eq!(
+            s.column(1).as_string::<i32>(),
+            &StringArray::from(vec!["x"; 2])
+        );
+    }
+
+    #[test]
+    fn test_cast_record_batch_null_column() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(NullArray::new(2)) as ArrayRef),
+            ("b", Arc::new(NullArray::new(2)) as ArrayRef),
+        ])
+        .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, false),
+        ]));
+
+        let err = cast_record_batch(&batch, &schema, &DEFAULT_CAST_OPTIONS).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot fill non-nullable field 'b' with nulls, no default value was provided"
+        );
+
+        let defaults = CastDefaults::new()
+            .with_default(&["b"], Arc::new(Int32Array::from(vec![7])))
+            .unwrap();
+        let options = CastOptions {
+            defaults: Some(Arc::new(defaults)),
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let out = cast_record_batch(&batch, &schema, &options).unwrap();
+        assert_eq!(out.column(0).null_count(), 2);
+        let b = out.column(1).as_primitive::<Int32Type>();
+        assert_eq!(b, &Int32Array::from(vec![7, 7]));
+
+        // An empty batch has no values to violate the schema
+        let out = cast_record_batch(&batch.slice(0, 0), &schema, &DEFAULT_CAST_OPTIONS);
+        assert_eq!(out.unwrap().num_rows(), 0);
+    }
+
+    #[test]
+    fn test_cast_record_batch_errors() {
+        let s = StructArray::try_new(
+            Fields::from(vec![Field::new("a", DataType::Utf8, true)]),
+            vec![Arc::new(StringArray::from(vec![
+                Some("1"),
+                None,
+                Some("x"),
+            ]))],
+            Some(NullBuffer::new(BooleanBuffer::collect_bool(3, |i| i != 1))),
+        )
+        .unwrap();
+        let batch = RecordBatch::try_from_iter([
+            ("s", Arc::new(s) as ArrayRef),
+            (
+                "b",
+                Arc::new(Int32Array::from(vec![Some(1), None, None])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let struct_type = |nullable| {
+            DataType::Struct(Fields::from(vec![Field::new(
+                "a",
+                DataType::Int32,
+                nullable,
+            )]))
+        };
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("s", struct_type(true), true),
+            Field::new("b", DataType::Int64, true),
+        ]));
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_record_batch(&batch, &schema, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: column 's': field 'a': Cannot cast string 'x' to value of Int32 type"
+        );
+
+        // The null in the child of the null struct row is masked
+        let batch = batch.slice(0, 2);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("s", struct_type(false), true),
+            Field::new("b", DataType::Int64, true),
+        ]));
+        let out = cast_record_batch(&batch, &schema, &options).unwrap();
+        assert_eq!(out.column(1).null_count(), 1);
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("s", struct_type(false), true),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        let err = cast_record_batch(&batch, &schema, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 1 null values to the non-nullable field 'b'"
+        );
+
+        // Values that fail to cast are nulls, which violate the non-nullable field
+        let batch = RecordBatch::try_from_iter([(
+            "s",
+            Arc::new(StructArray::from(vec![(
+                Arc::new(Field::new("a", DataType::Utf8, false)),
+                Arc::new(StringArray::from(vec!["1", "x"])) as ArrayRef,
+            )])) as ArrayRef,
+        )])
+        .unwrap();
+        let schema =
+            Arc::new(Schema::new(vec![Field::new("s", struct_type(false), true)]));
+        let err = cast_record_batch(&batch, &schema, &DEFAULT_CAST_OPTIONS).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: column 's': Cannot cast 1 null values to the non-nullable field 'a'"
+        );
+    }
+
+    #[test]
+    fn test_encode_columns_dictionary() {
+        let dict_type =
+            |k: DataType| DataType::Dictionary(Box::new(k), Box::new(DataType::Utf8));
+        let existing: DictionaryArray<Int32Type> =
+            vec!["a", "b", "a"].into_iter().collect();
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let schema = Schema::new(vec![
+            Field::new_dict("a", dict_type(DataType::Int32), true, 4, false),
+            Field::new("b", DataType::Utf8, false).with_metadata(metadata.clone()),
+            Field::new("c", DataType::Int32, true),
+            Field::new("d", DataType::Utf8, true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(existing),
+                Arc::new(StringArray::from(vec!["x", "y", "x"])),
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("z"), None, Some("z")])),
+            ],
+        )
+        .unwrap();
+
+        let encoded =
+            encode_columns_dictionary(&batch, &[0, 1, 3], &DataType::UInt8).unwrap();
+        let schema = encoded.schema();
+        let dict_ids: Vec<_> = schema.fields().iter().map(|f| f.dict_id()).collect();
+        assert_eq!(dict_ids, vec![Some(5), Some(6), None, Some(7)]);
+        assert_eq!(schema.field(0).data_type(), &dict_type(DataType::UInt8));
+        assert_eq!(schema.field(1).data_type(), &dict_type(DataType::UInt8));
+        assert!(!schema.field(1).is_nullable());
+        assert_eq!(schema.field(1).metadata(), &metadata);
+        assert_eq!(schema.field(2), batch.schema().field(2));
+
+        let b = encoded.column(1).as_dictionary::<UInt8Type>();
+        assert_eq!(b.keys(), &UInt8Array::from(vec![0, 1, 0]));
+        let d = encoded.column(3).as_dictionary::<UInt8Type>();
+        assert_eq!(d.keys(), &UInt8Array::from(vec![Some(0), None, Some(0)]));
+
+        let decoded = decode_columns_dictionary(&encoded, &[1, 2, 3]).unwrap();
+        assert_eq!(decoded.schema().field(1), batch.schema().field(1));
+        assert_eq!(decoded.columns()[1..], batch.columns()[1..]);
+        assert_eq!(decoded.schema().field(0), schema.field(0));
+
+        let err = encode_columns_dictionary(&batch, &[4], &DataType::UInt8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Column index 4 out of bounds for batch with 4 columns"
+        );
+        let err = encode_columns_dictionary(&batch, &[1], &DataType::Utf8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot dictionary encode columns with keys of type Utf8"
+        );
+
+        let values: StringArray = (0..300).map(|i| Some(i.to_string())).collect();
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(values) as ArrayRef)]).unwrap();
+        let err = encode_columns_dictionary(&batch, &[0], &DataType::Int8).unwrap_err();
+        assert!(
+            matches!(err, ArrowError::DictionaryKeyOverflowError),
+            "{err}"
+        );
+    }
+}
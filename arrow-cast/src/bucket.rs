@@ -0,0 +1,191 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels bucketing the values of arrays into labelled ranges
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType,
+    DurationSecondType, Int32Type,
+};
+use arrow_array::{Array, DictionaryArray, Int32Array, StringArray};
+use arrow_schema::{ArrowError, DataType, TimeUnit};
+
+/// Buckets each value of a `Duration` array by `boundaries`, returning a dictionary of
+/// human readable bucket labels, such as `<1ms`, `1-10ms` and `>=10ms`
+///
+/// `boundaries` are in the unit of `array` and must be strictly increasing. A value is
+/// in the bucket between the greatest boundary less than or equal to it, and the next
+/// boundary, with a bucket below the first and from the last boundary. The dictionary
+/// has a value for every bucket in order, including buckets without values, so that
+/// the keys of dictionaries with the same boundaries are comparable. Nulls remain null.
+///
+/// Boundaries are labelled in the largest of `d`, `h`, `min`, `s`, `ms`, `us` and `ns`
+/// that they are a whole number of.
+///
+/// ```
+/// # use arrow_array::DurationMicrosecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_cast::bucket_durations;
+/// let latencies = DurationMicrosecondArray::from(vec![Some(250), Some(4_000), None]);
+/// let buckets = bucket_durations(&latencies, &[1_000, 10_000]).unwrap();
+///
+/// let labels = buckets.values().as_string::<i32>();
+/// assert_eq!(labels.iter().flatten().collect::<Vec<_>>(), ["<1ms", "1-10ms", ">=10ms"]);
+/// assert_eq!(buckets.keys().iter().collect::<Vec<_>>(), [Some(0), Some(1), None]);
+/// ```
+pub fn bucket_durations(
+    array: &dyn Array,
+    boundaries: &[i64],
+) -> Result<DictionaryArray<Int32Type>, ArrowError> {
+    let (values, unit) = match array.data_type() {
+        DataType::Duration(TimeUnit::Second) => (
+            array.as_primitive::<DurationSecondType>().values(),
+            1_000_000_000,
+        ),
+        DataType::Duration(TimeUnit::Millisecond) => (
+            array.as_primitive::<DurationMillisecondType>().values(),
+            1_000_000,
+        ),
+        DataType::Duration(TimeUnit::Microsecond) => (
+            array.as_primitive::<DurationMicrosecondType>().values(),
+            1_000,
+        ),
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            (array.as_primitive::<DurationNanosecondType>().values(), 1)
+        }
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot bucket values of {d:?}, expected a duration"
+            )))
+        }
+    };
+    if boundaries.is_empty() || boundaries.len() >= i32::MAX as usize {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot bucket durations by {} boundaries",
+            boundaries.len()
+        )));
+    }
+    if boundaries.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Duration bucket boundaries must be strictly increasing, got {boundaries:?}"
+        )));
+    }
+
+    let labels: Vec<_> = boundaries
+        .iter()
+        .map(|b| duration_label(*b, unit))
+        .collect();
+    let (first, last) = (&labels[0], &labels[labels.len() - 1]);
+    let mut buckets = Vec::with_capacity(labels.len() + 1);
+    buckets.push(format!("<{}{}", first.0, first.1));
+    buckets.extend(labels.windows(2).map(|w| match w[0].1 == w[1].1 {
+        true => format!("{}-{}{}", w[0].0, w[1].0, w[1].1),
+        false => format!("{}{}-{}{}", w[0].0, w[0].1, w[1].0, w[1].1),
+    }));
+    buckets.push(format!(">={}{}", last.0, last.1));
+
+    let keys: Vec<i32> = values
+        .iter()
+        .map(|v| boundaries.partition_point(|b| b <= v) as i32)
+        .collect();
+    let keys = Int32Array::new(keys.into(), array.nulls().cloned());
+    DictionaryArray::try_new(keys, Arc::new(StringArray::from(buckets)))
+}
+
+/// Returns a duration of `value` units of `unit` nanoseconds as a number of the
+/// largest unit it is a whole number of, and the suffix of that unit
+fn duration_label(value: i64, unit: i64) -> (i128, &'static str) {
+    const UNITS: [(i128, &str); 7] = [
+        (86_400_000_000_000, "d"),
+        (3_600_000_000_000, "h"),
+        (60_000_000_000, "min"),
+        (1_000_000_000, "s"),
+        (1_000_000, "ms"),
+        (1_000, "us"),
+        (1, "ns"),
+    ];
+    let unit = unit as i128;
+    let nanos = value as i128 * unit;
+    // Zero is labelled in the unit of the array, rather than as `0d`
+    let (per, suffix) = UNITS
+        .iter()
+        .filter(|(per, _)| *per >= unit)
+        .find(|(per, _)| match nanos {
+            0 => *per == unit,
+            nanos => nanos % per == 0,
+        })
+        .unwrap();
+    (nanos / per, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{DurationMillisecondArray, DurationSecondArray, Int64Array};
+
+    #[test]
+    fn test_bucket_durations() {
+        let labels = |d: &DictionaryArray<Int32Type>| -> Vec<String> {
+            let values = d.values().as_string::<i32>();
+            values.iter().map(|v| v.unwrap().to_string()).collect()
+        };
+
+        let array = DurationMillisecondArray::from(vec![
+            Some(-5),
+            Some(0),
+            Some(500),
+            Some(1_000),
+            Some(90_000),
+            Some(7_200_000),
+            None,
+        ]);
+        let buckets = bucket_durations(&array, &[0, 1_000, 60_000, 3_600_000]).unwrap();
+        assert_eq!(
+            labels(&buckets),
+            ["<0ms", "0ms-1s", "1s-1min", "1min-1h", ">=1h"]
+        );
+        let keys: Vec<_> = buckets.keys().iter().collect();
+        assert_eq!(
+            keys,
+            [Some(0), Some(1), Some(1), Some(2), Some(3), Some(4), None]
+        );
+
+        let array = DurationSecondArray::from(vec![86_400, 172_799]);
+        let buckets = bucket_durations(&array, &[86_400, 172_800]).unwrap();
+        assert_eq!(labels(&buckets), ["<1d", "1-2d", ">=2d"]);
+        assert_eq!(buckets.keys().values(), &[1, 1]);
+
+        let err = bucket_durations(&array, &[10, 10]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Duration bucket boundaries must be strictly increasing, got [10, 10]"
+        );
+        let err = bucket_durations(&array, &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot bucket durations by 0 boundaries"
+        );
+        let err = bucket_durations(&Int64Array::from(vec![1]), &[1]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot bucket values of Int64, expected a duration"
+        );
+    }
+}
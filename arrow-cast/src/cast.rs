@@ -34,37 +34,921 @@
 //! assert_eq!(6.0, c.value(1));
 //! assert_eq!(7.0, c.value(2));
 //! ```
+//!
+//! Casting a valid array is not expected to panic: values that cannot be represented
+//! in the target type are returned as null, or as an error when [`CastOptions::safe`]
+//! is false, and casts between unsupported types return an error. Whether a cast is
+//! supported can be checked upfront with [`can_cast_types`]. This is tested with
+//! randomly generated arrays of a fixed set of types, and is not fuzzed, so a panic
+//! should be reported as a bug.
 
 use chrono::{NaiveTime, TimeZone, Timelike, Utc};
 use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 use std::sync::Arc;
 
-use crate::display::{array_value_to_string, ArrayFormatter, FormatOptions};
+pub use crate::batch::{
+    cast_record_batch, decode_columns_dictionary, encode_columns_dictionary,
+};
+pub use crate::bucket::bucket_durations;
+pub use crate::compatibility::{
+    cast_compatibility, is_infallible_cast, CastCompatibility,
+};
+pub use crate::decimal::{
+    rescale_decimal, rescale_decimal_with_stats, DecimalCast, DecimalOverflowStats,
+};
+pub use crate::offsets::promote_offsets_to_large;
+
+use crate::base64::{base64_string_to_binary, binary_to_base64_string, Base64Alphabet};
+use crate::display::{ArrayFormatter, FormatOptions};
+use crate::extension::parse_uuid;
 use crate::parse::{
+    expand_decimal_exponent, fractional_second_digits, parse_bool, parse_duration,
     parse_interval_day_time, parse_interval_month_day_nano, parse_interval_year_month,
-    string_to_datetime,
+    parse_radix_prefixed_integer, parse_relaxed_date, parse_relaxed_datetime,
+    split_time_offset, string_to_datetime_with_resolution, trim_whitespace,
+    BooleanParser, LocalTimeResolution, NumberFormat, Parser,
 };
+use crate::registry::{CastRegistry, CastType};
 use arrow_array::{
     builder::*, cast::*, temporal_conversions::*, timezone::Tz, types::*, *,
 };
-use arrow_buffer::{i256, ArrowNativeType, Buffer, MutableBuffer};
+use arrow_buffer::{
+    i256, ArrowNativeType, BooleanBuffer, Buffer, MutableBuffer, NullBuffer, OffsetBuffer,
+};
 use arrow_data::ArrayData;
 use arrow_schema::*;
+use arrow_select::concat::concat;
+use arrow_select::interleave::interleave;
+use arrow_select::nullif::nullif;
 use arrow_select::take::take;
+use half::f16;
 use num::cast::AsPrimitive;
-use num::{NumCast, ToPrimitive};
+use num::{Bounded, NumCast, ToPrimitive};
 
 /// CastOptions provides a way to override the default cast behaviors
+///
+/// New options may be added in future releases, construct it with [`CastOptions::new`]
+/// and the `with_` methods
+///
+/// With the `serde` feature, options can be serialized and deserialized, with fields
+/// missing when deserializing set to their default. [`Self::defaults`],
+/// [`Self::precision_loss`], [`Self::null_fill`], [`Self::timestamp_formats`] and
+/// [`Self::registry`], which hold arrays, counters and functions, cannot be serialized,
+/// and serializing options where any of them is set returns an error. The strings of
+/// [`Self::format_options`] are borrowed from the input, and so must not contain escape
+/// sequences
+///
+/// ```
+/// # use arrow_cast::CastOptions;
+/// let options = CastOptions::new().with_safe(false).with_sort_map_keys(true);
+/// assert!(!options.safe);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CastOptions {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[non_exhaustive]
+pub struct CastOptions<'a> {
     /// how to handle cast failures, either return NULL (safe=true) or return ERR (safe=false)
     pub safe: bool,
+    /// values for target fields that are absent from the source, see [`CastDefaults`]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_unsupported"
+        )
+    )]
+    pub defaults: Option<Arc<CastDefaults>>,
+    /// when casting strings to integers, accept `0x`, `0o` and `0b` prefixed literals
+    /// in addition to decimal, see [`parse_radix_prefixed_integer`]
+    pub parse_radix_prefix: bool,
+    /// the implementation whose edge-case behaviour casts should match, see [`CastCompat`]
+    pub compat: CastCompat,
+    /// how to cast Date64 values that are not at midnight to Date32, see [`Date64Alignment`]
+    pub date64_alignment: Date64Alignment,
+    /// the unit of numbers cast to and from `Interval(MonthDayNano)`
+    pub numeric_interval_unit: NumericIntervalUnit,
+    /// the expected number of distinct values when casting byte arrays to a dictionary,
+    /// used to size the dictionary, or `None` to estimate it from a sample of the input
+    pub dictionary_cardinality_hint: Option<usize>,
+    /// the timezone of strings without a UTC offset cast to timestamps with a timezone,
+    /// see [`TimestampWithoutOffset`]
+    pub timestamp_without_offset: TimestampWithoutOffset,
+    /// when casting a map to a map with sorted keys, sort the entries of each map by key,
+    /// otherwise only maps that are already sorted can be cast to a sorted map
+    pub sort_map_keys: bool,
+    /// when set, strings cast to dates and timestamps that are not in a supported format
+    /// are parsed as legacy dates with English month names, e.g. `02-JAN-99`, with two
+    /// digit years resolved relative to this pivot year, see [`parse_relaxed_date`]
+    pub two_digit_year_pivot: Option<i32>,
+    /// when set, lists cast to strings are formatted as their elements joined by this
+    /// delimiter, e.g. `a|b|c`, instead of `[a, b, c]`, with null elements formatted as
+    /// the null string of [`Self::format_options`], empty by default
+    pub list_delimiter: Option<Arc<str>>,
+    /// when casting strings to timestamps, treat strings with more fractional second
+    /// digits than the unit of the timestamp can represent as a failed cast, instead of
+    /// truncating them
+    pub strict_fractional_seconds: bool,
+    /// when casting between temporal types and integers, reinterpret the bits of the
+    /// values where they differ from the semantic conversion, currently
+    /// `Interval(DayTime)` to and from `Int64`, which are otherwise cast as a total
+    /// number of milliseconds, integers to `Time32` and `Time64`, which are otherwise
+    /// validated to be within a day, and `Decimal128` to and from `Int64`, which
+    /// otherwise rescale the values, see [`cast`]
+    pub reinterpret: bool,
+    /// when set, binary arrays cast to strings are base64 encoded with this alphabet,
+    /// and strings cast to binary arrays are base64 decoded, instead of casting between
+    /// the bytes of valid UTF-8 strings, see [`crate::base64`]
+    pub base64: Option<Base64Alphabet>,
+    /// when set, strings cast to floats and decimals are parsed with the decimal and
+    /// grouping separators of this format, e.g. `1.234,56`, optionally accepting
+    /// currency symbols and negative numbers in parentheses, see [`NumberFormat`]
+    pub number_format: Option<NumberFormat>,
+    /// how temporal, decimal and list values are formatted when cast to strings, see
+    /// [`FormatOptions`]. Null values are cast to null strings, regardless of the null
+    /// string of the options, which only applies to null elements of lists
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub format_options: FormatOptions<'a>,
+    /// when set, counts the values that lose precision without failing the cast, e.g.
+    /// when reducing the unit of timestamps, see [`PrecisionLoss`]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_unsupported"
+        )
+    )]
+    pub precision_loss: Option<Arc<PrecisionLoss>>,
+    /// when casting decimals to integers, clamp values that overflow the integer type to
+    /// its minimum or maximum, as SQL engines do, instead of treating them as a failed
+    /// cast, regardless of [`Self::safe`]
+    pub saturate: bool,
+    /// how the UTC offset of strings cast to times of day, e.g. `"10:00:00+02:00"`, is
+    /// handled, see [`TimeWithOffset`]
+    pub time_with_offset: TimeWithOffset,
+    /// how decimal values are rounded when a cast to a decimal reduces their scale, from
+    /// decimals, floats and strings, see [`RoundingMode`]
+    pub rounding: RoundingMode,
+    /// how timestamps with a timezone are cast to timestamps with a different timezone,
+    /// see [`TimezoneConversion`]
+    pub timezone_conversion: TimezoneConversion,
+    /// when set, null rows of the result of a cast, including values that fail to cast
+    /// if [`Self::safe`] is true, are replaced with this value, see [`NullFill`]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_unsupported"
+        )
+    )]
+    pub null_fill: Option<NullFill>,
+    /// when not empty, strings cast to timestamps are parsed with the first of these
+    /// [strftime](chrono::format::strftime) patterns that matches them, e.g.
+    /// `%m/%d/%Y %H:%M`, instead of as RFC 3339 style strings. Strings matching none of
+    /// the patterns are a failed cast
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            skip_serializing_if = "is_empty",
+            serialize_with = "serialize_unsupported"
+        )
+    )]
+    pub timestamp_formats: &'a [&'a str],
+    /// when casting dictionaries to other types, return an error if a key references a
+    /// null value, regardless of [`Self::safe`], instead of casting it to null, as such
+    /// keys often indicate corrupt dictionary encoding. Null keys are cast to null
+    pub strict_dictionary_values: bool,
+    /// how strings cast to timestamps with a timezone, that are local datetimes which are
+    /// ambiguous or do not exist in the timezone as a result of daylight saving time
+    /// transitions, are resolved, see [`LocalTimeResolution`]. Unresolved datetimes are a
+    /// failed cast
+    pub local_time_resolution: LocalTimeResolution,
+    /// casts registered by users, used for casts that are not otherwise supported, see
+    /// [`CastRegistry`]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_unsupported"
+        )
+    )]
+    pub registry: Option<Arc<CastRegistry>>,
+    /// when set, strings cast to booleans are parsed with this parser, e.g. to accept
+    /// additional tokens, or only case sensitive tokens, instead of the tokens of
+    /// [`parse_bool`], see [`BooleanParser`]
+    pub boolean_parser: Option<BooleanParser>,
+}
+
+#[cfg(feature = "serde")]
+fn is_empty(formats: &&[&str]) -> bool {
+    formats.is_empty()
+}
+
+/// Fails serialization of [`CastOptions`] fields that cannot be serialized, instead of
+/// silently dropping them
+#[cfg(feature = "serde")]
+fn serialize_unsupported<T, S: serde::Serializer>(
+    _: &T,
+    _: S,
+) -> Result<S::Ok, S::Error> {
+    Err(serde::ser::Error::custom(
+        "CastOptions with defaults, precision_loss, null_fill, timestamp_formats or registry set cannot be serialized",
+    ))
+}
+
+pub const DEFAULT_CAST_OPTIONS: CastOptions<'static> = CastOptions {
+    safe: true,
+    defaults: None,
+    parse_radix_prefix: false,
+    compat: CastCompat::Rust,
+    date64_alignment: Date64Alignment::Truncate,
+    numeric_interval_unit: NumericIntervalUnit::Seconds,
+    dictionary_cardinality_hint: None,
+    timestamp_without_offset: TimestampWithoutOffset::TargetTimezone,
+    sort_map_keys: false,
+    two_digit_year_pivot: None,
+    list_delimiter: None,
+    strict_fractional_seconds: false,
+    reinterpret: false,
+    base64: None,
+    number_format: None,
+    format_options: FormatOptions::new(),
+    precision_loss: None,
+    saturate: false,
+    time_with_offset: TimeWithOffset::Discard,
+    rounding: RoundingMode::HalfUp,
+    timezone_conversion: TimezoneConversion::Instant,
+    null_fill: None,
+    timestamp_formats: &[],
+    strict_dictionary_values: false,
+    local_time_resolution: LocalTimeResolution::Error,
+    registry: None,
+    boolean_parser: None,
+};
+
+impl<'a> CastOptions<'a> {
+    /// Creates a new [`CastOptions`] with the default cast behaviors,
+    /// equivalent to [`DEFAULT_CAST_OPTIONS`]
+    pub const fn new() -> Self {
+        DEFAULT_CAST_OPTIONS
+    }
+
+    /// Sets how to handle cast failures, see [`Self::safe`]
+    pub fn with_safe(mut self, safe: bool) -> Self {
+        self.safe = safe;
+        self
+    }
+
+    /// Sets the values for target fields that are absent from the source, see [`Self::defaults`]
+    pub fn with_defaults(mut self, defaults: Option<Arc<CastDefaults>>) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    /// Sets whether to accept radix prefixed integer literals, see [`Self::parse_radix_prefix`]
+    pub fn with_parse_radix_prefix(mut self, parse_radix_prefix: bool) -> Self {
+        self.parse_radix_prefix = parse_radix_prefix;
+        self
+    }
+
+    /// Sets the implementation whose edge-case behaviour casts should match, see [`Self::compat`]
+    pub fn with_compat(mut self, compat: CastCompat) -> Self {
+        self.compat = compat;
+        self
+    }
+
+    /// Sets how to cast Date64 values that are not at midnight to Date32, see [`Self::date64_alignment`]
+    pub fn with_date64_alignment(mut self, date64_alignment: Date64Alignment) -> Self {
+        self.date64_alignment = date64_alignment;
+        self
+    }
+
+    /// Sets the unit of numbers cast to and from `Interval(MonthDayNano)`, see [`Self::numeric_interval_unit`]
+    pub fn with_numeric_interval_unit(
+        mut self,
+        numeric_interval_unit: NumericIntervalUnit,
+    ) -> Self {
+        self.numeric_interval_unit = numeric_interval_unit;
+        self
+    }
+
+    /// Sets the expected number of distinct values when casting to a dictionary, see [`Self::dictionary_cardinality_hint`]
+    pub fn with_dictionary_cardinality_hint(
+        mut self,
+        dictionary_cardinality_hint: Option<usize>,
+    ) -> Self {
+        self.dictionary_cardinality_hint = dictionary_cardinality_hint;
+        self
+    }
+
+    /// Sets the timezone of strings without a UTC offset, see [`Self::timestamp_without_offset`]
+    pub fn with_timestamp_without_offset(
+        mut self,
+        timestamp_without_offset: TimestampWithoutOffset,
+    ) -> Self {
+        self.timestamp_without_offset = timestamp_without_offset;
+        self
+    }
+
+    /// Sets whether to sort the entries of maps cast to a map with sorted keys, see [`Self::sort_map_keys`]
+    pub fn with_sort_map_keys(mut self, sort_map_keys: bool) -> Self {
+        self.sort_map_keys = sort_map_keys;
+        self
+    }
+
+    /// Sets the pivot year for legacy dates with two digit years, see [`Self::two_digit_year_pivot`]
+    pub fn with_two_digit_year_pivot(
+        mut self,
+        two_digit_year_pivot: Option<i32>,
+    ) -> Self {
+        self.two_digit_year_pivot = two_digit_year_pivot;
+        self
+    }
+
+    /// Sets the delimiter of lists cast to strings, see [`Self::list_delimiter`]
+    pub fn with_list_delimiter(mut self, list_delimiter: Option<Arc<str>>) -> Self {
+        self.list_delimiter = list_delimiter;
+        self
+    }
+
+    /// Sets whether excess fractional second digits fail a cast, see [`Self::strict_fractional_seconds`]
+    pub fn with_strict_fractional_seconds(
+        mut self,
+        strict_fractional_seconds: bool,
+    ) -> Self {
+        self.strict_fractional_seconds = strict_fractional_seconds;
+        self
+    }
+
+    /// Sets whether temporal values are reinterpreted as integers, and vice versa, see [`Self::reinterpret`]
+    pub fn with_reinterpret(mut self, reinterpret: bool) -> Self {
+        self.reinterpret = reinterpret;
+        self
+    }
+
+    /// Sets the alphabet of binary arrays cast to and from base64 encoded strings, see
+    /// [`Self::base64`]
+    pub fn with_base64(mut self, base64: Option<Base64Alphabet>) -> Self {
+        self.base64 = base64;
+        self
+    }
+
+    /// Sets the separators of strings cast to floats and decimals, see
+    /// [`Self::number_format`]
+    pub fn with_number_format(mut self, number_format: Option<NumberFormat>) -> Self {
+        self.number_format = number_format;
+        self
+    }
+
+    /// Sets how values are formatted when cast to strings, see [`Self::format_options`]
+    pub fn with_format_options(mut self, format_options: FormatOptions<'a>) -> Self {
+        self.format_options = format_options;
+        self
+    }
+
+    /// Sets the counter of values that lose precision, see [`Self::precision_loss`]
+    pub fn with_precision_loss(
+        mut self,
+        precision_loss: Option<Arc<PrecisionLoss>>,
+    ) -> Self {
+        self.precision_loss = precision_loss;
+        self
+    }
+
+    /// Sets whether decimals that overflow integers are clamped, see [`Self::saturate`]
+    pub fn with_saturate(mut self, saturate: bool) -> Self {
+        self.saturate = saturate;
+        self
+    }
+
+    /// Sets how the UTC offset of times of day is handled, see
+    /// [`Self::time_with_offset`]
+    pub fn with_time_with_offset(mut self, time_with_offset: TimeWithOffset) -> Self {
+        self.time_with_offset = time_with_offset;
+        self
+    }
+
+    /// Sets how decimal values are rounded, see [`Self::rounding`]
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets how timestamps are cast to a different timezone, see
+    /// [`Self::timezone_conversion`]
+    pub fn with_timezone_conversion(
+        mut self,
+        timezone_conversion: TimezoneConversion,
+    ) -> Self {
+        self.timezone_conversion = timezone_conversion;
+        self
+    }
+
+    /// Sets the patterns strings cast to timestamps are parsed with, see
+    /// [`Self::timestamp_formats`]
+    pub fn with_timestamp_formats(mut self, timestamp_formats: &'a [&'a str]) -> Self {
+        self.timestamp_formats = timestamp_formats;
+        self
+    }
+
+    /// Sets whether keys of dictionaries referencing null values fail the cast, see
+    /// [`Self::strict_dictionary_values`]
+    pub fn with_strict_dictionary_values(
+        mut self,
+        strict_dictionary_values: bool,
+    ) -> Self {
+        self.strict_dictionary_values = strict_dictionary_values;
+        self
+    }
+
+    /// Sets how ambiguous and non-existent local datetimes are resolved, see
+    /// [`Self::local_time_resolution`]
+    pub fn with_local_time_resolution(
+        mut self,
+        local_time_resolution: LocalTimeResolution,
+    ) -> Self {
+        self.local_time_resolution = local_time_resolution;
+        self
+    }
+
+    /// Sets the casts registered by users, see [`Self::registry`]
+    pub fn with_registry(mut self, registry: Option<Arc<CastRegistry>>) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Sets the parser of strings cast to booleans, see [`Self::boolean_parser`]
+    pub fn with_boolean_parser(mut self, boolean_parser: Option<BooleanParser>) -> Self {
+        self.boolean_parser = boolean_parser;
+        self
+    }
+
+    /// Sets the value null rows are replaced with, see [`Self::null_fill`]
+    pub fn with_null_fill(mut self, null_fill: Option<NullFill>) -> Self {
+        self.null_fill = null_fill;
+        self
+    }
+}
+
+impl Default for CastOptions<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Specifies how Date64 values that are not a multiple of a day are cast to Date32
+///
+/// The Arrow specification requires Date64 values to be evenly divisible by
+/// 86400000, however, some producers write values with a time of day
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Date64Alignment {
+    /// Round towards zero, to the start of the day for values after the epoch, and the
+    /// start of the next day for values before it, e.g. `-1` is day `0`
+    #[default]
+    Truncate,
+    /// Round down to the start of the day, e.g. `-1` is day `-1`
+    Floor,
+    /// Treat such values as a failed cast, returning null if [`CastOptions::safe`]
+    /// is true, and an error otherwise
+    Checked,
+}
+
+/// Selects the implementation whose semantics [`cast_with_options`] follows for
+/// edge cases where the Arrow implementations diverge
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CastCompat {
+    /// The native behaviour of this crate
+    #[default]
+    Rust,
+    /// Match the `cast` compute function of the Arrow C++ implementation
+    ///
+    /// The following divergences are resolved:
+    ///
+    /// * Boolean to string produces `"true"` and `"false"`, instead of `"1"` and `"0"`
+    /// * Float to integer casts fail for values with a fractional part, instead of
+    ///   truncating them towards zero
+    /// * Integer to float casts fail for values that cannot be represented exactly
+    /// * Numeric values that overflow the target type are an error, even when
+    ///   [`CastOptions::safe`] is true, as Arrow C++ has no null on overflow mode
+    ///
+    /// The unchecked (`safe=false`) mode of Arrow C++, that wraps overflowing
+    /// values, is not replicated, such values are always an error
+    ArrowCpp,
+}
+
+/// Specifies the timezone of strings without a UTC offset, e.g. `"2023-01-02 10:00:00"`,
+/// when cast to a timestamp with a timezone
+///
+/// Strings with an offset, e.g. `"2023-01-02 10:00:00+05:30"`, always keep the instant
+/// they represent. Timestamps without a timezone store the UTC datetime of strings with
+/// an offset, and the datetime of strings without one, regardless of this option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimestampWithoutOffset {
+    /// Interpret strings without an offset as a local datetime in the timezone of the
+    /// target type
+    #[default]
+    TargetTimezone,
+    /// Interpret strings without an offset as UTC
+    Utc,
+}
+
+/// Specifies how the UTC offset of strings cast to times of day is handled, e.g.
+/// `"10:00:00+02:00"`
+///
+/// The offset is `Z`, or a sign followed by hours and optionally minutes, see
+/// [`split_time_offset`]. Strings without an offset are cast as is, regardless of
+/// this option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeWithOffset {
+    /// Ignore the offset, keeping the local time, e.g. `"10:00:00+02:00"` is `10:00:00`
+    #[default]
+    Discard,
+    /// Shift the time to UTC, wrapping around midnight, e.g. `"01:00:00+02:00"` is
+    /// `23:00:00`
+    Utc,
+}
+
+/// Specifies how timestamps with a timezone are cast to timestamps with a different
+/// timezone, e.g. `Timestamp(Second, Some("+02:00"))` to
+/// `Timestamp(Second, Some("America/New_York"))`
+///
+/// Casts from or to timestamps without a timezone are unaffected by this option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimezoneConversion {
+    /// Keep the instant in time, only changing the timezone it is displayed in, e.g.
+    /// `2023-01-01T10:00:00+02:00` is `2023-01-01T03:00:00-05:00`
+    #[default]
+    Instant,
+    /// Keep the wall clock time, changing the instant in time, e.g.
+    /// `2023-01-01T10:00:00+02:00` is `2023-01-01T10:00:00-05:00`
+    ///
+    /// Ambiguous wall clock times resolve to the earliest instant, and wall clock times
+    /// that do not exist in the target timezone, e.g. in a daylight saving time gap, are
+    /// a failed cast
+    WallClock,
+}
+
+/// Specifies how values are rounded when cast to a decimal with fewer fractional
+/// digits, e.g. `Decimal128(10, 3)` to `Decimal128(10, 1)`, `Float64` to a decimal, or
+/// a string with more fractional digits than the scale of the decimal
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingMode {
+    /// Round to the nearest value, and ties away from zero, e.g. `2.5` to `3` and
+    /// `-2.5` to `-3`
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, and ties to the nearest even value, also known as
+    /// bankers rounding, e.g. `2.5` to `2` and `3.5` to `4`
+    HalfEven,
+    /// Round towards zero, e.g. `2.7` to `2` and `-2.7` to `-2`
+    Truncate,
+    /// Round towards positive infinity, e.g. `2.1` to `3` and `-2.7` to `-2`
+    Ceil,
+    /// Round towards negative infinity, e.g. `2.7` to `2` and `-2.1` to `-3`
+    Floor,
+}
+
+impl RoundingMode {
+    /// Rounds the quotient `d` of a division by `div`, a positive even number, given
+    /// the remainder `r`, which has the sign of the dividend
+    pub(crate) fn round<N: ArrowNativeTypeOp>(self, d: N, r: N, div: N) -> N {
+        if r == N::ZERO {
+            return d;
+        }
+        let half = div.div_wrapping(N::from_usize(2).unwrap());
+        let (r, step) = match r > N::ZERO {
+            true => (r, N::ONE),
+            false => (r.neg_wrapping(), N::ONE.neg_wrapping()),
+        };
+        let odd = || d.mod_wrapping(N::from_usize(2).unwrap()) != N::ZERO;
+        let away_from_zero = match self {
+            Self::HalfUp => r >= half,
+            Self::HalfEven => r > half || (r == half && odd()),
+            Self::Truncate => false,
+            Self::Ceil => step == N::ONE,
+            Self::Floor => step != N::ONE,
+        };
+        match away_from_zero {
+            true => d.add_wrapping(step),
+            false => d,
+        }
+    }
+
+    /// Rounds `v` to an integer
+    fn round_f64(self, v: f64) -> f64 {
+        match self {
+            Self::HalfUp => v.round(),
+            Self::HalfEven => {
+                let rounded = v.round();
+                match (rounded - v).abs() == 0.5 && rounded % 2.0 != 0.0 {
+                    true => rounded - v.signum(),
+                    false => rounded,
+                }
+            }
+            Self::Truncate => v.trunc(),
+            Self::Ceil => v.ceil(),
+            Self::Floor => v.floor(),
+        }
+    }
+}
+
+/// The unit of numbers cast to and from `Interval(MonthDayNano)`
+///
+/// Values are converted to a number of nanoseconds, with zero months and days, except
+/// for [`Self::Months`]. Intervals with non-zero parts other than that, i.e. the months
+/// or days, are cast to null, or an error if [`CastOptions::safe`] is false.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumericIntervalUnit {
+    /// Values are a number of nanoseconds, e.g. the ticks of a duration
+    Nanoseconds,
+    /// Values are a number of seconds
+    #[default]
+    Seconds,
+    /// Values are a number of 24 hour days
+    Days,
+    /// Values are a number of months, with zero days and nanoseconds
+    Months,
+}
+
+impl NumericIntervalUnit {
+    /// The number of nanoseconds, or for [`Self::Months`] months, in the unit
+    fn factor(&self) -> i64 {
+        match self {
+            Self::Nanoseconds | Self::Months => 1,
+            Self::Seconds => NANOSECONDS,
+            Self::Days => SECONDS_IN_DAY * NANOSECONDS,
+        }
+    }
+
+    /// Returns the interval of `count` nanoseconds, or months
+    fn make_interval(&self, count: i64) -> Option<i128> {
+        match self {
+            Self::Months => count
+                .try_into()
+                .ok()
+                .map(|months| IntervalMonthDayNanoType::make_value(months, 0, 0)),
+            _ => Some(IntervalMonthDayNanoType::make_value(0, 0, count)),
+        }
+    }
+
+    /// Returns the number of nanoseconds, or months, of `interval`, or `None` if its
+    /// other parts are not zero
+    fn interval_count(&self, interval: i128) -> Option<i64> {
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(interval);
+        match self {
+            Self::Months => (days == 0 && nanos == 0).then_some(months as i64),
+            _ => (months == 0 && days == 0).then_some(nanos),
+        }
+    }
+}
+
+/// A value that the null rows of the result of a cast are replaced with, see
+/// [`CastOptions::null_fill`]
+///
+/// The value is a single element array, that is cast to the type of the result, and
+/// is only applied to the rows of the result, not to the elements of nested types.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Array, Int32Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_cast::{cast_with_options, CastOptions, NullFill};
+/// # use arrow_schema::DataType;
+/// let array = StringArray::from(vec![Some("1"), None, Some("x")]);
+/// let fill = NullFill::try_new(Arc::new(Int32Array::from(vec![0]))).unwrap();
+/// let options = CastOptions::new().with_null_fill(Some(fill));
+///
+/// let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+/// assert_eq!(b.null_count(), 0);
+/// assert_eq!(b.as_primitive::<Int64Type>().values(), &[1, 0, 0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NullFill {
+    value: ArrayRef,
+}
+
+impl PartialEq for NullFill {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.as_ref() == other.value.as_ref()
+    }
+}
+
+// Arrays are compared by value, and so equality is reflexive
+impl Eq for NullFill {}
+
+impl NullFill {
+    /// Create a [`NullFill`] of `value`
+    ///
+    /// Returns an error if `value` does not contain exactly one element, or it is null
+    pub fn try_new(value: ArrayRef) -> Result<Self, ArrowError> {
+        if value.len() != 1 || value.is_null(0) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Null fill value must contain exactly one non-null element, got {value:?}"
+            )));
+        }
+        Ok(Self { value })
+    }
+
+    /// Returns the single element array of the value
+    pub fn value(&self) -> &ArrayRef {
+        &self.value
+    }
+}
+
+/// Default values for target fields that are absent from the source of a cast
+///
+/// Values are keyed by the path of the field within the target, e.g. `["a", "b"]`
+/// identifies the child `b` of the struct column `a`. Each value is a single element
+/// array, that is cast to the type of the target field and repeated for every row.
+///
+/// Fields without a default are filled with nulls.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_cast::{cast_record_batch, CastDefaults, CastOptions};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// let batch = RecordBatch::try_from_iter([(
+///     "a",
+///     Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef,
+/// )])
+/// .unwrap();
+///
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("a", DataType::Utf8, true),
+///     Field::new("b", DataType::Int64, false),
+/// ]));
+///
+/// let defaults = CastDefaults::new()
+///     .with_default(&["b"], Arc::new(Int32Array::from(vec![42])))
+///     .unwrap();
+/// let options = CastOptions::new().with_defaults(Some(Arc::new(defaults)));
+///
+/// let batch = cast_record_batch(&batch, &schema, &options).unwrap();
+/// assert_eq!(batch.column(1).as_primitive::<Int64Type>().values(), &[42, 42]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CastDefaults {
+    values: HashMap<Vec<String>, ArrayRef>,
+}
+
+// Arrays are compared by value, and so equality is reflexive
+impl Eq for CastDefaults {}
+
+impl CastDefaults {
+    /// Create an empty [`CastDefaults`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `value` as the default for the field at `path`
+    ///
+    /// Returns an error if `path` is empty or `value` does not contain exactly one element
+    pub fn with_default(
+        mut self,
+        path: &[&str],
+        value: ArrayRef,
+    ) -> Result<Self, ArrowError> {
+        if path.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Default value path must not be empty".to_string(),
+            ));
+        }
+        if value.len() != 1 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Default value for {} must contain exactly one element, got {}",
+                path.join("."),
+                value.len()
+            )));
+        }
+        let path = path.iter().map(|x| x.to_string()).collect();
+        self.values.insert(path, value);
+        Ok(self)
+    }
+
+    /// Returns the default registered for the field at `path`, if any
+    pub fn get(&self, path: &[&str]) -> Option<&ArrayRef> {
+        let path: Vec<_> = path.iter().map(|x| x.to_string()).collect();
+        self.values.get(&path)
+    }
+
+    /// Returns the defaults nested within the field `name`, with paths relative to it
+    pub fn child(&self, name: &str) -> Self {
+        let values = self
+            .values
+            .iter()
+            .filter(|(k, _)| k.len() > 1 && k[0] == name)
+            .map(|(k, v)| (k[1..].to_vec(), v.clone()))
+            .collect();
+        Self { values }
+    }
+
+    /// Returns true if no defaults have been registered
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Counts the values that lose information in casts that succeed, see
+/// [`CastOptions::precision_loss`]
+///
+/// The following casts are counted, regardless of [`CastOptions::safe`]:
+///
+/// * Timestamps cast to a coarser unit, that are not a multiple of it
+/// * Decimals cast to a smaller scale, that are rounded
+/// * `Float64` cast to `Float32`, that are rounded or overflow to infinity
+///
+/// The counter is shared by all the casts of the options, including of nested values,
+/// and is not reset by a cast. A cast that returns an error may have counted some of
+/// its values before failing
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::TimestampMillisecondArray;
+/// # use arrow_cast::{cast_with_options, CastOptions, PrecisionLoss};
+/// # use arrow_schema::{DataType, TimeUnit};
+/// let loss = Arc::new(PrecisionLoss::new());
+/// let options = CastOptions::new().with_precision_loss(Some(loss.clone()));
+///
+/// let array = TimestampMillisecondArray::from(vec![Some(1000), Some(1500), None]);
+/// let to_type = DataType::Timestamp(TimeUnit::Second, None);
+/// cast_with_options(&array, &to_type, &options).unwrap();
+/// assert_eq!(loss.count(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct PrecisionLoss {
+    count: AtomicUsize,
+}
+
+// Counters are compared by identity, as options are equal if they report to the
+// same counter
+impl PartialEq for PrecisionLoss {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for PrecisionLoss {}
+
+impl PrecisionLoss {
+    /// Create a counter of zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of values that have lost precision
+    pub fn count(&self) -> usize {
+        self.count.load(AtomicOrdering::Relaxed)
+    }
+
+    /// Returns the number of values that have lost precision, and resets it to zero
+    pub fn take(&self) -> usize {
+        self.count.swap(0, AtomicOrdering::Relaxed)
+    }
+
+    fn add(&self, count: usize) {
+        if count > 0 {
+            self.count.fetch_add(count, AtomicOrdering::Relaxed);
+        }
+    }
 }
 
-pub const DEFAULT_CAST_OPTIONS: CastOptions = CastOptions { safe: true };
+/// Adds the valid values of `array` for which `lossy` returns true to the
+/// [`CastOptions::precision_loss`] counter, if any
+pub(crate) fn count_precision_loss<T, F>(
+    array: &PrimitiveArray<T>,
+    cast_options: &CastOptions,
+    lossy: F,
+) where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> bool,
+{
+    if let Some(loss) = &cast_options.precision_loss {
+        loss.add(array.iter().flatten().filter(|v| lossy(*v)).count());
+    }
+}
 
 /// Return true if a value of type `from_type` can be cast into a
-/// value of `to_type`. Note that such as cast may be lossy.
+/// value of `to_type`. Note that such as cast may be lossy, see [`cast_compatibility`]
+/// to also determine whether it is, or why a cast is not supported.
 ///
 /// If this function returns true to stay consistent with the `cast` kernel below.
 pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
@@ -83,6 +967,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | UInt16
             | Int32
             | UInt32
+            | Float16
             | Float32
             | Date32
             | Time32(_)
@@ -110,8 +995,33 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Dictionary(_, from_value_type), Dictionary(_, to_value_type)) => {
             can_cast_types(from_value_type, to_value_type)
         }
+        (Dictionary(_, value_type), RunEndEncoded(run_ends, values)) => {
+            matches!(run_ends.data_type(), Int16 | Int32 | Int64)
+                && can_cast_types(value_type, values.data_type())
+        }
         (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
+        (RunEndEncoded(_, from_values), RunEndEncoded(run_ends, to_values)) => {
+            matches!(run_ends.data_type(), Int16 | Int32 | Int64)
+                && can_cast_types(from_values.data_type(), to_values.data_type())
+        }
+        (RunEndEncoded(_, values), _) => can_cast_types(values.data_type(), to_type),
+        (_, RunEndEncoded(run_ends, values)) => {
+            matches!(run_ends.data_type(), Int16 | Int32 | Int64)
+                && can_cast_types(from_type, values.data_type())
+        }
+        (Union(from_fields, from_mode), Union(to_fields, to_mode)) => {
+            from_mode == to_mode
+                && from_fields.len() == to_fields.len()
+                && from_fields.iter().zip(to_fields.iter()).all(
+                    |((from_id, from), (to_id, to))| {
+                        from_id == to_id && can_cast_types(from.data_type(), to.data_type())
+                    },
+                )
+        }
+        (Union(fields, _), _) => fields
+            .iter()
+            .all(|(_, field)| can_cast_types(field.data_type(), to_type)),
         (LargeList(list_from), LargeList(list_to)) => {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
@@ -124,8 +1034,47 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (LargeList(list_from), List(list_to)) => {
             list_from.data_type() == list_to.data_type()
         }
+        (FixedSizeList(list_from, _), List(list_to) | LargeList(list_to))
+        | (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
+        (FixedSizeList(list_from, size_from), FixedSizeList(list_to, size_to)) => {
+            size_from == size_to
+                && can_cast_types(list_from.data_type(), list_to.data_type())
+        }
         (List(list_from) | LargeList(list_from), Utf8 | LargeUtf8) => can_cast_types(list_from.data_type(), to_type),
+        (Map(entries_from, _), Map(entries_to, _)) => {
+            match (map_entry_fields(entries_from), map_entry_fields(entries_to)) {
+                (Ok((from_key, from_value)), Ok((to_key, to_value))) => {
+                    can_cast_types(from_key.data_type(), to_key.data_type())
+                        && can_cast_types(from_value.data_type(), to_value.data_type())
+                }
+                _ => false,
+            }
+        }
+        (List(list_from), Map(entries_to, _)) => match list_from.data_type() {
+            Struct(fields) if fields.len() == 2 => match map_entry_fields(entries_to) {
+                Ok((to_key, to_value)) => {
+                    can_cast_types(fields[0].data_type(), to_key.data_type())
+                        && can_cast_types(fields[1].data_type(), to_value.data_type())
+                }
+                _ => false,
+            },
+            _ => false,
+        },
+        (Struct(fields), Map(entries_to, _)) => {
+            match (struct_map_fields(fields), map_entry_fields(entries_to)) {
+                (Some((from_key, from_value)), Ok((to_key, to_value))) => {
+                    can_cast_types(from_key.data_type(), to_key.data_type())
+                        && can_cast_types(from_value.data_type(), to_value.data_type())
+                }
+                _ => false,
+            }
+        }
         (List(_), _) => false,
+        (Utf8 | LargeUtf8, FixedSizeList(list_to, _)) => {
+            matches!(list_to.data_type(), Float32 | Float64)
+        }
         (_, List(list_to)) => can_cast_types(from_type, list_to.data_type()),
         (_, LargeList(list_to)) => can_cast_types(from_type, list_to.data_type()),
         // cast one decimal type to another decimal type
@@ -137,24 +1086,49 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (UInt8 | UInt16 | UInt32 | UInt64, Decimal128(_, _)) |
         (UInt8 | UInt16 | UInt32 | UInt64, Decimal256(_, _)) |
         // signed numeric to decimal
-        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal128(_, _)) |
-        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal256(_, _)) |
+        (Null | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64, Decimal128(_, _)) |
+        (Null | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64, Decimal256(_, _)) |
         // decimal to unsigned numeric
         (Decimal128(_, _), UInt8 | UInt16 | UInt32 | UInt64) |
         (Decimal256(_, _), UInt8 | UInt16 | UInt32 | UInt64) |
         // decimal to signed numeric
-        (Decimal128(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64) |
-        (Decimal256(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64) => true,
+        (Decimal128(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64) |
+        (Decimal256(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64) => true,
+        // decimal to and from boolean
+        (Decimal128(_, _) | Decimal256(_, _), Boolean) => true,
+        (Boolean, Decimal128(_, _) | Decimal256(_, _)) => true,
         // decimal to Utf8
         (Decimal128(_, _), Utf8 | LargeUtf8) => true,
         (Decimal256(_, _), Utf8 | LargeUtf8) => true,
         // Utf8 to decimal
         (Utf8 | LargeUtf8, Decimal128(_, _)) => true,
         (Utf8 | LargeUtf8, Decimal256(_, _)) => true,
+        // time to decimal seconds
+        (Time32(_) | Time64(_), Decimal128(_, _) | Decimal256(_, _)) => true,
+        // numbers to and from interval
+        (
+            Int64 | Float32 | Float64 | Decimal128(_, _) | Decimal256(_, _),
+            Interval(IntervalUnit::MonthDayNano),
+        ) => true,
+        (Interval(IntervalUnit::MonthDayNano), Int64 | Float64) => true,
         (Decimal128(_, _), _) => false,
         (_, Decimal128(_, _)) => false,
         (Decimal256(_, _), _) => false,
         (_, Decimal256(_, _)) => false,
+        (Timestamp(_, _), Struct(fields)) => {
+            !fields.is_empty()
+                && fields.iter().all(|f| is_date_time_component(f.data_type()))
+        }
+        (Struct(from_fields), Struct(to_fields)) => {
+            !to_fields.is_empty()
+                && struct_field_sources(from_fields, to_fields)
+                    .iter()
+                    .zip(to_fields.iter())
+                    .all(|(source, to)| match source {
+                        Some(i) => can_cast_types(from_fields[*i].data_type(), to.data_type()),
+                        None => true,
+                    })
+        }
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (_, Boolean) => DataType::is_numeric(from_type) || from_type == &Utf8 || from_type == &LargeUtf8,
@@ -162,10 +1136,11 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 
         (Binary, LargeBinary | Utf8 | LargeUtf8 | FixedSizeBinary(_)) => true,
         (LargeBinary, Binary | Utf8 | LargeUtf8 | FixedSizeBinary(_)) => true,
-        (FixedSizeBinary(_), Binary | LargeBinary) => true,
+        (FixedSizeBinary(_), Binary | LargeBinary | Utf8 | LargeUtf8) => true,
         (Utf8,
             Binary
             | LargeBinary
+            | FixedSizeBinary(_)
             | LargeUtf8
             | Date32
             | Date64
@@ -178,11 +1153,13 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Timestamp(TimeUnit::Microsecond, _)
             | Timestamp(TimeUnit::Nanosecond, _)
             | Interval(_)
+            | Duration(_)
         ) => true,
-        (Utf8, _) => to_type.is_numeric() && to_type != &Float16,
+        (Utf8, _) => to_type.is_numeric(),
         (LargeUtf8,
             Binary
             | LargeBinary
+            | FixedSizeBinary(_)
             | Utf8
             | Date32
             | Date64
@@ -195,59 +1172,65 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Timestamp(TimeUnit::Microsecond, _)
             | Timestamp(TimeUnit::Nanosecond, _)
             | Interval(_)
+            | Duration(_)
         ) => true,
-        (LargeUtf8, _) => to_type.is_numeric() && to_type != &Float16,
+        (LargeUtf8, _) => to_type.is_numeric(),
         (_, Utf8 | LargeUtf8) => from_type.is_primitive(),
 
         // start numeric casts
         (
             UInt8,
-            UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             UInt16,
-            UInt8 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             UInt32,
-            UInt8 | UInt16 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             UInt64,
-            UInt8 | UInt16 | UInt32 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             Int8,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             Int16,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             Int32,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             Int64,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Float16 | Float32 | Float64,
+        ) => true,
+
+        (
+            Float16,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
         ) => true,
 
         (
             Float32,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float64,
         ) => true,
 
         (
             Float64,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32,
         ) => true,
         // end numeric casts
 
@@ -256,6 +1239,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Date32, Int32 | Int64) => true,
         (Time32(_), Int32) => true,
         (Int64, Date64 | Date32 | Time64(_)) => true,
+        (UInt32, Date32) => true,
+        (UInt64, Date64 | Timestamp(_, _)) => true,
         (Date64, Int64 | Int32) => true,
         (Time64(_), Int64) => true,
         (Date32, Date64) => true,
@@ -286,7 +1271,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             match from_type {
                 IntervalUnit::YearMonth => true,
                 IntervalUnit::DayTime => true,
-                IntervalUnit::MonthDayNano => false, // Native type is i128
+                IntervalUnit::MonthDayNano => true,
             }
         }
         (Int32, Interval(to_type)) => {
@@ -300,7 +1285,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             match to_type {
                 IntervalUnit::YearMonth => false,
                 IntervalUnit::DayTime => true,
-                IntervalUnit::MonthDayNano => false,
+                IntervalUnit::MonthDayNano => true,
             }
         }
         (Duration(_), Interval(IntervalUnit::MonthDayNano)) => true,
@@ -315,140 +1300,404 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 /// Behavior:
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
 /// * Utf8 to boolean: `true`, `yes`, `on`, `1` => `true`, `false`, `no`, `off`, `0` => `false`,
-///   short variants are accepted, other strings return null or error
+///   short variants are accepted, or the tokens of [`CastOptions::boolean_parser`] if
+///   set, other strings return null or error
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
-///   in integer casts return null
+///   in integer casts return null. Floats and decimals are parsed with the separators,
+///   and currency symbols, of [`CastOptions::number_format`] if set, e.g. `$1,234.56`
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
+/// * Boolean to Decimal: `true` returns 1 and `false` returns 0, at the scale of the
+///   decimal, or null if 1 exceeds its precision
 /// * List to List: the underlying data type is cast
+/// * List to FixedSizeList: lists with a different number of values than the size of
+///   the FixedSizeList return null, or an error if [`CastOptions::safe`] is false
+/// * Utf8 to FixedSizeList of Float32 or Float64: strings of bracketed, comma separated
+///   floats, e.g. `[0.1, 0.2]`, with the same handling of lists of a different length
 /// * Primitive to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
+/// * Utf8 to Time32 and Time64: a trailing UTC offset, e.g. `10:00:00+02:00`, is
+///   discarded or applied, see [`CastOptions::time_with_offset`]
+/// * Utf8 to Timestamp: RFC 3339 style strings, see
+///   [`crate::parse::string_to_datetime`], or strings matching one of the strftime
+///   patterns of [`CastOptions::timestamp_formats`] if set, with local datetimes made
+///   ambiguous by daylight saving time resolved by [`CastOptions::local_time_resolution`]
+/// * Utf8 to Duration: ISO 8601 durations, e.g. `PT1H30M`, or interval expressions, e.g.
+///   `1 hour 30 minutes`, truncated to the unit of the duration, see [`parse_duration`]
+/// * Time32 and Time64 to Decimal: the number of seconds since midnight, rounded to the
+///   scale of the decimal
+/// * Struct to Struct: children are matched by name, or by position if no names match
+///   and the structs have the same number of fields, and cast to the type of the field
+///   they are matched with. Unmatched children are dropped, and fields without a
+///   matching child are filled with their value in [`CastOptions::defaults`], or with
+///   nulls if there is none and [`CastOptions::safe`] is true
+/// * List of Struct to Map: each struct of a key and a value becomes an entry
+/// * Struct to Map: a struct of a list of keys and a list of values, e.g.
+///   `Struct<keys: List<Utf8>, values: List<Int64>>`, pairs the keys and values of each
+///   row, with rows of a different number of keys and values returning null, or an
+///   error if [`CastOptions::safe`] is false
+/// * Timestamp to Struct: a struct of date and time children, e.g. `Struct<date: Date32,
+///   time: Time64(Nanosecond)>`, holding the local date and time of day of each timestamp
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
-/// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Timestamp to Date{32|64}: the local date of the timestamp in its timezone, with
+///   Date64 values at midnight
+/// * Timestamp to Timestamp with a different timezone: the instant in time is kept, or
+///   the wall clock time, see [`CastOptions::timezone_conversion`]
+/// * Union to Union: each child is cast to the type of the field with the same type id,
+///   for unions with the same type ids and mode
+/// * Union to other types: each value is cast from the child selected by its type id,
+///   e.g. a union of `Int32` and `Utf8` to `Utf8`, or a union of a single child to the
+///   type of that child
+/// * Binary to Utf8 and vice versa: the bytes of valid UTF-8 strings, or base64 encoded
+///   if [`CastOptions::base64`] is set
+/// * Utf8 to FixedSizeBinary: strings of the same number of bytes are copied, and
+///   strings of twice as many hex digits, or hyphenated UUIDs to `FixedSizeBinary(16)`,
+///   are decoded. FixedSizeBinary to Utf8 is formatted as lowercase hex
+/// * Dictionary to Dictionary: only the values are cast, and the keys are shared with
+///   the input if the key type is unchanged
+/// * Dictionary to RunEndEncoded: each run of equal keys becomes a run of its value, cast
+///   to the value type of the run end encoded type
+/// * Boolean to Dictionary of Utf8: a dictionary of the strings `false` and `true` are
+///   cast to, keyed by each value. Dictionary of Utf8 to Boolean parses each distinct
+///   value once, and values that fail to parse are only an error if referenced
+/// * RunEndEncoded to other types: the value of each run is cast, and repeated for the
+///   length of the run
+/// * To RunEndEncoded: each run of equal values, where nulls are equal to each other,
+///   becomes a run of its value, cast to the value type of the run end encoded type
 /// * Casting from `float32/float64` to `Decimal(precision, scale)` rounds to the `scale` decimals
 ///   (i.e. casting 6.4999 to Decimal(10, 1) becomes 6.5). This is the breaking change from `26.0.0`.
 ///   It used to truncate it instead of round (i.e. outputs 6.4 instead)
 ///
+/// Temporal to and from integers, converting the semantic value of each temporal value:
+/// * Date32 and Date64: the number of days and milliseconds since the epoch
+/// * Time32 and Time64: the number of units since midnight, with integers that are
+///   negative or not less than a day cast to null, or an error if
+///   [`CastOptions::safe`] is false, unless [`CastOptions::reinterpret`] is set
+/// * Timestamp: the number of units since the epoch, in UTC where there is a timezone
+/// * Duration: the number of units elapsed
+/// * Interval(YearMonth): the number of months, from Int32 and to Int64, see
+///   [`crate::interval::interval_to_months`]
+/// * Interval(DayTime) and Int64: the total number of milliseconds, with the days taken
+///   to be 24 hours, see [`crate::interval::interval_daytime_to_parts`]
+/// * Interval(MonthDayNano) and Int64 or Float64: the number of
+///   [`CastOptions::numeric_interval_unit`], defaulting to seconds, with intervals of
+///   other parts cast to null, see [`NumericIntervalUnit`]
+///
+/// If [`CastOptions::reinterpret`] is set, casts between `Interval(DayTime)` and `Int64`
+/// instead reinterpret the bits of the values, with the days in the upper and the
+/// milliseconds in the lower 32 bits. Casts between `Decimal128` and `Int64` instead
+/// transfer the unscaled values, e.g. `1.23` of `Decimal128(10, 2)` to `123` and back,
+/// for interchange with systems storing decimals with a precision of at most 18 as
+/// 64-bit integers. Values out of the range of the target type, or exceeding the
+/// precision of the target decimal, are cast to null, or an error if
+/// [`CastOptions::safe`] is false.
+///
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * To or from `StructArray`, except for Struct to Struct, Struct to Map and Timestamp
+///   to Struct
+/// * To `UnionArray`, except from another union
 /// * List to primitive
-/// * Interval and duration
+/// * Interval and duration, except for `Interval(MonthDayNano)` and duration
+/// * Duration and timestamp, as a duration is not an instant in time, add durations to
+///   or subtract timestamps using the arithmetic kernels instead
+/// * `Interval(MonthDayNano)` and integers other than `Int64`, as the months, days and
+///   nanoseconds have no single unit, see
+///   [`crate::interval::interval_month_day_nano_to_parts`]
 pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<ArrayRef, ArrowError> {
     cast_with_options(array, to_type, &DEFAULT_CAST_OPTIONS)
 }
 
-fn cast_integer_to_decimal<
-    T: ArrowPrimitiveType,
-    D: DecimalType + ArrowPrimitiveType<Native = M>,
-    M,
->(
-    array: &PrimitiveArray<T>,
-    precision: u8,
+/// Cast the decimal `array` of type `D` with `scale` to `to_type`
+fn cast_from_decimal<D>(
+    array: &dyn Array,
     scale: i8,
-    base: M,
+    from_type: &DataType,
+    to_type: &DataType,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError>
 where
-    <T as ArrowPrimitiveType>::Native: AsPrimitive<M>,
-    M: ArrowNativeTypeOp,
+    D: DecimalType + ArrowPrimitiveType,
+    D::Native: DecimalCast + ArrowNativeTypeOp + ToPrimitive + std::fmt::Display,
 {
-    let scale_factor = base.pow_checked(scale.unsigned_abs() as u32).map_err(|_| {
-        ArrowError::CastError(format!(
-            "Cannot cast to {:?}({}, {}). The scale causes overflow.",
-            D::PREFIX,
-            precision,
-            scale,
-        ))
-    })?;
-
-    let array = if scale < 0 {
-        match cast_options.safe {
-            true => array.unary_opt::<_, D>(|v| {
-                v.as_().div_checked(scale_factor).ok().and_then(|v| {
-                    (D::validate_decimal_precision(v, precision).is_ok()).then_some(v)
-                })
-            }),
-            false => array.try_unary::<_, D, _>(|v| {
-                v.as_()
-                    .div_checked(scale_factor)
-                    .and_then(|v| D::validate_decimal_precision(v, precision).map(|_| v))
-            })?,
+    use DataType::*;
+    let base = D::Native::usize_as(10);
+    match to_type {
+        UInt8 => {
+            cast_decimal_to_integer::<D, UInt8Type>(array, base, scale, cast_options)
         }
-    } else {
-        match cast_options.safe {
-            true => array.unary_opt::<_, D>(|v| {
-                v.as_().mul_checked(scale_factor).ok().and_then(|v| {
-                    (D::validate_decimal_precision(v, precision).is_ok()).then_some(v)
-                })
-            }),
-            false => array.try_unary::<_, D, _>(|v| {
-                v.as_()
-                    .mul_checked(scale_factor)
-                    .and_then(|v| D::validate_decimal_precision(v, precision).map(|_| v))
-            })?,
+        UInt16 => {
+            cast_decimal_to_integer::<D, UInt16Type>(array, base, scale, cast_options)
         }
-    };
-
-    Ok(Arc::new(array.with_precision_and_scale(precision, scale)?))
-}
-
-fn cast_floating_point_to_decimal128<T: ArrowPrimitiveType>(
-    array: &PrimitiveArray<T>,
-    precision: u8,
-    scale: i8,
-    cast_options: &CastOptions,
-) -> Result<ArrayRef, ArrowError>
+        UInt32 => {
+            cast_decimal_to_integer::<D, UInt32Type>(array, base, scale, cast_options)
+        }
+        UInt64 => {
+            cast_decimal_to_integer::<D, UInt64Type>(array, base, scale, cast_options)
+        }
+        Int8 => cast_decimal_to_integer::<D, Int8Type>(array, base, scale, cast_options),
+        Int16 => {
+            cast_decimal_to_integer::<D, Int16Type>(array, base, scale, cast_options)
+        }
+        Int32 => {
+            cast_decimal_to_integer::<D, Int32Type>(array, base, scale, cast_options)
+        }
+        Int64 => {
+            cast_decimal_to_integer::<D, Int64Type>(array, base, scale, cast_options)
+        }
+        Float16 => {
+            cast_decimal_to_float::<D, Float16Type, _>(array, |x| match x.to_i128() {
+                Some(x) => decimal_to_f16(i256::from_i128(x), scale),
+                // Only values of Decimal256 are out of the range of i128
+                None => decimal_to_f16(x.to_string().parse().unwrap(), scale),
+            })
+        }
+        Float32 => {
+            cast_decimal_to_float::<D, Float32Type, _>(array, |x| match x.to_i128() {
+                Some(x) => decimal128_to_f32(x, scale),
+                None => parse_decimal_float(x, scale),
+            })
+        }
+        Float64 => {
+            cast_decimal_to_float::<D, Float64Type, _>(array, |x| match x.to_i128() {
+                Some(x) => x as f64 / 10_f64.powi(scale as i32),
+                None => parse_decimal_float(x, scale),
+            })
+        }
+        Utf8 => value_to_string::<i32>(array, cast_options),
+        LargeUtf8 => value_to_string::<i64>(array, cast_options),
+        Boolean => cast_numeric_to_bool::<D>(array),
+        Interval(IntervalUnit::MonthDayNano) => {
+            cast_decimal_to_interval::<D>(array, scale, cast_options)
+        }
+        Null => Ok(new_null_array(to_type, array.len())),
+        _ => Err(ArrowError::CastError(format!(
+            "Casting from {from_type:?} to {to_type:?} not supported"
+        ))),
+    }
+}
+
+/// Cast `array` to a decimal array of type `D` with `precision` and `scale`
+fn cast_to_decimal<D>(
+    array: &dyn Array,
+    precision: u8,
+    scale: i8,
+    from_type: &DataType,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
 where
-    <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
+    D: DecimalType + ArrowPrimitiveType,
+    D::Native: DecimalCast + ArrowNativeTypeOp,
 {
-    let mul = 10_f64.powi(scale as i32);
-
-    if cast_options.safe {
-        array
-            .unary_opt::<_, Decimal128Type>(|v| (mul * v.as_()).round().to_i128())
-            .with_precision_and_scale(precision, scale)
-            .map(|a| Arc::new(a) as ArrayRef)
-    } else {
-        array
-            .try_unary::<_, Decimal128Type, _>(|v| {
-                (mul * v.as_()).round().to_i128().ok_or_else(|| {
-                    ArrowError::CastError(format!(
-                        "Cannot cast to {}({}, {}). Overflowing on {:?}",
-                        Decimal128Type::PREFIX,
-                        precision,
-                        scale,
-                        v
-                    ))
-                })
-            })?
-            .with_precision_and_scale(precision, scale)
-            .map(|a| Arc::new(a) as ArrayRef)
+    use DataType::*;
+    match from_type {
+        UInt8 => cast_integer_to_decimal::<_, D>(
+            array.as_primitive::<UInt8Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        UInt16 => cast_integer_to_decimal::<_, D>(
+            array.as_primitive::<UInt16Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        UInt32 => cast_integer_to_decimal::<_, D>(
+            array.as_primitive::<UInt32Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        UInt64 => cast_integer_to_decimal::<_, D>(
+            array.as_primitive::<UInt64Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        Int8 => cast_integer_to_decimal::<_, D>(
+            array.as_primitive::<Int8Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        Int16 => cast_integer_to_decimal::<_, D>(
+            array.as_primitive::<Int16Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        Int32 => cast_integer_to_decimal::<_, D>(
+            array.as_primitive::<Int32Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        Int64 => cast_integer_to_decimal::<_, D>(
+            array.as_primitive::<Int64Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        Float16 => cast_floating_point_to_decimal::<_, D>(
+            array.as_primitive::<Float16Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        Float32 => cast_floating_point_to_decimal::<_, D>(
+            array.as_primitive::<Float32Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        Float64 => cast_floating_point_to_decimal::<_, D>(
+            array.as_primitive::<Float64Type>(),
+            precision,
+            scale,
+            cast_options,
+        ),
+        Utf8 => cast_string_to_decimal::<D, i32>(array, precision, scale, cast_options),
+        LargeUtf8 => {
+            cast_string_to_decimal::<D, i64>(array, precision, scale, cast_options)
+        }
+        Boolean => cast_integer_to_decimal::<_, D>(
+            &bool_to_numeric_cast::<Int8Type>(array.as_boolean(), cast_options),
+            precision,
+            scale,
+            cast_options,
+        ),
+        Time32(_) | Time64(_) => cast_time_to_decimal(array, to_type, cast_options),
+        Null => Ok(new_null_array(to_type, array.len())),
+        _ => Err(ArrowError::CastError(format!(
+            "Casting from {from_type:?} to {to_type:?} not supported"
+        ))),
     }
 }
 
-fn cast_floating_point_to_decimal256<T: ArrowPrimitiveType>(
+fn cast_integer_to_decimal<T, D>(
+    array: &PrimitiveArray<T>,
+    precision: u8,
+    scale: i8,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: AsPrimitive<i128>,
+    D: DecimalType + ArrowPrimitiveType,
+    D::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    let base = D::Native::usize_as(10);
+    let scale_factor = base.pow_checked(scale.unsigned_abs() as u32).map_err(|_| {
+        ArrowError::CastError(format!(
+            "Cannot cast to {:?}({}, {}). The scale causes overflow.",
+            D::PREFIX,
+            precision,
+            scale,
+        ))
+    })?;
+
+    // Every integer fits in an i128, from which it is widened or narrowed to `D`
+    let rescale = |v: T::Native| -> Result<D::Native, ArrowError> {
+        let v = D::Native::from_decimal(v.as_()).ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Cannot cast to {}({}, {}). Overflowing on {:?}",
+                D::PREFIX,
+                precision,
+                scale,
+                v
+            ))
+        })?;
+        match scale < 0 {
+            true => v.div_checked(scale_factor),
+            false => v.mul_checked(scale_factor),
+        }
+    };
+
+    let array = match cast_options.safe {
+        true => array.unary_opt::<_, D>(|v| {
+            rescale(v).ok().and_then(|v| {
+                (D::validate_decimal_precision(v, precision).is_ok()).then_some(v)
+            })
+        }),
+        false => array.try_unary::<_, D, _>(|v| {
+            rescale(v)
+                .and_then(|v| D::validate_decimal_precision(v, precision).map(|_| v))
+        })?,
+    };
+
+    Ok(Arc::new(array.with_precision_and_scale(precision, scale)?))
+}
+
+/// Cast the unscaled values of a decimal array to `Int64`, for [`CastOptions::reinterpret`]
+fn cast_decimal128_to_unscaled_int64(
+    array: &Decimal128Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let result: Int64Array = match cast_options.safe {
+        true => array.unary_opt(|v| i64::try_from(v).ok()),
+        false => array.try_unary(|v| {
+            i64::try_from(v).map_err(|_| {
+                ArrowError::CastError(format!(
+                    "Cannot cast unscaled value {v} of {:?} to Int64",
+                    array.data_type()
+                ))
+            })
+        })?,
+    };
+    Ok(Arc::new(result))
+}
+
+/// Cast `Int64` values to the unscaled values of a decimal array, for
+/// [`CastOptions::reinterpret`]
+fn cast_unscaled_int64_to_decimal128(
+    array: &Int64Array,
+    precision: u8,
+    scale: i8,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let result: Decimal128Array = match cast_options.safe {
+        true => array.unary_opt(|v| {
+            let v = v as i128;
+            Decimal128Type::validate_decimal_precision(v, precision)
+                .is_ok()
+                .then_some(v)
+        }),
+        false => array.try_unary(|v| {
+            let v = v as i128;
+            Decimal128Type::validate_decimal_precision(v, precision).map(|_| v)
+        })?,
+    };
+    Ok(Arc::new(result.with_precision_and_scale(precision, scale)?))
+}
+
+fn cast_floating_point_to_decimal<T, D>(
     array: &PrimitiveArray<T>,
     precision: u8,
     scale: i8,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError>
 where
-    <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
+    T: ArrowPrimitiveType,
+    T::Native: AsPrimitive<f64>,
+    D: DecimalType + ArrowPrimitiveType,
+    D::Native: DecimalCast,
 {
     let mul = 10_f64.powi(scale as i32);
+    let round = |v: f64| cast_options.rounding.round_f64(v);
 
     if cast_options.safe {
         array
-            .unary_opt::<_, Decimal256Type>(|v| i256::from_f64((v.as_() * mul).round()))
+            .unary_opt::<_, D>(|v| D::Native::from_f64(round(mul * v.as_())))
             .with_precision_and_scale(precision, scale)
             .map(|a| Arc::new(a) as ArrayRef)
     } else {
         array
-            .try_unary::<_, Decimal256Type, _>(|v| {
-                i256::from_f64((v.as_() * mul).round()).ok_or_else(|| {
+            .try_unary::<_, D, _>(|v| {
+                D::Native::from_f64(round(mul * v.as_())).ok_or_else(|| {
                     ArrowError::CastError(format!(
                         "Cannot cast to {}({}, {}). Overflowing on {:?}",
-                        Decimal256Type::PREFIX,
+                        D::PREFIX,
                         precision,
                         scale,
                         v
@@ -522,6 +1771,175 @@ fn cast_interval_to_duration<D: ArrowTemporalType<Native = i64>>(
     }
 }
 
+/// Cast a time array to a decimal number of seconds since midnight, rounding digits
+/// beyond the scale of `to_type`
+fn cast_time_to_decimal(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    // A time is a decimal number of seconds, with a scale given by its unit
+    let (values, scale): (Decimal128Array, i8) = match array.data_type() {
+        DataType::Time32(TimeUnit::Second) => (
+            array
+                .as_primitive::<Time32SecondType>()
+                .unary(|v| v as i128),
+            0,
+        ),
+        DataType::Time32(TimeUnit::Millisecond) => (
+            array
+                .as_primitive::<Time32MillisecondType>()
+                .unary(|v| v as i128),
+            3,
+        ),
+        DataType::Time64(TimeUnit::Microsecond) => (
+            array
+                .as_primitive::<Time64MicrosecondType>()
+                .unary(|v| v as i128),
+            6,
+        ),
+        DataType::Time64(TimeUnit::Nanosecond) => (
+            array
+                .as_primitive::<Time64NanosecondType>()
+                .unary(|v| v as i128),
+            9,
+        ),
+        from_type => {
+            return Err(ArrowError::CastError(format!(
+                "Casting from {from_type:?} to {to_type:?} not supported"
+            )))
+        }
+    };
+    let decimal = values.with_precision_and_scale(DECIMAL128_MAX_PRECISION, scale)?;
+    cast_with_options(&decimal, to_type, cast_options)
+}
+
+/// Cast a total number of milliseconds to Interval(DayTime), splitting it into whole
+/// days and the remaining milliseconds, both with the sign of the total
+fn cast_millis_to_day_time_interval(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<Int64Type>();
+    let to_interval = |v: i64| {
+        let days = i32::try_from(v / MILLISECONDS_IN_DAY).ok()?;
+        let millis = (v % MILLISECONDS_IN_DAY) as i32;
+        Some(IntervalDayTimeType::make_value(days, millis))
+    };
+    let array: IntervalDayTimeArray = match cast_options.safe {
+        true => array.unary_opt(to_interval),
+        false => array.try_unary(|v| {
+            to_interval(v).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Cannot cast {v} milliseconds to Interval(DayTime) as the days overflow"
+                ))
+            })
+        })?,
+    };
+    Ok(Arc::new(array))
+}
+
+/// Cast floats to Interval(MonthDayNano), see [`NumericIntervalUnit`]
+fn cast_float_to_interval<T>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: ToPrimitive,
+{
+    let unit = cast_options.numeric_interval_unit.factor() as f64;
+    cast_numeric_to_interval::<T, _>(array, cast_options, |v| {
+        let count = (v.to_f64()? * unit).round();
+        // i64::MAX as f64 rounds up to 2^63, which is out of range
+        (count >= i64::MIN as f64 && count < i64::MAX as f64).then_some(count as i64)
+    })
+}
+
+/// Cast decimals to Interval(MonthDayNano), see [`NumericIntervalUnit`]
+///
+/// Digits beyond the precision of the interval are truncated
+fn cast_decimal_to_interval<D>(
+    array: &dyn Array,
+    scale: i8,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    D: DecimalType,
+    D::Native: DecimalCast,
+{
+    let unit = i256::from_i128(cast_options.numeric_interval_unit.factor() as i128);
+    let ten = i256::from_i128(10);
+    let scale_factor = ten.checked_pow(scale.unsigned_abs() as u32);
+    cast_numeric_to_interval::<D, _>(array, cast_options, |v| {
+        let count = v.to_i256()?.checked_mul(unit)?;
+        let count = match scale >= 0 {
+            true => count.checked_div(scale_factor?)?,
+            false => count.checked_mul(scale_factor?)?,
+        };
+        count.to_i128()?.try_into().ok()
+    })
+}
+
+/// Cast a primitive array to Interval(MonthDayNano), with `to_count` returning the
+/// number of nanoseconds, or months, for a value, or `None` if it is out of range
+fn cast_numeric_to_interval<T, F>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+    to_count: F,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> Option<i64>,
+{
+    let array = array.as_primitive::<T>();
+    let unit = cast_options.numeric_interval_unit;
+    let to_interval = |v| to_count(v).and_then(|count| unit.make_interval(count));
+    let array: IntervalMonthDayNanoArray = if cast_options.safe {
+        array.unary_opt(to_interval)
+    } else {
+        array.try_unary(|v| {
+            to_interval(v).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Cannot cast {v:?} to {:?}",
+                    IntervalMonthDayNanoType::DATA_TYPE
+                ))
+            })
+        })?
+    };
+    Ok(Arc::new(array))
+}
+
+/// Cast Interval(MonthDayNano) to a number of [`CastOptions::numeric_interval_unit`],
+/// with `from_count` converting the number of nanoseconds, or months, of an interval
+fn cast_interval_to_numeric<T, F>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+    from_count: F,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(i64) -> T::Native,
+{
+    let array = array.as_primitive::<IntervalMonthDayNanoType>();
+    let unit = cast_options.numeric_interval_unit;
+    let array: PrimitiveArray<T> = if cast_options.safe {
+        array.unary_opt(|v| unit.interval_count(v).map(&from_count))
+    } else {
+        array.try_unary(|v| {
+            unit.interval_count(v).map(&from_count).ok_or_else(|| {
+                let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(v);
+                ArrowError::CastError(format!(
+                    "Cannot cast interval of {months} months, {days} days and {nanos} \
+                    nanoseconds to {:?} in {unit:?}",
+                    T::DATA_TYPE
+                ))
+            })
+        })?
+    };
+    Ok(Arc::new(array))
+}
+
 /// Cast the array from duration and interval
 fn cast_duration_to_interval<D: ArrowTemporalType<Native = i64>>(
     array: &dyn Array,
@@ -576,6 +1994,45 @@ fn cast_duration_to_interval<D: ArrowTemporalType<Native = i64>>(
     }
 }
 
+/// Casts integers to times of day of `T`, where values that are negative or not less
+/// than a day are a failed cast, unless [`CastOptions::reinterpret`] is set
+fn cast_integer_to_time<I, T>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    I: ArrowPrimitiveType,
+    T: ArrowTemporalType<Native = I::Native>,
+{
+    let array = array.as_primitive::<I>();
+    if cast_options.reinterpret {
+        return Ok(Arc::new(array.reinterpret_cast::<T>()));
+    }
+
+    let units_per_day = match T::DATA_TYPE {
+        DataType::Time32(TimeUnit::Second) => SECONDS_IN_DAY,
+        DataType::Time32(TimeUnit::Millisecond) => MILLISECONDS_IN_DAY,
+        DataType::Time64(TimeUnit::Microsecond) => MICROSECONDS_IN_DAY,
+        DataType::Time64(TimeUnit::Nanosecond) => NANOSECONDS_IN_DAY,
+        _ => unreachable!(),
+    };
+    let end = I::Native::usize_as(units_per_day as usize);
+    let zero = I::Native::default();
+    let valid = |v: I::Native| v >= zero && v < end;
+
+    let array = match cast_options.safe {
+        true => array.unary_opt::<_, T>(|v| valid(v).then_some(v)),
+        false => array.try_unary::<_, T, _>(|v| match valid(v) {
+            true => Ok(v),
+            false => Err(ArrowError::CastError(format!(
+                "Cannot cast {v:?} to {:?}, as it is not a time of day",
+                T::DATA_TYPE
+            ))),
+        })?,
+    };
+    Ok(Arc::new(array))
+}
+
 /// Cast the primitive array using [`PrimitiveArray::reinterpret_cast`]
 fn cast_reinterpret_arrays<
     I: ArrowPrimitiveType,
@@ -594,7 +2051,7 @@ fn cast_decimal_to_integer<D, T>(
 ) -> Result<ArrayRef, ArrowError>
 where
     T: ArrowPrimitiveType,
-    <T as ArrowPrimitiveType>::Native: NumCast,
+    <T as ArrowPrimitiveType>::Native: NumCast + Bounded,
     D: DecimalType + ArrowPrimitiveType,
     <D as ArrowPrimitiveType>::Native: ArrowNativeTypeOp + ToPrimitive,
 {
@@ -608,48 +2065,54 @@ where
         ))
     })?;
 
-    let mut value_builder = PrimitiveBuilder::<T>::with_capacity(array.len());
-
-    if cast_options.safe {
-        for i in 0..array.len() {
-            if array.is_null(i) {
-                value_builder.append_null();
-            } else {
-                let v = array
-                    .value(i)
-                    .div_checked(div)
-                    .ok()
-                    .and_then(<T::Native as NumCast>::from::<D::Native>);
-
-                value_builder.append_option(v);
-            }
-        }
-    } else {
-        for i in 0..array.len() {
-            if array.is_null(i) {
-                value_builder.append_null();
-            } else {
-                let v = array.value(i).div_checked(div)?;
-
-                let value =
-                    <T::Native as NumCast>::from::<D::Native>(v).ok_or_else(|| {
-                        ArrowError::CastError(format!(
-                            "value of {:?} is out of range {}",
-                            v,
-                            T::DATA_TYPE
-                        ))
-                    })?;
-
-                value_builder.append_value(value);
-            }
-        }
-    }
-    Ok(Arc::new(value_builder.finish()))
+    // div is at least one, and so truncating towards zero cannot overflow
+    let array = match scale {
+        0 => decimal_to_integer::<D, T, _>(array, |v| v, cast_options)?,
+        _ => decimal_to_integer::<D, T, _>(array, |v| v.div_wrapping(div), cast_options)?,
+    };
+    Ok(Arc::new(array))
 }
 
-// cast the decimal array to floating-point array
-fn cast_decimal_to_float<D: DecimalType, T: ArrowPrimitiveType, F>(
-    array: &dyn Array,
+fn decimal_to_integer<D, T, F>(
+    array: &PrimitiveArray<D>,
+    truncate: F,
+    cast_options: &CastOptions,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    <T as ArrowPrimitiveType>::Native: NumCast + Bounded,
+    D: DecimalType + ArrowPrimitiveType,
+    <D as ArrowPrimitiveType>::Native: ArrowNativeTypeOp + ToPrimitive,
+    F: Fn(D::Native) -> D::Native,
+{
+    let convert = |v: D::Native| <T::Native as NumCast>::from::<D::Native>(v);
+    if cast_options.saturate {
+        Ok(array.unary(|v| {
+            let v = truncate(v);
+            convert(v).unwrap_or_else(|| match v.is_lt(D::Native::ZERO) {
+                true => T::Native::min_value(),
+                false => T::Native::max_value(),
+            })
+        }))
+    } else if cast_options.safe {
+        Ok(array.unary_opt(|v| convert(truncate(v))))
+    } else {
+        array.try_unary(|v| {
+            let v = truncate(v);
+            convert(v).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "value of {:?} is out of range {}",
+                    v,
+                    T::DATA_TYPE
+                ))
+            })
+        })
+    }
+}
+
+// cast the decimal array to floating-point array
+fn cast_decimal_to_float<D: DecimalType, T: ArrowPrimitiveType, F>(
+    array: &dyn Array,
     op: F,
 ) -> Result<ArrayRef, ArrowError>
 where
@@ -660,21 +2123,267 @@ where
     Ok(Arc::new(array))
 }
 
+/// Powers of ten that are exactly representable as `f32`
+const POWERS_OF_TEN_F32: [f32; 11] =
+    [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+
+/// Converts the decimal with the unscaled `value` and `scale` to the nearest `f32`
+///
+/// Converting to `f64`, and then to `f32`, rounds twice which may not yield the nearest
+/// `f32`, e.g. for values just above the midpoint of two `f32`
+fn decimal128_to_f32(value: i128, scale: i8) -> f32 {
+    match POWERS_OF_TEN_F32.get(scale.unsigned_abs() as usize) {
+        // Both operands are exact, and so the result is only rounded once
+        Some(power) if value.unsigned_abs() <= 1 << 24 => match scale >= 0 {
+            true => value as f32 / power,
+            false => value as f32 * power,
+        },
+        _ => parse_decimal_float(value, scale),
+    }
+}
+
+/// Powers of ten that are exactly representable as `f64`
+const POWERS_OF_TEN_F64: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+    1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+/// Converts the decimal with the unscaled `value` and `scale` to the nearest `f16`
+///
+/// The nearest `f64` has enough precision that rounding it to `f16` only differs from
+/// rounding the decimal when it is exactly halfway between two `f16`, and so such ties
+/// are resolved by comparing the decimal to them exactly. [`f16::from_f64`] is not used
+/// to round, as it may round twice, via `f32`
+fn decimal_to_f16(value: i256, scale: i8) -> f16 {
+    let float = match value.to_i128() {
+        Some(v) if v.unsigned_abs() <= 1 << 53 => {
+            match POWERS_OF_TEN_F64.get(scale.unsigned_abs() as usize) {
+                // Both operands are exact, and so the result is only rounded once
+                Some(power) if scale >= 0 => v as f64 / power,
+                Some(power) => v as f64 * power,
+                None => parse_decimal_float(value, scale),
+            }
+        }
+        _ => parse_decimal_float(value, scale),
+    };
+    let magnitude = float.abs();
+    if !magnitude.is_finite() {
+        return f16::from_f64(float);
+    }
+
+    // The value of the bits of a non-negative f16, where the bits of infinity are taken
+    // to be the next power of two after the largest finite f16
+    let bits_value = |bits: u16| match bits {
+        0x7C00 => 65536.0,
+        bits => f16::from_bits(bits).to_f64(),
+    };
+    // The bits of the largest f16 not above the magnitude, starting from an estimate
+    let mut below = f16::from_f64(magnitude).to_bits().min(0x7C00);
+    while bits_value(below) > magnitude {
+        below -= 1;
+    }
+    while below < 0x7C00 && bits_value(below + 1) <= magnitude {
+        below += 1;
+    }
+
+    let bits = match below == 0x7C00 || bits_value(below) == magnitude {
+        true => below,
+        false => {
+            let above = below + 1;
+            // Exactly representable, as it has one more significant bit than an f16
+            let halfway = (bits_value(below) + bits_value(above)) / 2.0;
+            let ordering = match magnitude.partial_cmp(&halfway) {
+                Some(Ordering::Equal) => cmp_decimal_magnitude(value, scale, halfway),
+                ordering => ordering.unwrap_or(Ordering::Equal),
+            };
+            match ordering {
+                Ordering::Less => below,
+                Ordering::Greater => above,
+                // Ties are rounded to the f16 with an even mantissa
+                Ordering::Equal => below + (below & 1),
+            }
+        }
+    };
+    match float.is_sign_negative() {
+        true => f16::from_bits(bits | 0x8000),
+        false => f16::from_bits(bits),
+    }
+}
+
+/// Compares the magnitude of the decimal with the unscaled `value` and `scale` to the
+/// finite, positive `float`, exactly
+fn cmp_decimal_magnitude(value: i256, scale: i8, float: f64) -> Ordering {
+    // The float is an odd integer mantissa multiplied by a power of two
+    let bits = float.to_bits();
+    let mut mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+    let mut exponent = ((bits >> 52) & 0x7FF) as i32 - 1075;
+    let zeros = mantissa.trailing_zeros();
+    mantissa >>= zeros;
+    exponent += zeros as i32;
+
+    // As a decimal, with an unscaled value of the sign of `value`, and a scale
+    let mantissa = match value < i256::ZERO {
+        true => i256::from_i128(-(mantissa as i128)),
+        false => i256::from_i128(mantissa as i128),
+    };
+    let (float_value, float_scale) = match exponent >= 0 {
+        true => (
+            i256::from_i128(2)
+                .checked_pow(exponent as u32)
+                .and_then(|p| mantissa.checked_mul(p)),
+            0,
+        ),
+        false => (
+            i256::from_i128(5)
+                .checked_pow(exponent.unsigned_abs())
+                .and_then(|p| mantissa.checked_mul(p)),
+            -exponent,
+        ),
+    };
+    let float_value = match float_value {
+        Some(float_value) => float_value,
+        None => return Ordering::Less,
+    };
+
+    // Scale the operand with the smaller scale, an overflow is larger than any decimal
+    let shift = scale as i32 - float_scale;
+    let power = i256::from_i128(10).checked_pow(shift.unsigned_abs());
+    let ordering = match shift >= 0 {
+        true => match power.and_then(|p| float_value.checked_mul(p)) {
+            Some(float_value) => value.cmp(&float_value),
+            None => return Ordering::Less,
+        },
+        false => match power.and_then(|p| value.checked_mul(p)) {
+            Some(value) => value.cmp(&float_value),
+            None => return Ordering::Greater,
+        },
+    };
+    match value < i256::ZERO {
+        true => ordering.reverse(),
+        false => ordering,
+    }
+}
+
+/// Converts the decimal with the unscaled `value` and `scale` to the nearest float by
+/// parsing its representation in scientific notation, which is correctly rounded
+fn parse_decimal_float<T: std::fmt::Display, F: num::Float + std::str::FromStr>(
+    value: T,
+    scale: i8,
+) -> F {
+    // Parsing an integer mantissa and exponent cannot fail, out of range values are
+    // parsed as infinity or zero
+    format!("{value}e{}", -(scale as i32))
+        .parse()
+        .unwrap_or_else(|_| F::nan())
+}
+
 // cast the List array to Utf8 array
 macro_rules! cast_list_to_string {
-    ($ARRAY:expr, $SIZE:ident) => {{
+    ($ARRAY:expr, $SIZE:ident, $OPTIONS:expr) => {{
+        let formatter = ArrayFormatter::try_new($ARRAY, $OPTIONS)?;
         let mut value_builder: GenericStringBuilder<$SIZE> = GenericStringBuilder::new();
         for i in 0..$ARRAY.len() {
             if $ARRAY.is_null(i) {
                 value_builder.append_null();
             } else {
-                value_builder.append_value(array_value_to_string($ARRAY, i)?);
+                value_builder.append_value(formatter.value(i).to_string());
             }
         }
         Ok(Arc::new(value_builder.finish()))
     }};
 }
 
+/// Cast the primitive `array` to `O` multiplying each value by `multiplier`, returning
+/// null, or an error if not safe, for values that overflow
+fn cast_with_multiplier<I, O>(
+    array: &dyn Array,
+    multiplier: O::Native,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    I: ArrowPrimitiveType,
+    O: ArrowPrimitiveType,
+    I::Native: Into<O::Native>,
+    O::Native: ArrowNativeTypeOp,
+{
+    let array = array.as_primitive::<I>();
+    let out: PrimitiveArray<O> = match cast_options.safe {
+        true => array.unary_opt(|x| x.into().mul_checked(multiplier).ok()),
+        false => array.try_unary(|x| x.into().mul_checked(multiplier))?,
+    };
+    Ok(Arc::new(out))
+}
+
+/// Cast an unsigned integer array of values since the epoch to the temporal type `T`,
+/// returning null, or an error if not safe, for values that overflow `T`
+fn cast_unsigned_to_temporal<I, T>(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    I: ArrowPrimitiveType,
+    I::Native: NumCast,
+    T: ArrowPrimitiveType,
+    T::Native: NumCast,
+{
+    let array = array.as_primitive::<I>();
+    let out: PrimitiveArray<T> = match cast_options.safe {
+        true => array.unary_opt(num::cast),
+        false => array.try_unary(|v| {
+            num::cast(v).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Can't cast value {v:?} to type {to_type:?}"
+                ))
+            })
+        })?,
+    };
+    Ok(Arc::new(out.with_data_type(to_type.clone())))
+}
+
+/// Cast a list array to a string array, joining the elements of each list with `delimiter`
+fn join_list_to_string<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    delimiter: &str,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let options = &cast_options.format_options;
+    match array.data_type() {
+        DataType::List(_) => {
+            join_list_values::<i32, O>(array.as_list(), delimiter, options)
+        }
+        DataType::LargeList(_) => {
+            join_list_values::<i64, O>(array.as_list(), delimiter, options)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn join_list_values<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    list: &GenericListArray<I>,
+    delimiter: &str,
+    options: &FormatOptions,
+) -> Result<ArrayRef, ArrowError> {
+    use std::fmt::Write;
+
+    let formatter = ArrayFormatter::try_new(list.values().as_ref(), options)?;
+    let mut builder = GenericStringBuilder::<O>::new();
+    for (i, offsets) in list.value_offsets().windows(2).enumerate() {
+        if list.is_null(i) {
+            builder.append_null();
+            continue;
+        }
+        for (n, idx) in (offsets[0].as_usize()..offsets[1].as_usize()).enumerate() {
+            if n != 0 {
+                builder.write_str(delimiter).unwrap();
+            }
+            write!(builder, "{}", formatter.value(idx)).unwrap();
+        }
+        builder.append_value("");
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
 fn make_timestamp_array(
     array: &PrimitiveArray<Int64Type>,
     unit: TimeUnit,
@@ -722,6 +2431,312 @@ fn as_time_res_with_timezone<T: ArrowPrimitiveType>(
     })
 }
 
+/// Returns true if `data_type` is a date or time type, that a timestamp can be
+/// decomposed into
+fn is_date_time_component(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(TimeUnit::Second | TimeUnit::Millisecond)
+            | DataType::Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond)
+    )
+}
+
+/// Cast a timestamp array to a struct with a date or time child for each of `fields`,
+/// e.g. `Struct<date: Date32, time: Time64(Nanosecond)>`
+///
+/// Timestamps with a timezone are decomposed into their local date and time of day.
+/// Rows that cannot be represented are null, or an error if not safe.
+fn cast_timestamp_to_date_time_struct<T: ArrowTimestampType>(
+    array: &dyn Array,
+    fields: &Fields,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    if let Some(f) = fields
+        .iter()
+        .find(|f| !is_date_time_component(f.data_type()))
+    {
+        return Err(ArrowError::CastError(format!(
+            "Cannot cast {:?} to struct field '{}' of type {:?}, expected a date or time",
+            array.data_type(),
+            f.name(),
+            f.data_type()
+        )));
+    }
+    let array = array.as_primitive::<T>();
+    let overflow = |v: i64| {
+        ArrowError::CastError(format!(
+            "Cannot decompose {v} of {:?} into a local date and time",
+            array.data_type()
+        ))
+    };
+    let from_multiple = time_unit_multiple(&T::UNIT);
+    let per_day = from_multiple * SECONDS_IN_DAY;
+    let local = local_timestamps(array, cast_options, overflow)?;
+
+    // The time of day in `unit`
+    let time = |v: i64, unit: TimeUnit| {
+        let to_multiple = time_unit_multiple(&unit);
+        let time = v.rem_euclid(per_day);
+        match to_multiple >= from_multiple {
+            true => time * (to_multiple / from_multiple),
+            false => time / (from_multiple / to_multiple),
+        }
+    };
+
+    let columns = fields
+        .iter()
+        .map(|f| {
+            let column: ArrayRef = match f.data_type() {
+                DataType::Date32 | DataType::Date64 => {
+                    local_dates(&local, &T::UNIT, f.data_type(), cast_options, overflow)?
+                }
+                DataType::Time32(TimeUnit::Second) => {
+                    Arc::new(local.unary::<_, Time32SecondType>(|v| {
+                        time(v, TimeUnit::Second) as i32
+                    }))
+                }
+                DataType::Time32(TimeUnit::Millisecond) => {
+                    Arc::new(local.unary::<_, Time32MillisecondType>(|v| {
+                        time(v, TimeUnit::Millisecond) as i32
+                    }))
+                }
+                DataType::Time64(TimeUnit::Microsecond) => {
+                    Arc::new(local.unary::<_, Time64MicrosecondType>(|v| {
+                        time(v, TimeUnit::Microsecond)
+                    }))
+                }
+                DataType::Time64(TimeUnit::Nanosecond) => {
+                    Arc::new(local.unary::<_, Time64NanosecondType>(|v| {
+                        time(v, TimeUnit::Nanosecond)
+                    }))
+                }
+                _ => unreachable!(),
+            };
+            Ok(column)
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    Ok(Arc::new(StructArray::try_new(
+        fields.clone(),
+        columns,
+        local.nulls().cloned(),
+    )?))
+}
+
+/// Cast a timestamp array to the local date of each timestamp, as a Date32 or Date64
+///
+/// Rows whose date cannot be represented are null, or an error if not safe
+fn cast_timestamp_to_date<T: ArrowTimestampType>(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<T>();
+    let overflow = |v: i64| {
+        ArrowError::CastError(format!(
+            "Cannot cast {v} of {:?} to {to_type:?}",
+            array.data_type()
+        ))
+    };
+    let local = local_timestamps(array, cast_options, overflow)?;
+    local_dates(&local, &T::UNIT, to_type, cast_options, overflow)
+}
+
+/// Returns the wall clock time of each timestamp of `array` in its timezone, as a
+/// timestamp without a timezone
+///
+/// Rows that cannot be represented are null, or the `overflow` error if not safe
+fn local_timestamps<T: ArrowTimestampType>(
+    array: &PrimitiveArray<T>,
+    cast_options: &CastOptions,
+    overflow: impl Fn(i64) -> ArrowError,
+) -> Result<PrimitiveArray<Int64Type>, ArrowError> {
+    let tz: Tz = match array.timezone() {
+        Some(tz) if !is_utc_timezone(tz) => tz.parse()?,
+        _ => return Ok(array.reinterpret_cast()),
+    };
+    let to_local = |v: i64| match tz.fixed_offset() {
+        Some(offset) => {
+            let offset = offset.local_minus_utc() as i64 * time_unit_multiple(&T::UNIT);
+            v.checked_add(offset)
+        }
+        None => as_datetime_with_timezone::<T>(v, tz)
+            .and_then(|d| T::make_value(d.naive_local())),
+    };
+    Ok(match cast_options.safe {
+        true => array.unary_opt(to_local),
+        false => array.try_unary(|v| to_local(v).ok_or_else(|| overflow(v)))?,
+    })
+}
+
+/// Returns true if `tz` names UTC, which is parsed as a zero offset without the
+/// `chrono-tz` feature of `arrow-array`
+fn is_utc_timezone(tz: &str) -> bool {
+    tz.eq_ignore_ascii_case("UTC") || tz == "Z" || tz == "Etc/UTC"
+}
+
+/// Cast an array of timestamps of type `T` to timestamps of the same unit in `to_tz`
+/// with the same wall clock time, see [`TimezoneConversion::WallClock`]
+fn cast_timestamp_reinterpret_tz<T: ArrowTimestampType>(
+    array: &dyn Array,
+    to_tz: &Arc<str>,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<T>();
+    let tz: Tz = to_tz.parse()?;
+    let to_type = DataType::Timestamp(T::UNIT, Some(to_tz.clone()));
+    let error = |v: i64| {
+        ArrowError::CastError(format!(
+            "Cannot cast {v} of {:?} to {to_type:?}, as its wall clock time cannot be represented in {to_tz}",
+            array.data_type()
+        ))
+    };
+    let local = local_timestamps(array, cast_options, error)?;
+
+    let to_utc = |v: i64| match tz.fixed_offset() {
+        Some(offset) => {
+            let offset = offset.local_minus_utc() as i64 * time_unit_multiple(&T::UNIT);
+            v.checked_sub(offset)
+        }
+        None => as_datetime::<T>(v)
+            .and_then(|naive| tz.from_local_datetime(&naive).earliest())
+            .and_then(|d| T::make_value(d.naive_utc())),
+    };
+    let array = match cast_options.safe {
+        true => local.unary_opt::<_, T>(to_utc),
+        false => local.try_unary::<_, T, _>(|v| to_utc(v).ok_or_else(|| error(v)))?,
+    };
+    Ok(Arc::new(array.with_timezone(to_tz.clone())))
+}
+
+/// Returns the date of each `local` timestamp in `unit`, as a Date32 or Date64 array of
+/// `to_type`, with Date64 values at midnight
+///
+/// Rows whose date cannot be represented are null, or the `overflow` error if not safe
+fn local_dates(
+    local: &PrimitiveArray<Int64Type>,
+    unit: &TimeUnit,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+    overflow: impl Fn(i64) -> ArrowError,
+) -> Result<ArrayRef, ArrowError> {
+    let per_day = time_unit_multiple(unit) * SECONDS_IN_DAY;
+    let days = |v: i64| v.div_euclid(per_day);
+    Ok(match to_type {
+        // The days of every microsecond and nanosecond timestamp fit in a Date32
+        DataType::Date32 if i64::MAX / per_day <= i32::MAX as i64 => {
+            Arc::new(local.unary::<_, Date32Type>(|v| days(v) as i32))
+        }
+        DataType::Date32 => {
+            let to_date = |v| i32::try_from(days(v)).ok();
+            Arc::new(match cast_options.safe {
+                true => local.unary_opt::<_, Date32Type>(to_date),
+                false => local.try_unary::<_, Date32Type, _>(|v| {
+                    to_date(v).ok_or_else(|| overflow(v))
+                })?,
+            })
+        }
+        DataType::Date64 => {
+            let to_date = |v| days(v).checked_mul(MILLISECONDS_IN_DAY);
+            Arc::new(match cast_options.safe {
+                true => local.unary_opt::<_, Date64Type>(to_date),
+                false => local.try_unary::<_, Date64Type, _>(|v| {
+                    to_date(v).ok_or_else(|| overflow(v))
+                })?,
+            })
+        }
+        _ => unreachable!(),
+    })
+}
+
+/// Cast Date64 to Date32, handling values not at midnight per [`Date64Alignment`]
+fn cast_date64_to_date32(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<Date64Type>();
+    let to_days = |x: i64| x.div_euclid(MILLISECONDS_IN_DAY) as i32;
+    let array = match (cast_options.date64_alignment, cast_options.safe) {
+        (Date64Alignment::Truncate, _) => {
+            array.unary::<_, Date32Type>(|x| (x / MILLISECONDS_IN_DAY) as i32)
+        }
+        (Date64Alignment::Floor, _) => array.unary::<_, Date32Type>(to_days),
+        (Date64Alignment::Checked, true) => array.unary_opt::<_, Date32Type>(|x| {
+            (x % MILLISECONDS_IN_DAY == 0).then(|| to_days(x))
+        }),
+        (Date64Alignment::Checked, false) => {
+            array.try_unary::<_, Date32Type, _>(|x| match x % MILLISECONDS_IN_DAY {
+                0 => Ok(to_days(x)),
+                _ => Err(ArrowError::CastError(format!(
+                    "Cannot cast Date64 value {x} to Date32 as it is not at midnight"
+                ))),
+            })?
+        }
+    };
+    Ok(Arc::new(array))
+}
+
+/// Cast an array of timestamps of type `F` to the time of day of type `T` in timezone `tz`
+///
+/// For timestamps without a timezone, or with a fixed offset, the time of day is computed
+/// with integer arithmetic, falling back to chrono for named timezones which may observe
+/// daylight saving time
+fn cast_timestamp_to_time<F, T>(
+    array: &dyn Array,
+    tz: Option<&str>,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: ArrowTimestampType,
+    T: ArrowTemporalType,
+    T::Native: NumCast,
+{
+    let tz: Option<Tz> = tz.map(|tz| tz.parse()).transpose()?;
+    let array = array.as_primitive::<F>();
+    let to_unit = match T::DATA_TYPE {
+        DataType::Time32(unit) | DataType::Time64(unit) => unit,
+        _ => unreachable!(),
+    };
+    let from_scale = time_unit_multiple(&F::UNIT);
+    let to_scale = time_unit_multiple(&to_unit);
+
+    let offset = match tz {
+        None => Some(0),
+        Some(tz) => tz.fixed_offset().map(|o| o.local_minus_utc() as i64),
+    };
+
+    let array: PrimitiveArray<T> = match offset {
+        Some(offset) => {
+            let offset = offset * from_scale;
+            let day = SECONDS_IN_DAY * from_scale;
+            array.try_unary::<_, T, ArrowError>(|x| {
+                let local = x.checked_add(offset).ok_or_else(|| {
+                    ArrowError::CastError(format!(
+                        "Failed to create naive time with {} {}",
+                        std::any::type_name::<F>(),
+                        x
+                    ))
+                })?;
+                let time = local.rem_euclid(day);
+                let time = match to_scale >= from_scale {
+                    true => time * (to_scale / from_scale),
+                    false => time / (from_scale / to_scale),
+                };
+                // time of day in the target unit always fits in T::Native
+                Ok(num::cast::cast(time).unwrap())
+            })?
+        }
+        None => array.try_unary::<_, T, ArrowError>(|x| {
+            let time = as_time_res_with_timezone::<F>(x, tz)?;
+            let nanos = time.num_seconds_from_midnight() as i64 * NANOSECONDS
+                + time.nanosecond() as i64;
+            Ok(num::cast::cast(nanos / (NANOSECONDS / to_scale)).unwrap())
+        })?,
+    };
+    Ok(Arc::new(array))
+}
+
 /// Cast `array` to the provided data type and return a new Array with
 /// type `to_type`, if possible. It accepts `CastOptions` to allow consumers
 /// to configure cast behavior.
@@ -729,31 +2744,112 @@ fn as_time_res_with_timezone<T: ArrowPrimitiveType>(
 /// Behavior:
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
 /// * Utf8 to boolean: `true`, `yes`, `on`, `1` => `true`, `false`, `no`, `off`, `0` => `false`,
-///   short variants are accepted, other strings return null or error
+///   short variants are accepted, or the tokens of [`CastOptions::boolean_parser`] if
+///   set, other strings return null or error
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
-///   in integer casts return null
+///   in integer casts return null. Floats and decimals are parsed with the separators,
+///   and currency symbols, of [`CastOptions::number_format`] if set, e.g. `$1,234.56`
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
+/// * Boolean to Decimal: `true` returns 1 and `false` returns 0, at the scale of the
+///   decimal, or null if 1 exceeds its precision
 /// * List to List: the underlying data type is cast
+/// * List to FixedSizeList: lists with a different number of values than the size of
+///   the FixedSizeList return null, or an error if [`CastOptions::safe`] is false
+/// * Utf8 to FixedSizeList of Float32 or Float64: strings of bracketed, comma separated
+///   floats, e.g. `[0.1, 0.2]`, with the same handling of lists of a different length
 /// * Primitive to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
+/// * Utf8 to Time32 and Time64: a trailing UTC offset, e.g. `10:00:00+02:00`, is
+///   discarded or applied, see [`CastOptions::time_with_offset`]
+/// * Utf8 to Timestamp: RFC 3339 style strings, see
+///   [`crate::parse::string_to_datetime`], or strings matching one of the strftime
+///   patterns of [`CastOptions::timestamp_formats`] if set, with local datetimes made
+///   ambiguous by daylight saving time resolved by [`CastOptions::local_time_resolution`]
+/// * Utf8 to Duration: ISO 8601 durations, e.g. `PT1H30M`, or interval expressions, e.g.
+///   `1 hour 30 minutes`, truncated to the unit of the duration, see [`parse_duration`]
+/// * Time32 and Time64 to Decimal: the number of seconds since midnight, rounded to the
+///   scale of the decimal
+/// * Struct to Struct: children are matched by name, or by position if no names match
+///   and the structs have the same number of fields, and cast to the type of the field
+///   they are matched with. Unmatched children are dropped, and fields without a
+///   matching child are filled with their value in [`CastOptions::defaults`], or with
+///   nulls if there is none and [`CastOptions::safe`] is true
+/// * List of Struct to Map: each struct of a key and a value becomes an entry
+/// * Struct to Map: a struct of a list of keys and a list of values, e.g.
+///   `Struct<keys: List<Utf8>, values: List<Int64>>`, pairs the keys and values of each
+///   row, with rows of a different number of keys and values returning null, or an
+///   error if [`CastOptions::safe`] is false
+/// * Timestamp to Struct: a struct of date and time children, e.g. `Struct<date: Date32,
+///   time: Time64(Nanosecond)>`, holding the local date and time of day of each timestamp
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
-/// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Timestamp to Date{32|64}: the local date of the timestamp in its timezone, with
+///   Date64 values at midnight
+/// * Timestamp to Timestamp with a different timezone: the instant in time is kept, or
+///   the wall clock time, see [`CastOptions::timezone_conversion`]
+/// * Binary to Utf8 and vice versa: the bytes of valid UTF-8 strings, or base64 encoded
+///   if [`CastOptions::base64`] is set
+/// * Utf8 to FixedSizeBinary: strings of the same number of bytes are copied, and
+///   strings of twice as many hex digits, or hyphenated UUIDs to `FixedSizeBinary(16)`,
+///   are decoded. FixedSizeBinary to Utf8 is formatted as lowercase hex
+/// * Dictionary to Dictionary: only the values are cast, and the keys are shared with
+///   the input if the key type is unchanged
+/// * Dictionary to RunEndEncoded: each run of equal keys becomes a run of its value, cast
+///   to the value type of the run end encoded type
+/// * Boolean to Dictionary of Utf8: a dictionary of the strings `false` and `true` are
+///   cast to, keyed by each value. Dictionary of Utf8 to Boolean parses each distinct
+///   value once, and values that fail to parse are only an error if referenced
+/// * RunEndEncoded to other types: the value of each run is cast, and repeated for the
+///   length of the run
+/// * To RunEndEncoded: each run of equal values, where nulls are equal to each other,
+///   becomes a run of its value, cast to the value type of the run end encoded type
+/// * Temporal to and from integers: the semantic value of each temporal value, or a
+///   reinterpretation of its bits if [`CastOptions::reinterpret`] is set, see [`cast`]
 ///
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * To or from `StructArray`, except for Struct to Struct, Struct to Map and Timestamp
+///   to Struct
+/// * To `UnionArray`, except from another union
 /// * List to primitive
+/// * Duration and timestamp, see [`cast`]
 pub fn cast_with_options(
     array: &dyn Array,
     to_type: &DataType,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     use DataType::*;
+    if let Some(fill) = &cast_options.null_fill {
+        // Nested casts, e.g. of the elements of lists, keep their nulls
+        let options = CastOptions {
+            null_fill: None,
+            ..cast_options.clone()
+        };
+        let array = cast_with_options(array, to_type, &options)?;
+        return fill_nulls(array, fill, &options);
+    }
+
     let from_type = array.data_type();
     // clone array if types are the same
     if from_type == to_type {
         return Ok(make_array(array.to_data()));
     }
+    if let Some(registry) = &cast_options.registry {
+        if !registry.is_empty() && !can_cast_types(from_type, to_type) {
+            let from = CastType::new(from_type.clone());
+            if let Some(cast) = registry.get(&from, &CastType::new(to_type.clone())) {
+                return cast(array, to_type, cast_options);
+            }
+        }
+    }
+    // skip the cast kernels for empty arrays, and for arrays containing only nulls of
+    // leaf types. The kernels of nested types validate their fields even if all values
+    // are null, e.g. that a field missing from a struct may be filled with nulls, and
+    // the run ends of run end encoded arrays may overflow
+    let is_leaf = !to_type.is_nested() && !matches!(to_type, RunEndEncoded(_, _));
+    let all_null = is_leaf && array.null_count() == array.len();
+    if (array.is_empty() || all_null) && can_cast_types(from_type, to_type) {
+        return cast_all_null(to_type, array.len());
+    }
     match (from_type, to_type) {
         (
             Null,
@@ -764,6 +2860,7 @@ pub fn cast_with_options(
             | UInt16
             | Int32
             | UInt32
+            | Float16
             | Float32
             | Date32
             | Time32(_)
@@ -813,11 +2910,73 @@ pub fn cast_with_options(
                 "Casting from type {from_type:?} to dictionary type {to_type:?} not supported",
             ))),
         },
+        (RunEndEncoded(_, _), RunEndEncoded(run_ends, values)) => {
+            downcast_run_array!(
+                array => match run_ends.data_type() {
+                    Int16 => cast_run_end_encoded::<_, Int16Type>(
+                        array,
+                        to_type,
+                        values.data_type(),
+                        cast_options,
+                    ),
+                    Int32 => cast_run_end_encoded::<_, Int32Type>(
+                        array,
+                        to_type,
+                        values.data_type(),
+                        cast_options,
+                    ),
+                    Int64 => cast_run_end_encoded::<_, Int64Type>(
+                        array,
+                        to_type,
+                        values.data_type(),
+                        cast_options,
+                    ),
+                    t => Err(ArrowError::CastError(format!(
+                        "Unsupported type {t:?} for run ends"
+                    ))),
+                },
+                _ => unreachable!()
+            )
+        }
+        (RunEndEncoded(_, _), _) => downcast_run_array!(
+            array => unpack_run_end_encoded(array, to_type, cast_options),
+            _ => unreachable!()
+        ),
+        (_, RunEndEncoded(run_ends, values)) => match run_ends.data_type() {
+            Int16 => pack_run_end_encoded::<Int16Type>(
+                array,
+                to_type,
+                values.data_type(),
+                cast_options,
+            ),
+            Int32 => pack_run_end_encoded::<Int32Type>(
+                array,
+                to_type,
+                values.data_type(),
+                cast_options,
+            ),
+            Int64 => pack_run_end_encoded::<Int64Type>(
+                array,
+                to_type,
+                values.data_type(),
+                cast_options,
+            ),
+            t => Err(ArrowError::CastError(format!(
+                "Unsupported type {t:?} for run ends"
+            ))),
+        },
+        (Union(_, _), Union(to_fields, to_mode)) => {
+            cast_union_to_union(array, to_fields, *to_mode, to_type, cast_options)
+        }
+        (Union(_, _), _) => cast_union_values(array, to_type, cast_options),
         (List(_), List(ref to)) => {
-            cast_list_inner::<i32>(array, to, to_type, cast_options)
+            cast_list_inner::<i32>(array, to, cast_options)
         }
+        (Map(_, _), Map(_, _)) => cast_map(array.as_map(), to_type, cast_options),
+        (List(_), Map(_, _)) => cast_list_to_map(array, to_type, cast_options),
+        (Struct(_), Map(_, _)) => cast_struct_to_map(array, to_type, cast_options),
         (LargeList(_), LargeList(ref to)) => {
-            cast_list_inner::<i64>(array, to, to_type, cast_options)
+            cast_list_inner::<i64>(array, to, cast_options)
         }
         (List(list_from), LargeList(list_to)) => {
             if list_to.data_type() != list_from.data_type() {
@@ -825,7 +2984,7 @@ pub fn cast_with_options(
                     "cannot cast list to large-list with different child data".into(),
                 ))
             } else {
-                cast_list_container::<i32, i64>(array, cast_options)
+                cast_list_container::<i32, i64>(array, list_to, cast_options)
             }
         }
         (LargeList(list_from), List(list_to)) => {
@@ -834,16 +2993,49 @@ pub fn cast_with_options(
                     "cannot cast large-list to list with different child data".into(),
                 ))
             } else {
-                cast_list_container::<i64, i32>(array, cast_options)
+                cast_list_container::<i64, i32>(array, list_to, cast_options)
             }
         }
+        (FixedSizeList(_, _), List(ref to)) => {
+            cast_fixed_size_list_to_list::<i32>(array, to, cast_options)
+        }
+        (FixedSizeList(_, _), LargeList(ref to)) => {
+            cast_fixed_size_list_to_list::<i64>(array, to, cast_options)
+        }
+        (List(_), FixedSizeList(ref to, size)) => {
+            cast_list_to_fixed_size_list::<i32>(array, to, *size, to_type, cast_options)
+        }
+        (LargeList(_), FixedSizeList(ref to, size)) => {
+            cast_list_to_fixed_size_list::<i64>(array, to, *size, to_type, cast_options)
+        }
+        (FixedSizeList(_, size_from), FixedSizeList(ref to, size_to))
+            if size_from == size_to =>
+        {
+            cast_fixed_size_list_inner(array, to, to_type, cast_options)
+        }
         (List(_) | LargeList(_), _) => match to_type {
-            Utf8 => cast_list_to_string!(array, i32),
-            LargeUtf8 => cast_list_to_string!(array, i64),
+            Utf8 => match &cast_options.list_delimiter {
+                Some(delimiter) => {
+                    join_list_to_string::<i32>(array, delimiter, cast_options)
+                }
+                None => cast_list_to_string!(array, i32, &cast_options.format_options),
+            },
+            LargeUtf8 => match &cast_options.list_delimiter {
+                Some(delimiter) => {
+                    join_list_to_string::<i64>(array, delimiter, cast_options)
+                }
+                None => cast_list_to_string!(array, i64, &cast_options.format_options),
+            },
             _ => Err(ArrowError::CastError(
                 "Cannot cast list to non-list data types".to_string(),
             )),
         },
+        (Utf8, FixedSizeList(ref to, size)) => {
+            cast_string_to_fixed_size_list::<i32>(array, to, *size, to_type, cast_options)
+        }
+        (LargeUtf8, FixedSizeList(ref to, size)) => {
+            cast_string_to_fixed_size_list::<i64>(array, to, *size, to_type, cast_options)
+        }
         (_, List(ref to)) => {
             cast_primitive_to_list::<i32>(array, to, to_type, cast_options)
         }
@@ -886,325 +3078,69 @@ pub fn cast_with_options(
                 cast_options,
             )
         }
-        (Decimal128(_, scale), _) => {
-            // cast decimal to other type
-            match to_type {
-                UInt8 => cast_decimal_to_integer::<Decimal128Type, UInt8Type>(
-                    array,
-                    10_i128,
-                    *scale,
-                    cast_options,
-                ),
-                UInt16 => cast_decimal_to_integer::<Decimal128Type, UInt16Type>(
-                    array,
-                    10_i128,
-                    *scale,
-                    cast_options,
-                ),
-                UInt32 => cast_decimal_to_integer::<Decimal128Type, UInt32Type>(
-                    array,
-                    10_i128,
-                    *scale,
-                    cast_options,
-                ),
-                UInt64 => cast_decimal_to_integer::<Decimal128Type, UInt64Type>(
-                    array,
-                    10_i128,
-                    *scale,
-                    cast_options,
-                ),
-                Int8 => cast_decimal_to_integer::<Decimal128Type, Int8Type>(
-                    array,
-                    10_i128,
-                    *scale,
-                    cast_options,
-                ),
-                Int16 => cast_decimal_to_integer::<Decimal128Type, Int16Type>(
-                    array,
-                    10_i128,
-                    *scale,
-                    cast_options,
-                ),
-                Int32 => cast_decimal_to_integer::<Decimal128Type, Int32Type>(
-                    array,
-                    10_i128,
-                    *scale,
-                    cast_options,
-                ),
-                Int64 => cast_decimal_to_integer::<Decimal128Type, Int64Type>(
-                    array,
-                    10_i128,
-                    *scale,
-                    cast_options,
-                ),
-                Float32 => {
-                    cast_decimal_to_float::<Decimal128Type, Float32Type, _>(array, |x| {
-                        (x as f64 / 10_f64.powi(*scale as i32)) as f32
-                    })
-                }
-                Float64 => {
-                    cast_decimal_to_float::<Decimal128Type, Float64Type, _>(array, |x| {
-                        x as f64 / 10_f64.powi(*scale as i32)
-                    })
-                }
-                Utf8 => value_to_string::<i32>(array),
-                LargeUtf8 => value_to_string::<i64>(array),
-                Null => Ok(new_null_array(to_type, array.len())),
-                _ => Err(ArrowError::CastError(format!(
-                    "Casting from {from_type:?} to {to_type:?} not supported"
-                ))),
-            }
-        }
-        (Decimal256(_, scale), _) => {
-            // cast decimal to other type
-            match to_type {
-                UInt8 => cast_decimal_to_integer::<Decimal256Type, UInt8Type>(
-                    array,
-                    i256::from_i128(10_i128),
-                    *scale,
-                    cast_options,
-                ),
-                UInt16 => cast_decimal_to_integer::<Decimal256Type, UInt16Type>(
-                    array,
-                    i256::from_i128(10_i128),
-                    *scale,
-                    cast_options,
-                ),
-                UInt32 => cast_decimal_to_integer::<Decimal256Type, UInt32Type>(
-                    array,
-                    i256::from_i128(10_i128),
-                    *scale,
-                    cast_options,
-                ),
-                UInt64 => cast_decimal_to_integer::<Decimal256Type, UInt64Type>(
-                    array,
-                    i256::from_i128(10_i128),
-                    *scale,
-                    cast_options,
-                ),
-                Int8 => cast_decimal_to_integer::<Decimal256Type, Int8Type>(
-                    array,
-                    i256::from_i128(10_i128),
-                    *scale,
-                    cast_options,
-                ),
-                Int16 => cast_decimal_to_integer::<Decimal256Type, Int16Type>(
-                    array,
-                    i256::from_i128(10_i128),
-                    *scale,
-                    cast_options,
-                ),
-                Int32 => cast_decimal_to_integer::<Decimal256Type, Int32Type>(
-                    array,
-                    i256::from_i128(10_i128),
-                    *scale,
-                    cast_options,
-                ),
-                Int64 => cast_decimal_to_integer::<Decimal256Type, Int64Type>(
-                    array,
-                    i256::from_i128(10_i128),
-                    *scale,
-                    cast_options,
-                ),
-                Float32 => {
-                    cast_decimal_to_float::<Decimal256Type, Float32Type, _>(array, |x| {
-                        (x.to_f64().unwrap() / 10_f64.powi(*scale as i32)) as f32
-                    })
-                }
-                Float64 => {
-                    cast_decimal_to_float::<Decimal256Type, Float64Type, _>(array, |x| {
-                        x.to_f64().unwrap() / 10_f64.powi(*scale as i32)
-                    })
-                }
-                Utf8 => value_to_string::<i32>(array),
-                LargeUtf8 => value_to_string::<i64>(array),
-                Null => Ok(new_null_array(to_type, array.len())),
-                _ => Err(ArrowError::CastError(format!(
-                    "Casting from {from_type:?} to {to_type:?} not supported"
-                ))),
-            }
+        (Decimal128(_, _), Int64) if cast_options.reinterpret => {
+            cast_decimal128_to_unscaled_int64(array.as_primitive(), cast_options)
         }
-        (_, Decimal128(precision, scale)) => {
-            // cast data to decimal
-            match from_type {
-                UInt8 => cast_integer_to_decimal::<_, Decimal128Type, _>(
-                    array.as_primitive::<UInt8Type>(),
-                    *precision,
-                    *scale,
-                    10_i128,
-                    cast_options,
-                ),
-                UInt16 => cast_integer_to_decimal::<_, Decimal128Type, _>(
-                    array.as_primitive::<UInt16Type>(),
-                    *precision,
-                    *scale,
-                    10_i128,
-                    cast_options,
-                ),
-                UInt32 => cast_integer_to_decimal::<_, Decimal128Type, _>(
-                    array.as_primitive::<UInt32Type>(),
-                    *precision,
-                    *scale,
-                    10_i128,
-                    cast_options,
-                ),
-                UInt64 => cast_integer_to_decimal::<_, Decimal128Type, _>(
-                    array.as_primitive::<UInt64Type>(),
-                    *precision,
-                    *scale,
-                    10_i128,
-                    cast_options,
-                ),
-                Int8 => cast_integer_to_decimal::<_, Decimal128Type, _>(
-                    array.as_primitive::<Int8Type>(),
-                    *precision,
-                    *scale,
-                    10_i128,
-                    cast_options,
-                ),
-                Int16 => cast_integer_to_decimal::<_, Decimal128Type, _>(
-                    array.as_primitive::<Int16Type>(),
-                    *precision,
-                    *scale,
-                    10_i128,
-                    cast_options,
-                ),
-                Int32 => cast_integer_to_decimal::<_, Decimal128Type, _>(
-                    array.as_primitive::<Int32Type>(),
-                    *precision,
-                    *scale,
-                    10_i128,
-                    cast_options,
-                ),
-                Int64 => cast_integer_to_decimal::<_, Decimal128Type, _>(
-                    array.as_primitive::<Int64Type>(),
-                    *precision,
-                    *scale,
-                    10_i128,
-                    cast_options,
-                ),
-                Float32 => cast_floating_point_to_decimal128(
-                    array.as_primitive::<Float32Type>(),
-                    *precision,
-                    *scale,
-                    cast_options,
-                ),
-                Float64 => cast_floating_point_to_decimal128(
-                    array.as_primitive::<Float64Type>(),
-                    *precision,
-                    *scale,
-                    cast_options,
-                ),
-                Utf8 => cast_string_to_decimal::<Decimal128Type, i32>(
-                    array,
-                    *precision,
-                    *scale,
-                    cast_options,
-                ),
-                LargeUtf8 => cast_string_to_decimal::<Decimal128Type, i64>(
-                    array,
-                    *precision,
-                    *scale,
-                    cast_options,
-                ),
-                Null => Ok(new_null_array(to_type, array.len())),
-                _ => Err(ArrowError::CastError(format!(
-                    "Casting from {from_type:?} to {to_type:?} not supported"
-                ))),
-            }
+        (Int64, Decimal128(precision, scale)) if cast_options.reinterpret => {
+            cast_unscaled_int64_to_decimal128(
+                array.as_primitive(),
+                *precision,
+                *scale,
+                cast_options,
+            )
         }
-        (_, Decimal256(precision, scale)) => {
-            // cast data to decimal
-            match from_type {
-                UInt8 => cast_integer_to_decimal::<_, Decimal256Type, _>(
-                    array.as_primitive::<UInt8Type>(),
-                    *precision,
-                    *scale,
-                    i256::from_i128(10_i128),
-                    cast_options,
-                ),
-                UInt16 => cast_integer_to_decimal::<_, Decimal256Type, _>(
-                    array.as_primitive::<UInt16Type>(),
-                    *precision,
-                    *scale,
-                    i256::from_i128(10_i128),
-                    cast_options,
-                ),
-                UInt32 => cast_integer_to_decimal::<_, Decimal256Type, _>(
-                    array.as_primitive::<UInt32Type>(),
-                    *precision,
-                    *scale,
-                    i256::from_i128(10_i128),
-                    cast_options,
-                ),
-                UInt64 => cast_integer_to_decimal::<_, Decimal256Type, _>(
-                    array.as_primitive::<UInt64Type>(),
-                    *precision,
-                    *scale,
-                    i256::from_i128(10_i128),
-                    cast_options,
-                ),
-                Int8 => cast_integer_to_decimal::<_, Decimal256Type, _>(
-                    array.as_primitive::<Int8Type>(),
-                    *precision,
-                    *scale,
-                    i256::from_i128(10_i128),
-                    cast_options,
-                ),
-                Int16 => cast_integer_to_decimal::<_, Decimal256Type, _>(
-                    array.as_primitive::<Int16Type>(),
-                    *precision,
-                    *scale,
-                    i256::from_i128(10_i128),
-                    cast_options,
-                ),
-                Int32 => cast_integer_to_decimal::<_, Decimal256Type, _>(
-                    array.as_primitive::<Int32Type>(),
-                    *precision,
-                    *scale,
-                    i256::from_i128(10_i128),
-                    cast_options,
-                ),
-                Int64 => cast_integer_to_decimal::<_, Decimal256Type, _>(
-                    array.as_primitive::<Int64Type>(),
-                    *precision,
-                    *scale,
-                    i256::from_i128(10_i128),
-                    cast_options,
-                ),
-                Float32 => cast_floating_point_to_decimal256(
-                    array.as_primitive::<Float32Type>(),
-                    *precision,
-                    *scale,
-                    cast_options,
-                ),
-                Float64 => cast_floating_point_to_decimal256(
-                    array.as_primitive::<Float64Type>(),
-                    *precision,
-                    *scale,
-                    cast_options,
-                ),
-                Utf8 => cast_string_to_decimal::<Decimal256Type, i32>(
-                    array,
-                    *precision,
-                    *scale,
-                    cast_options,
-                ),
-                LargeUtf8 => cast_string_to_decimal::<Decimal256Type, i64>(
-                    array,
-                    *precision,
-                    *scale,
-                    cast_options,
-                ),
-                Null => Ok(new_null_array(to_type, array.len())),
-                _ => Err(ArrowError::CastError(format!(
-                    "Casting from {from_type:?} to {to_type:?} not supported"
-                ))),
-            }
+        (Decimal128(_, scale), _) => cast_from_decimal::<Decimal128Type>(
+            array,
+            *scale,
+            from_type,
+            to_type,
+            cast_options,
+        ),
+        (Decimal256(_, scale), _) => cast_from_decimal::<Decimal256Type>(
+            array,
+            *scale,
+            from_type,
+            to_type,
+            cast_options,
+        ),
+        (_, Decimal128(precision, scale)) => cast_to_decimal::<Decimal128Type>(
+            array,
+            *precision,
+            *scale,
+            from_type,
+            to_type,
+            cast_options,
+        ),
+        (_, Decimal256(precision, scale)) => cast_to_decimal::<Decimal256Type>(
+            array,
+            *precision,
+            *scale,
+            from_type,
+            to_type,
+            cast_options,
+        ),
+        (Struct(_), Struct(to_fields)) => {
+            cast_struct_to_struct(array, to_fields, cast_options)
         }
         (Struct(_), _) => Err(ArrowError::CastError(
             "Cannot cast from struct to other types".to_string(),
         )),
+        (Timestamp(unit, _), Struct(fields)) => match unit {
+            TimeUnit::Second => cast_timestamp_to_date_time_struct::<TimestampSecondType>(
+                array,
+                fields,
+                cast_options,
+            ),
+            TimeUnit::Millisecond => cast_timestamp_to_date_time_struct::<
+                TimestampMillisecondType,
+            >(array, fields, cast_options),
+            TimeUnit::Microsecond => cast_timestamp_to_date_time_struct::<
+                TimestampMicrosecondType,
+            >(array, fields, cast_options),
+            TimeUnit::Nanosecond => cast_timestamp_to_date_time_struct::<
+                TimestampNanosecondType,
+            >(array, fields, cast_options),
+        },
         (_, Struct(_)) => Err(ArrowError::CastError(
             "Cannot cast to struct from other types".to_string(),
         )),
@@ -1238,28 +3174,18 @@ pub fn cast_with_options(
             Float16 => cast_bool_to_numeric::<Float16Type>(array, cast_options),
             Float32 => cast_bool_to_numeric::<Float32Type>(array, cast_options),
             Float64 => cast_bool_to_numeric::<Float64Type>(array, cast_options),
-            Utf8 => {
-                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
-                Ok(Arc::new(
-                    array
-                        .iter()
-                        .map(|value| value.map(|value| if value { "1" } else { "0" }))
-                        .collect::<StringArray>(),
-                ))
-            }
-            LargeUtf8 => {
-                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
-                Ok(Arc::new(
-                    array
-                        .iter()
-                        .map(|value| value.map(|value| if value { "1" } else { "0" }))
-                        .collect::<LargeStringArray>(),
-                ))
-            }
+            Utf8 => cast_bool_to_string::<i32>(array, cast_options),
+            LargeUtf8 => cast_bool_to_string::<i64>(array, cast_options),
             _ => Err(ArrowError::CastError(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
         },
+        (Binary | LargeBinary, Utf8 | LargeUtf8) if cast_options.base64.is_some() => {
+            cast_binary_to_base64(array, to_type, cast_options)
+        }
+        (Utf8 | LargeUtf8, Binary | LargeBinary) if cast_options.base64.is_some() => {
+            cast_base64_to_binary(array, to_type, cast_options)
+        }
         (Utf8, _) => match to_type {
             UInt8 => cast_string_to_numeric::<UInt8Type, i32>(array, cast_options),
             UInt16 => cast_string_to_numeric::<UInt16Type, i32>(array, cast_options),
@@ -1269,6 +3195,7 @@ pub fn cast_with_options(
             Int16 => cast_string_to_numeric::<Int16Type, i32>(array, cast_options),
             Int32 => cast_string_to_numeric::<Int32Type, i32>(array, cast_options),
             Int64 => cast_string_to_numeric::<Int64Type, i32>(array, cast_options),
+            Float16 => cast_string_to_float16::<i32>(array, cast_options),
             Float32 => cast_string_to_numeric::<Float32Type, i32>(array, cast_options),
             Float64 => cast_string_to_numeric::<Float64Type, i32>(array, cast_options),
             Date32 => cast_string_to_date32::<i32>(array, cast_options),
@@ -1312,6 +3239,21 @@ pub fn cast_with_options(
             Interval(IntervalUnit::MonthDayNano) => {
                 cast_string_to_month_day_nano_interval::<i32>(array, cast_options)
             }
+            Duration(TimeUnit::Second) => {
+                cast_string_to_duration::<i32, DurationSecondType>(array, cast_options)
+            }
+            Duration(TimeUnit::Millisecond) => {
+                cast_string_to_duration::<i32, DurationMillisecondType>(array, cast_options)
+            }
+            Duration(TimeUnit::Microsecond) => {
+                cast_string_to_duration::<i32, DurationMicrosecondType>(array, cast_options)
+            }
+            Duration(TimeUnit::Nanosecond) => {
+                cast_string_to_duration::<i32, DurationNanosecondType>(array, cast_options)
+            }
+            FixedSizeBinary(size) => {
+                cast_string_to_fixed_size_binary::<i32>(array, *size, cast_options)
+            }
             _ => Err(ArrowError::CastError(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
@@ -1325,6 +3267,7 @@ pub fn cast_with_options(
             Int16 => cast_string_to_numeric::<Int16Type, i64>(array, cast_options),
             Int32 => cast_string_to_numeric::<Int32Type, i64>(array, cast_options),
             Int64 => cast_string_to_numeric::<Int64Type, i64>(array, cast_options),
+            Float16 => cast_string_to_float16::<i64>(array, cast_options),
             Float32 => cast_string_to_numeric::<Float32Type, i64>(array, cast_options),
             Float64 => cast_string_to_numeric::<Float64Type, i64>(array, cast_options),
             Date32 => cast_string_to_date32::<i64>(array, cast_options),
@@ -1371,6 +3314,21 @@ pub fn cast_with_options(
             Interval(IntervalUnit::MonthDayNano) => {
                 cast_string_to_month_day_nano_interval::<i64>(array, cast_options)
             }
+            Duration(TimeUnit::Second) => {
+                cast_string_to_duration::<i64, DurationSecondType>(array, cast_options)
+            }
+            Duration(TimeUnit::Millisecond) => {
+                cast_string_to_duration::<i64, DurationMillisecondType>(array, cast_options)
+            }
+            Duration(TimeUnit::Microsecond) => {
+                cast_string_to_duration::<i64, DurationMicrosecondType>(array, cast_options)
+            }
+            Duration(TimeUnit::Nanosecond) => {
+                cast_string_to_duration::<i64, DurationNanosecondType>(array, cast_options)
+            }
+            FixedSizeBinary(size) => {
+                cast_string_to_fixed_size_binary::<i64>(array, *size, cast_options)
+            }
             _ => Err(ArrowError::CastError(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
@@ -1409,12 +3367,18 @@ pub fn cast_with_options(
             Binary => cast_fixed_size_binary_to_binary::<i32>(array, *size),
             LargeBinary =>
                 cast_fixed_size_binary_to_binary::<i64>(array, *size),
+            Utf8 => cast_fixed_size_binary_to_hex::<i32>(array, *size),
+            LargeUtf8 => cast_fixed_size_binary_to_hex::<i64>(array, *size),
             _ => Err(ArrowError::CastError(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
         },
-        (from_type, LargeUtf8) if from_type.is_primitive() => value_to_string::<i64>(array),
-        (from_type, Utf8) if from_type.is_primitive() => value_to_string::<i32>(array),
+        (from_type, LargeUtf8) if from_type.is_primitive() => {
+            value_to_string::<i64>(array, cast_options)
+        }
+        (from_type, Utf8) if from_type.is_primitive() => {
+            value_to_string::<i32>(array, cast_options)
+        }
         // start numeric casts
         (UInt8, UInt16) => {
             cast_numeric_arrays::<UInt8Type, UInt16Type>(array, cast_options)
@@ -1435,6 +3399,9 @@ pub fn cast_with_options(
         (UInt8, Int64) => {
             cast_numeric_arrays::<UInt8Type, Int64Type>(array, cast_options)
         }
+        (UInt8, Float16) => {
+            cast_numeric_arrays::<UInt8Type, Float16Type>(array, cast_options)
+        }
         (UInt8, Float32) => {
             cast_numeric_arrays::<UInt8Type, Float32Type>(array, cast_options)
         }
@@ -1463,6 +3430,9 @@ pub fn cast_with_options(
         (UInt16, Int64) => {
             cast_numeric_arrays::<UInt16Type, Int64Type>(array, cast_options)
         }
+        (UInt16, Float16) => {
+            cast_numeric_arrays::<UInt16Type, Float16Type>(array, cast_options)
+        }
         (UInt16, Float32) => {
             cast_numeric_arrays::<UInt16Type, Float32Type>(array, cast_options)
         }
@@ -1491,6 +3461,9 @@ pub fn cast_with_options(
         (UInt32, Int64) => {
             cast_numeric_arrays::<UInt32Type, Int64Type>(array, cast_options)
         }
+        (UInt32, Float16) => {
+            cast_numeric_arrays::<UInt32Type, Float16Type>(array, cast_options)
+        }
         (UInt32, Float32) => {
             cast_numeric_arrays::<UInt32Type, Float32Type>(array, cast_options)
         }
@@ -1519,6 +3492,9 @@ pub fn cast_with_options(
         (UInt64, Int64) => {
             cast_numeric_arrays::<UInt64Type, Int64Type>(array, cast_options)
         }
+        (UInt64, Float16) => {
+            cast_numeric_arrays::<UInt64Type, Float16Type>(array, cast_options)
+        }
         (UInt64, Float32) => {
             cast_numeric_arrays::<UInt64Type, Float32Type>(array, cast_options)
         }
@@ -1539,6 +3515,9 @@ pub fn cast_with_options(
         (Int8, Int16) => cast_numeric_arrays::<Int8Type, Int16Type>(array, cast_options),
         (Int8, Int32) => cast_numeric_arrays::<Int8Type, Int32Type>(array, cast_options),
         (Int8, Int64) => cast_numeric_arrays::<Int8Type, Int64Type>(array, cast_options),
+        (Int8, Float16) => {
+            cast_numeric_arrays::<Int8Type, Float16Type>(array, cast_options)
+        }
         (Int8, Float32) => {
             cast_numeric_arrays::<Int8Type, Float32Type>(array, cast_options)
         }
@@ -1565,6 +3544,9 @@ pub fn cast_with_options(
         (Int16, Int64) => {
             cast_numeric_arrays::<Int16Type, Int64Type>(array, cast_options)
         }
+        (Int16, Float16) => {
+            cast_numeric_arrays::<Int16Type, Float16Type>(array, cast_options)
+        }
         (Int16, Float32) => {
             cast_numeric_arrays::<Int16Type, Float32Type>(array, cast_options)
         }
@@ -1591,6 +3573,9 @@ pub fn cast_with_options(
         (Int32, Int64) => {
             cast_numeric_arrays::<Int32Type, Int64Type>(array, cast_options)
         }
+        (Int32, Float16) => {
+            cast_numeric_arrays::<Int32Type, Float16Type>(array, cast_options)
+        }
         (Int32, Float32) => {
             cast_numeric_arrays::<Int32Type, Float32Type>(array, cast_options)
         }
@@ -1617,6 +3602,9 @@ pub fn cast_with_options(
         (Int64, Int32) => {
             cast_numeric_arrays::<Int64Type, Int32Type>(array, cast_options)
         }
+        (Int64, Float16) => {
+            cast_numeric_arrays::<Int64Type, Float16Type>(array, cast_options)
+        }
         (Int64, Float32) => {
             cast_numeric_arrays::<Int64Type, Float32Type>(array, cast_options)
         }
@@ -1624,6 +3612,37 @@ pub fn cast_with_options(
             cast_numeric_arrays::<Int64Type, Float64Type>(array, cast_options)
         }
 
+        (Float16, UInt8) => {
+            cast_numeric_arrays::<Float16Type, UInt8Type>(array, cast_options)
+        }
+        (Float16, UInt16) => {
+            cast_numeric_arrays::<Float16Type, UInt16Type>(array, cast_options)
+        }
+        (Float16, UInt32) => {
+            cast_numeric_arrays::<Float16Type, UInt32Type>(array, cast_options)
+        }
+        (Float16, UInt64) => {
+            cast_numeric_arrays::<Float16Type, UInt64Type>(array, cast_options)
+        }
+        (Float16, Int8) => {
+            cast_numeric_arrays::<Float16Type, Int8Type>(array, cast_options)
+        }
+        (Float16, Int16) => {
+            cast_numeric_arrays::<Float16Type, Int16Type>(array, cast_options)
+        }
+        (Float16, Int32) => {
+            cast_numeric_arrays::<Float16Type, Int32Type>(array, cast_options)
+        }
+        (Float16, Int64) => {
+            cast_numeric_arrays::<Float16Type, Int64Type>(array, cast_options)
+        }
+        (Float16, Float32) => {
+            cast_numeric_arrays::<Float16Type, Float32Type>(array, cast_options)
+        }
+        (Float16, Float64) => {
+            cast_numeric_arrays::<Float16Type, Float64Type>(array, cast_options)
+        }
+
         (Float32, UInt8) => {
             cast_numeric_arrays::<Float32Type, UInt8Type>(array, cast_options)
         }
@@ -1648,6 +3667,9 @@ pub fn cast_with_options(
         (Float32, Int64) => {
             cast_numeric_arrays::<Float32Type, Int64Type>(array, cast_options)
         }
+        (Float32, Float16) => {
+            cast_numeric_arrays::<Float32Type, Float16Type>(array, cast_options)
+        }
         (Float32, Float64) => {
             cast_numeric_arrays::<Float32Type, Float64Type>(array, cast_options)
         }
@@ -1676,11 +3698,41 @@ pub fn cast_with_options(
         (Float64, Int64) => {
             cast_numeric_arrays::<Float64Type, Int64Type>(array, cast_options)
         }
+        (Float64, Float16) => {
+            cast_numeric_arrays::<Float64Type, Float16Type>(array, cast_options)
+        }
         (Float64, Float32) => {
+            count_precision_loss(array.as_primitive::<Float64Type>(), cast_options, |v| {
+                !v.is_nan() && v as f32 as f64 != v
+            });
             cast_numeric_arrays::<Float64Type, Float32Type>(array, cast_options)
         }
         // end numeric casts
 
+        (Float32, Interval(IntervalUnit::MonthDayNano)) => {
+            cast_float_to_interval::<Float32Type>(array, cast_options)
+        }
+        (Float64, Interval(IntervalUnit::MonthDayNano)) => {
+            cast_float_to_interval::<Float64Type>(array, cast_options)
+        }
+        (Int64, Interval(IntervalUnit::MonthDayNano)) => {
+            let unit = cast_options.numeric_interval_unit.factor();
+            cast_numeric_to_interval::<Int64Type, _>(array, cast_options, |v| {
+                v.checked_mul(unit)
+            })
+        }
+        (Interval(IntervalUnit::MonthDayNano), Int64) => {
+            // Truncates towards zero, as for decimals cast to integers
+            let unit = cast_options.numeric_interval_unit.factor();
+            cast_interval_to_numeric::<Int64Type, _>(array, cast_options, |v| v / unit)
+        }
+        (Interval(IntervalUnit::MonthDayNano), Float64) => {
+            let unit = cast_options.numeric_interval_unit.factor() as f64;
+            cast_interval_to_numeric::<Float64Type, _>(array, cast_options, |v| {
+                v as f64 / unit
+            })
+        }
+
         // temporal casts
         (Int32, Date32) => cast_reinterpret_arrays::<Int32Type, Date32Type>(array),
         (Int32, Date64) => cast_with_options(
@@ -1689,10 +3741,10 @@ pub fn cast_with_options(
             cast_options,
         ),
         (Int32, Time32(TimeUnit::Second)) => {
-            cast_reinterpret_arrays::<Int32Type, Time32SecondType>(array)
+            cast_integer_to_time::<Int32Type, Time32SecondType>(array, cast_options)
         }
         (Int32, Time32(TimeUnit::Millisecond)) => {
-            cast_reinterpret_arrays::<Int32Type, Time32MillisecondType>(array)
+            cast_integer_to_time::<Int32Type, Time32MillisecondType>(array, cast_options)
         }
         // No support for microsecond/nanosecond with i32
         (Date32, Int32) => cast_reinterpret_arrays::<Date32Type, Int32Type>(array),
@@ -1713,12 +3765,34 @@ pub fn cast_with_options(
             &Date32,
             cast_options,
         ),
+        (UInt32, Date32) => {
+            cast_unsigned_to_temporal::<UInt32Type, Date32Type>(array, to_type, cast_options)
+        }
+        (UInt64, Date64) => {
+            cast_unsigned_to_temporal::<UInt64Type, Date64Type>(array, to_type, cast_options)
+        }
+        (UInt64, Timestamp(TimeUnit::Second, _)) => cast_unsigned_to_temporal::<
+            UInt64Type,
+            TimestampSecondType,
+        >(array, to_type, cast_options),
+        (UInt64, Timestamp(TimeUnit::Millisecond, _)) => cast_unsigned_to_temporal::<
+            UInt64Type,
+            TimestampMillisecondType,
+        >(array, to_type, cast_options),
+        (UInt64, Timestamp(TimeUnit::Microsecond, _)) => cast_unsigned_to_temporal::<
+            UInt64Type,
+            TimestampMicrosecondType,
+        >(array, to_type, cast_options),
+        (UInt64, Timestamp(TimeUnit::Nanosecond, _)) => cast_unsigned_to_temporal::<
+            UInt64Type,
+            TimestampNanosecondType,
+        >(array, to_type, cast_options),
         // No support for second/milliseconds with i64
         (Int64, Time64(TimeUnit::Microsecond)) => {
-            cast_reinterpret_arrays::<Int64Type, Time64MicrosecondType>(array)
+            cast_integer_to_time::<Int64Type, Time64MicrosecondType>(array, cast_options)
         }
         (Int64, Time64(TimeUnit::Nanosecond)) => {
-            cast_reinterpret_arrays::<Int64Type, Time64NanosecondType>(array)
+            cast_integer_to_time::<Int64Type, Time64NanosecondType>(array, cast_options)
         }
 
         (Date64, Int64) => cast_reinterpret_arrays::<Date64Type, Int64Type>(array),
@@ -1737,15 +3811,15 @@ pub fn cast_with_options(
             array.as_primitive::<Date32Type>()
                 .unary::<_, Date64Type>(|x| x as i64 * MILLISECONDS_IN_DAY),
         )),
-        (Date64, Date32) => Ok(Arc::new(
-            array.as_primitive::<Date64Type>()
-                .unary::<_, Date32Type>(|x| (x / MILLISECONDS_IN_DAY) as i32),
-        )),
+        (Date64, Date32) => cast_date64_to_date32(array, cast_options),
 
-        (Time32(TimeUnit::Second), Time32(TimeUnit::Millisecond)) => Ok(Arc::new(
-            array.as_primitive::<Time32SecondType>()
-                .unary::<_, Time32MillisecondType>(|x| x * MILLISECONDS as i32),
-        )),
+        (Time32(TimeUnit::Second), Time32(TimeUnit::Millisecond)) => {
+            cast_with_multiplier::<Time32SecondType, Time32MillisecondType>(
+                array,
+                MILLISECONDS as i32,
+                cast_options,
+            )
+        }
         (Time32(TimeUnit::Second), Time64(TimeUnit::Microsecond)) => Ok(Arc::new(
             array.as_primitive::<Time32SecondType>()
                 .unary::<_, Time64MicrosecondType>(|x| x as i64 * MICROSECONDS),
@@ -1768,7 +3842,7 @@ pub fn cast_with_options(
         (Time32(TimeUnit::Millisecond), Time64(TimeUnit::Nanosecond)) => Ok(Arc::new(
             array.as_primitive::<Time32MillisecondType>()
                 .unary::<_, Time64NanosecondType>(|x| {
-                    x as i64 * (MICROSECONDS / NANOSECONDS)
+                    x as i64 * (NANOSECONDS / MILLISECONDS)
                 }),
         )),
 
@@ -1782,10 +3856,13 @@ pub fn cast_with_options(
                     (x / (MICROSECONDS / MILLISECONDS)) as i32
                 }),
         )),
-        (Time64(TimeUnit::Microsecond), Time64(TimeUnit::Nanosecond)) => Ok(Arc::new(
-            array.as_primitive::<Time64MicrosecondType>()
-                .unary::<_, Time64NanosecondType>(|x| x * (NANOSECONDS / MICROSECONDS)),
-        )),
+        (Time64(TimeUnit::Microsecond), Time64(TimeUnit::Nanosecond)) => {
+            cast_with_multiplier::<Time64MicrosecondType, Time64NanosecondType>(
+                array,
+                NANOSECONDS / MICROSECONDS,
+                cast_options,
+            )
+        }
 
         (Time64(TimeUnit::Nanosecond), Time32(TimeUnit::Second)) => Ok(Arc::new(
             array.as_primitive::<Time64NanosecondType>()
@@ -1821,8 +3898,31 @@ pub fn cast_with_options(
             tz.clone(),
         )),
 
-        (Timestamp(from_unit, _), Timestamp(to_unit, to_tz)) => {
-            let array = cast_with_options(array, &Int64, cast_options)?;
+        (Timestamp(from_unit, from_tz), Timestamp(to_unit, to_tz)) => {
+            let array = match (from_tz, to_tz) {
+                (Some(from_tz), Some(to_tz))
+                    if from_tz != to_tz
+                        && cast_options.timezone_conversion
+                            == TimezoneConversion::WallClock =>
+                {
+                    let array = match from_unit {
+                        TimeUnit::Second => cast_timestamp_reinterpret_tz::<
+                            TimestampSecondType,
+                        >(array, to_tz, cast_options)?,
+                        TimeUnit::Millisecond => cast_timestamp_reinterpret_tz::<
+                            TimestampMillisecondType,
+                        >(array, to_tz, cast_options)?,
+                        TimeUnit::Microsecond => cast_timestamp_reinterpret_tz::<
+                            TimestampMicrosecondType,
+                        >(array, to_tz, cast_options)?,
+                        TimeUnit::Nanosecond => cast_timestamp_reinterpret_tz::<
+                            TimestampNanosecondType,
+                        >(array, to_tz, cast_options)?,
+                    };
+                    cast_with_options(&array, &Int64, cast_options)?
+                }
+                _ => cast_with_options(array, &Int64, cast_options)?,
+            };
             let time_array = array.as_primitive::<Int64Type>();
             let from_size = time_unit_multiple(from_unit);
             let to_size = time_unit_multiple(to_unit);
@@ -1831,6 +3931,7 @@ pub fn cast_with_options(
             let converted = match from_size.cmp(&to_size) {
                 Ordering::Greater => {
                     let divisor = from_size / to_size;
+                    count_precision_loss(time_array, cast_options, |o| o % divisor != 0);
                     time_array.unary::<_, Int64Type>(|o| o / divisor)
                 }
                 Ordering::Equal => time_array.clone(),
@@ -1849,227 +3950,65 @@ pub fn cast_with_options(
                 to_tz.clone(),
             ))
         }
-        (Timestamp(from_unit, _), Date32) => {
-            let array = cast_with_options(array, &Int64, cast_options)?;
-            let time_array = array.as_primitive::<Int64Type>();
-            let from_size = time_unit_multiple(from_unit) * SECONDS_IN_DAY;
-
-            let mut b = Date32Builder::with_capacity(array.len());
-
-            for i in 0..array.len() {
-                if time_array.is_null(i) {
-                    b.append_null();
-                } else {
-                    b.append_value((time_array.value(i) / from_size) as i32);
-                }
-            }
-
-            Ok(Arc::new(b.finish()) as ArrayRef)
+        (Timestamp(TimeUnit::Second, _), Date32 | Date64) => {
+            cast_timestamp_to_date::<TimestampSecondType>(array, to_type, cast_options)
         }
-        (Timestamp(TimeUnit::Second, _), Date64) => Ok(Arc::new(
-            match cast_options.safe {
-                true => {
-                    // change error to None
-                    array.as_primitive::<TimestampSecondType>()
-                        .unary_opt::<_, Date64Type>(|x| {
-                            x.checked_mul(MILLISECONDS)
-                        })
-                }
-                false => {
-                    array.as_primitive::<TimestampSecondType>().try_unary::<_, Date64Type, _>(
-                        |x| {
-                            x.mul_checked(MILLISECONDS)
-                        },
-                    )?
-                }
-            },
-        )),
-        (Timestamp(TimeUnit::Millisecond, _), Date64) => {
-            cast_reinterpret_arrays::<TimestampMillisecondType, Date64Type>(array)
+        (Timestamp(TimeUnit::Millisecond, _), Date32 | Date64) => {
+            cast_timestamp_to_date::<TimestampMillisecondType>(array, to_type, cast_options)
+        }
+        (Timestamp(TimeUnit::Microsecond, _), Date32 | Date64) => {
+            cast_timestamp_to_date::<TimestampMicrosecondType>(array, to_type, cast_options)
+        }
+        (Timestamp(TimeUnit::Nanosecond, _), Date32 | Date64) => {
+            cast_timestamp_to_date::<TimestampNanosecondType>(array, to_type, cast_options)
         }
-        (Timestamp(TimeUnit::Microsecond, _), Date64) => Ok(Arc::new(
-            array.as_primitive::<TimestampMicrosecondType>()
-                .unary::<_, Date64Type>(|x| x / (MICROSECONDS / MILLISECONDS)),
-        )),
-        (Timestamp(TimeUnit::Nanosecond, _), Date64) => Ok(Arc::new(
-            array.as_primitive::<TimestampNanosecondType>()
-                .unary::<_, Date64Type>(|x| x / (NANOSECONDS / MILLISECONDS)),
-        )),
         (Timestamp(TimeUnit::Second, tz), Time64(TimeUnit::Microsecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampSecondType>()
-                    .try_unary::<_, Time64MicrosecondType, ArrowError>(|x| {
-                        Ok(time_to_time64us(as_time_res_with_timezone::<
-                            TimestampSecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampSecondType, Time64MicrosecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Second, tz), Time64(TimeUnit::Nanosecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampSecondType>()
-                    .try_unary::<_, Time64NanosecondType, ArrowError>(|x| {
-                        Ok(time_to_time64ns(as_time_res_with_timezone::<
-                            TimestampSecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampSecondType, Time64NanosecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Millisecond, tz), Time64(TimeUnit::Microsecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampMillisecondType>()
-                    .try_unary::<_, Time64MicrosecondType, ArrowError>(|x| {
-                        Ok(time_to_time64us(as_time_res_with_timezone::<
-                            TimestampMillisecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampMillisecondType, Time64MicrosecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Millisecond, tz), Time64(TimeUnit::Nanosecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampMillisecondType>()
-                    .try_unary::<_, Time64NanosecondType, ArrowError>(|x| {
-                        Ok(time_to_time64ns(as_time_res_with_timezone::<
-                            TimestampMillisecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampMillisecondType, Time64NanosecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Microsecond, tz), Time64(TimeUnit::Microsecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampMicrosecondType>()
-                    .try_unary::<_, Time64MicrosecondType, ArrowError>(|x| {
-                        Ok(time_to_time64us(as_time_res_with_timezone::<
-                            TimestampMicrosecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampMicrosecondType, Time64MicrosecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Microsecond, tz), Time64(TimeUnit::Nanosecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampMicrosecondType>()
-                    .try_unary::<_, Time64NanosecondType, ArrowError>(|x| {
-                        Ok(time_to_time64ns(as_time_res_with_timezone::<
-                            TimestampMicrosecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampMicrosecondType, Time64NanosecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Nanosecond, tz), Time64(TimeUnit::Microsecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampNanosecondType>()
-                    .try_unary::<_, Time64MicrosecondType, ArrowError>(|x| {
-                        Ok(time_to_time64us(as_time_res_with_timezone::<
-                            TimestampNanosecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampNanosecondType, Time64MicrosecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Nanosecond, tz), Time64(TimeUnit::Nanosecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampNanosecondType>()
-                    .try_unary::<_, Time64NanosecondType, ArrowError>(|x| {
-                        Ok(time_to_time64ns(as_time_res_with_timezone::<
-                            TimestampNanosecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampNanosecondType, Time64NanosecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Second, tz), Time32(TimeUnit::Second)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampSecondType>()
-                    .try_unary::<_, Time32SecondType, ArrowError>(|x| {
-                        Ok(time_to_time32s(as_time_res_with_timezone::<
-                            TimestampSecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampSecondType, Time32SecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Second, tz), Time32(TimeUnit::Millisecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampSecondType>()
-                    .try_unary::<_, Time32MillisecondType, ArrowError>(|x| {
-                        Ok(time_to_time32ms(as_time_res_with_timezone::<
-                            TimestampSecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampSecondType, Time32MillisecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Millisecond, tz), Time32(TimeUnit::Second)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampMillisecondType>()
-                    .try_unary::<_, Time32SecondType, ArrowError>(|x| {
-                        Ok(time_to_time32s(as_time_res_with_timezone::<
-                            TimestampMillisecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampMillisecondType, Time32SecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Millisecond, tz), Time32(TimeUnit::Millisecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampMillisecondType>()
-                    .try_unary::<_, Time32MillisecondType, ArrowError>(|x| {
-                        Ok(time_to_time32ms(as_time_res_with_timezone::<
-                            TimestampMillisecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampMillisecondType, Time32MillisecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Microsecond, tz), Time32(TimeUnit::Second)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampMicrosecondType>()
-                    .try_unary::<_, Time32SecondType, ArrowError>(|x| {
-                        Ok(time_to_time32s(as_time_res_with_timezone::<
-                            TimestampMicrosecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampMicrosecondType, Time32SecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Microsecond, tz), Time32(TimeUnit::Millisecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampMicrosecondType>()
-                    .try_unary::<_, Time32MillisecondType, ArrowError>(|x| {
-                        Ok(time_to_time32ms(as_time_res_with_timezone::<
-                            TimestampMicrosecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampMicrosecondType, Time32MillisecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Nanosecond, tz), Time32(TimeUnit::Second)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampNanosecondType>()
-                    .try_unary::<_, Time32SecondType, ArrowError>(|x| {
-                        Ok(time_to_time32s(as_time_res_with_timezone::<
-                            TimestampNanosecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampNanosecondType, Time32SecondType>(array, tz.as_deref())
         }
         (Timestamp(TimeUnit::Nanosecond, tz), Time32(TimeUnit::Millisecond)) => {
-            let tz = tz.as_ref().map(|tz| tz.parse()).transpose()?;
-            Ok(Arc::new(
-                array.as_primitive::<TimestampNanosecondType>()
-                    .try_unary::<_, Time32MillisecondType, ArrowError>(|x| {
-                        Ok(time_to_time32ms(as_time_res_with_timezone::<
-                            TimestampNanosecondType,
-                        >(x, tz)?))
-                    })?,
-            ))
+            cast_timestamp_to_time::<TimestampNanosecondType, Time32MillisecondType>(array, tz.as_deref())
         }
 
         (Date64, Timestamp(TimeUnit::Second, None)) => Ok(Arc::new(
@@ -2079,16 +4018,20 @@ pub fn cast_with_options(
         (Date64, Timestamp(TimeUnit::Millisecond, None)) => {
             cast_reinterpret_arrays::<Date64Type, TimestampMillisecondType>(array)
         }
-        (Date64, Timestamp(TimeUnit::Microsecond, None)) => Ok(Arc::new(
-            array.as_primitive::<Date64Type>().unary::<_, TimestampMicrosecondType>(
-                |x| x * (MICROSECONDS / MILLISECONDS),
-            ),
-        )),
-        (Date64, Timestamp(TimeUnit::Nanosecond, None)) => Ok(Arc::new(
-            array.as_primitive::<Date64Type>().unary::<_, TimestampNanosecondType>(
-                |x| x * (NANOSECONDS / MILLISECONDS),
-            ),
-        )),
+        (Date64, Timestamp(TimeUnit::Microsecond, None)) => {
+            cast_with_multiplier::<Date64Type, TimestampMicrosecondType>(
+                array,
+                MICROSECONDS / MILLISECONDS,
+                cast_options,
+            )
+        }
+        (Date64, Timestamp(TimeUnit::Nanosecond, None)) => {
+            cast_with_multiplier::<Date64Type, TimestampNanosecondType>(
+                array,
+                NANOSECONDS / MILLISECONDS,
+                cast_options,
+            )
+        }
         (Date32, Timestamp(TimeUnit::Second, None)) => Ok(Arc::new(
             array.as_primitive::<Date32Type>()
                 .unary::<_, TimestampSecondType>(|x| (x as i64) * SECONDS_IN_DAY),
@@ -2098,15 +4041,20 @@ pub fn cast_with_options(
                 |x| (x as i64) * MILLISECONDS_IN_DAY,
             ),
         )),
-        (Date32, Timestamp(TimeUnit::Microsecond, None)) => Ok(Arc::new(
-            array.as_primitive::<Date32Type>().unary::<_, TimestampMicrosecondType>(
-                |x| (x as i64) * MICROSECONDS_IN_DAY,
-            ),
-        )),
-        (Date32, Timestamp(TimeUnit::Nanosecond, None)) => Ok(Arc::new(
-            array.as_primitive::<Date32Type>()
-                .unary::<_, TimestampNanosecondType>(|x| (x as i64) * NANOSECONDS_IN_DAY),
-        )),
+        (Date32, Timestamp(TimeUnit::Microsecond, None)) => {
+            cast_with_multiplier::<Date32Type, TimestampMicrosecondType>(
+                array,
+                MICROSECONDS_IN_DAY,
+                cast_options,
+            )
+        }
+        (Date32, Timestamp(TimeUnit::Nanosecond, None)) => {
+            cast_with_multiplier::<Date32Type, TimestampNanosecondType>(
+                array,
+                NANOSECONDS_IN_DAY,
+                cast_options,
+            )
+        }
         (Int64, Duration(TimeUnit::Second)) => {
             cast_reinterpret_arrays::<Int64Type, DurationSecondType>(array)
         }
@@ -2160,13 +4108,27 @@ pub fn cast_with_options(
             cast_numeric_arrays::<IntervalYearMonthType, Int64Type>(array, cast_options)
         }
         (Interval(IntervalUnit::DayTime), Int64) => {
-            cast_reinterpret_arrays::<IntervalDayTimeType, Int64Type>(array)
+            match cast_options.reinterpret {
+                false => Ok(Arc::new(
+                    array
+                        .as_primitive::<IntervalDayTimeType>()
+                        .unary::<_, Int64Type>(|v| {
+                            let (days, millis) = IntervalDayTimeType::to_parts(v);
+                            days as i64 * MILLISECONDS_IN_DAY + millis as i64
+                        }),
+                )),
+                // Packs the days and milliseconds into one value, see interval_daytime_to_parts
+                true => cast_reinterpret_arrays::<IntervalDayTimeType, Int64Type>(array),
+            }
         }
         (Int32, Interval(IntervalUnit::YearMonth)) => {
             cast_reinterpret_arrays::<Int32Type, IntervalYearMonthType>(array)
         }
         (Int64, Interval(IntervalUnit::DayTime)) => {
-            cast_reinterpret_arrays::<Int64Type, IntervalDayTimeType>(array)
+            match cast_options.reinterpret {
+                false => cast_millis_to_day_time_interval(array, cast_options),
+                true => cast_reinterpret_arrays::<Int64Type, IntervalDayTimeType>(array),
+            }
         }
         (_, _) => Err(ArrowError::CastError(format!(
             "Casting from {from_type:?} to {to_type:?} not supported",
@@ -2174,140 +4136,257 @@ pub fn cast_with_options(
     }
 }
 
-/// Get the time unit as a multiple of a second
-const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
-    match unit {
-        TimeUnit::Second => 1,
-        TimeUnit::Millisecond => MILLISECONDS,
-        TimeUnit::Microsecond => MICROSECONDS,
-        TimeUnit::Nanosecond => NANOSECONDS,
+/// Returns the index of the field of `from` that each field of `to` is cast from, or
+/// `None` if it is absent from `from`
+///
+/// Fields are matched by name, or by position if no names match and `from` and `to`
+/// have the same number of fields
+fn struct_field_sources(from: &Fields, to: &Fields) -> Vec<Option<usize>> {
+    let by_name: Vec<_> = to
+        .iter()
+        .map(|to| from.iter().position(|from| from.name() == to.name()))
+        .collect();
+    match by_name.iter().all(Option::is_none) && from.len() == to.len() {
+        true => (0..to.len()).map(Some).collect(),
+        false => by_name,
     }
 }
 
-/// A utility trait that provides checked conversions between
-/// decimal types inspired by [`NumCast`]
-trait DecimalCast: Sized {
-    fn to_i128(self) -> Option<i128>;
-
-    fn to_i256(self) -> Option<i256>;
-
-    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self>;
+/// Returns `cast_options` with the defaults nested within the field `name`
+pub(crate) fn child_cast_options<'a>(
+    cast_options: &CastOptions<'a>,
+    name: &str,
+) -> CastOptions<'a> {
+    let defaults = cast_options
+        .defaults
+        .as_ref()
+        .map(|d| d.child(name))
+        .filter(|d| !d.is_empty())
+        .map(Arc::new);
+    cast_options.clone().with_defaults(defaults)
 }
 
-impl DecimalCast for i128 {
-    fn to_i128(self) -> Option<i128> {
-        Some(self)
+/// Casts the struct `array` to a struct of `to_fields`, casting each child to the
+/// field of `to_fields` it is matched with, see [`struct_field_sources`]
+///
+/// Fields of `to_fields` without a matching child are filled with their default value,
+/// or nulls if there is none and [`CastOptions::safe`] is true
+fn cast_struct_to_struct(
+    array: &dyn Array,
+    to_fields: &Fields,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_struct();
+    let from_fields = match array.data_type() {
+        DataType::Struct(fields) => fields,
+        _ => unreachable!(),
+    };
+    if to_fields.is_empty() {
+        return Err(ArrowError::CastError(format!(
+            "Cannot cast {:?} to a struct without fields",
+            array.data_type()
+        )));
     }
 
-    fn to_i256(self) -> Option<i256> {
-        Some(i256::from_i128(self))
-    }
+    let sources = struct_field_sources(from_fields, to_fields);
+    let columns = sources
+        .iter()
+        .zip(to_fields.iter())
+        .map(|(source, field)| {
+            let column = source.map(|i| array.column(i));
+            let has_default = cast_options
+                .defaults
+                .as_ref()
+                .map_or(false, |d| d.get(&[field.name()]).is_some());
+            match column {
+                // A null column cannot populate a non-nullable field
+                Some(column)
+                    if column.data_type() != &DataType::Null || field.is_nullable() =>
+                {
+                    let options = child_cast_options(cast_options, field.name());
+                    let column = cast_with_options(column, field.data_type(), &options)
+                        .map_err(|e| with_error_context("field", field.name(), e))?;
+                    check_non_nullable(field, column.as_ref(), array.nulls())?;
+                    Ok(column)
+                }
+                None if !cast_options.safe && !has_default => {
+                    Err(ArrowError::CastError(format!(
+                        "Cannot cast {:?} to {:?}, field '{}' is not present in the source",
+                        array.data_type(),
+                        DataType::Struct(to_fields.clone()),
+                        field.name()
+                    )))
+                }
+                _ => default_column(&[field.name()], field, array.len(), cast_options),
+            }
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
 
-    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
-        n.to_i128()
-    }
+    Ok(Arc::new(StructArray::try_new(
+        to_fields.clone(),
+        columns,
+        array.nulls().cloned(),
+    )?))
 }
 
-impl DecimalCast for i256 {
-    fn to_i128(self) -> Option<i128> {
-        self.to_i128()
+/// Prefixes the message of `e` with the `kind` and `name` of the column or field whose
+/// values caused it
+pub(crate) fn with_error_context(kind: &str, name: &str, e: ArrowError) -> ArrowError {
+    let context = |m: String| format!("{kind} '{name}': {m}");
+    match e {
+        ArrowError::CastError(m) => ArrowError::CastError(context(m)),
+        ArrowError::ComputeError(m) => ArrowError::ComputeError(context(m)),
+        ArrowError::ParseError(m) => ArrowError::ParseError(context(m)),
+        ArrowError::InvalidArgumentError(m) => {
+            ArrowError::InvalidArgumentError(context(m))
+        }
+        e => e,
     }
+}
 
-    fn to_i256(self) -> Option<i256> {
-        Some(self)
-    }
-
-    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
-        n.to_i256()
-    }
-}
-
-fn cast_decimal_to_decimal_error<I, O>(
-    output_precision: u8,
-    output_scale: i8,
-) -> impl Fn(<I as ArrowPrimitiveType>::Native) -> ArrowError
-where
-    I: DecimalType,
-    O: DecimalType,
-    I::Native: DecimalCast + ArrowNativeTypeOp,
-    O::Native: DecimalCast + ArrowNativeTypeOp,
-{
-    move |x: I::Native| {
-        ArrowError::CastError(format!(
-            "Cannot cast to {}({}, {}). Overflowing on {:?}",
-            O::PREFIX,
-            output_precision,
-            output_scale,
-            x
-        ))
+/// Returns an error if `column` contains nulls for the non-nullable `field`, ignoring
+/// those masked by the nulls of its parent, `parent_nulls`
+pub(crate) fn check_non_nullable(
+    field: &Field,
+    column: &dyn Array,
+    parent_nulls: Option<&NullBuffer>,
+) -> Result<(), ArrowError> {
+    let nulls = match column.nulls() {
+        Some(nulls) if !field.is_nullable() => nulls,
+        _ => return Ok(()),
+    };
+    let count = match parent_nulls {
+        Some(parent) => {
+            let valid = parent.inner() & nulls.inner();
+            parent.len() - parent.null_count() - valid.count_set_bits()
+        }
+        None => nulls.null_count(),
+    };
+    match count {
+        0 => Ok(()),
+        _ => Err(ArrowError::CastError(format!(
+            "Cannot cast {count} null values to the non-nullable field '{}'",
+            field.name()
+        ))),
     }
 }
 
-fn convert_to_smaller_scale_decimal<I, O>(
-    array: &PrimitiveArray<I>,
-    input_scale: i8,
-    output_precision: u8,
-    output_scale: i8,
+/// Replaces the null rows of `cast` with the value of `fill`, cast to the type of
+/// `cast`
+fn fill_nulls(
+    cast: ArrayRef,
+    fill: &NullFill,
     cast_options: &CastOptions,
-) -> Result<PrimitiveArray<O>, ArrowError>
-where
-    I: DecimalType,
-    O: DecimalType,
-    I::Native: DecimalCast + ArrowNativeTypeOp,
-    O::Native: DecimalCast + ArrowNativeTypeOp,
-{
-    let error = cast_decimal_to_decimal_error::<I, O>(output_precision, output_scale);
-    let div = I::Native::from_decimal(10_i128)
-        .unwrap()
-        .pow_checked((input_scale - output_scale) as u32)?;
-
-    let half = div.div_wrapping(I::Native::from_usize(2).unwrap());
-    let half_neg = half.neg_wrapping();
-
-    let f = |x: I::Native| {
-        // div is >= 10 and so this cannot overflow
-        let d = x.div_wrapping(div);
-        let r = x.mod_wrapping(div);
-
-        // Round result
-        let adjusted = match x >= I::Native::ZERO {
-            true if r >= half => d.add_wrapping(I::Native::ONE),
-            false if r <= half_neg => d.sub_wrapping(I::Native::ONE),
-            _ => d,
-        };
-        O::Native::from_decimal(adjusted)
+) -> Result<ArrayRef, ArrowError> {
+    let nulls = match cast.nulls() {
+        Some(nulls) if nulls.null_count() != 0 => nulls.clone(),
+        _ => return Ok(cast),
     };
+    let array = cast.as_ref();
+    let options = CastOptions {
+        safe: false,
+        ..cast_options.clone()
+    };
+    let value = cast_with_options(fill.value(), array.data_type(), &options)?;
+    if value.is_null(0) {
+        return Err(ArrowError::CastError(format!(
+            "Cannot cast null fill value {:?} to {:?}",
+            fill.value(),
+            array.data_type()
+        )));
+    }
 
-    Ok(match cast_options.safe {
-        true => array.unary_opt(f),
-        false => array.try_unary(|x| f(x).ok_or_else(|| error(x)))?,
-    })
+    downcast_primitive_array!(
+        array => Ok(fill_primitive_nulls(array, &nulls, value.as_ref())),
+        _ => {
+            // Take the fill value from after the end of the array for each null row
+            let len = array.len() as u64;
+            let indices: UInt64Array = nulls
+                .iter()
+                .enumerate()
+                .map(|(i, valid)| Some(if valid { i as u64 } else { len }))
+                .collect();
+            let values = concat(&[array, value.as_ref()])?;
+            take(values.as_ref(), &indices, None)
+        }
+    )
 }
 
-fn convert_to_bigger_or_equal_scale_decimal<I, O>(
-    array: &PrimitiveArray<I>,
-    input_scale: i8,
-    output_precision: u8,
-    output_scale: i8,
+/// Replaces the values of the null rows of `array` with the value of the single element
+/// array `fill`, and removes its nulls
+fn fill_primitive_nulls<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    nulls: &NullBuffer,
+    fill: &dyn Array,
+) -> ArrayRef {
+    let fill = fill.as_primitive::<T>().value(0);
+    let values: Vec<_> = array
+        .values()
+        .iter()
+        .zip(nulls.iter())
+        .map(|(v, valid)| if valid { *v } else { fill })
+        .collect();
+    let array = PrimitiveArray::<T>::new(values.into(), None)
+        .with_data_type(array.data_type().clone());
+    Arc::new(array)
+}
+
+/// Returns an array of `len` rows for `field`, which is absent from the source of a cast,
+/// containing the default value registered for `path`, or nulls if there is none
+///
+/// Returns an error if there is no default and `field` is not nullable
+pub(crate) fn default_column(
+    path: &[&str],
+    field: &Field,
+    len: usize,
     cast_options: &CastOptions,
-) -> Result<PrimitiveArray<O>, ArrowError>
-where
-    I: DecimalType,
-    O: DecimalType,
-    I::Native: DecimalCast + ArrowNativeTypeOp,
-    O::Native: DecimalCast + ArrowNativeTypeOp,
-{
-    let error = cast_decimal_to_decimal_error::<I, O>(output_precision, output_scale);
-    let mul = O::Native::from_decimal(10_i128)
-        .unwrap()
-        .pow_checked((output_scale - input_scale) as u32)?;
+) -> Result<ArrayRef, ArrowError> {
+    let value = cast_options.defaults.as_ref().and_then(|d| d.get(path));
+    match value {
+        Some(value) => {
+            let value = cast_with_options(value, field.data_type(), cast_options)?;
+            let indices = UInt32Array::from(vec![0; len]);
+            take(value.as_ref(), &indices, None)
+        }
+        None if !field.is_nullable() && len != 0 => {
+            Err(ArrowError::CastError(format!(
+                "Cannot fill non-nullable field '{}' with nulls, no default value was provided",
+                path.join(".")
+            )))
+        }
+        None => Ok(new_null_array(field.data_type(), len)),
+    }
+}
 
-    let f = |x| O::Native::from_decimal(x).and_then(|x| x.mul_checked(mul).ok());
+/// Returns an array of `len` nulls of `to_type`, validating the precision and scale
+/// of decimal types as the cast kernels would
+fn cast_all_null(to_type: &DataType, len: usize) -> Result<ArrayRef, ArrowError> {
+    let array = new_null_array(to_type, len);
+    match to_type {
+        DataType::Decimal128(precision, scale) => Ok(Arc::new(
+            array
+                .as_primitive::<Decimal128Type>()
+                .clone()
+                .with_precision_and_scale(*precision, *scale)?,
+        )),
+        DataType::Decimal256(precision, scale) => Ok(Arc::new(
+            array
+                .as_primitive::<Decimal256Type>()
+                .clone()
+                .with_precision_and_scale(*precision, *scale)?,
+        )),
+        _ => Ok(array),
+    }
+}
 
-    Ok(match cast_options.safe {
-        true => array.unary_opt(f),
-        false => array.try_unary(|x| f(x).ok_or_else(|| error(x)))?,
-    })
+/// Get the time unit as a multiple of a second
+pub(crate) const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
+    }
 }
 
 // Only support one type of decimal cast operations
@@ -2322,34 +4401,20 @@ where
     T: DecimalType,
     T::Native: DecimalCast + ArrowNativeTypeOp,
 {
-    let array: PrimitiveArray<T> = match input_scale.cmp(&output_scale) {
-        Ordering::Equal => {
-            // the scale doesn't change, the native value don't need to be changed
-            array.clone()
-        }
-        Ordering::Greater => convert_to_smaller_scale_decimal::<T, T>(
+    let array = match input_scale == output_scale {
+        // the scale doesn't change, the native value don't need to be changed
+        true => array
+            .clone()
+            .with_precision_and_scale(output_precision, output_scale)?,
+        false => rescale_decimal::<T, T>(
             array,
             input_scale,
             output_precision,
             output_scale,
             cast_options,
         )?,
-        Ordering::Less => {
-            // input_scale < output_scale
-            convert_to_bigger_or_equal_scale_decimal::<T, T>(
-                array,
-                input_scale,
-                output_precision,
-                output_scale,
-                cast_options,
-            )?
-        }
     };
-
-    Ok(Arc::new(array.with_precision_and_scale(
-        output_precision,
-        output_scale,
-    )?))
+    Ok(Arc::new(array))
 }
 
 // Support two different types of decimal cast operations
@@ -2366,27 +4431,12 @@ where
     I::Native: DecimalCast + ArrowNativeTypeOp,
     O::Native: DecimalCast + ArrowNativeTypeOp,
 {
-    let array: PrimitiveArray<O> = if input_scale > output_scale {
-        convert_to_smaller_scale_decimal::<I, O>(
-            array,
-            input_scale,
-            output_precision,
-            output_scale,
-            cast_options,
-        )?
-    } else {
-        convert_to_bigger_or_equal_scale_decimal::<I, O>(
-            array,
-            input_scale,
-            output_precision,
-            output_scale,
-            cast_options,
-        )?
-    };
-
-    Ok(Arc::new(array.with_precision_and_scale(
+    Ok(Arc::new(rescale_decimal::<I, O>(
+        array,
+        input_scale,
         output_precision,
         output_scale,
+        cast_options,
     )?))
 }
 
@@ -2401,7 +4451,12 @@ where
     FROM::Native: NumCast,
     TO::Native: NumCast,
 {
-    if cast_options.safe {
+    if cast_options.compat == CastCompat::ArrowCpp {
+        // Arrow C++ errors on overflow and truncation regardless of safe
+        Ok(Arc::new(try_exact_numeric_cast::<FROM, TO>(
+            from.as_primitive::<FROM>(),
+        )?))
+    } else if cast_options.safe {
         // If the value can't be casted to the `TO::Native`, return null
         Ok(Arc::new(numeric_cast::<FROM, TO>(
             from.as_primitive::<FROM>(),
@@ -2436,6 +4491,34 @@ where
     })
 }
 
+// Cast between numeric types that, in addition to the checks of `try_numeric_cast`,
+// returns an error if casting between integer and floating point loses precision
+fn try_exact_numeric_cast<T, R>(
+    from: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<R>, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    R: ArrowPrimitiveType,
+    T::Native: NumCast,
+    R::Native: NumCast,
+{
+    if T::DATA_TYPE.is_floating() == R::DATA_TYPE.is_floating() {
+        return try_numeric_cast(from);
+    }
+
+    from.try_unary(|value| {
+        num::cast::cast::<T::Native, R::Native>(value)
+            .filter(|x| num::cast::cast::<R::Native, T::Native>(*x) == Some(value))
+            .ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Can't cast value {:?} to type {} without loss of precision",
+                    value,
+                    R::DATA_TYPE
+                ))
+            })
+    })
+}
+
 // Natural cast between numeric types
 // If the value of T can't be casted to R, it will be converted to null
 fn numeric_cast<T, R>(from: &PrimitiveArray<T>) -> PrimitiveArray<R>
@@ -2450,10 +4533,10 @@ where
 
 fn value_to_string<O: OffsetSizeTrait>(
     array: &dyn Array,
+    cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let mut builder = GenericStringBuilder::<O>::new();
-    let options = FormatOptions::default();
-    let formatter = ArrayFormatter::try_new(array, &options)?;
+    let formatter = ArrayFormatter::try_new(array, &cast_options.format_options)?;
     let nulls = array.nulls();
     for i in 0..array.len() {
         match nulls.map(|x| x.is_null(i)).unwrap_or_default() {
@@ -2475,7 +4558,7 @@ fn cast_string_to_numeric<T, Offset: OffsetSizeTrait>(
 ) -> Result<ArrayRef, ArrowError>
 where
     T: ArrowPrimitiveType,
-    <T as ArrowPrimitiveType>::Native: lexical_core::FromLexical,
+    <T as ArrowPrimitiveType>::Native: lexical_core::FromLexical + num::NumCast,
 {
     Ok(Arc::new(string_to_numeric_cast::<T, Offset>(
         from.as_any()
@@ -2491,12 +4574,51 @@ fn string_to_numeric_cast<T, Offset: OffsetSizeTrait>(
 ) -> Result<PrimitiveArray<T>, ArrowError>
 where
     T: ArrowPrimitiveType,
-    <T as ArrowPrimitiveType>::Native: lexical_core::FromLexical,
+    <T as ArrowPrimitiveType>::Native: lexical_core::FromLexical + num::NumCast,
+{
+    let parse: fn(&str) -> Option<T::Native> =
+        match cast_options.parse_radix_prefix && T::DATA_TYPE.is_integer() {
+            true => parse_radix_prefixed_integer,
+            false => |v| lexical_core::parse(v.as_bytes()).ok(),
+        };
+    match &cast_options.number_format {
+        Some(format) if T::DATA_TYPE.is_floating() => {
+            string_to_primitive_cast(from, cast_options, |v| parse(&format.normalize(v)?))
+        }
+        _ => string_to_primitive_cast(from, cast_options, parse),
+    }
+}
+
+/// Cast string to Float16
+fn cast_string_to_float16<Offset: OffsetSizeTrait>(
+    from: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let from = from.as_string::<Offset>();
+    Ok(Arc::new(match &cast_options.number_format {
+        Some(format) => {
+            string_to_primitive_cast::<Float16Type, Offset>(from, cast_options, |v| {
+                Float16Type::parse(&format.normalize(v)?)
+            })?
+        }
+        None => string_to_primitive_cast::<Float16Type, Offset>(
+            from,
+            cast_options,
+            Float16Type::parse,
+        )?,
+    }))
+}
+
+fn string_to_primitive_cast<T, Offset: OffsetSizeTrait>(
+    from: &GenericStringArray<Offset>,
+    cast_options: &CastOptions,
+    parse: impl Fn(&str) -> Option<T::Native>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowPrimitiveType,
 {
     if cast_options.safe {
-        let iter = from
-            .iter()
-            .map(|v| v.and_then(|v| lexical_core::parse(v.as_bytes()).ok()));
+        let iter = from.iter().map(|v| v.and_then(&parse));
         // Benefit:
         //     20% performance improvement
         // Soundness:
@@ -2507,7 +4629,7 @@ where
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    lexical_core::parse(v.as_bytes()).map_err(|_| {
+                    parse(v).ok_or_else(|| {
                         ArrowError::CastError(format!(
                             "Cannot cast string '{}' to value of {:?} type",
                             v,
@@ -2536,15 +4658,16 @@ fn cast_string_to_date32<Offset: OffsetSizeTrait>(
         .as_any()
         .downcast_ref::<GenericStringArray<Offset>>()
         .unwrap();
+    let parse = |v: &str| {
+        let date = v
+            .parse::<chrono::NaiveDate>()
+            .ok()
+            .or_else(|| parse_relaxed_date(v, cast_options.two_digit_year_pivot?))?;
+        Some(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+    };
 
     let array = if cast_options.safe {
-        let iter = string_array.iter().map(|v| {
-            v.and_then(|v| {
-                v.parse::<chrono::NaiveDate>()
-                    .map(|date| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
-                    .ok()
-            })
-        });
+        let iter = string_array.iter().map(|v| v.and_then(parse));
 
         // Benefit:
         //     20% performance improvement
@@ -2556,15 +4679,13 @@ fn cast_string_to_date32<Offset: OffsetSizeTrait>(
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    v.parse::<chrono::NaiveDate>()
-                        .map(|date| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
-                        .map_err(|_| {
-                            ArrowError::CastError(format!(
-                                "Cannot cast string '{}' to value of {:?} type",
-                                v,
-                                DataType::Date32
-                            ))
-                        })
+                    parse(v).ok_or_else(|| {
+                        ArrowError::CastError(format!(
+                            "Cannot cast string '{}' to value of {:?} type",
+                            v,
+                            DataType::Date32
+                        ))
+                    })
                 })
                 .transpose()
             })
@@ -2589,15 +4710,16 @@ fn cast_string_to_date64<Offset: OffsetSizeTrait>(
         .as_any()
         .downcast_ref::<GenericStringArray<Offset>>()
         .unwrap();
+    let parse = |v: &str| {
+        let datetime = v
+            .parse::<chrono::NaiveDateTime>()
+            .ok()
+            .or_else(|| parse_relaxed_datetime(v, cast_options.two_digit_year_pivot?))?;
+        Some(datetime.timestamp_millis())
+    };
 
     let array = if cast_options.safe {
-        let iter = string_array.iter().map(|v| {
-            v.and_then(|v| {
-                v.parse::<chrono::NaiveDateTime>()
-                    .map(|datetime| datetime.timestamp_millis())
-                    .ok()
-            })
-        });
+        let iter = string_array.iter().map(|v| v.and_then(parse));
 
         // Benefit:
         //     20% performance improvement
@@ -2609,15 +4731,13 @@ fn cast_string_to_date64<Offset: OffsetSizeTrait>(
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    v.parse::<chrono::NaiveDateTime>()
-                        .map(|datetime| datetime.timestamp_millis())
-                        .map_err(|_| {
-                            ArrowError::CastError(format!(
-                                "Cannot cast string '{}' to value of {:?} type",
-                                v,
-                                DataType::Date64
-                            ))
-                        })
+                    parse(v).ok_or_else(|| {
+                        ArrowError::CastError(format!(
+                            "Cannot cast string '{}' to value of {:?} type",
+                            v,
+                            DataType::Date64
+                        ))
+                    })
                 })
                 .transpose()
             })
@@ -2633,6 +4753,26 @@ fn cast_string_to_date64<Offset: OffsetSizeTrait>(
     Ok(Arc::new(array) as ArrayRef)
 }
 
+/// Parses a time of day, with an optional UTC offset that is discarded or applied
+/// according to [`CastOptions::time_with_offset`]
+fn parse_string_time(
+    v: &str,
+    cast_options: &CastOptions,
+) -> Result<NaiveTime, chrono::ParseError> {
+    let err = match v.parse::<NaiveTime>() {
+        Ok(time) => return Ok(time),
+        Err(e) => e,
+    };
+    let (time, offset) = split_time_offset(v).ok_or(err)?;
+    let time = time.parse::<NaiveTime>()?;
+    Ok(match cast_options.time_with_offset {
+        TimeWithOffset::Discard => time,
+        TimeWithOffset::Utc => {
+            time - chrono::Duration::seconds(offset.local_minus_utc() as i64)
+        }
+    })
+}
+
 /// Casts generic string arrays to `Time32SecondArray`
 fn cast_string_to_time32second<Offset: OffsetSizeTrait>(
     array: &dyn Array,
@@ -2649,7 +4789,7 @@ fn cast_string_to_time32second<Offset: OffsetSizeTrait>(
     let array = if cast_options.safe {
         let iter = string_array.iter().map(|v| {
             v.and_then(|v| {
-                v.parse::<chrono::NaiveTime>()
+                parse_string_time(v, cast_options)
                     .map(|time| {
                         (time.num_seconds_from_midnight()
                             + time.nanosecond() / NANOS_PER_SEC)
@@ -2669,7 +4809,7 @@ fn cast_string_to_time32second<Offset: OffsetSizeTrait>(
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    v.parse::<chrono::NaiveTime>()
+                    parse_string_time(v, cast_options)
                         .map(|time| {
                             (time.num_seconds_from_midnight()
                                 + time.nanosecond() / NANOS_PER_SEC)
@@ -2715,7 +4855,7 @@ fn cast_string_to_time32millisecond<Offset: OffsetSizeTrait>(
     let array = if cast_options.safe {
         let iter = string_array.iter().map(|v| {
             v.and_then(|v| {
-                v.parse::<chrono::NaiveTime>()
+                parse_string_time(v, cast_options)
                     .map(|time| {
                         (time.num_seconds_from_midnight() * MILLIS_PER_SEC
                             + time.nanosecond() / NANOS_PER_MILLI)
@@ -2735,7 +4875,7 @@ fn cast_string_to_time32millisecond<Offset: OffsetSizeTrait>(
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    v.parse::<chrono::NaiveTime>()
+                    parse_string_time(v, cast_options)
                         .map(|time| {
                             (time.num_seconds_from_midnight() * MILLIS_PER_SEC
                                 + time.nanosecond() / NANOS_PER_MILLI)
@@ -2781,7 +4921,7 @@ fn cast_string_to_time64microsecond<Offset: OffsetSizeTrait>(
     let array = if cast_options.safe {
         let iter = string_array.iter().map(|v| {
             v.and_then(|v| {
-                v.parse::<chrono::NaiveTime>()
+                parse_string_time(v, cast_options)
                     .map(|time| {
                         time.num_seconds_from_midnight() as i64 * MICROS_PER_SEC
                             + time.nanosecond() as i64 / NANOS_PER_MICRO
@@ -2800,7 +4940,7 @@ fn cast_string_to_time64microsecond<Offset: OffsetSizeTrait>(
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    v.parse::<chrono::NaiveTime>()
+                    parse_string_time(v, cast_options)
                         .map(|time| {
                             time.num_seconds_from_midnight() as i64 * MICROS_PER_SEC
                                 + time.nanosecond() as i64 / NANOS_PER_MICRO
@@ -2843,7 +4983,7 @@ fn cast_string_to_time64nanosecond<Offset: OffsetSizeTrait>(
     let array = if cast_options.safe {
         let iter = string_array.iter().map(|v| {
             v.and_then(|v| {
-                v.parse::<chrono::NaiveTime>()
+                parse_string_time(v, cast_options)
                     .map(|time| {
                         time.num_seconds_from_midnight() as i64 * NANOS_PER_SEC
                             + time.nanosecond() as i64
@@ -2862,7 +5002,7 @@ fn cast_string_to_time64nanosecond<Offset: OffsetSizeTrait>(
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    v.parse::<chrono::NaiveTime>()
+                    parse_string_time(v, cast_options)
                         .map(|time| {
                             time.num_seconds_from_midnight() as i64 * NANOS_PER_SEC
                                 + time.nanosecond() as i64
@@ -2897,9 +5037,22 @@ fn cast_string_to_timestamp<O: OffsetSizeTrait, T: ArrowTimestampType>(
 ) -> Result<ArrayRef, ArrowError> {
     let array = array.as_string::<O>();
     let out: PrimitiveArray<T> = match to_tz {
+        Some(tz)
+            if cast_options.timestamp_without_offset == TimestampWithoutOffset::Utc =>
+        {
+            // Validate the timezone, which is not needed to parse the strings
+            tz.as_ref().parse::<Tz>()?;
+            cast_string_to_timestamp_impl(array, &Utc, cast_options)?
+        }
         Some(tz) => {
             let tz: Tz = tz.as_ref().parse()?;
-            cast_string_to_timestamp_impl(array, &tz, cast_options)?
+            // Avoid resolving the offset of the timezone for every row
+            match tz.fixed_offset() {
+                Some(offset) => {
+                    cast_string_to_timestamp_impl(array, &offset, cast_options)?
+                }
+                None => cast_string_to_timestamp_impl(array, &tz, cast_options)?,
+            }
         }
         None => cast_string_to_timestamp_impl(array, &Utc, cast_options)?,
     };
@@ -2915,10 +5068,41 @@ fn cast_string_to_timestamp_impl<
     tz: &Tz,
     cast_options: &CastOptions,
 ) -> Result<PrimitiveArray<T>, ArrowError> {
+    let unit_digits = match T::UNIT {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 3,
+        TimeUnit::Microsecond => 6,
+        TimeUnit::Nanosecond => 9,
+    };
+    let parse = |v: &str| {
+        if !cast_options.timestamp_formats.is_empty() {
+            return parse_timestamp_with_formats(tz, v, cast_options);
+        }
+        let resolution = cast_options.local_time_resolution;
+        match string_to_datetime_with_resolution(tz, v, resolution) {
+        Ok(_)
+            if cast_options.strict_fractional_seconds
+                && fractional_second_digits(v) > unit_digits =>
+        {
+            Err(ArrowError::CastError(format!(
+                "Cannot cast string '{v}' to {:?} without truncating its fractional seconds",
+                T::UNIT
+            )))
+        }
+        Ok(datetime) => Ok(datetime.naive_utc()),
+        Err(e) => cast_options
+            .two_digit_year_pivot
+            .and_then(|pivot| parse_relaxed_datetime(v, pivot))
+            .and_then(|naive| resolution.resolve(tz, &naive))
+            .map(|datetime| datetime.naive_utc())
+            .ok_or(e),
+    }
+    };
+
     if cast_options.safe {
         let iter = array.iter().map(|v| {
             v.and_then(|v| {
-                let naive = string_to_datetime(tz, v).ok()?.naive_utc();
+                let naive = parse(v).ok()?;
                 T::make_value(naive)
             })
         });
@@ -2933,13 +5117,9 @@ fn cast_string_to_timestamp_impl<
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    let naive = string_to_datetime(tz, v)?.naive_utc();
-                    T::make_value(naive).ok_or_else(|| {
-                        ArrowError::CastError(format!(
-                            "Overflow converting {naive} to {:?}",
-                            T::UNIT
-                        ))
-                    })
+                    let naive = parse(v)?;
+                    T::make_value(naive)
+                        .ok_or_else(|| timestamp_overflow_error::<T>(naive))
                 })
                 .transpose()
             })
@@ -2953,6 +5133,114 @@ fn cast_string_to_timestamp_impl<
     }
 }
 
+/// Parses `v` with the first of `formats` that matches it, returning the UTC datetime
+///
+/// Strings without a UTC offset are local datetimes in `tz`, and formats without a
+/// time are parsed as midnight
+fn parse_timestamp_with_formats<Tz: TimeZone>(
+    tz: &Tz,
+    v: &str,
+    cast_options: &CastOptions,
+) -> Result<chrono::NaiveDateTime, ArrowError> {
+    let formats = cast_options.timestamp_formats;
+    formats
+        .iter()
+        .find_map(|format| {
+            if let Ok(datetime) = chrono::DateTime::parse_from_str(v, format) {
+                return Some(datetime.naive_utc());
+            }
+            let naive = chrono::NaiveDateTime::parse_from_str(v, format)
+                .ok()
+                .or_else(|| {
+                    chrono::NaiveDate::parse_from_str(v, format)
+                        .ok()?
+                        .and_hms_opt(0, 0, 0)
+                })?;
+            let datetime = cast_options.local_time_resolution.resolve(tz, &naive)?;
+            Some(datetime.naive_utc())
+        })
+        .ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Cannot parse string '{v}' as a timestamp with the formats {formats:?}"
+            ))
+        })
+}
+
+/// Returns the error for a datetime outside the range of the timestamp type `T`
+fn timestamp_overflow_error<T: ArrowTimestampType>(
+    naive: chrono::NaiveDateTime,
+) -> ArrowError {
+    let min = as_datetime::<T>(i64::MIN);
+    let max = as_datetime::<T>(i64::MAX);
+    match min.zip(max) {
+        Some((min, max)) => ArrowError::CastError(format!(
+            "Overflow converting {naive} to {:?}, which can only represent datetimes from {min} to {max}",
+            T::UNIT
+        )),
+        None => ArrowError::CastError(format!(
+            "Overflow converting {naive} to {:?}",
+            T::UNIT
+        )),
+    }
+}
+
+/// Casts strings to timestamps with the finest unit that can represent every value
+///
+/// Timestamps with a [`TimeUnit::Nanosecond`] unit can only represent datetimes between
+/// the years 1677 and 2262, those of coarser units have a much larger range. This casts
+/// to nanoseconds if every value is within its range, otherwise to the first of
+/// microseconds, milliseconds and seconds that can represent every value, returning
+/// an array of `Timestamp(unit, to_tz)` with the chosen `unit`. Strings are otherwise
+/// parsed as by [`cast_with_options`], with values that cannot be represented in
+/// seconds treated as a failed cast.
+///
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_cast::{cast_string_to_timestamp_with_inferred_unit, DEFAULT_CAST_OPTIONS};
+/// # use arrow_schema::{DataType, TimeUnit};
+/// let array = StringArray::from(vec!["2023-04-01T12:00:00.123", "3000-01-01T00:00:00"]);
+/// let out =
+///     cast_string_to_timestamp_with_inferred_unit(&array, None, &DEFAULT_CAST_OPTIONS)
+///         .unwrap();
+/// assert_eq!(out.data_type(), &DataType::Timestamp(TimeUnit::Microsecond, None));
+/// ```
+pub fn cast_string_to_timestamp_with_inferred_unit(
+    array: &dyn Array,
+    to_tz: Option<Arc<str>>,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    if !matches!(array.data_type(), DataType::Utf8 | DataType::LargeUtf8) {
+        return Err(ArrowError::CastError(format!(
+            "Cannot infer the timestamp unit of {:?}, expected Utf8 or LargeUtf8",
+            array.data_type()
+        )));
+    }
+    // Values out of range of a unit are null when cast to it, in addition to those
+    // that fail to parse, which are null for every unit
+    let options = cast_options
+        .clone()
+        .with_safe(true)
+        .with_strict_fractional_seconds(false);
+    let to_type = |unit| DataType::Timestamp(unit, to_tz.clone());
+    let coarsest = cast_with_options(array, &to_type(TimeUnit::Second), &options)?;
+    let mut out = (TimeUnit::Second, coarsest.clone());
+    for unit in [
+        TimeUnit::Nanosecond,
+        TimeUnit::Microsecond,
+        TimeUnit::Millisecond,
+    ] {
+        let array = cast_with_options(array, &to_type(unit.clone()), &options)?;
+        if array.null_count() == coarsest.null_count() {
+            out = (unit, array);
+            break;
+        }
+    }
+    match &options == cast_options {
+        true => Ok(out.1),
+        false => cast_with_options(array, &to_type(out.0), cast_options),
+    }
+}
+
 fn cast_string_to_year_month_interval<Offset: OffsetSizeTrait>(
     array: &dyn Array,
     cast_options: &CastOptions,
@@ -3052,32 +5340,79 @@ fn cast_string_to_month_day_nano_interval<Offset: OffsetSizeTrait>(
     Ok(Arc::new(interval_array) as ArrayRef)
 }
 
-/// Casts Utf8 to Boolean
-fn cast_utf8_to_boolean<OffsetSize>(
-    from: &dyn Array,
+/// Casts strings to durations of `D`, see [`parse_duration`]
+///
+/// Durations with a fraction of the unit of `D` are truncated towards zero
+fn cast_string_to_duration<O: OffsetSizeTrait, D: ArrowPrimitiveType<Native = i64>>(
+    array: &dyn Array,
     cast_options: &CastOptions,
-) -> Result<ArrayRef, ArrowError>
-where
-    OffsetSize: OffsetSizeTrait,
-{
-    let array = from
-        .as_any()
-        .downcast_ref::<GenericStringArray<OffsetSize>>()
-        .unwrap();
+) -> Result<ArrayRef, ArrowError> {
+    let divisor = match D::DATA_TYPE {
+        DataType::Duration(TimeUnit::Second) => 1_000_000_000,
+        DataType::Duration(TimeUnit::Millisecond) => 1_000_000,
+        DataType::Duration(TimeUnit::Microsecond) => 1_000,
+        DataType::Duration(TimeUnit::Nanosecond) => 1,
+        _ => unreachable!(),
+    };
+    let string_array = array.as_string::<O>();
+    let array = if cast_options.safe {
+        let iter = string_array
+            .iter()
+            .map(|v| v.and_then(|v| parse_duration(v).ok().map(|v| v / divisor)));
 
-    let output_array = array
-        .iter()
-        .map(|value| match value {
-            Some(value) => match value.to_ascii_lowercase().trim() {
-                "t" | "tr" | "tru" | "true" | "y" | "ye" | "yes" | "on" | "1" => {
-                    Ok(Some(true))
-                }
-                "f" | "fa" | "fal" | "fals" | "false" | "n" | "no" | "of" | "off"
-                | "0" => Ok(Some(false)),
-                invalid_value => match cast_options.safe {
-                    true => Ok(None),
+        // Soundness:
+        //     The iterator is trustedLen because it comes from an `StringArray`.
+        unsafe { PrimitiveArray::<D>::from_trusted_len_iter(iter) }
+    } else {
+        let vec = string_array
+            .iter()
+            .map(|v| {
+                v.map(|v| {
+                    parse_duration(v).map(|v| v / divisor).map_err(|e| {
+                        ArrowError::CastError(format!(
+                            "Cannot cast string '{v}' to value of {:?} type: {e}",
+                            D::DATA_TYPE
+                        ))
+                    })
+                })
+                .transpose()
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        // Soundness:
+        //     The iterator is trustedLen because it comes from an `StringArray`.
+        unsafe { PrimitiveArray::<D>::from_trusted_len_iter(vec) }
+    };
+    Ok(Arc::new(array))
+}
+
+/// Casts Utf8 to Boolean
+fn cast_utf8_to_boolean<OffsetSize>(
+    from: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    OffsetSize: OffsetSizeTrait,
+{
+    let array = from
+        .as_any()
+        .downcast_ref::<GenericStringArray<OffsetSize>>()
+        .unwrap();
+
+    let parse = |v: &str| match &cast_options.boolean_parser {
+        Some(parser) => parser.parse(v),
+        None => parse_bool(v),
+    };
+    let output_array = array
+        .iter()
+        .map(|value| match value {
+            Some(value) => match parse(value) {
+                Some(value) => Ok(Some(value)),
+                None => match cast_options.safe {
+                    true => Ok(None),
                     false => Err(ArrowError::CastError(format!(
-                        "Cannot cast value '{invalid_value}' to value of Boolean type",
+                        "Cannot cast value '{}' to value of Boolean type",
+                        value.to_ascii_lowercase().trim()
                     ))),
                 },
             },
@@ -3093,11 +5428,16 @@ where
 fn parse_string_to_decimal_native<T: DecimalType>(
     value_str: &str,
     scale: usize,
+    rounding: RoundingMode,
 ) -> Result<T::Native, ArrowError>
 where
     T::Native: DecimalCast + ArrowNativeTypeOp,
 {
-    let value_str = value_str.trim();
+    let value_str = trim_whitespace(value_str);
+    let expanded = expand_decimal_exponent(value_str).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!("Invalid decimal format: {value_str:?}"))
+    })?;
+    let value_str = expanded.as_ref();
     let parts: Vec<&str> = value_str.split('.').collect();
     if parts.len() > 2 {
         return Err(ArrowError::InvalidArgumentError(format!(
@@ -3105,7 +5445,11 @@ where
         )));
     }
 
-    let integers = parts[0].trim_start_matches('0');
+    let (negative, integers) = match parts[0].strip_prefix('-') {
+        Some(integers) => (true, integers),
+        None => (false, parts[0]),
+    };
+    let integers = integers.trim_start_matches('0');
     let decimals = if parts.len() == 2 { parts[1] } else { "" };
 
     // Adjust decimal based on scale
@@ -3119,19 +5463,9 @@ where
         let div =
             i256::from_i128(10_i128).pow_checked((decimals.len() - scale) as u32)?;
 
-        let half = div.div_wrapping(i256::from_i128(2));
-        let half_neg = half.neg_wrapping();
-
         let d = decimal_number.div_wrapping(div);
         let r = decimal_number.mod_wrapping(div);
 
-        // Round result
-        let adjusted = match decimal_number >= i256::ZERO {
-            true if r >= half => d.add_wrapping(i256::ONE),
-            false if r <= half_neg => d.sub_wrapping(i256::ONE),
-            _ => d,
-        };
-
         let integers = if !integers.is_empty() {
             i256::from_string(integers)
                 .ok_or_else(|| {
@@ -3146,12 +5480,19 @@ where
             i256::ZERO
         };
 
-        format!("{}", integers.add_wrapping(adjusted))
+        // Round the value with the sign of the input, as the parity of the integer
+        // part matters for rounding half to even
+        let (truncated, r) = match negative {
+            true => (integers.add_wrapping(d).neg_wrapping(), r.neg_wrapping()),
+            false => (integers.add_wrapping(d), r),
+        };
+        format!("{}", rounding.round(truncated, r, div))
     } else {
         let padding = if scale > decimals.len() { scale } else { 0 };
 
+        let sign = if negative { "-" } else { "" };
         let decimals = format!("{decimals:0<padding$}");
-        format!("{integers}{decimals}")
+        format!("{sign}{integers}{decimals}")
     };
 
     let value = i256::from_string(number_decimals.as_str()).ok_or_else(|| {
@@ -3181,10 +5522,16 @@ where
     T: DecimalType,
     T::Native: DecimalCast + ArrowNativeTypeOp,
 {
+    let rounding = cast_options.rounding;
+    let parse = |v: &str| match &cast_options.number_format {
+        Some(format) => {
+            let v = format.normalize(v)?;
+            parse_string_to_decimal_native::<T>(&v, scale as usize, rounding).ok()
+        }
+        None => parse_string_to_decimal_native::<T>(v, scale as usize, rounding).ok(),
+    };
     if cast_options.safe {
-        let iter = from.iter().map(|v| {
-            v.and_then(|v| parse_string_to_decimal_native::<T>(v, scale as usize).ok())
-        });
+        let iter = from.iter().map(|v| v.and_then(parse));
         // Benefit:
         //     20% performance improvement
         // Soundness:
@@ -3198,7 +5545,7 @@ where
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    parse_string_to_decimal_native::<T>(v, scale as usize).map_err(|_| {
+                    parse(v).ok_or_else(|| {
                         ArrowError::CastError(format!(
                             "Cannot cast string '{}' to value of {:?} type",
                             v,
@@ -3254,6 +5601,24 @@ where
     )?))
 }
 
+/// Cast Boolean types to Utf8
+fn cast_bool_to_string<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let (t, f) = match cast_options.compat {
+        CastCompat::Rust => ("1", "0"),
+        CastCompat::ArrowCpp => ("true", "false"),
+    };
+    Ok(Arc::new(
+        array
+            .as_boolean()
+            .iter()
+            .map(|value| value.map(|value| if value { t } else { f }))
+            .collect::<GenericStringArray<O>>(),
+    ))
+}
+
 /// Cast numeric types to Boolean
 ///
 /// Any zero value returns `false` while non-zero returns `true`
@@ -3267,21 +5632,14 @@ where
 
 fn numeric_to_bool_cast<T>(from: &PrimitiveArray<T>) -> Result<BooleanArray, ArrowError>
 where
-    T: ArrowPrimitiveType + ArrowPrimitiveType,
+    T: ArrowPrimitiveType,
 {
-    let mut b = BooleanBuilder::with_capacity(from.len());
-
-    for i in 0..from.len() {
-        if from.is_null(i) {
-            b.append_null();
-        } else if from.value(i) != T::default_value() {
-            b.append_value(true);
-        } else {
-            b.append_value(false);
-        }
-    }
-
-    Ok(b.finish())
+    let values = from.values();
+    let zero = T::default_value();
+    // Compare every slot, including nulls, against zero so the result can be
+    // produced a word at a time, and reuse the null buffer of the input
+    let buffer = BooleanBuffer::collect_bool(from.len(), |i| values[i] != zero);
+    Ok(BooleanArray::new(buffer, from.nulls().cloned()))
 }
 
 /// Cast Boolean types to numeric
@@ -3337,6 +5695,10 @@ fn dictionary_cast<K: ArrowDictionaryKeyType>(
 ) -> Result<ArrayRef, ArrowError> {
     use DataType::*;
 
+    if cast_options.strict_dictionary_values && !matches!(to_type, Dictionary(_, _)) {
+        validate_dictionary_values(array.as_dictionary::<K>(), to_type)?;
+    }
+
     match to_type {
         Dictionary(to_index_type, to_value_type) => {
             let dict_array = array
@@ -3348,12 +5710,15 @@ fn dictionary_cast<K: ArrowDictionaryKeyType>(
                     )
                 })?;
 
-            let keys_array: ArrayRef =
-                Arc::new(PrimitiveArray::<K>::from(dict_array.keys().to_data()));
-            let values_array = dict_array.values();
-            let cast_keys = cast_with_options(&keys_array, to_index_type, cast_options)?;
             let cast_values =
-                cast_with_options(values_array, to_value_type, cast_options)?;
+                cast_with_options(dict_array.values(), to_value_type, cast_options)?;
+            if to_index_type.as_ref() == &K::DATA_TYPE {
+                // Only the values are cast, the keys are shared with the input
+                return Ok(Arc::new(dict_array.with_values(cast_values.as_ref())));
+            }
+
+            let keys_array = dict_array.keys();
+            let cast_keys = cast_with_options(keys_array, to_index_type, cast_options)?;
 
             // Failure to cast keys (because they don't fit in the
             // target type) results in NULL values;
@@ -3395,10 +5760,264 @@ fn dictionary_cast<K: ArrowDictionaryKeyType>(
 
             Ok(new_array)
         }
+        Utf8 | LargeUtf8 | FixedSizeBinary(_)
+            if matches!(
+                array.as_dictionary::<K>().values().data_type(),
+                Binary | LargeBinary
+            ) =>
+        {
+            unpack_binary_dictionary::<K>(array, to_type, cast_options)
+        }
+        Boolean
+            if matches!(
+                array.as_dictionary::<K>().values().data_type(),
+                Utf8 | LargeUtf8
+            ) =>
+        {
+            unpack_string_dictionary_to_boolean::<K>(array, cast_options)
+        }
+        RunEndEncoded(run_ends, values) => match run_ends.data_type() {
+            Int16 => pack_dictionary_to_run_end_encoded::<K, Int16Type>(
+                array.as_dictionary(),
+                to_type,
+                values.data_type(),
+                cast_options,
+            ),
+            Int32 => pack_dictionary_to_run_end_encoded::<K, Int32Type>(
+                array.as_dictionary(),
+                to_type,
+                values.data_type(),
+                cast_options,
+            ),
+            Int64 => pack_dictionary_to_run_end_encoded::<K, Int64Type>(
+                array.as_dictionary(),
+                to_type,
+                values.data_type(),
+                cast_options,
+            ),
+            t => Err(ArrowError::CastError(format!(
+                "Unsupported type {t:?} for run ends"
+            ))),
+        },
         _ => unpack_dictionary::<K>(array, to_type, cast_options),
     }
 }
 
+// Run end encodes a dictionary where the keys are of type <K>, detecting runs of equal
+// keys, so that only the value of each run is taken from the dictionary and cast
+fn pack_dictionary_to_run_end_encoded<K, R>(
+    array: &DictionaryArray<K>,
+    to_type: &DataType,
+    value_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+    R: RunEndIndexType,
+{
+    let len = array.len();
+    check_run_ends_overflow::<R>(array, to_type)?;
+
+    let mut run_ends = Vec::new();
+    let mut run_keys = Vec::new();
+    let mut keys = array.keys().iter().enumerate();
+    if let Some((_, first)) = keys.next() {
+        let mut current = first;
+        for (i, key) in keys {
+            if key != current {
+                run_ends.push(R::Native::usize_as(i));
+                run_keys.push(current);
+                current = key;
+            }
+        }
+        run_ends.push(R::Native::usize_as(len));
+        run_keys.push(current);
+    }
+
+    let run_keys: UInt64Array = run_keys
+        .into_iter()
+        .map(|k| k.map(|k| k.as_usize() as u64))
+        .collect();
+    let values = take(array.values().as_ref(), &run_keys, None)?;
+    let values = cast_with_options(&values, value_type, cast_options)?;
+    new_run_array::<R>(to_type, len, run_ends, values)
+}
+
+/// Returns an error if the length of `array` cannot be represented by run ends of type R
+fn check_run_ends_overflow<R: RunEndIndexType>(
+    array: &dyn Array,
+    to_type: &DataType,
+) -> Result<(), ArrowError> {
+    match R::Native::from_usize(array.len()) {
+        Some(_) => Ok(()),
+        None => Err(ArrowError::CastError(format!(
+            "Cannot cast {:?} of {} rows to {to_type:?}, the run ends overflow",
+            array.data_type(),
+            array.len()
+        ))),
+    }
+}
+
+fn new_run_array<R: RunEndIndexType>(
+    to_type: &DataType,
+    len: usize,
+    run_ends: Vec<R::Native>,
+    values: ArrayRef,
+) -> Result<ArrayRef, ArrowError> {
+    let data = ArrayData::builder(to_type.clone())
+        .len(len)
+        .add_child_data(PrimitiveArray::<R>::from_iter_values(run_ends).into_data())
+        .add_child_data(values.into_data())
+        .build()?;
+    Ok(Arc::new(RunArray::<R>::from(data)))
+}
+
+/// Returns the end of each run of `array` relative to its offset, and the range of
+/// its values referenced by those runs
+fn logical_run_ends<R: RunEndIndexType>(
+    array: &RunArray<R>,
+) -> (Vec<usize>, std::ops::Range<usize>) {
+    if array.is_empty() {
+        return (vec![], 0..0);
+    }
+    let run_ends = array.run_ends();
+    let start = run_ends.get_start_physical_index();
+    let end = run_ends.get_end_physical_index();
+    let ends = run_ends.values()[start..=end]
+        .iter()
+        .map(|e| (e.as_usize() - run_ends.offset()).min(array.len()))
+        .collect();
+    (ends, start..end + 1)
+}
+
+// Unpacks a run end encoded array into a flattened array of type to_type, casting only
+// the value of each run
+fn unpack_run_end_encoded<R: RunEndIndexType>(
+    array: &RunArray<R>,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let (run_ends, physical) = logical_run_ends(array);
+    let values = array.values().slice(physical.start, physical.len());
+    let values = cast_with_options(&values, to_type, cast_options)?;
+
+    let mut indices = Vec::with_capacity(array.len());
+    let mut start = 0;
+    for (i, end) in run_ends.into_iter().enumerate() {
+        indices.extend(std::iter::repeat(i as u64).take(end - start));
+        start = end;
+    }
+    take(values.as_ref(), &UInt64Array::from(indices), None)
+}
+
+// Casts a run end encoded array to one with run ends of type R, and values of
+// value_type, removing any runs outside of a slice
+fn cast_run_end_encoded<F: RunEndIndexType, R: RunEndIndexType>(
+    array: &RunArray<F>,
+    to_type: &DataType,
+    value_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    check_run_ends_overflow::<R>(array, to_type)?;
+    let (run_ends, physical) = logical_run_ends(array);
+    let values = array.values().slice(physical.start, physical.len());
+    let values = cast_with_options(&values, value_type, cast_options)?;
+    let run_ends = run_ends.into_iter().map(R::Native::usize_as).collect();
+    new_run_array::<R>(to_type, array.len(), run_ends, values)
+}
+
+// Run end encodes `array`, detecting runs of equal values, so that only the value of
+// each run is cast to value_type. Nulls are equal to each other
+fn pack_run_end_encoded<R: RunEndIndexType>(
+    array: &dyn Array,
+    to_type: &DataType,
+    value_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    check_run_ends_overflow::<R>(array, to_type)?;
+    let run_ends = find_run_ends(array);
+    let starts: UInt64Array = std::iter::once(0)
+        .chain(run_ends.iter().map(|e| *e as u64))
+        .take(run_ends.len())
+        .map(Some)
+        .collect();
+    let values = take(array, &starts, None)?;
+    let values = cast_with_options(&values, value_type, cast_options)?;
+    let run_ends = run_ends.into_iter().map(R::Native::usize_as).collect();
+    new_run_array::<R>(to_type, array.len(), run_ends, values)
+}
+
+/// Returns the end of each run of equal values of `array`
+fn find_run_ends(array: &dyn Array) -> Vec<usize> {
+    downcast_primitive_array!(
+        array => run_ends_by(array, |a, b| array.value(a).is_eq(array.value(b))),
+        DataType::Boolean => {
+            let values = array.as_boolean();
+            run_ends_by(array, |a, b| values.value(a) == values.value(b))
+        }
+        DataType::Utf8 => {
+            let values = array.as_string::<i32>();
+            run_ends_by(array, |a, b| values.value(a) == values.value(b))
+        }
+        DataType::LargeUtf8 => {
+            let values = array.as_string::<i64>();
+            run_ends_by(array, |a, b| values.value(a) == values.value(b))
+        }
+        DataType::Binary => {
+            let values = array.as_binary::<i32>();
+            run_ends_by(array, |a, b| values.value(a) == values.value(b))
+        }
+        DataType::LargeBinary => {
+            let values = array.as_binary::<i64>();
+            run_ends_by(array, |a, b| values.value(a) == values.value(b))
+        }
+        _ => {
+            let data = array.to_data();
+            run_ends_by(array, |a, b| data.slice(a, 1) == data.slice(b, 1))
+        }
+    )
+}
+
+fn run_ends_by<F: Fn(usize, usize) -> bool>(array: &dyn Array, eq: F) -> Vec<usize> {
+    let mut run_ends = Vec::new();
+    for i in 1..array.len() {
+        let equal = match (array.is_valid(i - 1), array.is_valid(i)) {
+            (true, true) => eq(i - 1, i),
+            (valid, next_valid) => valid == next_valid,
+        };
+        if !equal {
+            run_ends.push(i);
+        }
+    }
+    if !array.is_empty() {
+        run_ends.push(array.len());
+    }
+    run_ends
+}
+
+/// Returns an error if a key of `array` references a null value, for
+/// [`CastOptions::strict_dictionary_values`]
+fn validate_dictionary_values<K: ArrowDictionaryKeyType>(
+    array: &DictionaryArray<K>,
+    to_type: &DataType,
+) -> Result<(), ArrowError> {
+    let values = array.values();
+    if values.null_count() == 0 {
+        return Ok(());
+    }
+    let keys = array.keys();
+    match (0..keys.len())
+        .find(|i| keys.is_valid(*i) && values.is_null(keys.value(*i).as_usize()))
+    {
+        Some(i) => Err(ArrowError::CastError(format!(
+            "Cannot cast {:?} to {to_type:?}, as key {:?} at index {i} references a null value",
+            array.data_type(),
+            keys.value(i)
+        ))),
+        None => Ok(()),
+    }
+}
+
 // Unpack a dictionary where the keys are of type <K> into a flattened array of type to_type
 fn unpack_dictionary<K>(
     array: &dyn Array,
@@ -3420,7 +6039,97 @@ where
     // attempt to cast the dict values to the target type
     // use the take kernel to expand out the dictionary
     let cast_dict_values = cast_with_options(dict_array.values(), to_type, cast_options)?;
+    take_dictionary_values(dict_array, cast_dict_values.as_ref(), cast_options)
+}
+
+// Unpack a dictionary with binary values into a flattened array of type to_type
+//
+// Values that can't be converted to to_type only result in an error, when not safe,
+// if they are referenced by a key
+fn unpack_binary_dictionary<K>(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+{
+    let dict_array = array.as_dictionary::<K>();
+    let values = dict_array.values();
+
+    let safe_options = cast_options.clone().with_safe(true);
+    let cast_dict_values = cast_with_options(values, to_type, &safe_options)?;
+
+    if !cast_options.safe && cast_dict_values.null_count() > values.null_count() {
+        for key in dict_array.keys().iter().flatten() {
+            let idx = key.as_usize();
+            if cast_dict_values.is_null(idx) && values.is_valid(idx) {
+                // cast the value again to return the error of the failed conversion
+                cast_with_options(&values.slice(idx, 1), to_type, cast_options)?;
+            }
+        }
+    }
+
+    take_dictionary_values(dict_array, cast_dict_values.as_ref(), cast_options)
+}
+
+/// Cast a dictionary with string values to Boolean, parsing each distinct value once,
+/// see [`parse_bool`]
+///
+/// Values that can't be parsed only result in an error, when not safe, if they are
+/// referenced by a key
+fn unpack_string_dictionary_to_boolean<K>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+{
+    let dict_array = array.as_dictionary::<K>();
+    let values = dict_array.values();
+    let keys = dict_array.keys();
+
+    let safe_options = cast_options.clone().with_safe(true);
+    let cast_values = cast_with_options(values, &DataType::Boolean, &safe_options)?;
+    let cast_values = cast_values.as_boolean();
+
+    if !cast_options.safe && cast_values.null_count() > values.null_count() {
+        for key in keys.iter().flatten() {
+            let idx = key.as_usize();
+            if cast_values.is_null(idx) && values.is_valid(idx) {
+                // cast the value again to return the error of the failed conversion
+                cast_with_options(
+                    &values.slice(idx, 1),
+                    &DataType::Boolean,
+                    cast_options,
+                )?;
+            }
+        }
+    }
+
+    // The keys of null slots need not be valid indices, and so are not read
+    let key = |i: usize| keys.is_valid(i).then(|| keys.value(i).as_usize());
+    let nulls = BooleanBuffer::collect_bool(keys.len(), |i| {
+        key(i).map_or(false, |k| cast_values.is_valid(k))
+    });
+    let bits = BooleanBuffer::collect_bool(keys.len(), |i| {
+        key(i).map_or(false, |k| cast_values.value(k))
+    });
+    Ok(Arc::new(BooleanArray::new(
+        bits,
+        Some(NullBuffer::new(nulls)),
+    )))
+}
 
+// Expand the values of a dictionary, already cast to the target type, using its keys
+fn take_dictionary_values<K>(
+    dict_array: &DictionaryArray<K>,
+    values: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+{
     // Note take requires first casting the indices to u32
     let keys_array: ArrayRef =
         Arc::new(PrimitiveArray::<K>::from(dict_array.keys().to_data()));
@@ -3435,7 +6144,7 @@ where
                 )
             })?;
 
-    take(cast_dict_values.as_ref(), u32_indices, None)
+    take(values, u32_indices, None)
 }
 
 /// Attempts to encode an array into an `ArrayDictionary` with index
@@ -3500,22 +6209,180 @@ fn cast_to_dictionary<K: ArrowDictionaryKeyType>(
             dict_value_type,
             cast_options,
         ),
-        Utf8 => pack_byte_to_dictionary::<K, GenericStringType<i32>>(array, cast_options),
-        LargeUtf8 => {
-            pack_byte_to_dictionary::<K, GenericStringType<i64>>(array, cast_options)
-        }
+        Utf8 => match array.data_type() {
+            Boolean => pack_boolean_to_string_dictionary::<K, i32>(array, cast_options),
+            Decimal128(_, _) => pack_primitive_to_string_dictionary::<
+                K,
+                Decimal128Type,
+                i32,
+            >(array, cast_options),
+            Decimal256(_, _) => pack_primitive_to_string_dictionary::<
+                K,
+                Decimal256Type,
+                i32,
+            >(array, cast_options),
+            Timestamp(unit, _) => {
+                pack_timestamp_to_string_dictionary::<K, i32>(array, unit, cast_options)
+            }
+            _ => {
+                pack_byte_to_dictionary::<K, GenericStringType<i32>>(array, cast_options)
+            }
+        },
+        LargeUtf8 => match array.data_type() {
+            Boolean => pack_boolean_to_string_dictionary::<K, i64>(array, cast_options),
+            Decimal128(_, _) => pack_primitive_to_string_dictionary::<
+                K,
+                Decimal128Type,
+                i64,
+            >(array, cast_options),
+            Decimal256(_, _) => pack_primitive_to_string_dictionary::<
+                K,
+                Decimal256Type,
+                i64,
+            >(array, cast_options),
+            Timestamp(unit, _) => {
+                pack_timestamp_to_string_dictionary::<K, i64>(array, unit, cast_options)
+            }
+            _ => {
+                pack_byte_to_dictionary::<K, GenericStringType<i64>>(array, cast_options)
+            }
+        },
         Binary => {
             pack_byte_to_dictionary::<K, GenericBinaryType<i32>>(array, cast_options)
         }
         LargeBinary => {
             pack_byte_to_dictionary::<K, GenericBinaryType<i64>>(array, cast_options)
         }
+        FixedSizeBinary(byte_width) => {
+            pack_fixed_size_binary_to_dictionary::<K>(array, byte_width, cast_options)
+        }
+        Time32(TimeUnit::Second) => pack_numeric_to_dictionary::<K, Time32SecondType>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        Time32(TimeUnit::Millisecond) => pack_numeric_to_dictionary::<
+            K,
+            Time32MillisecondType,
+        >(array, dict_value_type, cast_options),
+        Time64(TimeUnit::Microsecond) => pack_numeric_to_dictionary::<
+            K,
+            Time64MicrosecondType,
+        >(array, dict_value_type, cast_options),
+        Time64(TimeUnit::Nanosecond) => pack_numeric_to_dictionary::<
+            K,
+            Time64NanosecondType,
+        >(array, dict_value_type, cast_options),
+        Date32 => pack_numeric_to_dictionary::<K, Date32Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        Date64 => pack_numeric_to_dictionary::<K, Date64Type>(
+            array,
+            dict_value_type,
+            cast_options,
+        ),
+        Timestamp(TimeUnit::Second, _) => pack_numeric_to_dictionary::<
+            K,
+            TimestampSecondType,
+        >(array, dict_value_type, cast_options),
+        Timestamp(TimeUnit::Millisecond, _) => pack_numeric_to_dictionary::<
+            K,
+            TimestampMillisecondType,
+        >(
+            array, dict_value_type, cast_options
+        ),
+        Timestamp(TimeUnit::Microsecond, _) => pack_numeric_to_dictionary::<
+            K,
+            TimestampMicrosecondType,
+        >(
+            array, dict_value_type, cast_options
+        ),
+        Timestamp(TimeUnit::Nanosecond, _) => pack_numeric_to_dictionary::<
+            K,
+            TimestampNanosecondType,
+        >(
+            array, dict_value_type, cast_options
+        ),
+        Duration(TimeUnit::Second) => {
+            pack_numeric_to_dictionary::<K, DurationSecondType>(
+                array,
+                dict_value_type,
+                cast_options,
+            )
+        }
+        Duration(TimeUnit::Millisecond) => pack_numeric_to_dictionary::<
+            K,
+            DurationMillisecondType,
+        >(array, dict_value_type, cast_options),
+        Duration(TimeUnit::Microsecond) => pack_numeric_to_dictionary::<
+            K,
+            DurationMicrosecondType,
+        >(array, dict_value_type, cast_options),
+        Duration(TimeUnit::Nanosecond) => pack_numeric_to_dictionary::<
+            K,
+            DurationNanosecondType,
+        >(array, dict_value_type, cast_options),
+        Interval(IntervalUnit::YearMonth) => pack_numeric_to_dictionary::<
+            K,
+            IntervalYearMonthType,
+        >(
+            array, dict_value_type, cast_options
+        ),
+        Interval(IntervalUnit::DayTime) => pack_numeric_to_dictionary::<
+            K,
+            IntervalDayTimeType,
+        >(array, dict_value_type, cast_options),
+        Interval(IntervalUnit::MonthDayNano) => pack_numeric_to_dictionary::<
+            K,
+            IntervalMonthDayNanoType,
+        >(
+            array, dict_value_type, cast_options
+        ),
+        Dictionary(_, _) => {
+            let inner = cast_with_options(array, dict_value_type, cast_options)?;
+            let inner = inner.as_ref();
+            downcast_dictionary_array!(
+                inner => nest_dictionary::<K, _>(inner, cast_options),
+                _ => unreachable!()
+            )
+        }
         _ => Err(ArrowError::CastError(format!(
             "Unsupported output type for dictionary packing: {dict_value_type:?}"
         ))),
     }
 }
 
+/// Encodes the dictionary `inner` as a dictionary of dictionaries with keys of type K,
+/// whose keys are those of `inner`, and whose values reference each value of `inner`
+fn nest_dictionary<K, I>(
+    inner: &DictionaryArray<I>,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+    I: ArrowDictionaryKeyType,
+{
+    let keys = cast_with_options(inner.keys(), &K::DATA_TYPE, cast_options)?;
+    if keys.null_count() > inner.keys().null_count() {
+        return Err(ArrowError::ComputeError(format!(
+            "Could not convert {} dictionary indexes from {:?} to {:?}",
+            keys.null_count() - inner.keys().null_count(),
+            I::DATA_TYPE,
+            K::DATA_TYPE
+        )));
+    }
+
+    let values = inner.values();
+    let value_keys =
+        PrimitiveArray::<I>::from_iter_values((0..values.len()).map(I::Native::usize_as));
+    let values = DictionaryArray::try_new(value_keys, values.clone())?;
+    let nested =
+        DictionaryArray::try_new(keys.as_primitive::<K>().clone(), Arc::new(values))?;
+    Ok(Arc::new(nested))
+}
+
 // Packs the data from the primitive array of type <V> to a
 // DictionaryArray with keys of type K and values of value_type V
 fn pack_numeric_to_dictionary<K, V>(
@@ -3542,58 +6409,364 @@ where
             b.append(values.value(i))?;
         }
     }
-    Ok(Arc::new(b.finish()))
+    let dict = b.finish();
+    // The builder produces values of V::DATA_TYPE, restore the parameters of
+    // dict_value_type such as the timezone, or decimal precision and scale
+    let values = dict
+        .values()
+        .as_primitive::<V>()
+        .clone()
+        .with_data_type(dict_value_type.clone());
+    let dict = DictionaryArray::<K>::try_new(dict.keys().clone(), Arc::new(values))?;
+    Ok(Arc::new(dict))
 }
 
-// Packs the data as a GenericByteDictionaryBuilder, if possible, with the
-// key types of K
-fn pack_byte_to_dictionary<K, T>(
+// Packs the primitive array of type <T> to a DictionaryArray with keys of type K
+// and string values, formatting only the distinct values with the same formatter
+// as [`value_to_string`], e.g. [`crate::display::write_decimal`] for decimals
+fn pack_primitive_to_string_dictionary<K, T, O>(
     array: &dyn Array,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError>
 where
     K: ArrowDictionaryKeyType,
-    T: ByteArrayType,
+    T: ArrowPrimitiveType,
+    O: OffsetSizeTrait,
 {
-    let cast_values = cast_with_options(array, &T::DATA_TYPE, cast_options)?;
-    let values = cast_values
-        .as_any()
-        .downcast_ref::<GenericByteArray<T>>()
-        .unwrap();
-    let mut b =
-        GenericByteDictionaryBuilder::<K, T>::with_capacity(values.len(), 1024, 1024);
+    let mut b = PrimitiveDictionaryBuilder::<K, T>::with_capacity(array.len(), 1024);
 
     // copy each element one at a time
-    for i in 0..values.len() {
-        if values.is_null(i) {
-            b.append_null();
-        } else {
-            b.append(values.value(i))?;
+    for v in array.as_primitive::<T>().iter() {
+        match v {
+            Some(v) => {
+                b.append(v)?;
+            }
+            None => b.append_null(),
         }
     }
-    Ok(Arc::new(b.finish()))
+    let dict = b.finish();
+
+    // Restore the precision and scale, or timezone, of the values
+    let values = dict
+        .values()
+        .as_primitive::<T>()
+        .clone()
+        .with_data_type(array.data_type().clone());
+    Ok(Arc::new(DictionaryArray::<K>::try_new(
+        dict.keys().clone(),
+        value_to_string::<O>(&values, cast_options)?,
+    )?))
 }
 
-/// Helper function that takes a primitive array and casts to a (generic) list array.
-fn cast_primitive_to_list<OffsetSize: OffsetSizeTrait + NumCast>(
+/// Packs a boolean array into a dictionary of the two strings that `false` and `true`
+/// are cast to, see [`cast_bool_to_string`], taking the key of each value from its bit
+fn pack_boolean_to_string_dictionary<K, O>(
     array: &dyn Array,
-    to: &Field,
-    to_type: &DataType,
     cast_options: &CastOptions,
-) -> Result<ArrayRef, ArrowError> {
-    // cast primitive to list's primitive
-    let cast_array = cast_with_options(array, to.data_type(), cast_options)?;
-    // create offsets, where if array.len() = 2, we have [0,1,2]
-    // Safety:
-    // Length of range can be trusted.
-    // Note: could not yet create a generic range in stable Rust.
-    let offsets = unsafe {
-        MutableBuffer::from_trusted_len_iter(
-            (0..=array.len()).map(|i| OffsetSize::from(i).expect("integer")),
-        )
-    };
-
-    let list_data = unsafe {
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+    O: OffsetSizeTrait,
+{
+    let array = array.as_boolean();
+    let labels = BooleanArray::from(vec![false, true]);
+    let values = cast_bool_to_string::<O>(&labels, cast_options)?;
+
+    let (f, t) = (K::Native::usize_as(0), K::Native::usize_as(1));
+    let keys: Vec<_> = array
+        .values()
+        .iter()
+        .map(|v| if v { t } else { f })
+        .collect();
+    let keys = PrimitiveArray::<K>::new(keys.into(), array.nulls().cloned());
+    // Safety: the keys are 0 and 1, the indices of the two values
+    Ok(Arc::new(unsafe {
+        DictionaryArray::new_unchecked(keys, values)
+    }))
+}
+
+fn pack_timestamp_to_string_dictionary<K, O>(
+    array: &dyn Array,
+    unit: &TimeUnit,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+    O: OffsetSizeTrait,
+{
+    match unit {
+        TimeUnit::Second => pack_primitive_to_string_dictionary::<
+            K,
+            TimestampSecondType,
+            O,
+        >(array, cast_options),
+        TimeUnit::Millisecond => pack_primitive_to_string_dictionary::<
+            K,
+            TimestampMillisecondType,
+            O,
+        >(array, cast_options),
+        TimeUnit::Microsecond => pack_primitive_to_string_dictionary::<
+            K,
+            TimestampMicrosecondType,
+            O,
+        >(array, cast_options),
+        TimeUnit::Nanosecond => pack_primitive_to_string_dictionary::<
+            K,
+            TimestampNanosecondType,
+            O,
+        >(array, cast_options),
+    }
+}
+
+// Packs the data to a DictionaryArray with keys of type K and FixedSizeBinary values,
+// validating the length of and deduplicating binary values in a single pass
+fn pack_fixed_size_binary_to_dictionary<K>(
+    array: &dyn Array,
+    byte_width: i32,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+{
+    match array.data_type() {
+        DataType::Binary => pack_fixed_size_values::<K, _>(
+            array.as_binary::<i32>().iter(),
+            array.len(),
+            byte_width,
+            cast_options,
+        ),
+        DataType::LargeBinary => pack_fixed_size_values::<K, _>(
+            array.as_binary::<i64>().iter(),
+            array.len(),
+            byte_width,
+            cast_options,
+        ),
+        DataType::FixedSizeBinary(w) if *w == byte_width => {
+            pack_fixed_size_values::<K, _>(
+                array
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap()
+                    .iter(),
+                array.len(),
+                byte_width,
+                cast_options,
+            )
+        }
+        _ => {
+            let to_type = DataType::FixedSizeBinary(byte_width);
+            let values = cast_with_options(array, &to_type, cast_options)?;
+            pack_fixed_size_binary_to_dictionary::<K>(&values, byte_width, cast_options)
+        }
+    }
+}
+
+fn pack_fixed_size_values<'a, K, I>(
+    values: I,
+    len: usize,
+    byte_width: i32,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+    I: Iterator<Item = Option<&'a [u8]>>,
+{
+    let distinct = cast_options
+        .dictionary_cardinality_hint
+        .unwrap_or(0)
+        .min(len);
+    let mut keys = PrimitiveBuilder::<K>::with_capacity(len);
+    let mut dict_values = FixedSizeBinaryBuilder::with_capacity(distinct, byte_width);
+    let mut dedup: HashMap<&[u8], K::Native> = HashMap::with_capacity(distinct);
+
+    for (i, v) in values.enumerate() {
+        match v {
+            Some(v) if v.len() == byte_width as usize => {
+                let next = dedup.len();
+                let key = match dedup.get(v) {
+                    Some(key) => *key,
+                    None => {
+                        let key = K::Native::from_usize(next)
+                            .ok_or(ArrowError::DictionaryKeyOverflowError)?;
+                        dict_values.append_value(v)?;
+                        dedup.insert(v, key);
+                        key
+                    }
+                };
+                keys.append_value(key);
+            }
+            Some(v) if !cast_options.safe => {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast binary value of length {} at row {i} to FixedSizeBinary({byte_width})",
+                    v.len()
+                )))
+            }
+            _ => keys.append_null(),
+        }
+    }
+
+    Ok(Arc::new(DictionaryArray::<K>::try_new(
+        keys.finish(),
+        Arc::new(dict_values.finish()),
+    )?))
+}
+
+// Packs the data as a GenericByteDictionaryBuilder, if possible, with the
+// key types of K
+fn pack_byte_to_dictionary<K, T>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+    T: ByteArrayType,
+{
+    use DataType::*;
+    // Strings and binary values are packed directly, copying only the distinct values
+    // to the offsets of T, instead of casting every value first
+    let cast_values;
+    let values = match (array.data_type(), T::DATA_TYPE) {
+        (Utf8 | LargeUtf8, Utf8 | LargeUtf8)
+        | (Binary | LargeBinary, Binary | LargeBinary) => array,
+        _ => {
+            cast_values = cast_with_options(array, &T::DATA_TYPE, cast_options)?;
+            cast_values.as_ref()
+        }
+    };
+
+    match values.data_type() {
+        Utf8 => pack_distinct_bytes::<K, _, T>(values.as_string::<i32>(), cast_options),
+        LargeUtf8 => {
+            pack_distinct_bytes::<K, _, T>(values.as_string::<i64>(), cast_options)
+        }
+        Binary => pack_distinct_bytes::<K, _, T>(values.as_binary::<i32>(), cast_options),
+        LargeBinary => {
+            pack_distinct_bytes::<K, _, T>(values.as_binary::<i64>(), cast_options)
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Packs `array` into a dictionary with values of type `T`, hashing each value once and
+/// copying each distinct value once, where `S` and `T` are both string or both binary
+fn pack_distinct_bytes<K, S, T>(
+    array: &GenericByteArray<S>,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+    S: ByteArrayType,
+    T: ByteArrayType,
+{
+    let distinct = cast_options
+        .dictionary_cardinality_hint
+        .unwrap_or_else(|| estimate_distinct(array))
+        .min(array.len());
+
+    let mut indices: HashMap<&[u8], K::Native> = HashMap::with_capacity(distinct);
+    let mut distinct_values: Vec<&[u8]> = Vec::with_capacity(distinct);
+    let mut keys = Vec::with_capacity(array.len());
+    for v in array.iter() {
+        let key = match v {
+            Some(v) => match indices.entry(v.as_ref()) {
+                Entry::Occupied(e) => *e.get(),
+                Entry::Vacant(e) => {
+                    let key = K::Native::from_usize(distinct_values.len())
+                        .ok_or(ArrowError::DictionaryKeyOverflowError)?;
+                    distinct_values.push(*e.key());
+                    *e.insert(key)
+                }
+            },
+            None => K::Native::default(),
+        };
+        keys.push(key);
+    }
+
+    let bytes = distinct_values.iter().map(|v| v.len()).sum();
+    let mut data = Vec::with_capacity(bytes);
+    let mut offsets = Vec::with_capacity(distinct_values.len() + 1);
+    offsets.push(T::Offset::default());
+    for v in distinct_values {
+        data.extend_from_slice(v);
+        let offset = T::Offset::from_usize(data.len()).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Distinct values too large to pack into a dictionary of {:?}",
+                T::DATA_TYPE
+            ))
+        })?;
+        offsets.push(offset);
+    }
+    // The values were copied from an array of the same kind, and so are valid UTF-8
+    // if T is a string type
+    let values = GenericByteArray::<T>::new_unchecked(
+        OffsetBuffer::new(offsets.into()),
+        Buffer::from_vec(data),
+        None,
+    );
+
+    let keys = PrimitiveArray::<K>::new(keys.into(), array.nulls().cloned());
+    // Safety: each valid key is the index of a distinct value
+    let dictionary = unsafe { DictionaryArray::new_unchecked(keys, Arc::new(values)) };
+    Ok(Arc::new(dictionary))
+}
+
+/// The maximum number of values sampled by [`estimate_distinct`]
+const DISTINCT_SAMPLE_SIZE: usize = 1024;
+
+/// Estimates the number of distinct non-null values in `array` from an evenly spaced
+/// sample, using the Guaranteed-Error Estimator of Charikar et al., which scales the
+/// number of values seen exactly once by `sqrt(len / sample_size)`
+fn estimate_distinct<T: ByteArrayType>(array: &GenericByteArray<T>) -> usize {
+    let len = array.len() - array.null_count();
+    if len == 0 {
+        return 0;
+    }
+
+    let step = (array.len() / DISTINCT_SAMPLE_SIZE).max(1);
+    let mut counts: HashMap<&[u8], usize> = HashMap::new();
+    for value in array.iter().step_by(step).flatten() {
+        let value: &[u8] = value.as_ref();
+        *counts.entry(value).or_default() += 1;
+    }
+
+    let sampled = counts.values().sum::<usize>();
+    if sampled == 0 {
+        // All sampled values were null, the distribution is unknown
+        return len.min(DISTINCT_SAMPLE_SIZE);
+    }
+    let once = counts.values().filter(|c| **c == 1).count();
+    let scale = (len as f64 / sampled as f64).sqrt();
+    let estimate = scale * once as f64 + (counts.len() - once) as f64;
+    (estimate.ceil() as usize).clamp(1, len)
+}
+
+/// Helper function that takes a primitive array and casts to a (generic) list array.
+fn cast_primitive_to_list<OffsetSize: OffsetSizeTrait + NumCast>(
+    array: &dyn Array,
+    to: &Field,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    if OffsetSize::from(array.len()).is_none() {
+        return Err(ArrowError::CastError(format!(
+            "Cannot cast array of length {} to {to_type:?}, offsets would overflow",
+            array.len()
+        )));
+    }
+    // cast primitive to list's primitive
+    let cast_array = cast_with_options(array, to.data_type(), cast_options)?;
+    // create offsets, where if array.len() = 2, we have [0,1,2]
+    // Safety:
+    // Length of range can be trusted.
+    // Note: could not yet create a generic range in stable Rust.
+    let offsets = unsafe {
+        MutableBuffer::from_trusted_len_iter(
+            // checked above that the length fits in OffsetSize
+            (0..=array.len()).map(|i| OffsetSize::from(i).unwrap()),
+        )
+    };
+
+    let list_data = unsafe {
         ArrayData::new_unchecked(
             to_type.clone(),
             array.len(),
@@ -3613,282 +6786,1077 @@ fn cast_primitive_to_list<OffsetSize: OffsetSizeTrait + NumCast>(
 /// Helper function that takes an Generic list container and casts the inner datatype.
 fn cast_list_inner<OffsetSize: OffsetSizeTrait>(
     array: &dyn Array,
-    to: &Field,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list::<OffsetSize>();
+    let offsets = list.offsets();
+    // Only cast the values within the offsets, which may be a small window of the
+    // values of a sliced list, and may be followed by values that fail to cast
+    let start = offsets[0];
+    let end = offsets[offsets.len() - 1];
+    let values = list
+        .values()
+        .slice(start.as_usize(), (end - start).as_usize());
+    let values = cast_with_options(values.as_ref(), to.data_type(), cast_options)?;
+
+    let offsets = match start.as_usize() {
+        0 => offsets.clone(),
+        _ => {
+            let offsets: Vec<_> = offsets.iter().map(|o| *o - start).collect();
+            OffsetBuffer::new(offsets.into())
+        }
+    };
+    validate_list_values_nullability(&offsets, values.as_ref(), to)?;
+    Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
+        to.clone(),
+        offsets,
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
+/// Cast a union to a union with the same type ids and mode, casting each child to the
+/// type of the field with the same type id
+fn cast_union_to_union(
+    array: &dyn Array,
+    to_fields: &UnionFields,
+    to_mode: UnionMode,
     to_type: &DataType,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    let data = array.to_data();
-    let underlying_array = make_array(data.child_data()[0].clone());
-    let cast_array =
-        cast_with_options(underlying_array.as_ref(), to.data_type(), cast_options)?;
-    let builder = data
-        .into_builder()
-        .data_type(to_type.clone())
-        .child_data(vec![cast_array.into_data()]);
+    let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+    let (from_fields, from_mode) = match array.data_type() {
+        DataType::Union(fields, mode) => (fields, *mode),
+        _ => unreachable!(),
+    };
+    let from_ids = from_fields.iter().map(|(type_id, _)| type_id);
+    if from_mode != to_mode || !from_ids.eq(to_fields.iter().map(|(type_id, _)| type_id))
+    {
+        return Err(ArrowError::CastError(format!(
+            "Casting from {:?} to {to_type:?} not supported, as the unions have different type ids or modes",
+            array.data_type()
+        )));
+    }
 
-    // Safety
-    // Data was valid before
-    let array_data = unsafe { builder.build_unchecked() };
-    let list = GenericListArray::<OffsetSize>::from(array_data);
-    Ok(Arc::new(list) as ArrayRef)
+    let children = to_fields
+        .iter()
+        .map(|(type_id, field)| {
+            let child = array.child(type_id);
+            let child = match to_mode {
+                // The values of a sparse child that are not selected by the type ids
+                // are unspecified, and should not fail the cast
+                UnionMode::Sparse => {
+                    let unselected = BooleanArray::from_unary(
+                        &PrimitiveArray::<Int8Type>::new(array.type_ids().clone(), None),
+                        |id| id != type_id,
+                    );
+                    nullif(child.as_ref(), &unselected)?
+                }
+                UnionMode::Dense => child.clone(),
+            };
+            let child =
+                cast_with_options(child.as_ref(), field.data_type(), cast_options)?;
+            Ok((field.as_ref().clone(), child))
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let type_ids: Vec<i8> = to_fields.iter().map(|(type_id, _)| type_id).collect();
+    let offsets = array.offsets().map(|offsets| offsets.inner().clone());
+    Ok(Arc::new(UnionArray::try_new(
+        &type_ids,
+        array.type_ids().inner().clone(),
+        offsets,
+        children,
+    )?))
 }
 
-/// A specified helper to cast from `GenericBinaryArray` to `GenericStringArray` when they have same
-/// offset size so re-encoding offset is unnecessary.
-fn cast_binary_to_string<O: OffsetSizeTrait>(
+/// Cast each value of a union to `to_type`, from the child selected by its type id
+fn cast_union_values(
     array: &dyn Array,
+    to_type: &DataType,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    let array = array
-        .as_any()
-        .downcast_ref::<GenericByteArray<GenericBinaryType<O>>>()
-        .unwrap();
+    let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+    let fields = match array.data_type() {
+        DataType::Union(fields, _) => fields,
+        _ => unreachable!(),
+    };
 
-    match GenericStringArray::<O>::try_from_binary(array.clone()) {
-        Ok(a) => Ok(Arc::new(a)),
-        Err(e) => match cast_options.safe {
-            true => {
-                // Fallback to slow method to convert invalid sequences to nulls
-                let mut builder = GenericStringBuilder::<O>::with_capacity(
-                    array.len(),
-                    array.value_data().len(),
-                );
+    // The index of each field by type id, and the positions within its child of the
+    // values selected by the type ids, such that only these values are cast
+    let mut field_index = [0; 128];
+    fields
+        .iter()
+        .enumerate()
+        .for_each(|(idx, (type_id, _))| field_index[type_id as usize] = idx);
+    let mut positions = vec![vec![]; fields.len()];
+    let indices: Vec<(usize, usize)> = (0..array.len())
+        .map(|i| {
+            let idx = field_index[array.type_id(i) as usize];
+            positions[idx].push(array.value_offset(i) as u32);
+            (idx, positions[idx].len() - 1)
+        })
+        .collect();
 
-                let iter = array
-                    .iter()
-                    .map(|v| v.and_then(|v| std::str::from_utf8(v).ok()));
+    let values = fields
+        .iter()
+        .zip(positions)
+        .map(|((type_id, _), positions)| {
+            let positions = UInt32Array::from(positions);
+            let values = take(array.child(type_id).as_ref(), &positions, None)?;
+            cast_with_options(values.as_ref(), to_type, cast_options)
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
 
-                builder.extend(iter);
-                Ok(Arc::new(builder.finish()))
-            }
-            false => Err(e),
-        },
+    match values.is_empty() {
+        true => Ok(new_empty_array(to_type)),
+        false => {
+            let values: Vec<_> = values.iter().map(|v| v.as_ref()).collect();
+            interleave(&values, &indices)
+        }
     }
 }
 
-/// Helper function to cast from one `BinaryArray` or 'LargeBinaryArray' to 'FixedSizeBinaryArray'.
-fn cast_binary_to_fixed_size_binary<O: OffsetSizeTrait>(
+/// Cast a fixed size list to a list, casting its values to the type of `to`
+fn cast_fixed_size_list_to_list<OffsetSize: OffsetSizeTrait>(
     array: &dyn Array,
-    byte_width: i32,
+    to: &FieldRef,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    let array = array.as_binary::<O>();
-    let mut builder = FixedSizeBinaryBuilder::with_capacity(array.len(), byte_width);
+    let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let size = array.value_length() as usize;
+    let offsets = (0..=array.len())
+        .map(|i| OffsetSize::from_usize(i * size))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "{:?} array too large to cast to {}List",
+                array.data_type(),
+                OffsetSize::PREFIX
+            ))
+        })?;
 
-    for i in 0..array.len() {
-        if array.is_null(i) {
-            builder.append_null();
-        } else {
-            match builder.append_value(array.value(i)) {
-                Ok(_) => {}
-                Err(e) => match cast_options.safe {
-                    true => builder.append_null(),
-                    false => return Err(e),
-                },
+    let values = cast_with_options(array.values(), to.data_type(), cast_options)?;
+    validate_list_values_nullability(&offsets, values.as_ref(), to)?;
+    Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
+        to.clone(),
+        OffsetBuffer::new(offsets.into()),
+        values,
+        array.nulls().cloned(),
+    )?))
+}
+
+/// Cast a list to a fixed size list of `size` values, casting its values to the type
+/// of `to`
+///
+/// Lists with a different number of values are null if [`CastOptions::safe`] is true,
+/// and an error otherwise
+fn cast_list_to_fixed_size_list<OffsetSize: OffsetSizeTrait>(
+    array: &dyn Array,
+    to: &FieldRef,
+    size: i32,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_list::<OffsetSize>();
+    let size = size as usize;
+    let offsets = array.value_offsets();
+
+    let (values, nulls) = if offsets.windows(2).all(|w| (w[1] - w[0]).as_usize() == size)
+    {
+        let start = offsets[0].as_usize();
+        let values = array.values().slice(start, array.len() * size);
+        (values, array.nulls().cloned())
+    } else {
+        // Take the values of each list with the expected length, and nulls in place
+        // of the others
+        let mut indices = Vec::with_capacity(array.len() * size);
+        let mut nulls = BooleanBufferBuilder::new(array.len());
+        for (i, w) in offsets.windows(2).enumerate() {
+            let (start, end) = (w[0].as_usize(), w[1].as_usize());
+            let valid = array.is_valid(i);
+            if valid && end - start == size {
+                indices.extend((start as u64..end as u64).map(Some));
+                nulls.append(true);
+                continue;
+            }
+            if valid && !cast_options.safe {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast list of length {} at row {i} to FixedSizeList({size})",
+                    end - start
+                )));
             }
+            indices.extend(std::iter::repeat(None).take(size));
+            nulls.append(false);
         }
-    }
+        let indices = UInt64Array::from(indices);
+        let values = take(array.values().as_ref(), &indices, None)?;
+        (values, Some(NullBuffer::new(nulls.finish())))
+    };
 
-    Ok(Arc::new(builder.finish()))
+    let values = cast_with_options(values.as_ref(), to.data_type(), cast_options)?;
+    fixed_size_list(to_type, to, array.len(), values, nulls)
 }
 
-/// Helper function to cast from 'FixedSizeBinaryArray' to one `BinaryArray` or 'LargeBinaryArray'.
-/// If the target one is too large for the source array it will return an Error.
-fn cast_fixed_size_binary_to_binary<O: OffsetSizeTrait>(
+/// Casts strings of bracketed, comma separated floats, e.g. `[0.1, -2, 3e-4]`, to a
+/// fixed size list of `Float32` or `Float64`, such as embeddings exported as text
+fn cast_string_to_fixed_size_list<O: OffsetSizeTrait>(
     array: &dyn Array,
-    byte_width: i32,
+    to: &FieldRef,
+    size: i32,
+    to_type: &DataType,
+    cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    let array = array
-        .as_any()
-        .downcast_ref::<FixedSizeBinaryArray>()
-        .unwrap();
+    let array = array.as_string::<O>();
+    let (values, nulls) = match to.data_type() {
+        DataType::Float32 => {
+            parse_float_lists::<O, Float32Type>(array, size as usize, cast_options)?
+        }
+        DataType::Float64 => {
+            parse_float_lists::<O, Float64Type>(array, size as usize, cast_options)?
+        }
+        _ => {
+            return Err(ArrowError::CastError(format!(
+                "Casting from {:?} to {to_type:?} not supported",
+                array.data_type()
+            )))
+        }
+    };
+    fixed_size_list(to_type, to, array.len(), values, nulls)
+}
 
-    let offsets: i128 = byte_width as i128 * array.len() as i128;
+/// Parses each string of `array` to a list of `size` floats, returning the values of
+/// all lists, and the nulls of the lists, where strings that are not lists of `size`
+/// floats are null, or an error if [`CastOptions::safe`] is false
+fn parse_float_lists<O, T>(
+    array: &GenericStringArray<O>,
+    size: usize,
+    cast_options: &CastOptions,
+) -> Result<(ArrayRef, Option<NullBuffer>), ArrowError>
+where
+    O: OffsetSizeTrait,
+    T: Parser,
+{
+    let mut values = Vec::with_capacity(array.len() * size);
+    let mut nulls = BooleanBufferBuilder::new(array.len());
+    for (i, v) in array.iter().enumerate() {
+        let v = match v {
+            Some(v) => v,
+            None => {
+                values.resize(
+                    values.len() + size,
+                    <T as ArrowPrimitiveType>::Native::default(),
+                );
+                nulls.append(false);
+                continue;
+            }
+        };
 
-    let is_binary = matches!(GenericBinaryType::<O>::DATA_TYPE, DataType::Binary);
-    if is_binary && offsets > i32::MAX as i128 {
-        return Err(ArrowError::ComputeError(
-            "FixedSizeBinary array too large to cast to Binary array".to_string(),
-        ));
-    } else if !is_binary && offsets > i64::MAX as i128 {
-        return Err(ArrowError::ComputeError(
-            "FixedSizeBinary array too large to cast to LargeBinary array".to_string(),
-        ));
+        let start = values.len();
+        let parsed = v
+            .trim()
+            .strip_prefix('[')
+            .and_then(|v| v.strip_suffix(']'))
+            .and_then(|v| match v.trim().is_empty() {
+                true => Some(0),
+                false => v.split(',').try_fold(0, |len, e| {
+                    values.push(T::parse(e.trim())?);
+                    Some(len + 1)
+                }),
+            });
+
+        match parsed {
+            Some(len) if len == size => nulls.append(true),
+            _ if !cast_options.safe => {
+                let reason = match parsed {
+                    Some(len) => format!("a list of length {len}"),
+                    None => format!("not a list of {:?}", T::DATA_TYPE),
+                };
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast string '{v}' at row {i} to FixedSizeList({size}), {reason}"
+                )));
+            }
+            _ => {
+                values.truncate(start);
+                values.resize(start + size, <T as ArrowPrimitiveType>::Native::default());
+                nulls.append(false);
+            }
+        }
     }
 
-    let mut builder = GenericBinaryBuilder::<O>::with_capacity(array.len(), array.len());
+    let values: ArrayRef = Arc::new(PrimitiveArray::<T>::new(values.into(), None));
+    let nulls = Some(NullBuffer::new(nulls.finish())).filter(|n| n.null_count() > 0);
+    Ok((values, nulls))
+}
 
-    for i in 0..array.len() {
-        if array.is_null(i) {
-            builder.append_null();
-        } else {
-            builder.append_value(array.value(i));
+/// Cast a fixed size list to a fixed size list of the same size, casting its values to
+/// the type of `to`
+fn cast_fixed_size_list_inner(
+    array: &dyn Array,
+    to: &FieldRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let values = cast_with_options(array.values(), to.data_type(), cast_options)?;
+    fixed_size_list(to_type, to, array.len(), values, array.nulls().cloned())
+}
+
+/// Creates a fixed size list of `to_type` from `values` cast to the type of `to`
+///
+/// Returns an error if `to` is not nullable, and a list that is not null contains nulls
+fn fixed_size_list(
+    to_type: &DataType,
+    to: &Field,
+    len: usize,
+    values: ArrayRef,
+    nulls: Option<NullBuffer>,
+) -> Result<ArrayRef, ArrowError> {
+    if !to.is_nullable() && values.null_count() != 0 {
+        let size = values.len().checked_div(len).unwrap_or_default();
+        let has_nulls = |i: usize| (i * size..(i + 1) * size).any(|j| values.is_null(j));
+        if (0..len)
+            .any(|i| nulls.as_ref().map_or(true, |n| n.is_valid(i)) && has_nulls(i))
+        {
+            return Err(ArrowError::CastError(format!(
+                "Cannot cast null values to the non-nullable list field '{}'",
+                to.name()
+            )));
         }
     }
 
-    Ok(Arc::new(builder.finish()))
+    let builder = ArrayData::builder(to_type.clone())
+        .len(len)
+        .add_child_data(values.into_data())
+        .nulls(nulls);
+
+    // Safety
+    // The values have the length of `len` lists of the size of `to_type`
+    let array_data = unsafe { builder.build_unchecked() };
+    Ok(Arc::new(FixedSizeListArray::from(array_data)))
 }
 
-/// Helper function to cast from one `ByteArrayType` to another and vice versa.
-/// If the target one (e.g., `LargeUtf8`) is too large for the source array it will return an Error.
-fn cast_byte_container<FROM, TO>(array: &dyn Array) -> Result<ArrayRef, ArrowError>
-where
-    FROM: ByteArrayType,
-    TO: ByteArrayType<Native = FROM::Native>,
-    FROM::Offset: OffsetSizeTrait + ToPrimitive,
-    TO::Offset: OffsetSizeTrait + NumCast,
-{
-    let data = array.to_data();
-    assert_eq!(data.data_type(), &FROM::DATA_TYPE);
-    let str_values_buf = data.buffers()[1].clone();
-    let offsets = data.buffers()[0].typed_data::<FROM::Offset>();
+/// Returns the key and value fields of the entries of a map
+fn map_entry_fields(entries: &Field) -> Result<(&FieldRef, &FieldRef), ArrowError> {
+    match entries.data_type() {
+        DataType::Struct(fields) if fields.len() == 2 => Ok((&fields[0], &fields[1])),
+        d => Err(ArrowError::CastError(format!(
+            "Map entries must be a struct of keys and values, got {d:?}"
+        ))),
+    }
+}
 
-    let mut offset_builder = BufferBuilder::<TO::Offset>::new(offsets.len());
-    offsets
+/// Returns the item fields of a struct of a list of keys and a list of values, that
+/// can be cast to a map
+fn struct_map_fields(fields: &Fields) -> Option<(&FieldRef, &FieldRef)> {
+    match fields
         .iter()
-        .try_for_each::<_, Result<_, ArrowError>>(|offset| {
-            let offset = <<TO as ByteArrayType>::Offset as NumCast>::from(*offset)
-                .ok_or_else(|| {
-                    ArrowError::ComputeError(format!(
-                        "{}{} array too large to cast to {}{} array",
-                        FROM::Offset::PREFIX,
-                        FROM::PREFIX,
-                        TO::Offset::PREFIX,
-                        TO::PREFIX
-                    ))
-                })?;
-            offset_builder.append(offset);
-            Ok(())
-        })?;
+        .map(|f| f.data_type())
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [DataType::List(keys), DataType::List(values)] => Some((keys, values)),
+        _ => None,
+    }
+}
 
-    let offset_buffer = offset_builder.finish();
+/// Cast a map to a map of `to_type`
+fn cast_map(
+    array: &MapArray,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let from_sorted = matches!(array.data_type(), DataType::Map(_, true));
+    // Only cast the entries referenced by the map, rebasing its offsets to start at zero
+    let first = array.value_offsets()[0];
+    let offsets: Vec<i32> = array.value_offsets().iter().map(|o| o - first).collect();
+    let len = offsets[offsets.len() - 1] as usize;
+    let entries = array.entries().slice(first as usize, len);
+    make_map(
+        offsets,
+        &entries,
+        array.nulls().cloned(),
+        from_sorted,
+        to_type,
+        cast_options,
+    )
+}
 
-    let dtype = TO::DATA_TYPE;
+/// Cast a list of structs of a key and a value to a map of `to_type`
+fn cast_list_to_map(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_list::<i32>();
+    let first = array.value_offsets()[0];
+    let offsets: Vec<i32> = array.value_offsets().iter().map(|o| o - first).collect();
+    let len = offsets[offsets.len() - 1] as usize;
+    let entries = array.values().slice(first as usize, len);
+    let entries = entries.as_struct();
+    if entries.null_count() != 0 {
+        return Err(ArrowError::CastError(format!(
+            "Cannot cast {:?} to {to_type:?}, map entries cannot be null",
+            array.data_type()
+        )));
+    }
+    make_map(
+        offsets,
+        entries,
+        array.nulls().cloned(),
+        false,
+        to_type,
+        cast_options,
+    )
+}
 
-    let builder = ArrayData::builder(dtype)
-        .offset(array.offset())
-        .len(array.len())
-        .add_buffer(offset_buffer)
-        .add_buffer(str_values_buf)
-        .nulls(data.nulls().cloned());
+/// Cast a struct of a list of keys and a list of values to a map of `to_type`, pairing
+/// the keys and values of each row
+///
+/// Rows are null where the struct or either list is null, or where the lists have a
+/// different number of elements, which is an error if not safe
+fn cast_struct_to_map(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_struct();
+    let keys = array.column(0).as_list::<i32>();
+    let values = array.column(1).as_list::<i32>();
+
+    let mut offsets = Vec::with_capacity(array.len() + 1);
+    offsets.push(0_i32);
+    let mut key_indices = Vec::new();
+    let mut value_indices = Vec::new();
+    let mut nulls = BooleanBufferBuilder::new(array.len());
+    for i in 0..array.len() {
+        let mut valid = array.is_valid(i) && keys.is_valid(i) && values.is_valid(i);
+        if valid {
+            let key_offsets = &keys.value_offsets()[i..i + 2];
+            let value_offsets = &values.value_offsets()[i..i + 2];
+            let (key_start, key_end) = (key_offsets[0] as u32, key_offsets[1] as u32);
+            let (value_start, value_end) =
+                (value_offsets[0] as u32, value_offsets[1] as u32);
+            if key_end - key_start == value_end - value_start {
+                key_indices.extend(key_start..key_end);
+                value_indices.extend(value_start..value_end);
+            } else if cast_options.safe {
+                valid = false;
+            } else {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast row {i} of {:?} to {to_type:?}, as it has {} keys and {} values",
+                    array.data_type(),
+                    key_end - key_start,
+                    value_end - value_start
+                )));
+            }
+        }
+        nulls.append(valid);
+        offsets.push(key_indices.len() as i32);
+    }
+
+    let keys = take(
+        keys.values().as_ref(),
+        &UInt32Array::from(key_indices),
+        None,
+    )?;
+    let values = take(
+        values.values().as_ref(),
+        &UInt32Array::from(value_indices),
+        None,
+    )?;
+    let fields = Fields::from(vec![
+        Field::new("keys", keys.data_type().clone(), true),
+        Field::new("values", values.data_type().clone(), true),
+    ]);
+    let entries = StructArray::try_new(fields, vec![keys, values], None)?;
+    let nulls = Some(NullBuffer::new(nulls.finish())).filter(|n| n.null_count() > 0);
+    make_map(offsets, &entries, nulls, false, to_type, cast_options)
+}
+
+/// Make a map of `to_type` from the `entries` delimited by `offsets`, starting at zero,
+/// casting the keys and values, and sorting the entries of each map by key if the map
+/// is sorted and [`CastOptions::sort_map_keys`] is set
+fn make_map(
+    offsets: Vec<i32>,
+    entries: &StructArray,
+    nulls: Option<NullBuffer>,
+    from_sorted: bool,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let (to, sorted) = match to_type {
+        DataType::Map(to, sorted) => (to, *sorted),
+        _ => unreachable!(),
+    };
+    if sorted && !from_sorted && !cast_options.sort_map_keys {
+        return Err(ArrowError::CastError(
+            "Cannot cast a map with unsorted keys to a map with sorted keys unless sort_map_keys is set".to_string(),
+        ));
+    }
+
+    let mut entries: ArrayRef =
+        Arc::new(cast_map_values(entries, &offsets, to, cast_options)?);
+    if sorted && cast_options.sort_map_keys {
+        let indices = sort_map_entries(&offsets, entries.as_struct().column(0))?;
+        entries = take(entries.as_ref(), &indices, None)?;
+    }
+
+    let builder = ArrayData::builder(to_type.clone())
+        .len(offsets.len() - 1)
+        .add_buffer(Buffer::from_vec(offsets))
+        .add_child_data(entries.into_data())
+        .nulls(nulls);
 
+    // Safety
+    // The offsets start at zero and delimit the entries
     let array_data = unsafe { builder.build_unchecked() };
+    Ok(Arc::new(MapArray::from(array_data)))
+}
 
-    Ok(Arc::new(GenericByteArray::<TO>::from(array_data)))
+/// Rebuilds the `entries` of a map as a struct of the `to` entry fields, by casting its
+/// keys and values to their types
+///
+/// Returns an error if a key is cast to null, or a value referenced by the map
+/// `offsets` is null and the `to` value field is not nullable
+fn cast_map_values(
+    entries: &StructArray,
+    offsets: &[i32],
+    to: &Field,
+    cast_options: &CastOptions,
+) -> Result<StructArray, ArrowError> {
+    let (to_key, to_value) = map_entry_fields(to)?;
+    let keys = cast_with_options(entries.column(0), to_key.data_type(), cast_options)?;
+    if keys.null_count() != 0 {
+        return Err(ArrowError::CastError(format!(
+            "Cannot cast map keys of type {:?} to {:?}, map keys cannot be null",
+            entries.column(0).data_type(),
+            to_key.data_type()
+        )));
+    }
+    let values =
+        cast_with_options(entries.column(1), to_value.data_type(), cast_options)?;
+    validate_list_values_nullability(offsets, values.as_ref(), to_value)?;
+
+    StructArray::try_new(
+        [to_key.clone(), to_value.clone()].into(),
+        vec![keys, values],
+        entries.nulls().cloned(),
+    )
 }
 
-/// Cast the container type of List/Largelist array but not the inner types.
-/// This function can leave the value data intact and only has to cast the offset dtypes.
-fn cast_list_container<OffsetSizeFrom, OffsetSizeTo>(
-    array: &dyn Array,
-    _cast_options: &CastOptions,
-) -> Result<ArrayRef, ArrowError>
-where
-    OffsetSizeFrom: OffsetSizeTrait + ToPrimitive,
-    OffsetSizeTo: OffsetSizeTrait + NumCast,
-{
-    let list = array.as_list::<OffsetSizeFrom>();
-    // the value data stored by the list
-    let values = list.values();
+/// Returns the indices that sort the `keys` of each map delimited by `offsets`, leaving
+/// the entries outside of the maps in place
+fn sort_map_entries(offsets: &[i32], keys: &ArrayRef) -> Result<UInt32Array, ArrowError> {
+    fn sort_by<F: Fn(usize, usize) -> Ordering>(
+        offsets: &[i32],
+        indices: &mut [u32],
+        cmp: F,
+    ) {
+        for w in offsets.windows(2) {
+            indices[w[0] as usize..w[1] as usize]
+                .sort_by(|a, b| cmp(*a as usize, *b as usize));
+        }
+    }
 
-    let out_dtype = match array.data_type() {
-        DataType::List(value_type) => {
-            assert_eq!(
-                std::mem::size_of::<OffsetSizeFrom>(),
-                std::mem::size_of::<i32>()
-            );
-            assert_eq!(
-                std::mem::size_of::<OffsetSizeTo>(),
-                std::mem::size_of::<i64>()
-            );
-            DataType::LargeList(value_type.clone())
+    let mut indices: Vec<u32> = (0..keys.len() as u32).collect();
+    downcast_primitive_array!(
+        keys => {
+            let values = keys.values();
+            sort_by(offsets, &mut indices, |a, b| values[a].compare(values[b]))
         }
-        DataType::LargeList(value_type) => {
-            assert_eq!(
-                std::mem::size_of::<OffsetSizeFrom>(),
-                std::mem::size_of::<i64>()
-            );
-            assert_eq!(
-                std::mem::size_of::<OffsetSizeTo>(),
-                std::mem::size_of::<i32>()
-            );
-            if values.len() > i32::MAX as usize {
-                return Err(ArrowError::ComputeError(
-                    "LargeList too large to cast to List".into(),
-                ));
-            }
-            DataType::List(value_type.clone())
+        DataType::Utf8 => {
+            let keys = keys.as_string::<i32>();
+            sort_by(offsets, &mut indices, |a, b| keys.value(a).cmp(keys.value(b)))
         }
-        // implementation error
-        _ => unreachable!(),
-    };
+        DataType::LargeUtf8 => {
+            let keys = keys.as_string::<i64>();
+            sort_by(offsets, &mut indices, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::Binary => {
+            let keys = keys.as_binary::<i32>();
+            sort_by(offsets, &mut indices, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::LargeBinary => {
+            let keys = keys.as_binary::<i64>();
+            sort_by(offsets, &mut indices, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::Boolean => {
+            let keys = keys.as_boolean();
+            sort_by(offsets, &mut indices, |a, b| keys.value(a).cmp(&keys.value(b)))
+        }
+        d => {
+            return Err(ArrowError::CastError(format!(
+                "Cannot sort map keys of type {d:?}"
+            )))
+        }
+    );
+    Ok(UInt32Array::from(indices))
+}
 
-    let iter = list.value_offsets().iter().map(|idx| {
-        let idx: OffsetSizeTo = NumCast::from(*idx).unwrap();
-        idx
-    });
+/// Returns an error if `to` is not nullable, and the `values` referenced by the list
+/// `offsets` contain nulls
+fn validate_list_values_nullability<O: OffsetSizeTrait>(
+    offsets: &[O],
+    values: &dyn Array,
+    to: &Field,
+) -> Result<(), ArrowError> {
+    if to.is_nullable() || values.null_count() == 0 {
+        return Ok(());
+    }
+    let start = offsets[0].as_usize();
+    let end = offsets[offsets.len() - 1].as_usize();
+    match values.slice(start, end - start).null_count() {
+        0 => Ok(()),
+        _ => Err(ArrowError::CastError(format!(
+            "Cannot cast null values to the non-nullable list field '{}'",
+            to.name()
+        ))),
+    }
+}
 
-    // SAFETY
-    //      A slice produces a trusted length iterator
-    let offset_buffer = unsafe { Buffer::from_trusted_len_iter(iter) };
+/// A specified helper to cast from `GenericBinaryArray` to `GenericStringArray` when they have same
+/// offset size so re-encoding offset is unnecessary.
+fn cast_binary_to_string<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array
+        .as_any()
+        .downcast_ref::<GenericByteArray<GenericBinaryType<O>>>()
+        .unwrap();
 
-    // wrap up
-    let builder = ArrayData::builder(out_dtype)
-        .len(list.len())
-        .add_buffer(offset_buffer)
-        .add_child_data(values.to_data())
-        .nulls(list.nulls().cloned());
+    match GenericStringArray::<O>::try_from_binary(array.clone()) {
+        Ok(a) => Ok(Arc::new(a)),
+        Err(e) => match cast_options.safe {
+            true => {
+                // Fallback to slow method to convert invalid sequences to nulls
+                let mut builder = GenericStringBuilder::<O>::with_capacity(
+                    array.len(),
+                    array.value_data().len(),
+                );
 
-    let array_data = unsafe { builder.build_unchecked() };
-    Ok(Arc::new(GenericListArray::<OffsetSizeTo>::from(array_data)))
+                let iter = array
+                    .iter()
+                    .map(|v| v.and_then(|v| std::str::from_utf8(v).ok()));
+
+                builder.extend(iter);
+                Ok(Arc::new(builder.finish()))
+            }
+            false => Err(e),
+        },
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Helper function to cast from one `BinaryArray` or 'LargeBinaryArray' to 'FixedSizeBinaryArray'.
+fn cast_binary_to_fixed_size_binary<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    byte_width: i32,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_binary::<O>();
+    let mut builder = FixedSizeBinaryBuilder::with_capacity(array.len(), byte_width);
 
-    macro_rules! generate_cast_test_case {
-        ($INPUT_ARRAY: expr, $OUTPUT_TYPE_ARRAY: ident, $OUTPUT_TYPE: expr, $OUTPUT_VALUES: expr) => {
-            // assert cast type
-            let input_array_type = $INPUT_ARRAY.data_type();
-            assert!(can_cast_types(input_array_type, $OUTPUT_TYPE));
-            let casted_array = cast($INPUT_ARRAY, $OUTPUT_TYPE).unwrap();
-            let result_array = casted_array
-                .as_any()
-                .downcast_ref::<$OUTPUT_TYPE_ARRAY>()
-                .unwrap();
-            assert_eq!($OUTPUT_TYPE, result_array.data_type());
-            assert_eq!(result_array.len(), $OUTPUT_VALUES.len());
-            for (i, x) in $OUTPUT_VALUES.iter().enumerate() {
-                match x {
-                    Some(x) => {
-                        assert!(!result_array.is_null(i));
-                        assert_eq!(result_array.value(i), *x);
-                    }
-                    None => {
-                        assert!(result_array.is_null(i));
-                    }
-                }
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            builder.append_null();
+        } else {
+            match builder.append_value(array.value(i)) {
+                Ok(_) => {}
+                Err(e) => match cast_options.safe {
+                    true => builder.append_null(),
+                    false => return Err(e),
+                },
             }
+        }
+    }
 
-            let cast_option = CastOptions { safe: false };
-            let casted_array_with_option =
-                cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
-            let result_array = casted_array_with_option
-                .as_any()
-                .downcast_ref::<$OUTPUT_TYPE_ARRAY>()
-                .unwrap();
-            assert_eq!($OUTPUT_TYPE, result_array.data_type());
-            assert_eq!(result_array.len(), $OUTPUT_VALUES.len());
-            for (i, x) in $OUTPUT_VALUES.iter().enumerate() {
-                match x {
-                    Some(x) => {
-                        assert_eq!(result_array.value(i), *x);
-                    }
-                    None => {
-                        assert!(result_array.is_null(i));
-                    }
-                }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Cast `Utf8` or `LargeUtf8` to `FixedSizeBinary(byte_width)`
+///
+/// Strings of `byte_width` bytes are copied as is, and strings of twice as many hex
+/// digits, or UUIDs hyphenated as 8-4-4-4-12 hex digits if `byte_width` is 16, are
+/// decoded. Other strings are a failed cast.
+fn cast_string_to_fixed_size_binary<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    byte_width: i32,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_string::<O>();
+    let width = byte_width as usize;
+    let mut builder = FixedSizeBinaryBuilder::with_capacity(array.len(), byte_width);
+    let mut decoded = Vec::with_capacity(width);
+
+    for v in array.iter() {
+        let v = match v {
+            Some(v) => v,
+            None => {
+                builder.append_null();
+                continue;
+            }
+        };
+        let valid = match v.len() {
+            len if len == width => {
+                builder.append_value(v)?;
+                continue;
+            }
+            len if len == 2 * width => decode_hex(v, &mut decoded),
+            36 if width == 16 => match parse_uuid(v) {
+                Some(uuid) => {
+                    decoded.clear();
+                    decoded.extend_from_slice(&uuid);
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        };
+        match valid {
+            true => builder.append_value(&decoded)?,
+            false if cast_options.safe => builder.append_null(),
+            false => {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast string '{v}' to FixedSizeBinary({byte_width})"
+                )))
+            }
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Decodes the hex digits of either case of `s` into `out`, returning false if `s` is
+/// not an even number of hex digits
+fn decode_hex(s: &str, out: &mut Vec<u8>) -> bool {
+    out.clear();
+    let digit = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    s.as_bytes().chunks(2).all(|pair| match pair {
+        [hi, lo] => match (digit(*hi), digit(*lo)) {
+            (Some(hi), Some(lo)) => {
+                out.push(hi << 4 | lo);
+                true
+            }
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
+/// Cast `FixedSizeBinary` to `Utf8` or `LargeUtf8`, formatting each value as lowercase
+/// hex, the inverse of [`cast_string_to_fixed_size_binary`] for hex strings
+fn cast_fixed_size_binary_to_hex<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    byte_width: i32,
+) -> Result<ArrayRef, ArrowError> {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let array = array
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+
+    let data_capacity = (array.len() - array.null_count())
+        .checked_mul(2 * byte_width as usize)
+        .filter(|len| O::from_usize(*len).is_some())
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "FixedSizeBinary array too large to cast to {:?}",
+                GenericStringType::<O>::DATA_TYPE
+            ))
+        })?;
+
+    let mut builder =
+        GenericStringBuilder::<O>::with_capacity(array.len(), data_capacity);
+    let mut buf = Vec::with_capacity(2 * byte_width as usize);
+    for v in array.iter() {
+        match v {
+            Some(v) => {
+                buf.clear();
+                buf.extend(
+                    v.iter()
+                        .flat_map(|b| [HEX[(b >> 4) as usize], HEX[(b & 0xf) as usize]]),
+                );
+                // Safety: buf only contains ASCII characters
+                builder.append_value(unsafe { std::str::from_utf8_unchecked(&buf) });
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Base64 encodes the `Binary` or `LargeBinary` array to a string array of `to_type`
+fn cast_binary_to_base64(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let alphabet = cast_options.base64.unwrap_or_default();
+    match (array.data_type(), to_type) {
+        (DataType::Binary, DataType::Utf8) => Ok(Arc::new(binary_to_base64_string(
+            array.as_binary::<i32>(),
+            alphabet,
+        )?)),
+        (DataType::LargeBinary, DataType::Utf8) => {
+            let array = binary_to_base64_string(array.as_binary::<i64>(), alphabet)?;
+            cast_byte_container::<LargeUtf8Type, Utf8Type>(&array)
+        }
+        _ => {
+            // Encode with 64-bit offsets, as the encoded values are larger
+            let array = cast_with_options(array, &DataType::LargeBinary, cast_options)?;
+            Ok(Arc::new(binary_to_base64_string(
+                array.as_binary::<i64>(),
+                alphabet,
+            )?))
+        }
+    }
+}
+
+/// Base64 decodes the `Utf8` or `LargeUtf8` array to a binary array of `to_type`
+fn cast_base64_to_binary(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let alphabet = cast_options.base64.unwrap_or_default();
+    match (array.data_type(), to_type) {
+        (DataType::Utf8, DataType::Binary) => Ok(Arc::new(base64_string_to_binary(
+            array.as_string::<i32>(),
+            alphabet,
+            cast_options,
+        )?)),
+        (DataType::Utf8, _) => {
+            let array = base64_string_to_binary(
+                array.as_string::<i32>(),
+                alphabet,
+                cast_options,
+            )?;
+            cast_byte_container::<BinaryType, LargeBinaryType>(&array)
+        }
+        (_, DataType::Binary) => {
+            let array = base64_string_to_binary(
+                array.as_string::<i64>(),
+                alphabet,
+                cast_options,
+            )?;
+            cast_byte_container::<LargeBinaryType, BinaryType>(&array)
+        }
+        _ => Ok(Arc::new(base64_string_to_binary(
+            array.as_string::<i64>(),
+            alphabet,
+            cast_options,
+        )?)),
+    }
+}
+
+/// Helper function to cast from 'FixedSizeBinaryArray' to one `BinaryArray` or 'LargeBinaryArray'.
+/// If the target one is too large for the source array it will return an Error.
+fn cast_fixed_size_binary_to_binary<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    byte_width: i32,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+
+    let offsets: i128 = byte_width as i128 * array.len() as i128;
+
+    let is_binary = matches!(GenericBinaryType::<O>::DATA_TYPE, DataType::Binary);
+    if is_binary && offsets > i32::MAX as i128 {
+        return Err(ArrowError::ComputeError(
+            "FixedSizeBinary array too large to cast to Binary array".to_string(),
+        ));
+    } else if !is_binary && offsets > i64::MAX as i128 {
+        return Err(ArrowError::ComputeError(
+            "FixedSizeBinary array too large to cast to LargeBinary array".to_string(),
+        ));
+    }
+
+    let mut builder = GenericBinaryBuilder::<O>::with_capacity(array.len(), array.len());
+
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            builder.append_null();
+        } else {
+            builder.append_value(array.value(i));
+        }
+    }
+
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Helper function to cast from one `ByteArrayType` to another and vice versa.
+/// If the target one (e.g., `LargeUtf8`) is too large for the source array it will return an Error.
+fn cast_byte_container<FROM, TO>(array: &dyn Array) -> Result<ArrayRef, ArrowError>
+where
+    FROM: ByteArrayType,
+    TO: ByteArrayType<Native = FROM::Native>,
+{
+    let array = array.as_bytes::<FROM>();
+    let offsets = array.value_offsets();
+    let start = offsets[0];
+    let end = offsets[offsets.len() - 1];
+
+    // Share the values buffer if all of it is referenced, otherwise copy the values
+    // of the slice, so as to not retain the values of the rest of a larger array
+    let values = array.values();
+    let str_values_buf = if start.as_usize() == 0 && end.as_usize() == values.len() {
+        values.clone()
+    } else {
+        Buffer::from_slice_ref(&values.as_slice()[start.as_usize()..end.as_usize()])
+    };
+
+    // The offsets are monotonic, and so all fit in `TO::Offset` if the last one does
+    if TO::Offset::from_usize((end - start).as_usize()).is_none() {
+        return Err(ArrowError::ComputeError(format!(
+            "{}{} array too large to cast to {}{} array",
+            FROM::Offset::PREFIX,
+            FROM::PREFIX,
+            TO::Offset::PREFIX,
+            TO::PREFIX
+        )));
+    }
+
+    // Unchecked conversions of the offsets, which are widening copies when promoting
+    // to 64-bit offsets, and can be vectorized
+    let offsets: Vec<TO::Offset> = offsets
+        .iter()
+        .map(|offset| TO::Offset::usize_as((*offset - start).as_usize()))
+        .collect();
+
+    // Safety: the offsets are those of a valid array, rebased to start at zero
+    let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.into()) };
+
+    // The values are those of a valid array of the same native type, and so do not
+    // need to be validated again
+    Ok(Arc::new(GenericByteArray::<TO>::new_unchecked(
+        offsets,
+        str_values_buf,
+        array.nulls().cloned(),
+    )))
+}
+
+/// Cast the container type of List/Largelist array but not the inner types.
+/// This function can leave the value data intact and only has to cast the offset dtypes.
+fn cast_list_container<OffsetSizeFrom, OffsetSizeTo>(
+    array: &dyn Array,
+    to: &FieldRef,
+    _cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    OffsetSizeFrom: OffsetSizeTrait + ToPrimitive,
+    OffsetSizeTo: OffsetSizeTrait + NumCast,
+{
+    let list = array.as_list::<OffsetSizeFrom>();
+    // the value data stored by the list
+    let values = list.values();
+    validate_list_values_nullability(list.value_offsets(), values.as_ref(), to)?;
+
+    let out_dtype = match array.data_type() {
+        DataType::List(_) => {
+            assert_eq!(
+                std::mem::size_of::<OffsetSizeFrom>(),
+                std::mem::size_of::<i32>()
+            );
+            assert_eq!(
+                std::mem::size_of::<OffsetSizeTo>(),
+                std::mem::size_of::<i64>()
+            );
+            DataType::LargeList(to.clone())
+        }
+        DataType::LargeList(_) => {
+            assert_eq!(
+                std::mem::size_of::<OffsetSizeFrom>(),
+                std::mem::size_of::<i64>()
+            );
+            assert_eq!(
+                std::mem::size_of::<OffsetSizeTo>(),
+                std::mem::size_of::<i32>()
+            );
+            if values.len() > i32::MAX as usize {
+                return Err(ArrowError::ComputeError(
+                    "LargeList too large to cast to List".into(),
+                ));
+            }
+            DataType::List(to.clone())
+        }
+        // implementation error
+        _ => unreachable!(),
+    };
+
+    let iter = list.value_offsets().iter().map(|idx| {
+        let idx: OffsetSizeTo = NumCast::from(*idx).unwrap();
+        idx
+    });
+
+    // SAFETY
+    //      A slice produces a trusted length iterator
+    let offset_buffer = unsafe { Buffer::from_trusted_len_iter(iter) };
+
+    // wrap up
+    let builder = ArrayData::builder(out_dtype)
+        .len(list.len())
+        .add_buffer(offset_buffer)
+        .add_child_data(values.to_data())
+        .nulls(list.nulls().cloned());
+
+    let array_data = unsafe { builder.build_unchecked() };
+    Ok(Arc::new(GenericListArray::<OffsetSizeTo>::from(array_data)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::{array_value_to_string, DurationFormat};
+    use half::f16;
+
+    macro_rules! generate_cast_test_case {
+        ($INPUT_ARRAY: expr, $OUTPUT_TYPE_ARRAY: ident, $OUTPUT_TYPE: expr, $OUTPUT_VALUES: expr) => {
+            // assert cast type
+            let input_array_type = $INPUT_ARRAY.data_type();
+            assert!(can_cast_types(input_array_type, $OUTPUT_TYPE));
+            let casted_array = cast($INPUT_ARRAY, $OUTPUT_TYPE).unwrap();
+            let result_array = casted_array
+                .as_any()
+                .downcast_ref::<$OUTPUT_TYPE_ARRAY>()
+                .unwrap();
+            assert_eq!($OUTPUT_TYPE, result_array.data_type());
+            assert_eq!(result_array.len(), $OUTPUT_VALUES.len());
+            for (i, x) in $OUTPUT_VALUES.iter().enumerate() {
+                match x {
+                    Some(x) => {
+                        assert!(!result_array.is_null(i));
+                        assert_eq!(result_array.value(i), *x);
+                    }
+                    None => {
+                        assert!(result_array.is_null(i));
+                    }
+                }
+            }
+
+            let cast_option = CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let casted_array_with_option =
+                cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
+            let result_array = casted_array_with_option
+                .as_any()
+                .downcast_ref::<$OUTPUT_TYPE_ARRAY>()
+                .unwrap();
+            assert_eq!($OUTPUT_TYPE, result_array.data_type());
+            assert_eq!(result_array.len(), $OUTPUT_VALUES.len());
+            for (i, x) in $OUTPUT_VALUES.iter().enumerate() {
+                match x {
+                    Some(x) => {
+                        assert_eq!(result_array.value(i), *x);
+                    }
+                    None => {
+                        assert!(result_array.is_null(i));
+                    }
+                }
             }
         };
     }
@@ -4079,8 +8047,14 @@ mod tests {
 
         let array = vec![Some(i128::MAX)];
         let array = create_decimal_array(array, 38, 3).unwrap();
-        let result =
-            cast_with_options(&array, &output_type, &CastOptions { safe: false });
+        let result = cast_with_options(
+            &array,
+            &output_type,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 38). Overflowing on 170141183460469231731687303715884105727",
                    result.unwrap_err().to_string());
     }
@@ -4093,8 +8067,14 @@ mod tests {
 
         let array = vec![Some(i128::MAX)];
         let array = create_decimal_array(array, 38, 3).unwrap();
-        let result =
-            cast_with_options(&array, &output_type, &CastOptions { safe: false });
+        let result = cast_with_options(
+            &array,
+            &output_type,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 76). Overflowing on 170141183460469231731687303715884105727",
                    result.unwrap_err().to_string());
     }
@@ -4126,8 +8106,14 @@ mod tests {
         assert!(can_cast_types(&input_type, &output_type));
         let array = vec![Some(i256::from_i128(i128::MAX))];
         let array = create_decimal256_array(array, 76, 5).unwrap();
-        let result =
-            cast_with_options(&array, &output_type, &CastOptions { safe: false });
+        let result = cast_with_options(
+            &array,
+            &output_type,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 7). Overflowing on 170141183460469231731687303715884105727",
                    result.unwrap_err().to_string());
     }
@@ -4139,8 +8125,14 @@ mod tests {
         assert!(can_cast_types(&input_type, &output_type));
         let array = vec![Some(i256::from_i128(i128::MAX))];
         let array = create_decimal256_array(array, 76, 5).unwrap();
-        let result =
-            cast_with_options(&array, &output_type, &CastOptions { safe: false });
+        let result = cast_with_options(
+            &array,
+            &output_type,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 55). Overflowing on 170141183460469231731687303715884105727",
                    result.unwrap_err().to_string());
     }
@@ -4286,30 +8278,54 @@ mod tests {
         // overflow test: out of range of max u8
         let value_array: Vec<Option<i128>> = vec![Some(51300)];
         let array = create_decimal_array(value_array, 38, 2).unwrap();
-        let casted_array =
-            cast_with_options(&array, &DataType::UInt8, &CastOptions { safe: false });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::UInt8,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         assert_eq!(
             "Cast error: value of 513 is out of range UInt8".to_string(),
             casted_array.unwrap_err().to_string()
         );
 
-        let casted_array =
-            cast_with_options(&array, &DataType::UInt8, &CastOptions { safe: true });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::UInt8,
+            &CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
 
         // overflow test: out of range of max i8
         let value_array: Vec<Option<i128>> = vec![Some(24400)];
         let array = create_decimal_array(value_array, 38, 2).unwrap();
-        let casted_array =
-            cast_with_options(&array, &DataType::Int8, &CastOptions { safe: false });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Int8,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         assert_eq!(
             "Cast error: value of 244 is out of range Int8".to_string(),
             casted_array.unwrap_err().to_string()
         );
 
-        let casted_array =
-            cast_with_options(&array, &DataType::Int8, &CastOptions { safe: true });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Int8,
+            &CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
 
@@ -4465,18 +8481,30 @@ mod tests {
         // overflow test: out of range of max i8
         let value_array: Vec<Option<i256>> = vec![Some(i256::from_i128(24400))];
         let array = create_decimal256_array(value_array, 38, 2).unwrap();
-        let casted_array =
-            cast_with_options(&array, &DataType::Int8, &CastOptions { safe: false });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Int8,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         assert_eq!(
             "Cast error: value of 244 is out of range Int8".to_string(),
             casted_array.unwrap_err().to_string()
         );
 
-        let casted_array =
-            cast_with_options(&array, &DataType::Int8, &CastOptions { safe: true });
-        assert!(casted_array.is_ok());
-        assert!(casted_array.unwrap().is_null(0));
-
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Int8,
+            &CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        assert!(casted_array.is_ok());
+        assert!(casted_array.unwrap().is_null(0));
+
         // loss the precision: convert decimal to f32、f64
         // f32
         // 112345678_f32 and 112345679_f32 are same, so the 112345679_f32 will lose precision.
@@ -4533,6 +8561,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_decimal_to_integer_saturate() {
+        let array = create_decimal_array(
+            vec![Some(51300), Some(-51399), Some(-12799), None, Some(12850)],
+            38,
+            2,
+        )
+        .unwrap();
+        let options = CastOptions::new().with_saturate(true);
+
+        let b = cast_with_options(&array, &DataType::Int8, &options).unwrap();
+        let expected =
+            Int8Array::from(vec![Some(127), Some(-128), Some(-127), None, Some(127)]);
+        assert_eq!(b.as_primitive::<Int8Type>(), &expected);
+
+        let b = cast_with_options(&array, &DataType::UInt8, &options).unwrap();
+        let expected =
+            UInt8Array::from(vec![Some(255), Some(0), Some(0), None, Some(128)]);
+        assert_eq!(b.as_primitive::<UInt8Type>(), &expected);
+
+        // Saturation takes precedence over errors
+        let b = cast_with_options(
+            &array,
+            &DataType::Int16,
+            &options.clone().with_safe(false),
+        );
+        let expected =
+            Int16Array::from(vec![Some(513), Some(-513), Some(-127), None, Some(128)]);
+        assert_eq!(b.unwrap().as_primitive::<Int16Type>(), &expected);
+
+        // Without saturation overflowing values are null or an error
+        let b = cast(&array, &DataType::Int8).unwrap();
+        let expected = Int8Array::from(vec![None, None, Some(-127), None, None]);
+        assert_eq!(b.as_primitive::<Int8Type>(), &expected);
+
+        let array = Decimal256Array::from(vec![
+            Some(i256::from_i128(-40000)),
+            Some(i256::MAX),
+            Some(i256::from_i128(7)),
+        ])
+        .with_precision_and_scale(76, 0)
+        .unwrap();
+        let b = cast_with_options(&array, &DataType::Int16, &options).unwrap();
+        let expected = Int16Array::from(vec![i16::MIN, i16::MAX, 7]);
+        assert_eq!(b.as_primitive::<Int16Type>(), &expected);
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &DataType::Int16, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: value of -40000 is out of range Int16"
+        );
+    }
+
     #[test]
     fn test_cast_numeric_to_decimal128() {
         let decimal_type = DataType::Decimal128(38, 6);
@@ -4886,7 +8968,10 @@ mod tests {
     fn test_cast_int32_to_u8_with_error() {
         let array = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
         // overflow with the error
-        let cast_option = CastOptions { safe: false };
+        let cast_option = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
         result.unwrap();
@@ -5007,11 +9092,256 @@ mod tests {
         assert!(!c.is_valid(4));
     }
 
+    #[test]
+    fn test_cast_utf8_to_int_radix_prefix() {
+        let array = StringArray::from(vec![
+            Some("0x1F"),
+            Some("0b1010"),
+            Some("-0o17"),
+            Some("12"),
+            Some("0xZZ"),
+            None,
+        ]);
+
+        // Prefixed literals are not accepted by default
+        let b = cast(&array, &DataType::Int64).unwrap();
+        assert_eq!(b.null_count(), 5);
+
+        let options = CastOptions {
+            parse_radix_prefix: true,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        let expected =
+            Int64Array::from(vec![Some(31), Some(10), Some(-15), Some(12), None, None]);
+        assert_eq!(b.as_primitive::<Int64Type>(), &expected);
+
+        let array = LargeStringArray::from(vec!["0xFF", "0x100"]);
+        let b = cast_with_options(&array, &DataType::UInt8, &options).unwrap();
+        let expected = UInt8Array::from(vec![Some(255), None]);
+        assert_eq!(b.as_primitive::<UInt8Type>(), &expected);
+
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        let err = cast_with_options(&array, &DataType::UInt8, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '0x100' to value of UInt8 type"
+        );
+
+        // Floating point targets are unaffected
+        let array = StringArray::from(vec!["0x1F", "1.5"]);
+        let b = cast_with_options(&array, &DataType::Float64, &options);
+        assert!(b.is_err());
+    }
+
+    #[test]
+    fn test_cast_utf8_float16_roundtrip() {
+        // Every half precision value survives a round trip through a string
+        let values: Float16Array = (0..=u16::MAX).map(f16::from_bits).collect();
+        for to_type in [DataType::Utf8, DataType::LargeUtf8] {
+            let strings = cast(&values, &to_type).unwrap();
+            let b = cast(&strings, &DataType::Float16).unwrap();
+            let b = b.as_primitive::<Float16Type>();
+            for (a, b) in values.values().iter().zip(b.values()) {
+                assert!(a == b || a.is_nan() && b.is_nan(), "{a} {b}");
+            }
+        }
+
+        let array = StringArray::from(vec![Some("0.1"), Some("65504"), Some("x"), None]);
+        let b = cast(&array, &DataType::Float16).unwrap();
+        let expected = Float16Array::from(vec![
+            Some(f16::from_f32(0.1)),
+            Some(f16::MAX),
+            None,
+            None,
+        ]);
+        assert_eq!(b.as_primitive::<Float16Type>(), &expected);
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err = cast_with_options(&array, &DataType::Float16, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string 'x' to value of Float16 type"
+        );
+    }
+
+    #[test]
+    fn test_cast_float16() {
+        let array = Float16Array::from(vec![
+            Some(f16::from_f32(1.5)),
+            Some(f16::from_f32(-2.0)),
+            None,
+            Some(f16::MAX),
+            Some(f16::NAN),
+        ]);
+
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let b = b.as_primitive::<Float64Type>();
+        assert_eq!(&b.values()[..4], &[1.5, -2.0, 0.0, 65504.0]);
+        assert!(b.is_null(2));
+        assert!(b.value(4).is_nan());
+
+        let b = cast(&array, &DataType::Int32).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(-2), None, Some(65504), None]);
+        assert_eq!(b.as_primitive::<Int32Type>(), &expected);
+
+        let b = cast(&array, &DataType::UInt8).unwrap();
+        let expected = UInt8Array::from(vec![Some(1), None, None, None, None]);
+        assert_eq!(b.as_primitive::<UInt8Type>(), &expected);
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &DataType::UInt8, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Can't cast value -2.0 to type UInt8"
+        );
+
+        let b = cast(&array, &DataType::Boolean).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            None,
+            Some(true),
+            Some(true),
+        ]);
+        assert_eq!(b.as_boolean(), &expected);
+
+        let b = cast(&array, &DataType::Decimal128(8, 2)).unwrap();
+        let expected =
+            Decimal128Array::from(vec![Some(150), Some(-200), None, Some(6550400), None])
+                .with_precision_and_scale(8, 2)
+                .unwrap();
+        assert_eq!(b.as_primitive::<Decimal128Type>(), &expected);
+
+        let b = cast(&array, &DataType::Decimal256(8, 2)).unwrap();
+        assert_eq!(
+            b.as_primitive::<Decimal256Type>().value(1),
+            i256::from_i128(-200)
+        );
+
+        // Values out of the range of Float16 are infinite
+        let array = Int32Array::from(vec![Some(3), None, Some(-70000)]);
+        let b = cast(&array, &DataType::Float16).unwrap();
+        let expected = Float16Array::from(vec![
+            Some(f16::from_f32(3.0)),
+            None,
+            Some(f16::NEG_INFINITY),
+        ]);
+        assert_eq!(b.as_primitive::<Float16Type>(), &expected);
+
+        let array = Float64Array::from(vec![0.1, 1e10]);
+        let b = cast(&array, &DataType::Float16).unwrap();
+        let b = b.as_primitive::<Float16Type>();
+        assert_eq!(b.values(), &[f16::from_f64(0.1), f16::INFINITY]);
+
+        let array = Decimal128Array::from(vec![Some(125), None, Some(-1)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let b = cast(&array, &DataType::Float16).unwrap();
+        let expected = Float16Array::from(vec![
+            Some(f16::from_f32(1.25)),
+            None,
+            Some(f16::from_f32(-0.01)),
+        ]);
+        assert_eq!(b.as_primitive::<Float16Type>(), &expected);
+
+        let array = Decimal256Array::from(vec![Some(i256::from_i128(125))])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let b = cast(&array, &DataType::Float16).unwrap();
+        assert_eq!(
+            b.as_primitive::<Float16Type>().value(0),
+            f16::from_f32(1.25)
+        );
+
+        let array = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        let b = cast(&array, &DataType::Float16).unwrap();
+        let expected = Float16Array::from(vec![Some(f16::ONE), None, Some(f16::ZERO)]);
+        assert_eq!(b.as_primitive::<Float16Type>(), &expected);
+
+        let array = NullArray::new(2);
+        let b = cast(&array, &DataType::Float16).unwrap();
+        assert_eq!(b.data_type(), &DataType::Float16);
+        assert_eq!(b.null_count(), 2);
+
+        for t in [
+            DataType::Int8,
+            DataType::UInt64,
+            DataType::Float32,
+            DataType::Utf8,
+        ] {
+            assert!(can_cast_types(&t, &DataType::Float16), "{t:?}");
+            assert!(can_cast_types(&DataType::Float16, &t), "{t:?}");
+        }
+    }
+
+    #[test]
+    fn test_cast_decimal_to_float16_rounds_once() {
+        // Halfway between 1 and the next f16 is 1.00048828125
+        let one = f16::ONE;
+        let next = f16::from_bits(0x3C01);
+        let cases = [
+            (100048828125, 11, one),
+            (1000488281249, 12, one),
+            (1000488281251, 12, next),
+            // The nearest f32 is exactly halfway
+            (10004882813500, 13, next),
+            // The nearest f32 and f64 are exactly halfway
+            (100048828125000000001, 20, next),
+            (-100048828125000000001, 20, -next),
+            (
+                99999999999999999999999999999999999999,
+                37,
+                f16::from_f32(10.0),
+            ),
+            // Halfway between the largest f16 and the next power of two
+            (6552000000000000000001, 17, f16::INFINITY),
+            (6551999999999999999999, 17, f16::MAX),
+            (65520, 0, f16::INFINITY),
+        ];
+        for (value, scale, expected) in cases {
+            let array = Decimal128Array::from(vec![value])
+                .with_precision_and_scale(38, scale)
+                .unwrap();
+            let b = cast(&array, &DataType::Float16).unwrap();
+            let actual = b.as_primitive::<Float16Type>().value(0);
+            assert_eq!(actual, expected, "{value} {scale}");
+
+            let array = Decimal256Array::from(vec![i256::from_i128(value)])
+                .with_precision_and_scale(76, scale)
+                .unwrap();
+            let b = cast(&array, &DataType::Float16).unwrap();
+            let actual = b.as_primitive::<Float16Type>().value(0);
+            assert_eq!(actual, expected, "{value} {scale}");
+        }
+
+        let value = i256::from_i128(100048828125)
+            .wrapping_mul(i256::from_i128(10).wrapping_pow(60))
+            .wrapping_add(i256::ONE);
+        let array = Decimal256Array::from(vec![value])
+            .with_precision_and_scale(76, 71)
+            .unwrap();
+        let b = cast(&array, &DataType::Float16).unwrap();
+        assert_eq!(b.as_primitive::<Float16Type>().value(0), next);
+    }
+
     #[test]
     fn test_cast_with_options_utf8_to_i32() {
         let array = StringArray::from(vec!["5", "6", "seven", "8", "9.1"]);
-        let result =
-            cast_with_options(&array, &DataType::Int32, &CastOptions { safe: false });
+        let result = cast_with_options(
+            &array,
+            &DataType::Int32,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         match result {
             Ok(_) => panic!("expected error"),
             Err(e) => {
@@ -5037,8 +9367,14 @@ mod tests {
     #[test]
     fn test_cast_with_options_utf8_to_bool() {
         let strings = StringArray::from(vec!["true", "false", "invalid", " Y ", ""]);
-        let casted =
-            cast_with_options(&strings, &DataType::Boolean, &CastOptions { safe: false });
+        let casted = cast_with_options(
+            &strings,
+            &DataType::Boolean,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         match casted {
             Ok(_) => panic!("expected error"),
             Err(e) => {
@@ -5050,674 +9386,1118 @@ mod tests {
     }
 
     #[test]
-    fn test_cast_bool_to_i32() {
-        let array = BooleanArray::from(vec![Some(true), Some(false), None]);
-        let b = cast(&array, &DataType::Int32).unwrap();
-        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
-        assert_eq!(1, c.value(0));
-        assert_eq!(0, c.value(1));
-        assert!(!c.is_valid(2));
+    fn test_cast_utf8_to_bool_with_parser() {
+        let parser = BooleanParser::new()
+            .with_canonical_tokens(false)
+            .with_case_sensitive(true)
+            .with_true_token("Y")
+            .with_false_token("N");
+        let options = CastOptions::new().with_boolean_parser(Some(parser));
+
+        let strings = StringArray::from(vec![Some("Y"), Some(" N "), Some("y"), None]);
+        let b = cast_with_options(&strings, &DataType::Boolean, &options).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, None]);
+        assert_eq!(b.as_boolean(), &expected);
+
+        let options = options.with_safe(false);
+        let err = cast_with_options(&strings, &DataType::Boolean, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast value 'y' to value of Boolean type"
+        );
+
+        // The values of dictionaries are parsed once, and only referenced values fail
+        let keys = Int8Array::from(vec![Some(0), Some(1), Some(0), None]);
+        let dict = DictionaryArray::new(keys, Arc::new(strings));
+        let b = cast_with_options(&dict, &DataType::Boolean, &options).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(false), Some(true), None]);
+        assert_eq!(b.as_boolean(), &expected);
     }
 
     #[test]
-    fn test_cast_bool_to_f64() {
-        let array = BooleanArray::from(vec![Some(true), Some(false), None]);
-        let b = cast(&array, &DataType::Float64).unwrap();
-        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
-        assert_eq!(1.0, c.value(0));
-        assert_eq!(0.0, c.value(1));
-        assert!(!c.is_valid(2));
+    fn test_cast_bool_to_string_dictionary() {
+        let array = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let b = cast(&array, &to_type).unwrap();
+        let dict = b.as_dictionary::<Int8Type>();
+        assert_eq!(
+            dict.values().as_string::<i32>(),
+            &StringArray::from(vec!["0", "1"])
+        );
+        let keys: Vec<_> = dict.keys().iter().collect();
+        assert_eq!(keys, vec![Some(1), None, Some(0), Some(1)]);
+
+        let options = CastOptions::new().with_compat(CastCompat::ArrowCpp);
+        let to_type = DataType::Dictionary(
+            Box::new(DataType::UInt16),
+            Box::new(DataType::LargeUtf8),
+        );
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let dict = b.as_dictionary::<UInt16Type>();
+        let values = dict.values().as_string::<i64>();
+        assert_eq!(values, &LargeStringArray::from(vec!["false", "true"]));
+
+        // And back again
+        let b = cast(&b, &DataType::Boolean).unwrap();
+        assert_eq!(b.as_boolean(), &array);
     }
 
     #[test]
-    #[should_panic(
-        expected = "Casting from Int32 to Timestamp(Microsecond, None) not supported"
-    )]
-    fn test_cast_int32_to_timestamp() {
-        let array = Int32Array::from(vec![Some(2), Some(10), None]);
-        cast(&array, &DataType::Timestamp(TimeUnit::Microsecond, None)).unwrap();
+    fn test_cast_string_dictionary_to_bool() {
+        let values = StringArray::from(vec![Some("Y"), Some("n"), None, Some("invalid")]);
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(2), Some(0)]);
+        let dict = DictionaryArray::new(keys, Arc::new(values.clone()));
+        let b = cast(&dict, &DataType::Boolean).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(false), None, None, Some(true)]);
+        assert_eq!(b.as_boolean(), &expected);
+
+        // Values that are not referenced are not cast
+        let options = CastOptions::new().with_safe(false);
+        let b = cast_with_options(&dict, &DataType::Boolean, &options).unwrap();
+        assert_eq!(b.as_boolean(), &expected);
+
+        let keys = Int32Array::from(vec![0, 3]);
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+        let b = cast(&dict, &DataType::Boolean).unwrap();
+        assert_eq!(b.as_boolean(), &BooleanArray::from(vec![Some(true), None]));
+        let err = cast_with_options(&dict, &DataType::Boolean, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast value 'invalid' to value of Boolean type"
+        );
     }
 
     #[test]
-    fn test_cast_list_i32_to_list_u16() {
-        let value_data =
-            Int32Array::from(vec![0, 0, 0, -1, -2, -1, 2, 100000000]).into_data();
+    fn test_cast_time32_ms_to_time64() {
+        let array = Time32MillisecondArray::from(vec![Some(1), Some(45_296_500), None]);
 
-        let value_offsets = Buffer::from_slice_ref([0, 3, 6, 8]);
+        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
+        let expected =
+            Time64MicrosecondArray::from(vec![Some(1_000), Some(45_296_500_000), None]);
+        assert_eq!(b.as_primitive::<Time64MicrosecondType>(), &expected);
 
-        // Construct a list array from the above two
-        // [[0,0,0], [-1, -2, -1], [2, 100000000]]
-        let list_data_type =
-            DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
-        let list_data = ArrayData::builder(list_data_type)
-            .len(3)
-            .add_buffer(value_offsets)
-            .add_child_data(value_data)
-            .build()
-            .unwrap();
-        let list_array = ListArray::from(list_data);
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        let expected = Time64NanosecondArray::from(vec![
+            Some(1_000_000),
+            Some(45_296_500_000_000),
+            None,
+        ]);
+        assert_eq!(b.as_primitive::<Time64NanosecondType>(), &expected);
+    }
 
-        let cast_array = cast(
-            &list_array,
-            &DataType::List(Arc::new(Field::new("item", DataType::UInt16, true))),
+    #[test]
+    fn test_cast_time_to_decimal() {
+        let array = Time64NanosecondArray::from(vec![
+            Some(3_723_123_456_789),
+            Some(999_999_999),
+            None,
+            Some(86_399_999_999_999),
+        ]);
+        let b = cast(&array, &DataType::Decimal128(10, 3)).unwrap();
+        assert_eq!(b.data_type(), &DataType::Decimal128(10, 3));
+        let b = b.as_primitive::<Decimal128Type>();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            vec![Some(3_723_123), Some(1_000), None, Some(86_400_000)]
+        );
+
+        let array = Time32SecondArray::from(vec![0, 45_296]);
+        let b = cast(&array, &DataType::Decimal256(10, 2)).unwrap();
+        let b = b.as_primitive::<Decimal256Type>();
+        assert_eq!(b.value(1), i256::from_i128(4_529_600));
+        assert_eq!(b.value_as_string(1), "45296.00");
+
+        let array = Time32MillisecondArray::from(vec![45_296_500]);
+        let b = cast(&array, &DataType::Decimal128(6, 0)).unwrap();
+        assert_eq!(b.as_primitive::<Decimal128Type>().value(0), 45_297);
+
+        for from_type in [
+            DataType::Time32(TimeUnit::Second),
+            DataType::Time32(TimeUnit::Millisecond),
+            DataType::Time64(TimeUnit::Microsecond),
+            DataType::Time64(TimeUnit::Nanosecond),
+        ] {
+            assert!(can_cast_types(&from_type, &DataType::Decimal128(38, 9)));
+            assert!(can_cast_types(&from_type, &DataType::Decimal256(76, 9)));
+        }
+    }
+
+    #[test]
+    fn test_cast_struct_to_struct() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let inner = StructArray::from(vec![(
+            Arc::new(Field::new("c", DataType::Int8, true)),
+            Arc::new(Int8Array::from(vec![7, 8, 9])) as ArrayRef,
+        )]);
+        let array = StructArray::try_new(
+            Fields::from(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, false),
+                Field::new("s", inner.data_type().clone(), false),
+            ]),
+            vec![a, b, Arc::new(inner)],
+            BooleanArray::from(vec![Some(true), Some(true), None])
+                .nulls()
+                .cloned(),
         )
         .unwrap();
 
-        // For the ListArray itself, there are no null values (as there were no nulls when they went in)
-        //
-        // 3 negative values should get lost when casting to unsigned,
-        // 1 value should overflow
-        assert_eq!(0, cast_array.null_count());
-
-        // offsets should be the same
-        let array = cast_array.as_list::<i32>();
-        assert_eq!(list_array.value_offsets(), array.value_offsets());
+        let inner_fields = Fields::from(vec![
+            Field::new("d", DataType::Utf8, false),
+            Field::new("c", DataType::Int64, true),
+        ]);
+        let to_fields = Fields::from(vec![
+            Field::new("b", DataType::LargeUtf8, false),
+            Field::new("a", DataType::Float64, true),
+            Field::new("s", DataType::Struct(inner_fields), true),
+            Field::new("e", DataType::Boolean, true),
+        ]);
+        let to_type = DataType::Struct(to_fields.clone());
+        assert!(can_cast_types(array.data_type(), &to_type));
 
-        assert_eq!(DataType::UInt16, array.value_type());
-        assert_eq!(3, array.value_length(0));
-        assert_eq!(3, array.value_length(1));
-        assert_eq!(2, array.value_length(2));
+        let defaults = CastDefaults::new()
+            .with_default(&["s", "d"], Arc::new(StringArray::from(vec!["-"])))
+            .unwrap();
+        let options = CastOptions::new().with_defaults(Some(Arc::new(defaults)));
+        let c = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(c.data_type(), &to_type);
+        let c = c.as_struct();
+        assert_eq!(c.nulls(), array.nulls());
+        assert_eq!(
+            c.column(0).as_string::<i64>(),
+            &LargeStringArray::from(vec!["x", "y", "z"])
+        );
+        assert_eq!(
+            c.column(1).as_primitive::<Float64Type>(),
+            &Float64Array::from(vec![Some(1.), None, Some(3.)])
+        );
+        let s = c.column(2).as_struct();
+        assert_eq!(
+            s.column(0).as_string::<i32>(),
+            &StringArray::from(vec!["-"; 3])
+        );
+        assert_eq!(
+            s.column(1).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![7, 8, 9])
+        );
+        assert_eq!(c.column(3).null_count(), 3);
 
-        // expect 4 nulls: negative numbers and overflow
-        let u16arr = array.values().as_primitive::<UInt16Type>();
-        assert_eq!(4, u16arr.null_count());
+        // Without a default the non-nullable nested field cannot be filled
+        let err = cast(&array, &to_type).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: field 's': Cannot fill non-nullable field 'd' with nulls, no default value was provided"
+        );
 
-        // expect 4 nulls: negative numbers and overflow
-        let expected: UInt16Array =
-            vec![Some(0), Some(0), Some(0), None, None, None, Some(2), None]
-                .into_iter()
-                .collect();
+        // Missing fields are an error if not safe
+        let options = options.with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert!(
+            err.to_string()
+                .ends_with("field 'e' is not present in the source"),
+            "{err}"
+        );
 
-        assert_eq!(u16arr, &expected);
+        // Fields are matched by position if no names match
+        let to_type = DataType::Struct(Fields::from(vec![
+            Field::new("x", DataType::Int64, true),
+            Field::new("y", DataType::Utf8, false),
+            Field::new("z", array.column(2).data_type().clone(), true),
+        ]));
+        let c = cast(&array, &to_type).unwrap();
+        let c = c.as_struct();
+        assert_eq!(
+            c.column(0).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![Some(1), None, Some(3)])
+        );
+        assert_eq!(c.column(1), array.column(1));
+
+        let back = cast(c, array.data_type()).unwrap();
+        assert_eq!(back.as_struct(), &array);
+
+        let to_type = DataType::Struct(Fields::from(vec![Field::new(
+            "a",
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            true,
+        )]));
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        assert!(!can_cast_types(
+            array.data_type(),
+            &DataType::Struct(Fields::empty())
+        ));
+        let err = cast(&array, &DataType::Struct(Fields::empty())).unwrap_err();
+        assert!(
+            err.to_string().ends_with("to a struct without fields"),
+            "{err}"
+        );
     }
 
     #[test]
-    #[should_panic(
-        expected = "Casting from Int32 to Timestamp(Microsecond, None) not supported"
-    )]
-    fn test_cast_list_i32_to_list_timestamp() {
-        // Construct a value array
-        let value_data =
-            Int32Array::from(vec![0, 0, 0, -1, -2, -1, 2, 8, 100000000]).into_data();
+    fn test_cast_timestamp_to_struct() {
+        let fields = Fields::from(vec![
+            Field::new("date", DataType::Date32, true),
+            Field::new("time", DataType::Time64(TimeUnit::Nanosecond), true),
+        ]);
+        let to_type = DataType::Struct(fields.clone());
 
-        let value_offsets = Buffer::from_slice_ref([0, 3, 6, 9]);
+        // 2023-04-01T12:30:00.5, 1969-12-31T23:59:59
+        let array =
+            TimestampMillisecondArray::from(vec![Some(1680352200500), None, Some(-1000)]);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_struct();
+        assert!(b.is_null(1));
+        let dates = b.column(0).as_primitive::<Date32Type>();
+        assert_eq!(dates.value(0), 19448);
+        assert_eq!(dates.value(2), -1);
+        let times = b.column(1).as_primitive::<Time64NanosecondType>();
+        assert_eq!(times.value(0), 45_000_500_000_000);
+        assert_eq!(times.value(2), 86_399_000_000_000);
+
+        // The local date and time, 2023-04-02T01:30:00.5+13:00
+        let array = array.with_timezone("+13:00");
+        let to_type = DataType::Struct(Fields::from(vec![
+            Field::new("time", DataType::Time32(TimeUnit::Second), true),
+            Field::new("date", DataType::Date64, true),
+        ]));
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_struct();
+        let times = b.column(0).as_primitive::<Time32SecondType>();
+        assert_eq!(times.value(0), 5400);
+        let dates = b.column(1).as_primitive::<Date64Type>();
+        assert_eq!(dates.value(0), 19449 * MILLISECONDS_IN_DAY);
+
+        let to_type = DataType::Struct(Fields::from(vec![
+            Field::new("date", DataType::Date32, true),
+            Field::new("hour", DataType::Int32, true),
+        ]));
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        let err = cast(&array, &to_type).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast Timestamp(Millisecond, Some(\"+13:00\")) to struct field 'hour' of type Int32, expected a date or time"
+        );
+        assert!(!can_cast_types(
+            array.data_type(),
+            &DataType::Struct(Fields::empty())
+        ));
 
-        // Construct a list array from the above two
-        let list_data_type =
-            DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
-        let list_data = ArrayData::builder(list_data_type)
-            .len(3)
-            .add_buffer(value_offsets)
-            .add_child_data(value_data)
-            .build()
-            .unwrap();
-        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+        // Dates beyond the range of Date32
+        let array = TimestampSecondArray::from(vec![i64::MAX]);
+        let b = cast(&array, &DataType::Struct(fields.clone())).unwrap();
+        assert!(b.as_struct().column(0).is_null(0));
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err =
+            cast_with_options(&array, &DataType::Struct(fields), &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot decompose 9223372036854775807 of Timestamp(Second, None) into a local date and time"
+        );
+    }
 
-        cast(
-            &list_array,
-            &DataType::List(Arc::new(Field::new(
-                "item",
-                DataType::Timestamp(TimeUnit::Microsecond, None),
-                true,
-            ))),
-        )
-        .unwrap();
+    #[test]
+    fn test_cast_time_to_dictionary() {
+        let array =
+            Time32SecondArray::from(vec![Some(3600), Some(7200), None, Some(3600)]);
+        let to_type = DataType::Dictionary(
+            Box::new(DataType::UInt8),
+            Box::new(DataType::Time32(TimeUnit::Second)),
+        );
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_dictionary::<UInt8Type>();
+        assert_eq!(
+            b.keys(),
+            &UInt8Array::from(vec![Some(0), Some(1), None, Some(0)])
+        );
+        let values = b.values().as_primitive::<Time32SecondType>();
+        assert_eq!(values, &Time32SecondArray::from(vec![3600, 7200]));
+
+        let back = cast(&b, &DataType::Time32(TimeUnit::Second)).unwrap();
+        assert_eq!(back.as_primitive::<Time32SecondType>(), &array);
+
+        // Casting to a dictionary of another time unit casts the values
+        let to_type = DataType::Dictionary(
+            Box::new(DataType::Int16),
+            Box::new(DataType::Time64(TimeUnit::Nanosecond)),
+        );
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_dictionary::<Int16Type>();
+        let values = b.values().as_primitive::<Time64NanosecondType>();
+        assert_eq!(values.values(), &[3_600_000_000_000, 7_200_000_000_000]);
+
+        let array = Time64MicrosecondArray::from(vec![1, 1, 1]);
+        let to_type = DataType::Dictionary(
+            Box::new(DataType::Int8),
+            Box::new(DataType::Time64(TimeUnit::Microsecond)),
+        );
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.as_dictionary::<Int8Type>().values().len(), 1);
     }
 
     #[test]
-    fn test_cast_date32_to_date64() {
-        let a = Date32Array::from(vec![10000, 17890]);
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Date64).unwrap();
-        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
-        assert_eq!(864000000000, c.value(0));
-        assert_eq!(1545696000000, c.value(1));
+    fn test_cast_temporal_to_dictionary() {
+        let array = StringArray::from(vec![
+            Some("2023-04-01T10:00:00+01:00"),
+            Some("2023-04-01T12:00:00Z"),
+            None,
+            Some("2023-04-01T09:00:00Z"),
+        ]);
+        let tz: Arc<str> = "+01:00".into();
+        let value_type = DataType::Timestamp(TimeUnit::Millisecond, Some(tz));
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(value_type.clone()));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_dictionary::<Int32Type>();
+        assert_eq!(
+            b.keys(),
+            &Int32Array::from(vec![Some(0), Some(1), None, Some(0)])
+        );
+        assert_eq!(b.values().data_type(), &value_type);
+        let values = b.values().as_primitive::<TimestampMillisecondType>();
+        assert_eq!(values.values(), &[1680339600000, 1680350400000]);
+
+        let back = cast(&b, &value_type).unwrap();
+        assert_eq!(back.as_ref(), cast(&array, &value_type).unwrap().as_ref());
+
+        let array = Int64Array::from(vec![Some(5), None, Some(5), Some(-1)]);
+        let types = [
+            DataType::Date64,
+            DataType::Duration(TimeUnit::Second),
+            DataType::Duration(TimeUnit::Nanosecond),
+            DataType::Interval(IntervalUnit::DayTime),
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+        ];
+        for value_type in types {
+            let to_type =
+                DataType::Dictionary(Box::new(DataType::UInt8), Box::new(value_type));
+            assert!(can_cast_types(array.data_type(), &to_type));
+            let b = cast(&array, &to_type).unwrap();
+            assert_eq!(b.data_type(), &to_type);
+            let b = b.as_dictionary::<UInt8Type>();
+            assert_eq!(
+                b.keys(),
+                &UInt8Array::from(vec![Some(0), None, Some(0), Some(1)])
+            );
+            assert_eq!(b.values().len(), 2);
+        }
+
+        let array = Int32Array::from(vec![Some(19448), Some(19448), None]);
+        for value_type in [
+            DataType::Date32,
+            DataType::Interval(IntervalUnit::YearMonth),
+        ] {
+            let to_type =
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(value_type));
+            let b = cast(&array, &to_type).unwrap();
+            assert_eq!(b.data_type(), &to_type);
+            assert_eq!(b.as_dictionary::<Int8Type>().values().len(), 1);
+        }
+
+        let array = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNanoType::make_value(1, 2, 3),
+            IntervalMonthDayNanoType::make_value(1, 2, 3),
+        ]);
+        let to_type = DataType::Dictionary(
+            Box::new(DataType::Int16),
+            Box::new(DataType::Interval(IntervalUnit::MonthDayNano)),
+        );
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_dictionary::<Int16Type>();
+        assert_eq!(b.keys(), &Int16Array::from(vec![0, 0]));
+        let back = cast(b, array.data_type()).unwrap();
+        assert_eq!(back.as_primitive::<IntervalMonthDayNanoType>(), &array);
     }
 
     #[test]
-    fn test_cast_date64_to_date32() {
-        let a = Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Date32).unwrap();
-        let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
-        assert_eq!(10000, c.value(0));
-        assert_eq!(17890, c.value(1));
-        assert!(c.is_null(2));
+    fn test_cast_decimal_to_dictionary_keeps_precision() {
+        let array = Decimal128Array::from(vec![Some(123), None, Some(123)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let to_type = DataType::Dictionary(
+            Box::new(DataType::Int32),
+            Box::new(DataType::Decimal128(7, 3)),
+        );
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let values = b.as_dictionary::<Int32Type>().values();
+        assert_eq!(values.as_primitive::<Decimal128Type>().values(), &[1230]);
     }
 
     #[test]
-    fn test_cast_string_to_timestamp() {
-        let a1 = Arc::new(StringArray::from(vec![
-            Some("2020-09-08T12:00:00.123456789+00:00"),
-            Some("Not a valid date"),
-            None,
-        ])) as ArrayRef;
-        let a2 = Arc::new(LargeStringArray::from(vec![
-            Some("2020-09-08T12:00:00.123456789+00:00"),
-            Some("Not a valid date"),
+    fn test_cast_utf8_to_binary_dictionary() {
+        let array = LargeStringArray::from(vec![Some("a"), Some("bc"), None, Some("a")]);
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Binary));
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_dictionary::<Int32Type>();
+        assert_eq!(
+            b.keys(),
+            &Int32Array::from(vec![Some(0), Some(1), None, Some(0)])
+        );
+        let values: Vec<_> = b.values().as_binary::<i32>().iter().flatten().collect();
+        assert_eq!(values, vec![b"a".as_ref(), b"bc".as_ref()]);
+    }
+
+    #[test]
+    fn test_cast_binary_to_fixed_size_binary_dictionary() {
+        let a = [0xAA_u8; 4];
+        let b = [0xBB_u8; 4];
+        let array = BinaryArray::from(vec![
+            Some(&a[..]),
             None,
-        ])) as ArrayRef;
-        for array in &[a1, a2] {
-            for time_unit in &[
-                TimeUnit::Second,
-                TimeUnit::Millisecond,
-                TimeUnit::Microsecond,
-                TimeUnit::Nanosecond,
-            ] {
-                let to_type = DataType::Timestamp(time_unit.clone(), None);
-                let b = cast(array, &to_type).unwrap();
+            Some(&b[..]),
+            Some(&a[..]),
+            Some(&b"short"[..3]),
+        ]);
+        let to_type = DataType::Dictionary(
+            Box::new(DataType::UInt8),
+            Box::new(DataType::FixedSizeBinary(4)),
+        );
+        assert!(can_cast_types(array.data_type(), &to_type));
 
-                match time_unit {
-                    TimeUnit::Second => {
-                        let c =
-                            b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
-                        assert_eq!(1599566400, c.value(0));
-                        assert!(c.is_null(1));
-                        assert!(c.is_null(2));
-                    }
-                    TimeUnit::Millisecond => {
-                        let c = b
-                            .as_any()
-                            .downcast_ref::<TimestampMillisecondArray>()
-                            .unwrap();
-                        assert_eq!(1599566400123, c.value(0));
-                        assert!(c.is_null(1));
-                        assert!(c.is_null(2));
-                    }
-                    TimeUnit::Microsecond => {
-                        let c = b
-                            .as_any()
-                            .downcast_ref::<TimestampMicrosecondArray>()
-                            .unwrap();
-                        assert_eq!(1599566400123456, c.value(0));
-                        assert!(c.is_null(1));
-                        assert!(c.is_null(2));
-                    }
-                    TimeUnit::Nanosecond => {
-                        let c = b
-                            .as_any()
-                            .downcast_ref::<TimestampNanosecondArray>()
-                            .unwrap();
-                        assert_eq!(1599566400123456789, c.value(0));
-                        assert!(c.is_null(1));
-                        assert!(c.is_null(2));
-                    }
-                }
+        let c = cast(&array, &to_type).unwrap();
+        assert_eq!(c.data_type(), &to_type);
+        let c = c.as_dictionary::<UInt8Type>();
+        assert_eq!(
+            c.keys(),
+            &UInt8Array::from(vec![Some(0), None, Some(1), Some(0), None])
+        );
+        let values = c
+            .values()
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.value(0), &a);
+        assert_eq!(values.value(1), &b);
 
-                let options = CastOptions { safe: false };
-                let err = cast_with_options(array, &to_type, &options).unwrap_err();
-                assert_eq!(
-                    err.to_string(),
-                    "Parser error: Error parsing timestamp from 'Not a valid date': error parsing date"
-                );
-            }
-        }
-    }
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast binary value of length 3 at row 4 to FixedSizeBinary(4)"
+        );
 
-    #[test]
-    fn test_cast_string_to_timestamp_overflow() {
-        let array = StringArray::from(vec!["9800-09-08T12:00:00.123456789"]);
-        let result = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
-        let result = result.as_primitive::<TimestampSecondType>();
-        assert_eq!(result.values(), &[247112596800]);
+        let array = LargeBinaryArray::from(vec![&a[..], &a[..]]);
+        let c = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(c.as_dictionary::<UInt8Type>().values().len(), 1);
+
+        let array = FixedSizeBinaryArray::try_from_iter([a, b, b].into_iter()).unwrap();
+        let c = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = c.as_dictionary::<UInt8Type>();
+        assert_eq!(c.keys(), &UInt8Array::from(vec![0, 1, 1]));
+
+        // Keys that overflow the key type are an error
+        let array = BinaryArray::from_iter_values((0..=256_u32).map(|v| v.to_le_bytes()));
+        let err = cast(&array, &to_type).unwrap_err();
+        assert_eq!(err.to_string(), "Dictionary key bigger than the key type");
     }
 
     #[test]
-    fn test_cast_string_to_date32() {
-        let a1 = Arc::new(StringArray::from(vec![
-            Some("2018-12-25"),
-            Some("Not a valid date"),
+    fn test_cast_dictionary_to_run_end_encoded() {
+        let dict: DictionaryArray<Int8Type> = vec![
+            Some("a"),
+            Some("a"),
             None,
-        ])) as ArrayRef;
-        let a2 = Arc::new(LargeStringArray::from(vec![
-            Some("2018-12-25"),
-            Some("Not a valid date"),
             None,
-        ])) as ArrayRef;
-        for array in &[a1, a2] {
-            let to_type = DataType::Date32;
-            let b = cast(array, &to_type).unwrap();
-            let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
-            assert_eq!(17890, c.value(0));
-            assert!(c.is_null(1));
-            assert!(c.is_null(2));
+            Some("b"),
+            Some("a"),
+            Some("a"),
+        ]
+        .into_iter()
+        .collect();
+        let to_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int32, false)),
+            Arc::new(Field::new("values", DataType::LargeUtf8, true)),
+        );
+        assert!(can_cast_types(dict.data_type(), &to_type));
+
+        let c = cast(&dict, &to_type).unwrap();
+        assert_eq!(c.data_type(), &to_type);
+        assert_eq!(c.len(), 7);
+        let c = c.as_any().downcast_ref::<RunArray<Int32Type>>().unwrap();
+        assert_eq!(c.run_ends().values(), &[2, 4, 5, 7]);
+        let values = c.values().as_string::<i64>();
+        assert_eq!(
+            values,
+            &LargeStringArray::from(vec![Some("a"), None, Some("b"), Some("a")])
+        );
 
-            let options = CastOptions { safe: false };
-            let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date32 type");
-        }
+        let c = cast(&dict.slice(1, 3), &to_type).unwrap();
+        let c = c.as_any().downcast_ref::<RunArray<Int32Type>>().unwrap();
+        assert_eq!(c.run_ends().values(), &[1, 3]);
+
+        let c = cast(&dict.slice(0, 0), &to_type).unwrap();
+        assert_eq!(c.len(), 0);
+
+        let dict: DictionaryArray<Int8Type> = vec!["a"; 200].into_iter().collect();
+        let to_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int16, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        );
+        let c = cast(&dict, &to_type).unwrap();
+        let c = c.as_any().downcast_ref::<RunArray<Int16Type>>().unwrap();
+        assert_eq!(c.run_ends().values(), &[200]);
+
+        let to_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::UInt32, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        );
+        assert!(!can_cast_types(dict.data_type(), &to_type));
+        let err = cast(&dict, &to_type).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Unsupported type UInt32 for run ends"
+        );
     }
 
     #[test]
-    fn test_cast_string_to_time32second() {
-        let a1 = Arc::new(StringArray::from(vec![
-            Some("08:08:35.091323414"),
-            Some("08:08:60.091323414"), // leap second
-            Some("08:08:61.091323414"), // not valid
-            Some("Not a valid time"),
+    fn test_cast_run_end_encoded() {
+        let array = Int32Array::from(vec![
+            Some(1),
+            Some(1),
             None,
-        ])) as ArrayRef;
-        let a2 = Arc::new(LargeStringArray::from(vec![
-            Some("08:08:35.091323414"),
-            Some("08:08:60.091323414"), // leap second
-            Some("08:08:61.091323414"), // not valid
-            Some("Not a valid time"),
             None,
-        ])) as ArrayRef;
-        for array in &[a1, a2] {
-            let to_type = DataType::Time32(TimeUnit::Second);
-            let b = cast(array, &to_type).unwrap();
-            let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
-            assert_eq!(29315, c.value(0));
-            assert_eq!(29340, c.value(1));
-            assert!(c.is_null(2));
-            assert!(c.is_null(3));
-            assert!(c.is_null(4));
+            Some(2),
+            Some(1),
+            Some(1),
+        ]);
+        let to_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int16, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        );
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let c = cast(&array, &to_type).unwrap();
+        assert_eq!(c.data_type(), &to_type);
+        assert_eq!(c.len(), 7);
+        let run = c.as_any().downcast_ref::<RunArray<Int16Type>>().unwrap();
+        assert_eq!(run.run_ends().values(), &[2, 4, 5, 7]);
+        let values = run.values().as_string::<i32>();
+        assert_eq!(
+            values,
+            &StringArray::from(vec![Some("1"), None, Some("2"), Some("1")])
+        );
 
-            let options = CastOptions { safe: false };
-            let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
+        // Flattened to the value type, or any type the values can be cast to
+        assert!(can_cast_types(&to_type, &DataType::Int64));
+        let b = cast(&c, &DataType::Int64).unwrap();
+        let expected = Int64Array::from(vec![
+            Some(1),
+            Some(1),
+            None,
+            None,
+            Some(2),
+            Some(1),
+            Some(1),
+        ]);
+        assert_eq!(b.as_primitive::<Int64Type>(), &expected);
+
+        // Only the runs of a slice are kept
+        let sliced = c.slice(1, 4);
+        let b = cast(&sliced, &DataType::Int32).unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, None, Some(2)]);
+        assert_eq!(b.as_primitive::<Int32Type>(), &expected);
+
+        let ree_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int64, false)),
+            Arc::new(Field::new("values", DataType::Float64, true)),
+        );
+        assert!(can_cast_types(&to_type, &ree_type));
+        let b = cast(&sliced, &ree_type).unwrap();
+        assert_eq!(b.data_type(), &ree_type);
+        assert_eq!(b.len(), 4);
+        let run = b.as_any().downcast_ref::<RunArray<Int64Type>>().unwrap();
+        assert_eq!(run.run_ends().values(), &[1, 3, 4]);
+        let values = run.values().as_primitive::<Float64Type>();
+        assert_eq!(
+            values,
+            &Float64Array::from(vec![Some(1.0), None, Some(2.0)])
+        );
+
+        let b = cast(&c.slice(0, 0), &ree_type).unwrap();
+        assert_eq!(b.len(), 0);
+
+        // Runs are detected for types without a specialized comparison
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(1)]),
+            Some(vec![Some(2)]),
+        ]);
+        let ree_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int32, false)),
+            Arc::new(Field::new("values", list.data_type().clone(), true)),
+        );
+        let b = cast(&list, &ree_type).unwrap();
+        let run = b.as_any().downcast_ref::<RunArray<Int32Type>>().unwrap();
+        assert_eq!(run.run_ends().values(), &[2, 3]);
+
+        let array = Int32Array::from(vec![None; 40000]);
+        let err = cast(&array, &to_type).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast Int32 of 40000 rows to RunEndEncoded(Field { name: \"run_ends\", data_type: Int16, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: {} }, Field { name: \"values\", data_type: Utf8, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} }), the run ends overflow"
+        );
+        let b = cast(&array.slice(0, 3), &to_type).unwrap();
+        let run = b.as_any().downcast_ref::<RunArray<Int16Type>>().unwrap();
+        assert_eq!(run.run_ends().values(), &[3]);
+        assert_eq!(run.values().null_count(), 1);
+
+        let to_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::UInt16, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        );
+        assert!(!can_cast_types(array.data_type(), &to_type));
+    }
+
+    #[test]
+    fn test_cast_base64() {
+        let options = CastOptions::new().with_base64(Some(Base64Alphabet::UrlSafe));
+        let values: Vec<Option<&[u8]>> = vec![Some(b"arrow"), None, Some(&[0xfb, 0xff])];
+        let encoded = vec![Some("YXJyb3c="), None, Some("-_8=")];
+
+        let binaries: Vec<ArrayRef> = vec![
+            Arc::new(BinaryArray::from(values.clone())),
+            Arc::new(LargeBinaryArray::from(values)),
+        ];
+        let strings: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(encoded.clone())),
+            Arc::new(LargeStringArray::from(encoded)),
+        ];
+        for binary in &binaries {
+            for string in &strings {
+                let c = cast_with_options(binary, string.data_type(), &options).unwrap();
+                assert_eq!(&c, string);
+                let c = cast_with_options(string, binary.data_type(), &options).unwrap();
+                assert_eq!(&c, binary);
+            }
         }
+
+        // Without the option, binary values that are not valid UTF-8 are null
+        let c = cast(&binaries[0], &DataType::Utf8).unwrap();
+        assert!(c.is_null(2));
+
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Binary));
+        let dict = cast(&binaries[0], &dict_type).unwrap();
+        let c = cast_with_options(&dict, &DataType::Utf8, &options).unwrap();
+        assert_eq!(&c, &strings[0]);
+
+        let options = options.with_safe(false);
+        let array = StringArray::from(vec!["YXJyb3c=", "+/8="]);
+        let err = cast_with_options(&array, &DataType::Binary, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot decode base64 string '+/8=': Invalid byte 43, offset 0."
+        );
     }
 
     #[test]
-    fn test_cast_string_to_time32millisecond() {
-        let a1 = Arc::new(StringArray::from(vec![
-            Some("08:08:35.091323414"),
-            Some("08:08:60.091323414"), // leap second
-            Some("08:08:61.091323414"), // not valid
-            Some("Not a valid time"),
+    fn test_cast_string_currency() {
+        let format = NumberFormat::new('.', Some(','))
+            .with_currency_symbols(["$", "USD"])
+            .with_parentheses_negative(true);
+        let options = CastOptions::new().with_number_format(Some(format));
+        let array = StringArray::from(vec![
+            Some("$1,234.56"),
+            Some("(45.00)"),
+            Some("-$0.5"),
+            Some(" 12 USD "),
+            Some("(-1)"),
+            Some("€1"),
             None,
-        ])) as ArrayRef;
-        let a2 = Arc::new(LargeStringArray::from(vec![
-            Some("08:08:35.091323414"),
-            Some("08:08:60.091323414"), // leap second
-            Some("08:08:61.091323414"), // not valid
-            Some("Not a valid time"),
+        ]);
+
+        let c =
+            cast_with_options(&array, &DataType::Decimal128(10, 2), &options).unwrap();
+        let expected = Decimal128Array::from(vec![
+            Some(123456),
+            Some(-4500),
+            Some(-50),
+            Some(1200),
             None,
-        ])) as ArrayRef;
-        for array in &[a1, a2] {
-            let to_type = DataType::Time32(TimeUnit::Millisecond);
-            let b = cast(array, &to_type).unwrap();
-            let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
-            assert_eq!(29315091, c.value(0));
-            assert_eq!(29340091, c.value(1));
-            assert!(c.is_null(2));
-            assert!(c.is_null(3));
-            assert!(c.is_null(4));
+            None,
+            None,
+        ])
+        .with_precision_and_scale(10, 2)
+        .unwrap();
+        assert_eq!(c.as_primitive::<Decimal128Type>(), &expected);
+
+        let c = cast_with_options(&array, &DataType::Float64, &options).unwrap();
+        let expected = Float64Array::from(vec![
+            Some(1234.56),
+            Some(-45.0),
+            Some(-0.5),
+            Some(12.0),
+            None,
+            None,
+            None,
+        ]);
+        assert_eq!(c.as_primitive::<Float64Type>(), &expected);
 
-            let options = CastOptions { safe: false };
-            let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
-        }
+        // Strict parsing is the default
+        let c = cast(&array, &DataType::Decimal128(10, 2)).unwrap();
+        assert_eq!(c.null_count(), 7);
     }
 
     #[test]
-    fn test_cast_string_to_time64microsecond() {
-        let a1 = Arc::new(StringArray::from(vec![
-            Some("08:08:35.091323414"),
-            Some("Not a valid time"),
-            None,
-        ])) as ArrayRef;
-        let a2 = Arc::new(LargeStringArray::from(vec![
-            Some("08:08:35.091323414"),
-            Some("Not a valid time"),
+    fn test_cast_format_options() {
+        let format_options = FormatOptions::new()
+            .with_null("NA")
+            .with_date_format(Some("%d/%m/%Y"))
+            .with_timestamp_format(Some("%Y-%m-%d %H:%M"))
+            .with_decimal_scientific(true);
+        let options = CastOptions::new().with_format_options(format_options);
+
+        let array = TimestampSecondArray::from(vec![Some(1_000_000_000), None]);
+        let c = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let expected = StringArray::from(vec![Some("2001-09-09 01:46"), None]);
+        assert_eq!(c.as_string::<i32>(), &expected);
+
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::LargeUtf8));
+        let c = cast_with_options(&array, &dict_type, &options).unwrap();
+        let c = cast(&c, &DataType::LargeUtf8).unwrap();
+        assert_eq!(c.as_string::<i64>().value(0), "2001-09-09 01:46");
+
+        let array = Date32Array::from(vec![19000]);
+        let c = cast_with_options(&array, &DataType::LargeUtf8, &options).unwrap();
+        assert_eq!(c.as_string::<i64>().value(0), "08/01/2022");
+
+        let array = Decimal128Array::from(vec![Some(12345), None])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let c = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let expected = StringArray::from(vec![Some("1.2345E2"), None]);
+        assert_eq!(c.as_string::<i32>(), &expected);
+        let c = cast(&array, &DataType::Utf8).unwrap();
+        assert_eq!(c.as_string::<i32>().value(0), "123.45");
+
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
             None,
-        ])) as ArrayRef;
-        for array in &[a1, a2] {
-            let to_type = DataType::Time64(TimeUnit::Microsecond);
-            let b = cast(array, &to_type).unwrap();
-            let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
-            assert_eq!(29315091323, c.value(0));
-            assert!(c.is_null(1));
-            assert!(c.is_null(2));
-
-            let options = CastOptions { safe: false };
-            let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
-        }
+        ]);
+        let c = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let expected = StringArray::from(vec![Some("[1, NA]"), None]);
+        assert_eq!(c.as_string::<i32>(), &expected);
+
+        let options = options.with_list_delimiter(Some("|".into()));
+        let c = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let expected = StringArray::from(vec![Some("1|NA"), None]);
+        assert_eq!(c.as_string::<i32>(), &expected);
     }
 
     #[test]
-    fn test_cast_string_to_time64nanosecond() {
-        let a1 = Arc::new(StringArray::from(vec![
-            Some("08:08:35.091323414"),
-            Some("Not a valid time"),
-            None,
-        ])) as ArrayRef;
-        let a2 = Arc::new(LargeStringArray::from(vec![
-            Some("08:08:35.091323414"),
-            Some("Not a valid time"),
+    fn test_cast_string_number_format() {
+        let options = CastOptions::new()
+            .with_number_format(Some(NumberFormat::new(',', Some('.'))));
+        let array = StringArray::from(vec![
+            Some("1.234,5"),
+            Some("-0,25"),
             None,
-        ])) as ArrayRef;
-        for array in &[a1, a2] {
-            let to_type = DataType::Time64(TimeUnit::Nanosecond);
-            let b = cast(array, &to_type).unwrap();
-            let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
-            assert_eq!(29315091323414, c.value(0));
-            assert!(c.is_null(1));
-            assert!(c.is_null(2));
+            Some("12,5e2"),
+            Some("1,234.5"),
+        ]);
 
-            let options = CastOptions { safe: false };
-            let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
-        }
-    }
+        let c = cast_with_options(&array, &DataType::Float64, &options).unwrap();
+        let expected =
+            Float64Array::from(vec![Some(1234.5), Some(-0.25), None, Some(1250.0), None]);
+        assert_eq!(c.as_primitive::<Float64Type>(), &expected);
 
-    #[test]
-    fn test_cast_string_to_date64() {
-        let a1 = Arc::new(StringArray::from(vec![
-            Some("2020-09-08T12:00:00"),
-            Some("Not a valid date"),
+        let c = cast_with_options(&array, &DataType::Float32, &options).unwrap();
+        assert_eq!(c.as_primitive::<Float32Type>().value(0), 1234.5);
+
+        let c = cast_with_options(&array, &DataType::Float16, &options).unwrap();
+        assert_eq!(
+            c.as_primitive::<Float16Type>().value(1),
+            f16::from_f32(-0.25)
+        );
+        assert!(c.is_null(4));
+
+        let c =
+            cast_with_options(&array, &DataType::Decimal128(10, 2), &options).unwrap();
+        let expected = Decimal128Array::from(vec![
+            Some(123450),
+            Some(-25),
             None,
-        ])) as ArrayRef;
-        let a2 = Arc::new(LargeStringArray::from(vec![
-            Some("2020-09-08T12:00:00"),
-            Some("Not a valid date"),
+            Some(125000),
             None,
-        ])) as ArrayRef;
-        for array in &[a1, a2] {
-            let to_type = DataType::Date64;
-            let b = cast(array, &to_type).unwrap();
-            let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
-            assert_eq!(1599566400000, c.value(0));
-            assert!(c.is_null(1));
-            assert!(c.is_null(2));
+        ])
+        .with_precision_and_scale(10, 2)
+        .unwrap();
+        assert_eq!(c.as_primitive::<Decimal128Type>(), &expected);
 
-            let options = CastOptions { safe: false };
-            let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date64 type");
-        }
-    }
+        let c =
+            cast_with_options(&array, &DataType::Decimal256(10, 1), &options).unwrap();
+        assert_eq!(
+            c.as_primitive::<Decimal256Type>().value(0),
+            i256::from_i128(12345)
+        );
 
-    macro_rules! test_safe_string_to_interval {
-        ($data_vec:expr, $interval_unit:expr, $array_ty:ty, $expect_vec:expr) => {
-            let source_string_array =
-                Arc::new(StringArray::from($data_vec.clone())) as ArrayRef;
+        // Integers are not affected by the number format
+        let array = StringArray::from(vec!["1.234", "5"]);
+        let c = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+        assert_eq!(
+            c.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![None, Some(5)])
+        );
 
-            let options = CastOptions { safe: true };
+        let options = options.with_safe(false);
+        let array = StringArray::from(vec!["1,5", "1.5,5,5"]);
+        let err = cast_with_options(&array, &DataType::Float64, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '1.5,5,5' to value of Float64 type"
+        );
+        let err =
+            cast_with_options(&array, &DataType::Decimal128(5, 1), &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '1.5,5,5' to value of Decimal128(38, 10) type"
+        );
+    }
 
-            let target_interval_array = cast_with_options(
-                &source_string_array.clone(),
-                &DataType::Interval($interval_unit),
-                &options,
-            )
-            .unwrap()
-            .as_any()
-            .downcast_ref::<$array_ty>()
+    #[test]
+    fn test_cast_defaults() {
+        let value = Arc::new(Int32Array::from(vec![1])) as ArrayRef;
+        let defaults = CastDefaults::new()
+            .with_default(&["a", "b"], value.clone())
             .unwrap()
-            .clone() as $array_ty;
+            .with_default(&["c"], value.clone())
+            .unwrap();
 
-            let target_string_array =
-                cast_with_options(&target_interval_array, &DataType::Utf8, &options)
-                    .unwrap()
-                    .as_any()
-                    .downcast_ref::<StringArray>()
-                    .unwrap()
-                    .clone();
+        assert_eq!(defaults.get(&["a", "b"]), Some(&value));
+        assert_eq!(defaults.get(&["a"]), None);
+        assert_eq!(defaults.get(&["c"]), Some(&value));
 
-            let expect_string_array = StringArray::from($expect_vec);
+        let child = defaults.child("a");
+        assert_eq!(child.get(&["b"]), Some(&value));
+        assert_eq!(child.get(&["c"]), None);
+        assert!(defaults.child("c").is_empty());
 
-            assert_eq!(target_string_array, expect_string_array);
+        let err = CastDefaults::new()
+            .with_default(&["a"], Arc::new(Int32Array::from(vec![1, 2])))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Default value for a must contain exactly one element, got 2"
+        );
+    }
 
-            let target_large_string_array =
-                cast_with_options(&target_interval_array, &DataType::LargeUtf8, &options)
-                    .unwrap()
-                    .as_any()
-                    .downcast_ref::<LargeStringArray>()
-                    .unwrap()
-                    .clone();
+    #[test]
+    fn test_cast_numeric_to_bool() {
+        let array = Int32Array::from(vec![Some(0), Some(-3), None, Some(7), Some(0)]);
+        let b = cast(&array, &DataType::Boolean).unwrap();
+        let c = b.as_boolean();
+        let expected = BooleanArray::from(vec![
+            Some(false),
+            Some(true),
+            None,
+            Some(true),
+            Some(false),
+        ]);
+        assert_eq!(c, &expected);
 
-            let expect_large_string_array = LargeStringArray::from($expect_vec);
+        let sliced = array.slice(1, 3);
+        let b = cast(&sliced, &DataType::Boolean).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), None, Some(true)]);
+        assert_eq!(b.as_boolean(), &expected);
 
-            assert_eq!(target_large_string_array, expect_large_string_array);
-        };
+        let array = Float64Array::from(vec![0.0, -0.0, 0.5, f64::NAN]);
+        let b = cast(&array, &DataType::Boolean).unwrap();
+        let expected = BooleanArray::from(vec![false, false, true, true]);
+        assert_eq!(b.as_boolean(), &expected);
     }
 
     #[test]
-    fn test_cast_string_to_interval_year_month() {
-        test_safe_string_to_interval!(
-            vec![
-                Some("1 year 1 month"),
-                Some("1.5 years 13 month"),
-                Some("30 days"),
-                Some("31 days"),
-                Some("2 months 31 days"),
-                Some("2 months 31 days 1 second"),
-                Some("foobar"),
-            ],
-            IntervalUnit::YearMonth,
-            IntervalYearMonthArray,
-            vec![
-                Some("1 years 1 mons 0 days 0 hours 0 mins 0.00 secs"),
-                Some("2 years 7 mons 0 days 0 hours 0 mins 0.00 secs"),
-                None,
-                None,
-                None,
-                None,
-                None,
-            ]
+    fn test_cast_compat_arrow_cpp() {
+        let options = CastOptions {
+            compat: CastCompat::ArrowCpp,
+            ..DEFAULT_CAST_OPTIONS
+        };
+
+        let array = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let expected = StringArray::from(vec![Some("1"), Some("0"), None]);
+        assert_eq!(b.as_string::<i32>(), &expected);
+        let b = cast_with_options(&array, &DataType::LargeUtf8, &options).unwrap();
+        let expected = LargeStringArray::from(vec![Some("true"), Some("false"), None]);
+        assert_eq!(b.as_string::<i64>(), &expected);
+
+        let array = Float64Array::from(vec![Some(1.0), Some(-3.0), None]);
+        let b = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(-3), None]);
+        assert_eq!(b.as_primitive::<Int32Type>(), &expected);
+
+        // Truncation and overflow are an error even though safe is true
+        let array = Float64Array::from(vec![1.5]);
+        let b = cast(&array, &DataType::Int32).unwrap();
+        assert_eq!(b.as_primitive::<Int32Type>().value(0), 1);
+        let err = cast_with_options(&array, &DataType::Int32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Can't cast value 1.5 to type Int32 without loss of precision"
+        );
+
+        let array = Int32Array::from(vec![300]);
+        let b = cast(&array, &DataType::UInt8).unwrap();
+        assert!(b.is_null(0));
+        let err = cast_with_options(&array, &DataType::UInt8, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Can't cast value 300 to type UInt8"
         );
+
+        let array = Int64Array::from(vec![(1 << 53) + 1]);
+        let err = cast_with_options(&array, &DataType::Float64, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Can't cast value 9007199254740993 to type Float64 without loss of precision"
+        );
+
+        // Casts between floating point types may still lose precision
+        let array = Float64Array::from(vec![0.1]);
+        let b = cast_with_options(&array, &DataType::Float32, &options).unwrap();
+        assert_eq!(b.as_primitive::<Float32Type>().value(0), 0.1);
     }
 
     #[test]
-    fn test_cast_string_to_interval_day_time() {
-        test_safe_string_to_interval!(
-            vec![
-                Some("1 year 1 month"),
-                Some("1.5 years 13 month"),
-                Some("30 days"),
-                Some("1 day 2 second 3.5 milliseconds"),
-                Some("foobar"),
-            ],
-            IntervalUnit::DayTime,
-            IntervalDayTimeArray,
-            vec![
-                Some("0 years 0 mons 390 days 0 hours 0 mins 0.000 secs"),
-                Some("0 years 0 mons 930 days 0 hours 0 mins 0.000 secs"),
-                Some("0 years 0 mons 30 days 0 hours 0 mins 0.000 secs"),
-                None,
-                None,
-            ]
-        );
+    fn test_cast_bool_to_i32() {
+        let array = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let b = cast(&array, &DataType::Int32).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(1, c.value(0));
+        assert_eq!(0, c.value(1));
+        assert!(!c.is_valid(2));
     }
 
     #[test]
-    fn test_cast_string_to_interval_month_day_nano() {
-        test_safe_string_to_interval!(
-            vec![
-                Some("1 year 1 month 1 day"),
-                None,
-                Some("1.5 years 13 month 35 days 1.4 milliseconds"),
-                Some("3 days"),
-                Some("8 seconds"),
-                None,
-                Some("1 day 29800 milliseconds"),
-                Some("3 months 1 second"),
-                Some("6 minutes 120 second"),
-                Some("2 years 39 months 9 days 19 hours 1 minute 83 seconds 399222 milliseconds"),
-                Some("foobar"),
-            ],
-            IntervalUnit::MonthDayNano,
-            IntervalMonthDayNanoArray,
-            vec![
-                Some("0 years 13 mons 1 days 0 hours 0 mins 0.000000000 secs"),
-                None,
-                Some("0 years 31 mons 35 days 0 hours 0 mins 0.001400000 secs"),
-                Some("0 years 0 mons 3 days 0 hours 0 mins 0.000000000 secs"),
-                Some("0 years 0 mons 0 days 0 hours 0 mins 8.000000000 secs"),
-                None,
-                Some("0 years 0 mons 1 days 0 hours 0 mins 29.800000000 secs"),
-                Some("0 years 3 mons 0 days 0 hours 0 mins 1.000000000 secs"),
-                Some("0 years 0 mons 0 days 0 hours 8 mins 0.000000000 secs"),
-                Some("0 years 63 mons 9 days 19 hours 9 mins 2.222000000 secs"),
-                None,
-            ]
-        );
+    fn test_cast_bool_to_f64() {
+        let array = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(1.0, c.value(0));
+        assert_eq!(0.0, c.value(1));
+        assert!(!c.is_valid(2));
     }
 
-    macro_rules! test_unsafe_string_to_interval_err {
-        ($data_vec:expr, $interval_unit:expr, $error_msg:expr) => {
-            let string_array = Arc::new(StringArray::from($data_vec.clone())) as ArrayRef;
-            let options = CastOptions { safe: false };
-            let arrow_err = cast_with_options(
-                &string_array.clone(),
-                &DataType::Interval($interval_unit),
-                &options,
-            )
-            .unwrap_err();
-            assert_eq!($error_msg, arrow_err.to_string());
-        };
+    #[test]
+    #[should_panic(
+        expected = "Casting from Int32 to Timestamp(Microsecond, None) not supported"
+    )]
+    fn test_cast_int32_to_timestamp() {
+        let array = Int32Array::from(vec![Some(2), Some(10), None]);
+        cast(&array, &DataType::Timestamp(TimeUnit::Microsecond, None)).unwrap();
     }
 
     #[test]
-    fn test_cast_string_to_interval_err() {
-        test_unsafe_string_to_interval_err!(
-            vec![Some("foobar")],
-            IntervalUnit::YearMonth,
-            r#"Not yet implemented: Unsupported Interval Expression with value "foobar""#
-        );
-        test_unsafe_string_to_interval_err!(
-            vec![Some("foobar")],
-            IntervalUnit::DayTime,
-            r#"Not yet implemented: Unsupported Interval Expression with value "foobar""#
-        );
-        test_unsafe_string_to_interval_err!(
-            vec![Some("foobar")],
-            IntervalUnit::MonthDayNano,
-            r#"Not yet implemented: Unsupported Interval Expression with value "foobar""#
-        );
-        test_unsafe_string_to_interval_err!(
-            vec![Some("2 months 31 days 1 second")],
-            IntervalUnit::YearMonth,
-            r#"Cast error: Cannot cast 2 months 31 days 1 second to IntervalYearMonth. Only year and month fields are allowed."#
-        );
-        test_unsafe_string_to_interval_err!(
-            vec![Some("1 day 1.5 milliseconds")],
-            IntervalUnit::DayTime,
-            r#"Cast error: Cannot cast 1 day 1.5 milliseconds to IntervalDayTime because the nanos part isn't multiple of milliseconds"#
-        );
+    fn test_cast_list_i32_to_list_u16() {
+        let value_data =
+            Int32Array::from(vec![0, 0, 0, -1, -2, -1, 2, 100000000]).into_data();
 
-        // overflow
-        test_unsafe_string_to_interval_err!(
-            vec![Some(format!(
-                "{} century {} year {} month",
-                i64::MAX - 2,
-                i64::MAX - 2,
-                i64::MAX - 2
-            ))],
-            IntervalUnit::DayTime,
-            r#"Parser error: Parsed interval field value out of range: 11068046444225730000000 months 331764692165666300000000 days 28663672503769583000000000000000000000 nanos"#
-        );
-        test_unsafe_string_to_interval_err!(
-            vec![Some(format!(
-                "{} year {} month {} day",
-                i64::MAX - 2,
-                i64::MAX - 2,
-                i64::MAX - 2
-            ))],
-            IntervalUnit::MonthDayNano,
-            r#"Parser error: Parsed interval field value out of range: 110680464442257310000 months 3043712772162076000000 days 262179884170819100000000000000000000 nanos"#
-        );
-    }
+        let value_offsets = Buffer::from_slice_ref([0, 3, 6, 8]);
 
-    #[test]
-    fn test_cast_binary_to_fixed_size_binary() {
-        let bytes_1 = "Hiiii".as_bytes();
-        let bytes_2 = "Hello".as_bytes();
+        // Construct a list array from the above two
+        // [[0,0,0], [-1, -2, -1], [2, 100000000]]
+        let list_data_type =
+            DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
+        let list_data = ArrayData::builder(list_data_type)
+            .len(3)
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build()
+            .unwrap();
+        let list_array = ListArray::from(list_data);
 
-        let binary_data = vec![Some(bytes_1), Some(bytes_2), None];
-        let a1 = Arc::new(BinaryArray::from(binary_data.clone())) as ArrayRef;
-        let a2 = Arc::new(LargeBinaryArray::from(binary_data)) as ArrayRef;
+        let cast_array = cast(
+            &list_array,
+            &DataType::List(Arc::new(Field::new("item", DataType::UInt16, true))),
+        )
+        .unwrap();
 
-        let array_ref = cast(&a1, &DataType::FixedSizeBinary(5)).unwrap();
-        let down_cast = array_ref
-            .as_any()
-            .downcast_ref::<FixedSizeBinaryArray>()
-            .unwrap();
-        assert_eq!(bytes_1, down_cast.value(0));
-        assert_eq!(bytes_2, down_cast.value(1));
-        assert!(down_cast.is_null(2));
+        // For the ListArray itself, there are no null values (as there were no nulls when they went in)
+        //
+        // 3 negative values should get lost when casting to unsigned,
+        // 1 value should overflow
+        assert_eq!(0, cast_array.null_count());
 
-        let array_ref = cast(&a2, &DataType::FixedSizeBinary(5)).unwrap();
-        let down_cast = array_ref
-            .as_any()
-            .downcast_ref::<FixedSizeBinaryArray>()
-            .unwrap();
-        assert_eq!(bytes_1, down_cast.value(0));
-        assert_eq!(bytes_2, down_cast.value(1));
-        assert!(down_cast.is_null(2));
+        // offsets should be the same
+        let array = cast_array.as_list::<i32>();
+        assert_eq!(list_array.value_offsets(), array.value_offsets());
 
-        // test error cases when the length of binary are not same
-        let bytes_1 = "Hi".as_bytes();
-        let bytes_2 = "Hello".as_bytes();
+        assert_eq!(DataType::UInt16, array.value_type());
+        assert_eq!(3, array.value_length(0));
+        assert_eq!(3, array.value_length(1));
+        assert_eq!(2, array.value_length(2));
 
-        let binary_data = vec![Some(bytes_1), Some(bytes_2), None];
-        let a1 = Arc::new(BinaryArray::from(binary_data.clone())) as ArrayRef;
-        let a2 = Arc::new(LargeBinaryArray::from(binary_data)) as ArrayRef;
+        // expect 4 nulls: negative numbers and overflow
+        let u16arr = array.values().as_primitive::<UInt16Type>();
+        assert_eq!(4, u16arr.null_count());
 
-        let array_ref = cast_with_options(
-            &a1,
-            &DataType::FixedSizeBinary(5),
-            &CastOptions { safe: false },
-        );
-        assert!(array_ref.is_err());
+        // expect 4 nulls: negative numbers and overflow
+        let expected: UInt16Array =
+            vec![Some(0), Some(0), Some(0), None, None, None, Some(2), None]
+                .into_iter()
+                .collect();
 
-        let array_ref = cast_with_options(
-            &a2,
-            &DataType::FixedSizeBinary(5),
-            &CastOptions { safe: false },
-        );
-        assert!(array_ref.is_err());
+        assert_eq!(u16arr, &expected);
     }
 
     #[test]
-    fn test_fixed_size_binary_to_binary() {
-        let bytes_1 = "Hiiii".as_bytes();
-        let bytes_2 = "Hello".as_bytes();
-
-        let binary_data = vec![Some(bytes_1), Some(bytes_2), None];
-        let a1 = Arc::new(FixedSizeBinaryArray::from(binary_data.clone())) as ArrayRef;
+    #[should_panic(
+        expected = "Casting from Int32 to Timestamp(Microsecond, None) not supported"
+    )]
+    fn test_cast_list_i32_to_list_timestamp() {
+        // Construct a value array
+        let value_data =
+            Int32Array::from(vec![0, 0, 0, -1, -2, -1, 2, 8, 100000000]).into_data();
 
-        let array_ref = cast(&a1, &DataType::Binary).unwrap();
-        let down_cast = array_ref.as_binary::<i32>();
-        assert_eq!(bytes_1, down_cast.value(0));
-        assert_eq!(bytes_2, down_cast.value(1));
-        assert!(down_cast.is_null(2));
+        let value_offsets = Buffer::from_slice_ref([0, 3, 6, 9]);
 
-        let array_ref = cast(&a1, &DataType::LargeBinary).unwrap();
-        let down_cast = array_ref.as_binary::<i64>();
-        assert_eq!(bytes_1, down_cast.value(0));
-        assert_eq!(bytes_2, down_cast.value(1));
-        assert!(down_cast.is_null(2));
-    }
+        // Construct a list array from the above two
+        let list_data_type =
+            DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
+        let list_data = ArrayData::builder(list_data_type)
+            .len(3)
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build()
+            .unwrap();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
 
-    #[test]
-    fn test_cast_date32_to_int32() {
-        let array = Date32Array::from(vec![10000, 17890]);
-        let b = cast(&array, &DataType::Int32).unwrap();
-        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
-        assert_eq!(10000, c.value(0));
-        assert_eq!(17890, c.value(1));
+        cast(
+            &list_array,
+            &DataType::List(Arc::new(Field::new(
+                "item",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                true,
+            ))),
+        )
+        .unwrap();
     }
 
     #[test]
-    fn test_cast_int32_to_date32() {
-        let array = Int32Array::from(vec![10000, 17890]);
-        let b = cast(&array, &DataType::Date32).unwrap();
-        let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
-        assert_eq!(10000, c.value(0));
-        assert_eq!(17890, c.value(1));
+    fn test_cast_date32_to_date64() {
+        let a = Date32Array::from(vec![10000, 17890]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Date64).unwrap();
+        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
+        assert_eq!(864000000000, c.value(0));
+        assert_eq!(1545696000000, c.value(1));
     }
 
     #[test]
-    fn test_cast_timestamp_to_date32() {
-        let array = TimestampMillisecondArray::from(vec![
-            Some(864000000005),
-            Some(1545696000001),
-            None,
-        ])
-        .with_timezone("UTC".to_string());
+    fn test_cast_date64_to_date32() {
+        let a = Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
+        let array = Arc::new(a) as ArrayRef;
         let b = cast(&array, &DataType::Date32).unwrap();
         let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
         assert_eq!(10000, c.value(0));
@@ -5726,636 +10506,2501 @@ mod tests {
     }
 
     #[test]
-    fn test_cast_timestamp_to_date64() {
-        let array = TimestampMillisecondArray::from(vec![
+    fn test_cast_date64_to_date32_alignment() {
+        let array = Date64Array::from(vec![
+            Some(864000000000),
             Some(864000000005),
-            Some(1545696000001),
+            Some(-1),
+            Some(-86400000),
             None,
         ]);
-        let b = cast(&array, &DataType::Date64).unwrap();
-        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
-        assert_eq!(864000000005, c.value(0));
-        assert_eq!(1545696000001, c.value(1));
-        assert!(c.is_null(2));
 
-        let array =
-            TimestampSecondArray::from(vec![Some(864000000005), Some(1545696000001)]);
-        let b = cast(&array, &DataType::Date64).unwrap();
-        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
-        assert_eq!(864000000005000, c.value(0));
-        assert_eq!(1545696000001000, c.value(1));
+        let b = cast(&array, &DataType::Date32).unwrap();
+        let expected =
+            Date32Array::from(vec![Some(10000), Some(10000), Some(0), Some(-1), None]);
+        assert_eq!(b.as_primitive::<Date32Type>(), &expected);
 
-        // test overflow, safe cast
-        let array = TimestampSecondArray::from(vec![Some(i64::MAX)]);
-        let b = cast(&array, &DataType::Date64).unwrap();
-        assert!(b.is_null(0));
-        // test overflow, unsafe cast
-        let array = TimestampSecondArray::from(vec![Some(i64::MAX)]);
-        let options = CastOptions { safe: false };
-        let b = cast_with_options(&array, &DataType::Date64, &options);
-        assert!(b.is_err());
+        let options = CastOptions::new().with_date64_alignment(Date64Alignment::Floor);
+        let b = cast_with_options(&array, &DataType::Date32, &options).unwrap();
+        let expected =
+            Date32Array::from(vec![Some(10000), Some(10000), Some(-1), Some(-1), None]);
+        assert_eq!(b.as_primitive::<Date32Type>(), &expected);
+
+        let options = CastOptions {
+            date64_alignment: Date64Alignment::Checked,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &DataType::Date32, &options).unwrap();
+        let expected = Date32Array::from(vec![Some(10000), None, None, Some(-1), None]);
+        assert_eq!(b.as_primitive::<Date32Type>(), &expected);
+
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        let err = cast_with_options(&array, &DataType::Date32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast Date64 value 864000000005 to Date32 as it is not at midnight"
+        );
     }
 
     #[test]
-    fn test_cast_timestamp_to_time64() {
-        // test timestamp secs
-        let array = TimestampSecondArray::from(vec![Some(86405), Some(1), None])
-            .with_timezone("+01:00".to_string());
-        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
-        assert_eq!(3605000000, c.value(0));
-        assert_eq!(3601000000, c.value(1));
-        assert!(c.is_null(2));
-        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
-        assert_eq!(3605000000000, c.value(0));
-        assert_eq!(3601000000000, c.value(1));
-        assert!(c.is_null(2));
+    fn test_cast_string_to_timestamp() {
+        let a1 = Arc::new(StringArray::from(vec![
+            Some("2020-09-08T12:00:00.123456789+00:00"),
+            Some("Not a valid date"),
+            None,
+        ])) as ArrayRef;
+        let a2 = Arc::new(LargeStringArray::from(vec![
+            Some("2020-09-08T12:00:00.123456789+00:00"),
+            Some("Not a valid date"),
+            None,
+        ])) as ArrayRef;
+        for array in &[a1, a2] {
+            for time_unit in &[
+                TimeUnit::Second,
+                TimeUnit::Millisecond,
+                TimeUnit::Microsecond,
+                TimeUnit::Nanosecond,
+            ] {
+                let to_type = DataType::Timestamp(time_unit.clone(), None);
+                let b = cast(array, &to_type).unwrap();
 
-        // test timestamp milliseconds
-        let a = TimestampMillisecondArray::from(vec![Some(86405000), Some(1000), None])
-            .with_timezone("+01:00".to_string());
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
-        assert_eq!(3605000000, c.value(0));
-        assert_eq!(3601000000, c.value(1));
-        assert!(c.is_null(2));
-        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
-        assert_eq!(3605000000000, c.value(0));
-        assert_eq!(3601000000000, c.value(1));
-        assert!(c.is_null(2));
+                match time_unit {
+                    TimeUnit::Second => {
+                        let c =
+                            b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+                        assert_eq!(1599566400, c.value(0));
+                        assert!(c.is_null(1));
+                        assert!(c.is_null(2));
+                    }
+                    TimeUnit::Millisecond => {
+                        let c = b
+                            .as_any()
+                            .downcast_ref::<TimestampMillisecondArray>()
+                            .unwrap();
+                        assert_eq!(1599566400123, c.value(0));
+                        assert!(c.is_null(1));
+                        assert!(c.is_null(2));
+                    }
+                    TimeUnit::Microsecond => {
+                        let c = b
+                            .as_any()
+                            .downcast_ref::<TimestampMicrosecondArray>()
+                            .unwrap();
+                        assert_eq!(1599566400123456, c.value(0));
+                        assert!(c.is_null(1));
+                        assert!(c.is_null(2));
+                    }
+                    TimeUnit::Nanosecond => {
+                        let c = b
+                            .as_any()
+                            .downcast_ref::<TimestampNanosecondArray>()
+                            .unwrap();
+                        assert_eq!(1599566400123456789, c.value(0));
+                        assert!(c.is_null(1));
+                        assert!(c.is_null(2));
+                    }
+                }
 
-        // test timestamp microseconds
-        let a =
-            TimestampMicrosecondArray::from(vec![Some(86405000000), Some(1000000), None])
-                .with_timezone("+01:00".to_string());
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
-        assert_eq!(3605000000, c.value(0));
-        assert_eq!(3601000000, c.value(1));
-        assert!(c.is_null(2));
-        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
-        assert_eq!(3605000000000, c.value(0));
-        assert_eq!(3601000000000, c.value(1));
-        assert!(c.is_null(2));
+                let options = CastOptions {
+                    safe: false,
+                    ..DEFAULT_CAST_OPTIONS
+                };
+                let err = cast_with_options(array, &to_type, &options).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "Parser error: Error parsing timestamp from 'Not a valid date': error parsing date"
+                );
+            }
+        }
+    }
 
-        // test timestamp nanoseconds
-        let a = TimestampNanosecondArray::from(vec![
-            Some(86405000000000),
-            Some(1000000000),
+    #[test]
+    fn test_cast_string_to_timestamp_overflow() {
+        let array = StringArray::from(vec!["9800-09-08T12:00:00.123456789"]);
+        let result = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
+        let result = result.as_primitive::<TimestampSecondType>();
+        assert_eq!(result.values(), &[247112596800]);
+    }
+
+    #[test]
+    fn test_cast_string_to_date32() {
+        let a1 = Arc::new(StringArray::from(vec![
+            Some("2018-12-25"),
+            Some("Not a valid date"),
             None,
-        ])
-        .with_timezone("+01:00".to_string());
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
-        assert_eq!(3605000000, c.value(0));
-        assert_eq!(3601000000, c.value(1));
-        assert!(c.is_null(2));
-        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
-        assert_eq!(3605000000000, c.value(0));
-        assert_eq!(3601000000000, c.value(1));
-        assert!(c.is_null(2));
+        ])) as ArrayRef;
+        let a2 = Arc::new(LargeStringArray::from(vec![
+            Some("2018-12-25"),
+            Some("Not a valid date"),
+            None,
+        ])) as ArrayRef;
+        for array in &[a1, a2] {
+            let to_type = DataType::Date32;
+            let b = cast(array, &to_type).unwrap();
+            let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
+            assert_eq!(17890, c.value(0));
+            assert!(c.is_null(1));
+            assert!(c.is_null(2));
 
-        // test overflow
-        let a = TimestampSecondArray::from(vec![Some(i64::MAX)])
-            .with_timezone("+01:00".to_string());
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond));
-        assert!(b.is_err());
-        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond));
-        assert!(b.is_err());
-        let b = cast(&array, &DataType::Time64(TimeUnit::Millisecond));
-        assert!(b.is_err());
+            let options = CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let err = cast_with_options(array, &to_type, &options).unwrap_err();
+            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date32 type");
+        }
     }
 
     #[test]
-    fn test_cast_timestamp_to_time32() {
-        // test timestamp secs
-        let a = TimestampSecondArray::from(vec![Some(86405), Some(1), None])
-            .with_timezone("+01:00".to_string());
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
-        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
-        assert_eq!(3605, c.value(0));
-        assert_eq!(3601, c.value(1));
-        assert!(c.is_null(2));
-        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
-        assert_eq!(3605000, c.value(0));
-        assert_eq!(3601000, c.value(1));
-        assert!(c.is_null(2));
+    fn test_cast_string_to_time32second() {
+        let a1 = Arc::new(StringArray::from(vec![
+            Some("08:08:35.091323414"),
+            Some("08:08:60.091323414"), // leap second
+            Some("08:08:61.091323414"), // not valid
+            Some("Not a valid time"),
+            None,
+        ])) as ArrayRef;
+        let a2 = Arc::new(LargeStringArray::from(vec![
+            Some("08:08:35.091323414"),
+            Some("08:08:60.091323414"), // leap second
+            Some("08:08:61.091323414"), // not valid
+            Some("Not a valid time"),
+            None,
+        ])) as ArrayRef;
+        for array in &[a1, a2] {
+            let to_type = DataType::Time32(TimeUnit::Second);
+            let b = cast(array, &to_type).unwrap();
+            let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+            assert_eq!(29315, c.value(0));
+            assert_eq!(29340, c.value(1));
+            assert!(c.is_null(2));
+            assert!(c.is_null(3));
+            assert!(c.is_null(4));
 
-        // test timestamp milliseconds
-        let a = TimestampMillisecondArray::from(vec![Some(86405000), Some(1000), None])
-            .with_timezone("+01:00".to_string());
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
-        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
-        assert_eq!(3605, c.value(0));
-        assert_eq!(3601, c.value(1));
-        assert!(c.is_null(2));
-        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
-        assert_eq!(3605000, c.value(0));
-        assert_eq!(3601000, c.value(1));
-        assert!(c.is_null(2));
+            let options = CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let err = cast_with_options(array, &to_type, &options).unwrap_err();
+            assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
+        }
+    }
 
-        // test timestamp microseconds
-        let a =
-            TimestampMicrosecondArray::from(vec![Some(86405000000), Some(1000000), None])
-                .with_timezone("+01:00".to_string());
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
-        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
-        assert_eq!(3605, c.value(0));
-        assert_eq!(3601, c.value(1));
-        assert!(c.is_null(2));
-        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
-        assert_eq!(3605000, c.value(0));
-        assert_eq!(3601000, c.value(1));
-        assert!(c.is_null(2));
+    #[test]
+    fn test_cast_string_to_time32millisecond() {
+        let a1 = Arc::new(StringArray::from(vec![
+            Some("08:08:35.091323414"),
+            Some("08:08:60.091323414"), // leap second
+            Some("08:08:61.091323414"), // not valid
+            Some("Not a valid time"),
+            None,
+        ])) as ArrayRef;
+        let a2 = Arc::new(LargeStringArray::from(vec![
+            Some("08:08:35.091323414"),
+            Some("08:08:60.091323414"), // leap second
+            Some("08:08:61.091323414"), // not valid
+            Some("Not a valid time"),
+            None,
+        ])) as ArrayRef;
+        for array in &[a1, a2] {
+            let to_type = DataType::Time32(TimeUnit::Millisecond);
+            let b = cast(array, &to_type).unwrap();
+            let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
+            assert_eq!(29315091, c.value(0));
+            assert_eq!(29340091, c.value(1));
+            assert!(c.is_null(2));
+            assert!(c.is_null(3));
+            assert!(c.is_null(4));
+
+            let options = CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let err = cast_with_options(array, &to_type, &options).unwrap_err();
+            assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_time64microsecond() {
+        let a1 = Arc::new(StringArray::from(vec![
+            Some("08:08:35.091323414"),
+            Some("Not a valid time"),
+            None,
+        ])) as ArrayRef;
+        let a2 = Arc::new(LargeStringArray::from(vec![
+            Some("08:08:35.091323414"),
+            Some("Not a valid time"),
+            None,
+        ])) as ArrayRef;
+        for array in &[a1, a2] {
+            let to_type = DataType::Time64(TimeUnit::Microsecond);
+            let b = cast(array, &to_type).unwrap();
+            let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
+            assert_eq!(29315091323, c.value(0));
+            assert!(c.is_null(1));
+            assert!(c.is_null(2));
+
+            let options = CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let err = cast_with_options(array, &to_type, &options).unwrap_err();
+            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_time64nanosecond() {
+        let a1 = Arc::new(StringArray::from(vec![
+            Some("08:08:35.091323414"),
+            Some("Not a valid time"),
+            None,
+        ])) as ArrayRef;
+        let a2 = Arc::new(LargeStringArray::from(vec![
+            Some("08:08:35.091323414"),
+            Some("Not a valid time"),
+            None,
+        ])) as ArrayRef;
+        for array in &[a1, a2] {
+            let to_type = DataType::Time64(TimeUnit::Nanosecond);
+            let b = cast(array, &to_type).unwrap();
+            let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+            assert_eq!(29315091323414, c.value(0));
+            assert!(c.is_null(1));
+            assert!(c.is_null(2));
+
+            let options = CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let err = cast_with_options(array, &to_type, &options).unwrap_err();
+            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_date64() {
+        let a1 = Arc::new(StringArray::from(vec![
+            Some("2020-09-08T12:00:00"),
+            Some("Not a valid date"),
+            None,
+        ])) as ArrayRef;
+        let a2 = Arc::new(LargeStringArray::from(vec![
+            Some("2020-09-08T12:00:00"),
+            Some("Not a valid date"),
+            None,
+        ])) as ArrayRef;
+        for array in &[a1, a2] {
+            let to_type = DataType::Date64;
+            let b = cast(array, &to_type).unwrap();
+            let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
+            assert_eq!(1599566400000, c.value(0));
+            assert!(c.is_null(1));
+            assert!(c.is_null(2));
+
+            let options = CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let err = cast_with_options(array, &to_type, &options).unwrap_err();
+            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date64 type");
+        }
+    }
+
+    macro_rules! test_safe_string_to_interval {
+        ($data_vec:expr, $interval_unit:expr, $array_ty:ty, $expect_vec:expr) => {
+            let source_string_array =
+                Arc::new(StringArray::from($data_vec.clone())) as ArrayRef;
+
+            let options = CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            };
+
+            let target_interval_array = cast_with_options(
+                &source_string_array.clone(),
+                &DataType::Interval($interval_unit),
+                &options,
+            )
+            .unwrap()
+            .as_any()
+            .downcast_ref::<$array_ty>()
+            .unwrap()
+            .clone() as $array_ty;
+
+            let target_string_array =
+                cast_with_options(&target_interval_array, &DataType::Utf8, &options)
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap()
+                    .clone();
+
+            let expect_string_array = StringArray::from($expect_vec);
+
+            assert_eq!(target_string_array, expect_string_array);
+
+            let target_large_string_array =
+                cast_with_options(&target_interval_array, &DataType::LargeUtf8, &options)
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref::<LargeStringArray>()
+                    .unwrap()
+                    .clone();
+
+            let expect_large_string_array = LargeStringArray::from($expect_vec);
+
+            assert_eq!(target_large_string_array, expect_large_string_array);
+        };
+    }
+
+    #[test]
+    fn test_cast_string_to_interval_year_month() {
+        test_safe_string_to_interval!(
+            vec![
+                Some("1 year 1 month"),
+                Some("1.5 years 13 month"),
+                Some("30 days"),
+                Some("31 days"),
+                Some("2 months 31 days"),
+                Some("2 months 31 days 1 second"),
+                Some("foobar"),
+            ],
+            IntervalUnit::YearMonth,
+            IntervalYearMonthArray,
+            vec![
+                Some("1 years 1 mons 0 days 0 hours 0 mins 0.00 secs"),
+                Some("2 years 7 mons 0 days 0 hours 0 mins 0.00 secs"),
+                None,
+                None,
+                None,
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cast_string_to_interval_day_time() {
+        test_safe_string_to_interval!(
+            vec![
+                Some("1 year 1 month"),
+                Some("1.5 years 13 month"),
+                Some("30 days"),
+                Some("1 day 2 second 3.5 milliseconds"),
+                Some("foobar"),
+            ],
+            IntervalUnit::DayTime,
+            IntervalDayTimeArray,
+            vec![
+                Some("0 years 0 mons 390 days 0 hours 0 mins 0.000 secs"),
+                Some("0 years 0 mons 930 days 0 hours 0 mins 0.000 secs"),
+                Some("0 years 0 mons 30 days 0 hours 0 mins 0.000 secs"),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cast_string_to_interval_month_day_nano() {
+        test_safe_string_to_interval!(
+            vec![
+                Some("1 year 1 month 1 day"),
+                None,
+                Some("1.5 years 13 month 35 days 1.4 milliseconds"),
+                Some("3 days"),
+                Some("8 seconds"),
+                None,
+                Some("1 day 29800 milliseconds"),
+                Some("3 months 1 second"),
+                Some("6 minutes 120 second"),
+                Some("2 years 39 months 9 days 19 hours 1 minute 83 seconds 399222 milliseconds"),
+                Some("foobar"),
+            ],
+            IntervalUnit::MonthDayNano,
+            IntervalMonthDayNanoArray,
+            vec![
+                Some("0 years 13 mons 1 days 0 hours 0 mins 0.000000000 secs"),
+                None,
+                Some("0 years 31 mons 35 days 0 hours 0 mins 0.001400000 secs"),
+                Some("0 years 0 mons 3 days 0 hours 0 mins 0.000000000 secs"),
+                Some("0 years 0 mons 0 days 0 hours 0 mins 8.000000000 secs"),
+                None,
+                Some("0 years 0 mons 1 days 0 hours 0 mins 29.800000000 secs"),
+                Some("0 years 3 mons 0 days 0 hours 0 mins 1.000000000 secs"),
+                Some("0 years 0 mons 0 days 0 hours 8 mins 0.000000000 secs"),
+                Some("0 years 63 mons 9 days 19 hours 9 mins 2.222000000 secs"),
+                None,
+            ]
+        );
+    }
+
+    macro_rules! test_unsafe_string_to_interval_err {
+        ($data_vec:expr, $interval_unit:expr, $error_msg:expr) => {
+            let string_array = Arc::new(StringArray::from($data_vec.clone())) as ArrayRef;
+            let options = CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let arrow_err = cast_with_options(
+                &string_array.clone(),
+                &DataType::Interval($interval_unit),
+                &options,
+            )
+            .unwrap_err();
+            assert_eq!($error_msg, arrow_err.to_string());
+        };
+    }
+
+    #[test]
+    fn test_cast_string_to_interval_err() {
+        test_unsafe_string_to_interval_err!(
+            vec![Some("foobar")],
+            IntervalUnit::YearMonth,
+            r#"Not yet implemented: Unsupported Interval Expression with value "foobar""#
+        );
+        test_unsafe_string_to_interval_err!(
+            vec![Some("foobar")],
+            IntervalUnit::DayTime,
+            r#"Not yet implemented: Unsupported Interval Expression with value "foobar""#
+        );
+        test_unsafe_string_to_interval_err!(
+            vec![Some("foobar")],
+            IntervalUnit::MonthDayNano,
+            r#"Not yet implemented: Unsupported Interval Expression with value "foobar""#
+        );
+        test_unsafe_string_to_interval_err!(
+            vec![Some("2 months 31 days 1 second")],
+            IntervalUnit::YearMonth,
+            r#"Cast error: Cannot cast 2 months 31 days 1 second to IntervalYearMonth. Only year and month fields are allowed."#
+        );
+        test_unsafe_string_to_interval_err!(
+            vec![Some("1 day 1.5 milliseconds")],
+            IntervalUnit::DayTime,
+            r#"Cast error: Cannot cast 1 day 1.5 milliseconds to IntervalDayTime because the nanos part isn't multiple of milliseconds"#
+        );
+
+        // overflow
+        test_unsafe_string_to_interval_err!(
+            vec![Some(format!(
+                "{} century {} year {} month",
+                i64::MAX - 2,
+                i64::MAX - 2,
+                i64::MAX - 2
+            ))],
+            IntervalUnit::DayTime,
+            r#"Parser error: Parsed interval field value out of range: 11068046444225730000000 months 331764692165666300000000 days 28663672503769583000000000000000000000 nanos"#
+        );
+        test_unsafe_string_to_interval_err!(
+            vec![Some(format!(
+                "{} year {} month {} day",
+                i64::MAX - 2,
+                i64::MAX - 2,
+                i64::MAX - 2
+            ))],
+            IntervalUnit::MonthDayNano,
+            r#"Parser error: Parsed interval field value out of range: 110680464442257310000 months 3043712772162076000000 days 262179884170819100000000000000000000 nanos"#
+        );
+    }
+
+    #[test]
+    fn test_cast_binary_to_fixed_size_binary() {
+        let bytes_1 = "Hiiii".as_bytes();
+        let bytes_2 = "Hello".as_bytes();
+
+        let binary_data = vec![Some(bytes_1), Some(bytes_2), None];
+        let a1 = Arc::new(BinaryArray::from(binary_data.clone())) as ArrayRef;
+        let a2 = Arc::new(LargeBinaryArray::from(binary_data)) as ArrayRef;
+
+        let array_ref = cast(&a1, &DataType::FixedSizeBinary(5)).unwrap();
+        let down_cast = array_ref
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        assert_eq!(bytes_1, down_cast.value(0));
+        assert_eq!(bytes_2, down_cast.value(1));
+        assert!(down_cast.is_null(2));
+
+        let array_ref = cast(&a2, &DataType::FixedSizeBinary(5)).unwrap();
+        let down_cast = array_ref
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        assert_eq!(bytes_1, down_cast.value(0));
+        assert_eq!(bytes_2, down_cast.value(1));
+        assert!(down_cast.is_null(2));
+
+        // test error cases when the length of binary are not same
+        let bytes_1 = "Hi".as_bytes();
+        let bytes_2 = "Hello".as_bytes();
+
+        let binary_data = vec![Some(bytes_1), Some(bytes_2), None];
+        let a1 = Arc::new(BinaryArray::from(binary_data.clone())) as ArrayRef;
+        let a2 = Arc::new(LargeBinaryArray::from(binary_data)) as ArrayRef;
+
+        let array_ref = cast_with_options(
+            &a1,
+            &DataType::FixedSizeBinary(5),
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        assert!(array_ref.is_err());
+
+        let array_ref = cast_with_options(
+            &a2,
+            &DataType::FixedSizeBinary(5),
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        assert!(array_ref.is_err());
+    }
+
+    #[test]
+    fn test_fixed_size_binary_to_binary() {
+        let bytes_1 = "Hiiii".as_bytes();
+        let bytes_2 = "Hello".as_bytes();
+
+        let binary_data = vec![Some(bytes_1), Some(bytes_2), None];
+        let a1 = Arc::new(FixedSizeBinaryArray::from(binary_data.clone())) as ArrayRef;
+
+        let array_ref = cast(&a1, &DataType::Binary).unwrap();
+        let down_cast = array_ref.as_binary::<i32>();
+        assert_eq!(bytes_1, down_cast.value(0));
+        assert_eq!(bytes_2, down_cast.value(1));
+        assert!(down_cast.is_null(2));
+
+        let array_ref = cast(&a1, &DataType::LargeBinary).unwrap();
+        let down_cast = array_ref.as_binary::<i64>();
+        assert_eq!(bytes_1, down_cast.value(0));
+        assert_eq!(bytes_2, down_cast.value(1));
+        assert!(down_cast.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_string_to_fixed_size_binary() {
+        let array = StringArray::from(vec![
+            Some("00112233-4455-6677-8899-aabbccddeeff"),
+            Some("00112233445566778899AABBCCDDEEFF"),
+            Some("raw bytes 16 len"),
+            None,
+            Some("00112233"),
+            Some("g0112233445566778899aabbccddeeff"),
+        ]);
+        let to_type = DataType::FixedSizeBinary(16);
+        assert!(can_cast_types(&DataType::Utf8, &to_type));
+        assert!(can_cast_types(&DataType::LargeUtf8, &to_type));
+
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+        let expected: Vec<u8> = (0..16).map(|x| x * 0x11).collect();
+        assert_eq!(b.value(0), expected);
+        assert_eq!(b.value(1), expected);
+        assert_eq!(b.value(2), b"raw bytes 16 len");
+        assert_eq!(b.null_count(), 3);
+
+        let c = cast(b, &DataType::Utf8).unwrap();
+        let c = c.as_string::<i32>();
+        assert_eq!(c.value(0), "00112233445566778899aabbccddeeff");
+        assert_eq!(c.value(2), "726177206279746573203136206c656e");
+        assert_eq!(c.null_count(), 3);
+        let d = cast(c, &to_type).unwrap();
+        assert_eq!(d.as_ref(), b as &dyn Array);
+
+        let array = LargeStringArray::from(vec!["abcd", "0xff"]);
+        let b = cast(&array, &DataType::FixedSizeBinary(2)).unwrap();
+        let b = b.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+        assert_eq!(b.value(0), [0xab, 0xcd]);
+        assert!(b.is_null(1));
+        let c = cast(b, &DataType::LargeUtf8).unwrap();
+        assert_eq!(c.as_string::<i64>().value(0), "abcd");
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &DataType::FixedSizeBinary(2), &options)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '0xff' to FixedSizeBinary(2)"
+        );
+    }
+
+    #[test]
+    fn test_cast_date32_to_int32() {
+        let array = Date32Array::from(vec![10000, 17890]);
+        let b = cast(&array, &DataType::Int32).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(10000, c.value(0));
+        assert_eq!(17890, c.value(1));
+    }
+
+    #[test]
+    fn test_cast_int32_to_date32() {
+        let array = Int32Array::from(vec![10000, 17890]);
+        let b = cast(&array, &DataType::Date32).unwrap();
+        let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(10000, c.value(0));
+        assert_eq!(17890, c.value(1));
+    }
+
+    #[test]
+    fn test_cast_timestamp_timezone_conversion() {
+        // 2023-01-01T08:00:00Z
+        let array =
+            TimestampSecondArray::from(vec![Some(1672560000), Some(i64::MAX), None]);
+        let array = array.with_timezone("+02:00");
+        let to_type = DataType::Timestamp(TimeUnit::Second, Some("-05:00".into()));
+
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        assert_eq!(
+            b.as_primitive::<TimestampSecondType>().values(),
+            array.values()
+        );
+
+        // 2023-01-01T10:00:00-05:00
+        let options =
+            CastOptions::new().with_timezone_conversion(TimezoneConversion::WallClock);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_primitive::<TimestampSecondType>();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            vec![Some(1672585200), None, None]
+        );
+
+        let to_type = DataType::Timestamp(TimeUnit::Millisecond, Some("-03:30".into()));
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<TimestampMillisecondType>();
+        assert_eq!(b.value(0), 1672579800000);
+
+        let options = options.with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 9223372036854775807 of Timestamp(Second, Some(\"+02:00\")) to Timestamp(Second, Some(\"-03:30\")), as its wall clock time cannot be represented in -03:30"
+        );
+    }
+
+    #[test]
+    fn test_cast_timestamp_to_date32() {
+        let array = TimestampMillisecondArray::from(vec![
+            Some(864000000005),
+            Some(1545696000001),
+            None,
+            Some(-1),
+        ])
+        .with_timezone("UTC".to_string());
+        let b = cast(&array, &DataType::Date32).unwrap();
+        let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(10000, c.value(0));
+        assert_eq!(17890, c.value(1));
+        assert!(c.is_null(2));
+        assert_eq!(-1, c.value(3));
+
+        // The date is that of the local time in the timezone
+        let array = TimestampSecondArray::from(vec![Some(82800), Some(-3600)])
+            .with_timezone("+01:00".to_string());
+        let b = cast(&array, &DataType::Date32).unwrap();
+        let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(c.values(), &[1, 0]);
+
+        // The date of the earliest and latest nanosecond timestamps are 1677-09-21 and
+        // 2262-04-11
+        let array = TimestampNanosecondArray::from(vec![
+            Some(i64::MIN),
+            Some(-1),
+            None,
+            Some(86_400_000_000_000),
+            Some(i64::MAX),
+        ]);
+        let b = cast(&array, &DataType::Date32).unwrap();
+        let c = b.as_primitive::<Date32Type>();
+        assert_eq!(c.value(0), -106752);
+        assert_eq!(c.value(1), -1);
+        assert!(c.is_null(2));
+        assert_eq!(c.value(3), 1);
+        assert_eq!(c.value(4), 106751);
+
+        let array = TimestampSecondArray::from(vec![Some(i64::MAX), Some(0)]);
+        let b = cast(&array, &DataType::Date32).unwrap();
+        assert!(b.is_null(0));
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &DataType::Date32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 9223372036854775807 of Timestamp(Second, None) to Date32"
+        );
+    }
+
+    #[test]
+    fn test_cast_timestamp_to_date64() {
+        let array = TimestampMillisecondArray::from(vec![
+            Some(864000000005),
+            Some(1545696000001),
+            None,
+            Some(-1),
+        ]);
+        let b = cast(&array, &DataType::Date64).unwrap();
+        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
+        assert_eq!(864000000000, c.value(0));
+        assert_eq!(1545696000000, c.value(1));
+        assert!(c.is_null(2));
+        assert_eq!(-MILLISECONDS_IN_DAY, c.value(3));
+
+        let array =
+            TimestampSecondArray::from(vec![Some(864000000005), Some(1545696000001)]);
+        let b = cast(&array, &DataType::Date64).unwrap();
+        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
+        assert_eq!(864000000000000, c.value(0));
+        assert_eq!(1545696000000000, c.value(1));
+
+        let array = TimestampNanosecondArray::from(vec![82_800_000_000_000])
+            .with_timezone("+01:00".to_string());
+        let b = cast(&array, &DataType::Date64).unwrap();
+        assert_eq!(b.as_primitive::<Date64Type>().value(0), MILLISECONDS_IN_DAY);
+
+        // test overflow, safe cast
+        let array = TimestampSecondArray::from(vec![Some(i64::MAX)]);
+        let b = cast(&array, &DataType::Date64).unwrap();
+        assert!(b.is_null(0));
+        // test overflow, unsafe cast
+        let array = TimestampSecondArray::from(vec![Some(i64::MAX)]);
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &DataType::Date64, &options);
+        assert!(b.is_err());
+    }
+
+    #[test]
+    fn test_cast_timestamp_to_time64() {
+        // test timestamp secs
+        let array = TimestampSecondArray::from(vec![Some(86405), Some(1), None])
+            .with_timezone("+01:00".to_string());
+        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
+        assert_eq!(3605000000, c.value(0));
+        assert_eq!(3601000000, c.value(1));
+        assert!(c.is_null(2));
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+        assert_eq!(3605000000000, c.value(0));
+        assert_eq!(3601000000000, c.value(1));
+        assert!(c.is_null(2));
+
+        // test timestamp milliseconds
+        let a = TimestampMillisecondArray::from(vec![Some(86405000), Some(1000), None])
+            .with_timezone("+01:00".to_string());
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
+        assert_eq!(3605000000, c.value(0));
+        assert_eq!(3601000000, c.value(1));
+        assert!(c.is_null(2));
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+        assert_eq!(3605000000000, c.value(0));
+        assert_eq!(3601000000000, c.value(1));
+        assert!(c.is_null(2));
+
+        // test timestamp microseconds
+        let a =
+            TimestampMicrosecondArray::from(vec![Some(86405000000), Some(1000000), None])
+                .with_timezone("+01:00".to_string());
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
+        assert_eq!(3605000000, c.value(0));
+        assert_eq!(3601000000, c.value(1));
+        assert!(c.is_null(2));
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+        assert_eq!(3605000000000, c.value(0));
+        assert_eq!(3601000000000, c.value(1));
+        assert!(c.is_null(2));
+
+        // test timestamp nanoseconds
+        let a = TimestampNanosecondArray::from(vec![
+            Some(86405000000000),
+            Some(1000000000),
+            None,
+        ])
+        .with_timezone("+01:00".to_string());
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
+        assert_eq!(3605000000, c.value(0));
+        assert_eq!(3601000000, c.value(1));
+        assert!(c.is_null(2));
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+        assert_eq!(3605000000000, c.value(0));
+        assert_eq!(3601000000000, c.value(1));
+        assert!(c.is_null(2));
+
+        // test overflow
+        let a = TimestampSecondArray::from(vec![Some(i64::MAX)])
+            .with_timezone("+01:00".to_string());
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond));
+        assert!(b.is_err());
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond));
+        assert!(b.is_err());
+        let b = cast(&array, &DataType::Time64(TimeUnit::Millisecond));
+        assert!(b.is_err());
+    }
+
+    #[test]
+    fn test_cast_timestamp_to_time32() {
+        // test timestamp secs
+        let a = TimestampSecondArray::from(vec![Some(86405), Some(1), None])
+            .with_timezone("+01:00".to_string());
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+        assert_eq!(3605, c.value(0));
+        assert_eq!(3601, c.value(1));
+        assert!(c.is_null(2));
+        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
+        assert_eq!(3605000, c.value(0));
+        assert_eq!(3601000, c.value(1));
+        assert!(c.is_null(2));
+
+        // test timestamp milliseconds
+        let a = TimestampMillisecondArray::from(vec![Some(86405000), Some(1000), None])
+            .with_timezone("+01:00".to_string());
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+        assert_eq!(3605, c.value(0));
+        assert_eq!(3601, c.value(1));
+        assert!(c.is_null(2));
+        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
+        assert_eq!(3605000, c.value(0));
+        assert_eq!(3601000, c.value(1));
+        assert!(c.is_null(2));
+
+        // test timestamp microseconds
+        let a =
+            TimestampMicrosecondArray::from(vec![Some(86405000000), Some(1000000), None])
+                .with_timezone("+01:00".to_string());
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+        assert_eq!(3605, c.value(0));
+        assert_eq!(3601, c.value(1));
+        assert!(c.is_null(2));
+        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
+        assert_eq!(3605000, c.value(0));
+        assert_eq!(3601000, c.value(1));
+        assert!(c.is_null(2));
+
+        // test timestamp nanoseconds
+        let a = TimestampNanosecondArray::from(vec![
+            Some(86405000000000),
+            Some(1000000000),
+            None,
+        ])
+        .with_timezone("+01:00".to_string());
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+        assert_eq!(3605, c.value(0));
+        assert_eq!(3601, c.value(1));
+        assert!(c.is_null(2));
+        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
+        assert_eq!(3605000, c.value(0));
+        assert_eq!(3601000, c.value(1));
+        assert!(c.is_null(2));
+
+        // test overflow
+        let a = TimestampSecondArray::from(vec![Some(i64::MAX)])
+            .with_timezone("+01:00".to_string());
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time32(TimeUnit::Second));
+        assert!(b.is_err());
+        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond));
+        assert!(b.is_err());
+    }
+
+    #[test]
+    fn test_cast_timestamp_to_time_offset() {
+        let values = vec![
+            Some(-1_500),
+            Some(0),
+            Some(86_399_999),
+            Some(1_234_567_890_123),
+            None,
+        ];
+        for tz in [None, Some("-05:30"), Some("+14:00")] {
+            let array = TimestampMillisecondArray::from(values.clone())
+                .with_timezone_opt(tz.map(|x| x.to_string()));
+            let parsed: Option<Tz> = tz.map(|x| x.parse().unwrap());
+
+            let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
+            let c = b.as_primitive::<Time64MicrosecondType>();
+            let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
+            let d = b.as_primitive::<Time32SecondType>();
+            for (i, v) in values.iter().enumerate() {
+                let v = match v {
+                    Some(v) => *v,
+                    None => {
+                        assert!(c.is_null(i) && d.is_null(i));
+                        continue;
+                    }
+                };
+                let time =
+                    as_time_res_with_timezone::<TimestampMillisecondType>(v, parsed)
+                        .unwrap();
+                assert_eq!(c.value(i), time_to_time64us(time), "{v} {tz:?}");
+                assert_eq!(d.value(i), time_to_time32s(time), "{v} {tz:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_binary_dictionary() {
+        let values = BinaryArray::from(vec![
+            b"abc".as_ref(),
+            b"\xff\xfe".as_ref(),
+            b"def".as_ref(),
+        ]);
+        let keys = Int8Array::from(vec![Some(0), Some(2), None, Some(0)]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let expected =
+            StringArray::from(vec![Some("abc"), Some("def"), None, Some("abc")]);
+        assert_eq!(b.as_string::<i32>(), &expected);
+
+        let b = cast(&array, &DataType::FixedSizeBinary(3)).unwrap();
+        let b = b.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+        assert_eq!(b.value(1), b"def");
+        assert!(b.is_null(2));
+
+        // The value that is not valid UTF-8 or of size 3 is never referenced
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &DataType::LargeUtf8, &options).unwrap();
+        let expected =
+            LargeStringArray::from(vec![Some("abc"), Some("def"), None, Some("abc")]);
+        assert_eq!(b.as_string::<i64>(), &expected);
+        let b = cast_with_options(&array, &DataType::FixedSizeBinary(3), &options);
+        assert_eq!(b.unwrap().len(), 4);
+
+        let keys = Int8Array::from(vec![Some(0), Some(1)]);
+        let array = DictionaryArray::new(keys, array.values().clone());
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        assert!(b.is_null(1));
+        let err = cast_with_options(&array, &DataType::Utf8, &options).unwrap_err();
+        assert!(
+            err.to_string().contains("Encountered non UTF-8 data"),
+            "{err}"
+        );
+        let err = cast_with_options(&array, &DataType::FixedSizeBinary(3), &options)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Byte slice does not have the same length"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_estimate_distinct() {
+        let array =
+            StringArray::from_iter_values((0..10_000).map(|x| (x % 10).to_string()));
+        assert_eq!(estimate_distinct(&array), 10);
+
+        // All sampled values are unique
+        let array = StringArray::from_iter_values((0..10_000).map(|x| x.to_string()));
+        let estimate = estimate_distinct(&array);
+        assert!((3_000..=10_000).contains(&estimate), "{estimate}");
+
+        let array = StringArray::from(vec![None, Some("a"), None]);
+        assert_eq!(estimate_distinct(&array), 1);
+        assert_eq!(estimate_distinct(&StringArray::from(vec![None::<&str>])), 0);
+    }
+
+    #[test]
+    fn test_cast_utf8_to_dictionary_cardinality_hint() {
+        let array = StringArray::from(vec![Some("a"), None, Some("b"), Some("a")]);
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8));
+        for hint in [None, Some(0), Some(1), Some(100)] {
+            let options = CastOptions {
+                dictionary_cardinality_hint: hint,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let b = cast_with_options(&array, &to_type, &options).unwrap();
+            let b = b.as_dictionary::<Int16Type>();
+            assert_eq!(
+                b.keys(),
+                &Int16Array::from(vec![Some(0), None, Some(1), Some(0)])
+            );
+            assert_eq!(
+                b.values().as_string::<i32>(),
+                &StringArray::from(vec!["a", "b"])
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_utf8_to_large_utf8_dictionary() {
+        let a = "a".repeat(1000);
+        let b = "b".repeat(1000);
+        let array = StringArray::from(vec![
+            Some(a.as_str()),
+            None,
+            Some(b.as_str()),
+            Some(a.as_str()),
+            Some(b.as_str()),
+            Some(""),
+        ]);
+        let array = array.slice(1, 5);
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::LargeUtf8));
+        let d = cast(&array, &to_type).unwrap();
+        assert_eq!(d.data_type(), &to_type);
+        let d = d.as_dictionary::<Int8Type>();
+        assert_eq!(
+            d.keys(),
+            &Int8Array::from(vec![None, Some(0), Some(1), Some(0), Some(2)])
+        );
+        let values = d.values().as_string::<i64>();
+        assert_eq!(values, &LargeStringArray::from(vec![b.as_str(), &a, ""]));
+        // Each distinct value is copied once
+        assert_eq!(values.value_data().len(), 2000);
+
+        let array = BinaryArray::from_iter_values([b"x".as_slice(), b"y", b"x"]);
+        let to_type = DataType::Dictionary(
+            Box::new(DataType::Int16),
+            Box::new(DataType::LargeBinary),
+        );
+        let d = cast(&array, &to_type).unwrap();
+        let d = d.as_dictionary::<Int16Type>();
+        assert_eq!(d.keys(), &Int16Array::from(vec![0, 1, 0]));
+        assert_eq!(d.values().as_binary::<i64>().value(1), b"y");
+
+        let array = StringArray::from_iter_values((0..200).map(|i| format!("val{i}")));
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let err = cast(&array, &to_type).unwrap_err();
+        assert_eq!(err.to_string(), "Dictionary key bigger than the key type");
+    }
+
+    #[test]
+    fn test_cast_null_fill() {
+        let fill = NullFill::try_new(Arc::new(StringArray::from(vec!["-1"]))).unwrap();
+        let options = CastOptions::new().with_null_fill(Some(fill));
+
+        let array = StringArray::from(vec![Some("1"), None, Some("x"), Some("4")]);
+        let b = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+        assert_eq!(
+            b.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![1, -1, -1, 4])
+        );
+
+        // Types with parameters are kept
+        let to_type = DataType::Decimal128(10, 2);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_primitive::<Decimal128Type>();
+        assert_eq!(b.values(), &[100, -100, -100, 400]);
+
+        // Arrays of the target type are filled
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let expected = StringArray::from(vec!["1", "-1", "x", "4"]);
+        assert_eq!(b.as_string::<i32>(), &expected);
+        let b = cast_with_options(&array.slice(1, 2), &DataType::LargeUtf8, &options)
+            .unwrap();
+        let expected = LargeStringArray::from(vec!["-1", "x"]);
+        assert_eq!(b.as_string::<i64>(), &expected);
+
+        // Only the rows of nested types are filled
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+        ]);
+        let fill =
+            ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(0)])]);
+        let options = CastOptions::new()
+            .with_null_fill(Some(NullFill::try_new(Arc::new(fill)).unwrap()));
+        let to_type = DataType::List(Arc::new(Field::new("item", DataType::Int64, true)));
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_list::<i32>();
+        assert_eq!(b.null_count(), 0);
+        assert_eq!(b.value(0).null_count(), 1);
+        assert_eq!(b.value(1).as_primitive::<Int64Type>().values(), &[0]);
+
+        let fill = NullFill::try_new(Arc::new(StringArray::from(vec!["x"]))).unwrap();
+        let options = CastOptions::new().with_null_fill(Some(fill));
+        let array = StringArray::from(vec![Some("1"), None]);
+        let err = cast_with_options(&array, &DataType::Int32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string 'x' to value of Int32 type"
+        );
+
+        let err = NullFill::try_new(Arc::new(Int32Array::from(vec![None]))).unwrap_err();
+        assert!(
+            err.to_string().contains("exactly one non-null element"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_cast_all_null() {
+        let array = StringArray::from(vec![None::<&str>; 3]);
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+
+        let types = [
+            DataType::Int32,
+            DataType::Decimal128(10, 2),
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".into())),
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+            DataType::List(Arc::new(Field::new("item", DataType::Float64, true))),
+        ];
+        for to_type in types {
+            let b = cast_with_options(&array, &to_type, &options).unwrap();
+            assert_eq!(b.data_type(), &to_type);
+            assert_eq!(b.len(), 3);
+            assert_eq!(b.null_count(), 3);
+        }
+
+        // Errors independent of the values are still reported
+        let err = cast(&array, &DataType::Decimal128(39, 2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: precision 39 is greater than max 38"
+        );
+        let err = cast(&array, &DataType::Struct(Fields::empty())).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast to struct from other types"
+        );
+
+        // Nested types are validated as if some values were not null
+        let source = Field::new("a", DataType::Int32, true);
+        let to_type = DataType::Struct(Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let mostly_null = StructArray::from(vec![(
+            Arc::new(source),
+            Arc::new(Int32Array::from(vec![Some(1), None, None])) as ArrayRef,
+        )]);
+        let mostly_null = mostly_null
+            .into_data()
+            .into_builder()
+            .null_bit_buffer(Some(Buffer::from([0b001])))
+            .build()
+            .unwrap();
+        let all_null = mostly_null
+            .clone()
+            .into_builder()
+            .null_bit_buffer(Some(Buffer::from([0b000])))
+            .build()
+            .unwrap();
+        for data in [mostly_null, all_null] {
+            let array = make_array(data);
+            let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("field 'b' is not present in the source"),
+                "{err}"
+            );
+            let b = cast(&array, &to_type).unwrap();
+            assert_eq!(b.null_count(), array.null_count());
+        }
+    }
+
+    #[test]
+    fn test_cast_uint64_decimal256_full_range() {
+        let array = UInt64Array::from(vec![Some(u64::MAX), Some(0), None]);
+        let to_type = DataType::Decimal256(40, 10);
+        let b = cast(&array, &to_type).unwrap();
+        let decimals = b.as_primitive::<Decimal256Type>();
+        let expected = i256::from_i128(u64::MAX as i128 * 10_i128.pow(10));
+        assert_eq!(decimals.value(0), expected);
+        assert_eq!(
+            decimals.value_as_string(0),
+            "18446744073709551615.0000000000"
+        );
+        assert_eq!(decimals.value(1), i256::ZERO);
+        assert!(decimals.is_null(2));
+
+        let c = cast(&b, &DataType::UInt64).unwrap();
+        assert_eq!(c.as_primitive::<UInt64Type>(), &array);
+
+        // Values outside the range of UInt64
+        let decimals = create_decimal256_array(
+            vec![
+                Some(expected.wrapping_add(i256::from_i128(10_i128.pow(10)))),
+                Some(i256::from_i128(-10_i128.pow(10))),
+                Some(i256::from_i128(-1)),
+            ],
+            40,
+            10,
+        )
+        .unwrap();
+        let c = cast(&decimals, &DataType::UInt64).unwrap();
+        let c = c.as_primitive::<UInt64Type>();
+        assert!(c.is_null(0));
+        assert!(c.is_null(1));
+        // Truncated towards zero
+        assert_eq!(c.value(2), 0);
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err = cast_with_options(&decimals, &DataType::UInt64, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: value of 18446744073709551616 is out of range UInt64"
+        );
+    }
+
+    #[test]
+    fn test_cast_numeric_to_interval() {
+        let to_type = DataType::Interval(IntervalUnit::MonthDayNano);
+        let nanos = |n| IntervalMonthDayNanoType::make_value(0, 0, n);
+
+        let array =
+            Float64Array::from(vec![Some(1.5), Some(-0.000000001), Some(1e20), None]);
+        let b = cast(&array, &to_type).unwrap();
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            Some(nanos(1_500_000_000)),
+            Some(nanos(-1)),
+            None,
+            None,
+        ]);
+        assert_eq!(b.as_primitive::<IntervalMonthDayNanoType>(), &expected);
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 1e20 to Interval(MonthDayNano)"
+        );
+
+        let array = Float32Array::from(vec![0.25]);
+        let options = CastOptions {
+            numeric_interval_unit: NumericIntervalUnit::Days,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<IntervalMonthDayNanoType>();
+        assert_eq!(b.value(0), nanos(6 * 3600 * 1_000_000_000));
+
+        let array =
+            create_decimal_array(vec![Some(1_234_567_891_234), Some(-5), None], 38, 12)
+                .unwrap();
+        let b = cast(&array, &to_type).unwrap();
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            Some(nanos(1_234_567_891)),
+            Some(nanos(0)),
+            None,
+        ]);
+        assert_eq!(b.as_primitive::<IntervalMonthDayNanoType>(), &expected);
+
+        let array =
+            create_decimal256_array(vec![Some(i256::from_i128(-15))], 10, -1).unwrap();
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<IntervalMonthDayNanoType>();
+        assert_eq!(b.value(0), nanos(-150 * 86400 * 1_000_000_000));
+    }
+
+    #[test]
+    fn test_cast_int64_and_interval() {
+        let to_type = DataType::Interval(IntervalUnit::MonthDayNano);
+        let make = IntervalMonthDayNanoType::make_value;
+        let ticks = CastOptions::new()
+            .with_numeric_interval_unit(NumericIntervalUnit::Nanoseconds);
+
+        let array = Int64Array::from(vec![Some(1_500), Some(-1), None, Some(i64::MAX)]);
+        let b = cast_with_options(&array, &to_type, &ticks).unwrap();
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            Some(make(0, 0, 1_500)),
+            Some(make(0, 0, -1)),
+            None,
+            Some(make(0, 0, i64::MAX)),
+        ]);
+        assert_eq!(b.as_primitive::<IntervalMonthDayNanoType>(), &expected);
+        let c = cast_with_options(&b, &DataType::Int64, &ticks).unwrap();
+        assert_eq!(c.as_primitive::<Int64Type>(), &array);
+
+        // Seconds overflow the nanoseconds of the interval
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_primitive::<IntervalMonthDayNanoType>();
+        assert_eq!(b.value(0), make(0, 0, 1_500_000_000_000));
+        assert!(b.is_null(3));
+
+        let months =
+            CastOptions::new().with_numeric_interval_unit(NumericIntervalUnit::Months);
+        let array = Int64Array::from(vec![14, -3, 1 << 40]);
+        let b = cast_with_options(&array, &to_type, &months).unwrap();
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            Some(make(14, 0, 0)),
+            Some(make(-3, 0, 0)),
+            None,
+        ]);
+        assert_eq!(b.as_primitive::<IntervalMonthDayNanoType>(), &expected);
+
+        let intervals = IntervalMonthDayNanoArray::from(vec![
+            Some(make(0, 0, -2_500_000_000)),
+            Some(make(2, 0, 0)),
+            Some(make(0, 1, 0)),
+            None,
+        ]);
+        let b = cast(&intervals, &DataType::Int64).unwrap();
+        let expected = Int64Array::from(vec![Some(-2), None, None, None]);
+        assert_eq!(b.as_primitive::<Int64Type>(), &expected);
+
+        let b = cast(&intervals, &DataType::Float64).unwrap();
+        let expected = Float64Array::from(vec![Some(-2.5), None, None, None]);
+        assert_eq!(b.as_primitive::<Float64Type>(), &expected);
+
+        let b = cast_with_options(&intervals, &DataType::Int64, &months).unwrap();
+        let expected = Int64Array::from(vec![None, Some(2), None, None]);
+        assert_eq!(b.as_primitive::<Int64Type>(), &expected);
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&intervals, &DataType::Int64, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast interval of 2 months, 0 days and 0 nanoseconds to \
+            Int64 in Seconds"
+        );
+    }
+
+    #[test]
+    fn test_cast_date64_to_timestamp() {
+        let array =
+            Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
+        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+        assert_eq!(864000000, c.value(0));
+        assert_eq!(1545696000, c.value(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_date64_to_timestamp_ms() {
+        let array =
+            Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Millisecond, None)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(864000000005, c.value(0));
+        assert_eq!(1545696000001, c.value(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_date64_to_timestamp_us() {
+        let array =
+            Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Microsecond, None)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        assert_eq!(864000000005000, c.value(0));
+        assert_eq!(1545696000001000, c.value(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_date64_to_timestamp_ns() {
+        let array =
+            Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Nanosecond, None)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(864000000005000000, c.value(0));
+        assert_eq!(1545696000001000000, c.value(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_timestamp_to_i64() {
+        let array = TimestampMillisecondArray::from(vec![
+            Some(864000000005),
+            Some(1545696000001),
+            None,
+        ])
+        .with_timezone("UTC".to_string());
+        let b = cast(&array, &DataType::Int64).unwrap();
+        let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(&DataType::Int64, c.data_type());
+        assert_eq!(864000000005, c.value(0));
+        assert_eq!(1545696000001, c.value(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_date32_to_string() {
+        let array = Date32Array::from(vec![10000, 17890]);
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(&DataType::Utf8, c.data_type());
+        assert_eq!("1997-05-19", c.value(0));
+        assert_eq!("2018-12-25", c.value(1));
+    }
+
+    #[test]
+    fn test_cast_date64_to_string() {
+        let array = Date64Array::from(vec![10000 * 86400000, 17890 * 86400000]);
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(&DataType::Utf8, c.data_type());
+        assert_eq!("1997-05-19T00:00:00", c.value(0));
+        assert_eq!("2018-12-25T00:00:00", c.value(1));
+    }
+
+    #[test]
+    fn test_cast_between_timestamps() {
+        let array = TimestampMillisecondArray::from(vec![
+            Some(864000003005),
+            Some(1545696002001),
+            None,
+        ]);
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
+        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+        assert_eq!(864000003, c.value(0));
+        assert_eq!(1545696002, c.value(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_duration_to_i64() {
+        let base = vec![5, 6, 7, 8, 100000000];
+
+        let duration_arrays = vec![
+            Arc::new(DurationNanosecondArray::from(base.clone())) as ArrayRef,
+            Arc::new(DurationMicrosecondArray::from(base.clone())) as ArrayRef,
+            Arc::new(DurationMillisecondArray::from(base.clone())) as ArrayRef,
+            Arc::new(DurationSecondArray::from(base.clone())) as ArrayRef,
+        ];
+
+        for arr in duration_arrays {
+            assert!(can_cast_types(arr.data_type(), &DataType::Int64));
+            let result = cast(&arr, &DataType::Int64).unwrap();
+            let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
+            assert_eq!(base.as_slice(), result.values());
+        }
+    }
+
+    #[test]
+    fn test_cast_interval_to_i64() {
+        let base = vec![5, 6, 7, 8];
+
+        let interval_arrays = vec![
+            Arc::new(IntervalDayTimeArray::from(base.clone())) as ArrayRef,
+            Arc::new(IntervalYearMonthArray::from(
+                base.iter().map(|x| *x as i32).collect::<Vec<i32>>(),
+            )) as ArrayRef,
+        ];
+
+        for arr in interval_arrays {
+            assert!(can_cast_types(arr.data_type(), &DataType::Int64));
+            let result = cast(&arr, &DataType::Int64).unwrap();
+            let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
+            assert_eq!(base.as_slice(), result.values());
+        }
+    }
+
+    #[test]
+    fn test_cast_day_time_interval_millis() {
+        let options = DEFAULT_CAST_OPTIONS;
+        let to_type = DataType::Interval(IntervalUnit::DayTime);
+        let day = MILLISECONDS_IN_DAY;
+        let array = Int64Array::from(vec![
+            Some(0),
+            Some(day + 1500),
+            Some(-day - 1),
+            Some(-1),
+            None,
+            Some(i64::MAX),
+        ]);
+
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<IntervalDayTimeType>();
+        let parts: Vec<_> = b
+            .iter()
+            .map(|v| v.map(IntervalDayTimeType::to_parts))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                Some((0, 0)),
+                Some((1, 1500)),
+                Some((-1, -1)),
+                Some((0, -1)),
+                None,
+                None
+            ]
+        );
+
+        let c = cast_with_options(&b, &DataType::Int64, &options).unwrap();
+        let c = c.as_primitive::<Int64Type>();
+        assert_eq!(c.slice(0, 5), array.slice(0, 5));
+
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 9223372036854775807 milliseconds to Interval(DayTime) as the days overflow"
+        );
+
+        // Days and milliseconds that do not share a sign
+        let array =
+            IntervalDayTimeArray::from(vec![IntervalDayTimeType::make_value(2, -1000)]);
+        let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        assert_eq!(b.as_primitive::<Int64Type>().value(0), 2 * day - 1000);
+    }
+
+    #[test]
+    fn test_cast_reinterpret() {
+        let options = CastOptions::new().with_reinterpret(true);
+        let interval = IntervalDayTimeType::make_value(1, 1500);
+        let array = IntervalDayTimeArray::from(vec![Some(interval), None]);
+
+        let b = cast(&array, &DataType::Int64).unwrap();
+        let b = b.as_primitive::<Int64Type>();
+        assert_eq!(
+            b,
+            &Int64Array::from(vec![Some(MILLISECONDS_IN_DAY + 1500), None])
+        );
+
+        // The days are the upper and the milliseconds the lower 32 bits
+        let c = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        let c = c.as_primitive::<Int64Type>();
+        assert_eq!(c, &Int64Array::from(vec![Some((1 << 32) + 1500), None]));
+
+        let to_type = DataType::Interval(IntervalUnit::DayTime);
+        let d = cast_with_options(c, &to_type, &options).unwrap();
+        assert_eq!(d.as_primitive::<IntervalDayTimeType>(), &array);
+        let d = cast(c, &to_type).unwrap();
+        let d = d.as_primitive::<IntervalDayTimeType>();
+        assert_eq!(IntervalDayTimeType::to_parts(d.value(0)), (49, 61_368_796));
+    }
+
+    #[test]
+    fn test_cast_string_to_timestamp_with_formats() {
+        let array = StringArray::from(vec![
+            Some("03/25/2023 14:00"),
+            Some("2023-03-25"),
+            Some("25 Mar 2023 14:00:00 +0200"),
+            Some("2023-03-25T14:00:00Z"),
+            None,
+        ]);
+        let formats = ["%m/%d/%Y %H:%M", "%Y-%m-%d", "%d %b %Y %H:%M:%S %z"];
+        let options = CastOptions::new().with_timestamp_formats(&formats);
+
+        let to_type = DataType::Timestamp(TimeUnit::Second, None);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let expected = TimestampSecondArray::from(vec![
+            Some(1679752800),
+            Some(1679702400),
+            Some(1679745600),
+            None,
+            None,
+        ]);
+        assert_eq!(b.as_primitive::<TimestampSecondType>(), &expected);
+
+        // Strings without an offset are in the timezone of the target
+        let to_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into()));
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<TimestampMillisecondType>();
+        assert_eq!(b.value(0), 1679749200000);
+        assert_eq!(b.value(2), 1679745600000);
+
+        let options = options.with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot parse string '2023-03-25T14:00:00Z' as a timestamp with the formats [\"%m/%d/%Y %H:%M\", \"%Y-%m-%d\", \"%d %b %Y %H:%M:%S %z\"]"
+        );
+    }
+
+    #[test]
+    fn test_cast_decimal128_unscaled_int64() {
+        let options = CastOptions::new().with_reinterpret(true);
+        let array = Decimal128Array::from(vec![Some(123), Some(-999_999), None])
+            .with_precision_and_scale(18, 2)
+            .unwrap();
+
+        // 1.23 is rounded to 1 without reinterpret
+        let b = cast(&array, &DataType::Int64).unwrap();
+        assert_eq!(b.as_primitive::<Int64Type>().value(0), 1);
+
+        let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        let b = b.as_primitive::<Int64Type>();
+        assert_eq!(b, &Int64Array::from(vec![Some(123), Some(-999_999), None]));
+
+        let c = cast_with_options(b, array.data_type(), &options).unwrap();
+        assert_eq!(c.as_primitive::<Decimal128Type>(), &array);
+
+        // Values exceeding the precision of the target
+        let to_type = DataType::Decimal128(4, 2);
+        let c = cast_with_options(b, &to_type, &options).unwrap();
+        let c = c.as_primitive::<Decimal128Type>();
+        assert_eq!(c.iter().collect::<Vec<_>>(), vec![Some(123), None, None]);
+        assert_eq!(c.data_type(), &to_type);
+
+        let options = options.with_safe(false);
+        let err = cast_with_options(b, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: -999999 is too small to store in a Decimal128 of precision 4. Min is -9999"
+        );
+
+        // Values out of the range of Int64
+        let array = Decimal128Array::from(vec![Some(1), Some(i64::MAX as i128 + 1)])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        let err = cast_with_options(&array, &DataType::Int64, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast unscaled value 9223372036854775808 of Decimal128(38, 0) to Int64"
+        );
+        let options = options.with_safe(true);
+        let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        let b = b.as_primitive::<Int64Type>();
+        assert_eq!(b, &Int64Array::from(vec![Some(1), None]));
+    }
+
+    #[test]
+    fn test_cast_integer_to_time_out_of_range() {
+        let array =
+            Int32Array::from(vec![Some(0), Some(86_399), Some(86_400), Some(-1), None]);
+        let to_type = DataType::Time32(TimeUnit::Second);
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_primitive::<Time32SecondType>();
+        let expected =
+            Time32SecondArray::from(vec![Some(0), Some(86_399), None, None, None]);
+        assert_eq!(b, &expected);
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 86400 to Time32(Second), as it is not a time of day"
+        );
+
+        let options = options.with_reinterpret(true);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(
+            b.as_primitive::<Time32SecondType>().values(),
+            array.values()
+        );
+
+        let array = Int64Array::from(vec![NANOSECONDS_IN_DAY - 1, NANOSECONDS_IN_DAY]);
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        assert!(b.is_valid(0));
+        assert!(b.is_null(1));
+
+        let array = Int32Array::from(vec![MILLISECONDS_IN_DAY as i32]);
+        let to_type = DataType::Time32(TimeUnit::Millisecond);
+        assert_eq!(cast(&array, &to_type).unwrap().null_count(), 1);
+        let array = Int64Array::from(vec![-MICROSECONDS_IN_DAY]);
+        let to_type = DataType::Time64(TimeUnit::Microsecond);
+        assert_eq!(cast(&array, &to_type).unwrap().null_count(), 1);
+    }
+
+    #[test]
+    fn test_cast_duration_timestamp_unsupported() {
+        for unit in [
+            TimeUnit::Second,
+            TimeUnit::Millisecond,
+            TimeUnit::Microsecond,
+            TimeUnit::Nanosecond,
+        ] {
+            let duration = DataType::Duration(unit.clone());
+            for timestamp in [
+                DataType::Timestamp(unit.clone(), None),
+                DataType::Timestamp(unit.clone(), Some("+00:00".into())),
+            ] {
+                assert!(!can_cast_types(&duration, &timestamp));
+                assert!(!can_cast_types(&timestamp, &duration));
+            }
+        }
+
+        let array = DurationSecondArray::from(vec![1]);
+        let err = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Casting from Duration(Second) to Timestamp(Second, None) not supported"
+        );
+
+        let to_type = DataType::Int32;
+        assert!(!can_cast_types(
+            &DataType::Interval(IntervalUnit::MonthDayNano),
+            &to_type
+        ));
+    }
+
+    #[test]
+    fn test_cast_to_strings() {
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let out = cast(&a, &DataType::Utf8).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(out, vec![Some("1"), Some("2"), Some("3")]);
+        let out = cast(&a, &DataType::LargeUtf8).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<LargeStringArray>()
+            .unwrap()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(out, vec![Some("1"), Some("2"), Some("3")]);
+    }
+
+    #[test]
+    fn test_str_to_str_casts() {
+        for data in vec![
+            vec![Some("foo"), Some("bar"), Some("ham")],
+            vec![Some("foo"), None, Some("bar")],
+        ] {
+            let a = LargeStringArray::from(data.clone());
+            let to = cast(&a, &DataType::Utf8).unwrap();
+            let expect = a
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>();
+            let out = to
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>();
+            assert_eq!(expect, out);
+
+            let a = StringArray::from(data);
+            let to = cast(&a, &DataType::LargeUtf8).unwrap();
+            let expect = a
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>();
+            let out = to
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>();
+            assert_eq!(expect, out);
+        }
+    }
+
+    #[test]
+    fn test_cast_from_f64() {
+        let f64_values: Vec<f64> = vec![
+            i64::MIN as f64,
+            i32::MIN as f64,
+            i16::MIN as f64,
+            i8::MIN as f64,
+            0_f64,
+            u8::MAX as f64,
+            u16::MAX as f64,
+            u32::MAX as f64,
+            u64::MAX as f64,
+        ];
+        let f64_array: ArrayRef = Arc::new(Float64Array::from(f64_values));
+
+        let f64_expected = vec![
+            -9223372036854776000.0,
+            -2147483648.0,
+            -32768.0,
+            -128.0,
+            0.0,
+            255.0,
+            65535.0,
+            4294967295.0,
+            18446744073709552000.0,
+        ];
+        assert_eq!(
+            f64_expected,
+            get_cast_values::<Float64Type>(&f64_array, &DataType::Float64)
+                .iter()
+                .map(|i| i.parse::<f64>().unwrap())
+                .collect::<Vec<f64>>()
+        );
+
+        let f32_expected = vec![
+            -9223372000000000000.0,
+            -2147483600.0,
+            -32768.0,
+            -128.0,
+            0.0,
+            255.0,
+            65535.0,
+            4294967300.0,
+            18446744000000000000.0,
+        ];
+        assert_eq!(
+            f32_expected,
+            get_cast_values::<Float32Type>(&f64_array, &DataType::Float32)
+                .iter()
+                .map(|i| i.parse::<f32>().unwrap())
+                .collect::<Vec<f32>>()
+        );
+
+        let i64_expected = vec![
+            "-9223372036854775808",
+            "-2147483648",
+            "-32768",
+            "-128",
+            "0",
+            "255",
+            "65535",
+            "4294967295",
+            "null",
+        ];
+        assert_eq!(
+            i64_expected,
+            get_cast_values::<Int64Type>(&f64_array, &DataType::Int64)
+        );
+
+        let i32_expected = vec![
+            "null",
+            "-2147483648",
+            "-32768",
+            "-128",
+            "0",
+            "255",
+            "65535",
+            "null",
+            "null",
+        ];
+        assert_eq!(
+            i32_expected,
+            get_cast_values::<Int32Type>(&f64_array, &DataType::Int32)
+        );
+
+        let i16_expected = vec![
+            "null", "null", "-32768", "-128", "0", "255", "null", "null", "null",
+        ];
+        assert_eq!(
+            i16_expected,
+            get_cast_values::<Int16Type>(&f64_array, &DataType::Int16)
+        );
+
+        let i8_expected = vec![
+            "null", "null", "null", "-128", "0", "null", "null", "null", "null",
+        ];
+        assert_eq!(
+            i8_expected,
+            get_cast_values::<Int8Type>(&f64_array, &DataType::Int8)
+        );
+
+        let u64_expected = vec![
+            "null",
+            "null",
+            "null",
+            "null",
+            "0",
+            "255",
+            "65535",
+            "4294967295",
+            "null",
+        ];
+        assert_eq!(
+            u64_expected,
+            get_cast_values::<UInt64Type>(&f64_array, &DataType::UInt64)
+        );
+
+        let u32_expected = vec![
+            "null",
+            "null",
+            "null",
+            "null",
+            "0",
+            "255",
+            "65535",
+            "4294967295",
+            "null",
+        ];
+        assert_eq!(
+            u32_expected,
+            get_cast_values::<UInt32Type>(&f64_array, &DataType::UInt32)
+        );
+
+        let u16_expected = vec![
+            "null", "null", "null", "null", "0", "255", "65535", "null", "null",
+        ];
+        assert_eq!(
+            u16_expected,
+            get_cast_values::<UInt16Type>(&f64_array, &DataType::UInt16)
+        );
+
+        let u8_expected = vec![
+            "null", "null", "null", "null", "0", "255", "null", "null", "null",
+        ];
+        assert_eq!(
+            u8_expected,
+            get_cast_values::<UInt8Type>(&f64_array, &DataType::UInt8)
+        );
+    }
+
+    #[test]
+    fn test_cast_from_f32() {
+        let f32_values: Vec<f32> = vec![
+            i32::MIN as f32,
+            i32::MIN as f32,
+            i16::MIN as f32,
+            i8::MIN as f32,
+            0_f32,
+            u8::MAX as f32,
+            u16::MAX as f32,
+            u32::MAX as f32,
+            u32::MAX as f32,
+        ];
+        let f32_array: ArrayRef = Arc::new(Float32Array::from(f32_values));
+
+        let f64_expected = vec![
+            "-2147483648.0",
+            "-2147483648.0",
+            "-32768.0",
+            "-128.0",
+            "0.0",
+            "255.0",
+            "65535.0",
+            "4294967296.0",
+            "4294967296.0",
+        ];
+        assert_eq!(
+            f64_expected,
+            get_cast_values::<Float64Type>(&f32_array, &DataType::Float64)
+        );
+
+        let f32_expected = vec![
+            "-2147483600.0",
+            "-2147483600.0",
+            "-32768.0",
+            "-128.0",
+            "0.0",
+            "255.0",
+            "65535.0",
+            "4294967300.0",
+            "4294967300.0",
+        ];
+        assert_eq!(
+            f32_expected,
+            get_cast_values::<Float32Type>(&f32_array, &DataType::Float32)
+        );
+
+        let i64_expected = vec![
+            "-2147483648",
+            "-2147483648",
+            "-32768",
+            "-128",
+            "0",
+            "255",
+            "65535",
+            "4294967296",
+            "4294967296",
+        ];
+        assert_eq!(
+            i64_expected,
+            get_cast_values::<Int64Type>(&f32_array, &DataType::Int64)
+        );
+
+        let i32_expected = vec![
+            "-2147483648",
+            "-2147483648",
+            "-32768",
+            "-128",
+            "0",
+            "255",
+            "65535",
+            "null",
+            "null",
+        ];
+        assert_eq!(
+            i32_expected,
+            get_cast_values::<Int32Type>(&f32_array, &DataType::Int32)
+        );
+
+        let i16_expected = vec![
+            "null", "null", "-32768", "-128", "0", "255", "null", "null", "null",
+        ];
+        assert_eq!(
+            i16_expected,
+            get_cast_values::<Int16Type>(&f32_array, &DataType::Int16)
+        );
+
+        let i8_expected = vec![
+            "null", "null", "null", "-128", "0", "null", "null", "null", "null",
+        ];
+        assert_eq!(
+            i8_expected,
+            get_cast_values::<Int8Type>(&f32_array, &DataType::Int8)
+        );
+
+        let u64_expected = vec![
+            "null",
+            "null",
+            "null",
+            "null",
+            "0",
+            "255",
+            "65535",
+            "4294967296",
+            "4294967296",
+        ];
+        assert_eq!(
+            u64_expected,
+            get_cast_values::<UInt64Type>(&f32_array, &DataType::UInt64)
+        );
+
+        let u32_expected = vec![
+            "null", "null", "null", "null", "0", "255", "65535", "null", "null",
+        ];
+        assert_eq!(
+            u32_expected,
+            get_cast_values::<UInt32Type>(&f32_array, &DataType::UInt32)
+        );
+
+        let u16_expected = vec![
+            "null", "null", "null", "null", "0", "255", "65535", "null", "null",
+        ];
+        assert_eq!(
+            u16_expected,
+            get_cast_values::<UInt16Type>(&f32_array, &DataType::UInt16)
+        );
+
+        let u8_expected = vec![
+            "null", "null", "null", "null", "0", "255", "null", "null", "null",
+        ];
+        assert_eq!(
+            u8_expected,
+            get_cast_values::<UInt8Type>(&f32_array, &DataType::UInt8)
+        );
+    }
+
+    #[test]
+    fn test_cast_from_uint64() {
+        let u64_values: Vec<u64> = vec![
+            0,
+            u8::MAX as u64,
+            u16::MAX as u64,
+            u32::MAX as u64,
+            u64::MAX,
+        ];
+        let u64_array: ArrayRef = Arc::new(UInt64Array::from(u64_values));
+
+        let f64_expected =
+            vec![0.0, 255.0, 65535.0, 4294967295.0, 18446744073709552000.0];
+        assert_eq!(
+            f64_expected,
+            get_cast_values::<Float64Type>(&u64_array, &DataType::Float64)
+                .iter()
+                .map(|i| i.parse::<f64>().unwrap())
+                .collect::<Vec<f64>>()
+        );
+
+        let f32_expected =
+            vec![0.0, 255.0, 65535.0, 4294967300.0, 18446744000000000000.0];
+        assert_eq!(
+            f32_expected,
+            get_cast_values::<Float32Type>(&u64_array, &DataType::Float32)
+                .iter()
+                .map(|i| i.parse::<f32>().unwrap())
+                .collect::<Vec<f32>>()
+        );
+
+        let i64_expected = vec!["0", "255", "65535", "4294967295", "null"];
+        assert_eq!(
+            i64_expected,
+            get_cast_values::<Int64Type>(&u64_array, &DataType::Int64)
+        );
+
+        let i32_expected = vec!["0", "255", "65535", "null", "null"];
+        assert_eq!(
+            i32_expected,
+            get_cast_values::<Int32Type>(&u64_array, &DataType::Int32)
+        );
+
+        let i16_expected = vec!["0", "255", "null", "null", "null"];
+        assert_eq!(
+            i16_expected,
+            get_cast_values::<Int16Type>(&u64_array, &DataType::Int16)
+        );
+
+        let i8_expected = vec!["0", "null", "null", "null", "null"];
+        assert_eq!(
+            i8_expected,
+            get_cast_values::<Int8Type>(&u64_array, &DataType::Int8)
+        );
+
+        let u64_expected =
+            vec!["0", "255", "65535", "4294967295", "18446744073709551615"];
+        assert_eq!(
+            u64_expected,
+            get_cast_values::<UInt64Type>(&u64_array, &DataType::UInt64)
+        );
 
-        // test timestamp nanoseconds
-        let a = TimestampNanosecondArray::from(vec![
-            Some(86405000000000),
-            Some(1000000000),
-            None,
-        ])
-        .with_timezone("+01:00".to_string());
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
-        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
-        assert_eq!(3605, c.value(0));
-        assert_eq!(3601, c.value(1));
-        assert!(c.is_null(2));
-        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
-        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
-        assert_eq!(3605000, c.value(0));
-        assert_eq!(3601000, c.value(1));
-        assert!(c.is_null(2));
+        let u32_expected = vec!["0", "255", "65535", "4294967295", "null"];
+        assert_eq!(
+            u32_expected,
+            get_cast_values::<UInt32Type>(&u64_array, &DataType::UInt32)
+        );
 
-        // test overflow
-        let a = TimestampSecondArray::from(vec![Some(i64::MAX)])
-            .with_timezone("+01:00".to_string());
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Time32(TimeUnit::Second));
-        assert!(b.is_err());
-        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond));
-        assert!(b.is_err());
-    }
+        let u16_expected = vec!["0", "255", "65535", "null", "null"];
+        assert_eq!(
+            u16_expected,
+            get_cast_values::<UInt16Type>(&u64_array, &DataType::UInt16)
+        );
 
-    #[test]
-    fn test_cast_date64_to_timestamp() {
-        let array =
-            Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
-        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
-        assert_eq!(864000000, c.value(0));
-        assert_eq!(1545696000, c.value(1));
-        assert!(c.is_null(2));
+        let u8_expected = vec!["0", "255", "null", "null", "null"];
+        assert_eq!(
+            u8_expected,
+            get_cast_values::<UInt8Type>(&u64_array, &DataType::UInt8)
+        );
     }
 
     #[test]
-    fn test_cast_date64_to_timestamp_ms() {
-        let array =
-            Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Millisecond, None)).unwrap();
-        let c = b
-            .as_any()
-            .downcast_ref::<TimestampMillisecondArray>()
-            .unwrap();
-        assert_eq!(864000000005, c.value(0));
-        assert_eq!(1545696000001, c.value(1));
-        assert!(c.is_null(2));
-    }
+    fn test_cast_from_uint32() {
+        let u32_values: Vec<u32> = vec![0, u8::MAX as u32, u16::MAX as u32, u32::MAX];
+        let u32_array: ArrayRef = Arc::new(UInt32Array::from(u32_values));
 
-    #[test]
-    fn test_cast_date64_to_timestamp_us() {
-        let array =
-            Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Microsecond, None)).unwrap();
-        let c = b
-            .as_any()
-            .downcast_ref::<TimestampMicrosecondArray>()
-            .unwrap();
-        assert_eq!(864000000005000, c.value(0));
-        assert_eq!(1545696000001000, c.value(1));
-        assert!(c.is_null(2));
-    }
+        let f64_expected = vec!["0.0", "255.0", "65535.0", "4294967295.0"];
+        assert_eq!(
+            f64_expected,
+            get_cast_values::<Float64Type>(&u32_array, &DataType::Float64)
+        );
 
-    #[test]
-    fn test_cast_date64_to_timestamp_ns() {
-        let array =
-            Date64Array::from(vec![Some(864000000005), Some(1545696000001), None]);
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Nanosecond, None)).unwrap();
-        let c = b
-            .as_any()
-            .downcast_ref::<TimestampNanosecondArray>()
-            .unwrap();
-        assert_eq!(864000000005000000, c.value(0));
-        assert_eq!(1545696000001000000, c.value(1));
-        assert!(c.is_null(2));
-    }
+        let f32_expected = vec!["0.0", "255.0", "65535.0", "4294967300.0"];
+        assert_eq!(
+            f32_expected,
+            get_cast_values::<Float32Type>(&u32_array, &DataType::Float32)
+        );
 
-    #[test]
-    fn test_cast_timestamp_to_i64() {
-        let array = TimestampMillisecondArray::from(vec![
-            Some(864000000005),
-            Some(1545696000001),
-            None,
-        ])
-        .with_timezone("UTC".to_string());
-        let b = cast(&array, &DataType::Int64).unwrap();
-        let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
-        assert_eq!(&DataType::Int64, c.data_type());
-        assert_eq!(864000000005, c.value(0));
-        assert_eq!(1545696000001, c.value(1));
-        assert!(c.is_null(2));
-    }
+        let i64_expected = vec!["0", "255", "65535", "4294967295"];
+        assert_eq!(
+            i64_expected,
+            get_cast_values::<Int64Type>(&u32_array, &DataType::Int64)
+        );
 
-    #[test]
-    fn test_cast_date32_to_string() {
-        let array = Date32Array::from(vec![10000, 17890]);
-        let b = cast(&array, &DataType::Utf8).unwrap();
-        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
-        assert_eq!(&DataType::Utf8, c.data_type());
-        assert_eq!("1997-05-19", c.value(0));
-        assert_eq!("2018-12-25", c.value(1));
-    }
+        let i32_expected = vec!["0", "255", "65535", "null"];
+        assert_eq!(
+            i32_expected,
+            get_cast_values::<Int32Type>(&u32_array, &DataType::Int32)
+        );
 
-    #[test]
-    fn test_cast_date64_to_string() {
-        let array = Date64Array::from(vec![10000 * 86400000, 17890 * 86400000]);
-        let b = cast(&array, &DataType::Utf8).unwrap();
-        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
-        assert_eq!(&DataType::Utf8, c.data_type());
-        assert_eq!("1997-05-19T00:00:00", c.value(0));
-        assert_eq!("2018-12-25T00:00:00", c.value(1));
-    }
+        let i16_expected = vec!["0", "255", "null", "null"];
+        assert_eq!(
+            i16_expected,
+            get_cast_values::<Int16Type>(&u32_array, &DataType::Int16)
+        );
 
-    #[test]
-    fn test_cast_between_timestamps() {
-        let array = TimestampMillisecondArray::from(vec![
-            Some(864000003005),
-            Some(1545696002001),
-            None,
-        ]);
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
-        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
-        assert_eq!(864000003, c.value(0));
-        assert_eq!(1545696002, c.value(1));
-        assert!(c.is_null(2));
-    }
+        let i8_expected = vec!["0", "null", "null", "null"];
+        assert_eq!(
+            i8_expected,
+            get_cast_values::<Int8Type>(&u32_array, &DataType::Int8)
+        );
 
-    #[test]
-    fn test_cast_duration_to_i64() {
-        let base = vec![5, 6, 7, 8, 100000000];
+        let u64_expected = vec!["0", "255", "65535", "4294967295"];
+        assert_eq!(
+            u64_expected,
+            get_cast_values::<UInt64Type>(&u32_array, &DataType::UInt64)
+        );
 
-        let duration_arrays = vec![
-            Arc::new(DurationNanosecondArray::from(base.clone())) as ArrayRef,
-            Arc::new(DurationMicrosecondArray::from(base.clone())) as ArrayRef,
-            Arc::new(DurationMillisecondArray::from(base.clone())) as ArrayRef,
-            Arc::new(DurationSecondArray::from(base.clone())) as ArrayRef,
-        ];
+        let u32_expected = vec!["0", "255", "65535", "4294967295"];
+        assert_eq!(
+            u32_expected,
+            get_cast_values::<UInt32Type>(&u32_array, &DataType::UInt32)
+        );
 
-        for arr in duration_arrays {
-            assert!(can_cast_types(arr.data_type(), &DataType::Int64));
-            let result = cast(&arr, &DataType::Int64).unwrap();
-            let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
-            assert_eq!(base.as_slice(), result.values());
-        }
+        let u16_expected = vec!["0", "255", "65535", "null"];
+        assert_eq!(
+            u16_expected,
+            get_cast_values::<UInt16Type>(&u32_array, &DataType::UInt16)
+        );
+
+        let u8_expected = vec!["0", "255", "null", "null"];
+        assert_eq!(
+            u8_expected,
+            get_cast_values::<UInt8Type>(&u32_array, &DataType::UInt8)
+        );
     }
 
     #[test]
-    fn test_cast_interval_to_i64() {
-        let base = vec![5, 6, 7, 8];
+    fn test_cast_from_uint16() {
+        let u16_values: Vec<u16> = vec![0, u8::MAX as u16, u16::MAX];
+        let u16_array: ArrayRef = Arc::new(UInt16Array::from(u16_values));
 
-        let interval_arrays = vec![
-            Arc::new(IntervalDayTimeArray::from(base.clone())) as ArrayRef,
-            Arc::new(IntervalYearMonthArray::from(
-                base.iter().map(|x| *x as i32).collect::<Vec<i32>>(),
-            )) as ArrayRef,
-        ];
+        let f64_expected = vec!["0.0", "255.0", "65535.0"];
+        assert_eq!(
+            f64_expected,
+            get_cast_values::<Float64Type>(&u16_array, &DataType::Float64)
+        );
 
-        for arr in interval_arrays {
-            assert!(can_cast_types(arr.data_type(), &DataType::Int64));
-            let result = cast(&arr, &DataType::Int64).unwrap();
-            let result = result.as_any().downcast_ref::<Int64Array>().unwrap();
-            assert_eq!(base.as_slice(), result.values());
-        }
-    }
+        let f32_expected = vec!["0.0", "255.0", "65535.0"];
+        assert_eq!(
+            f32_expected,
+            get_cast_values::<Float32Type>(&u16_array, &DataType::Float32)
+        );
+
+        let i64_expected = vec!["0", "255", "65535"];
+        assert_eq!(
+            i64_expected,
+            get_cast_values::<Int64Type>(&u16_array, &DataType::Int64)
+        );
+
+        let i32_expected = vec!["0", "255", "65535"];
+        assert_eq!(
+            i32_expected,
+            get_cast_values::<Int32Type>(&u16_array, &DataType::Int32)
+        );
+
+        let i16_expected = vec!["0", "255", "null"];
+        assert_eq!(
+            i16_expected,
+            get_cast_values::<Int16Type>(&u16_array, &DataType::Int16)
+        );
+
+        let i8_expected = vec!["0", "null", "null"];
+        assert_eq!(
+            i8_expected,
+            get_cast_values::<Int8Type>(&u16_array, &DataType::Int8)
+        );
 
-    #[test]
-    fn test_cast_to_strings() {
-        let a = Int32Array::from(vec![1, 2, 3]);
-        let out = cast(&a, &DataType::Utf8).unwrap();
-        let out = out
-            .as_any()
-            .downcast_ref::<StringArray>()
-            .unwrap()
-            .into_iter()
-            .collect::<Vec<_>>();
-        assert_eq!(out, vec![Some("1"), Some("2"), Some("3")]);
-        let out = cast(&a, &DataType::LargeUtf8).unwrap();
-        let out = out
-            .as_any()
-            .downcast_ref::<LargeStringArray>()
-            .unwrap()
-            .into_iter()
-            .collect::<Vec<_>>();
-        assert_eq!(out, vec![Some("1"), Some("2"), Some("3")]);
-    }
+        let u64_expected = vec!["0", "255", "65535"];
+        assert_eq!(
+            u64_expected,
+            get_cast_values::<UInt64Type>(&u16_array, &DataType::UInt64)
+        );
 
-    #[test]
-    fn test_str_to_str_casts() {
-        for data in vec![
-            vec![Some("foo"), Some("bar"), Some("ham")],
-            vec![Some("foo"), None, Some("bar")],
-        ] {
-            let a = LargeStringArray::from(data.clone());
-            let to = cast(&a, &DataType::Utf8).unwrap();
-            let expect = a
-                .as_any()
-                .downcast_ref::<LargeStringArray>()
-                .unwrap()
-                .into_iter()
-                .collect::<Vec<_>>();
-            let out = to
-                .as_any()
-                .downcast_ref::<StringArray>()
-                .unwrap()
-                .into_iter()
-                .collect::<Vec<_>>();
-            assert_eq!(expect, out);
+        let u32_expected = vec!["0", "255", "65535"];
+        assert_eq!(
+            u32_expected,
+            get_cast_values::<UInt32Type>(&u16_array, &DataType::UInt32)
+        );
 
-            let a = StringArray::from(data);
-            let to = cast(&a, &DataType::LargeUtf8).unwrap();
-            let expect = a
-                .as_any()
-                .downcast_ref::<StringArray>()
-                .unwrap()
-                .into_iter()
-                .collect::<Vec<_>>();
-            let out = to
-                .as_any()
-                .downcast_ref::<LargeStringArray>()
-                .unwrap()
-                .into_iter()
-                .collect::<Vec<_>>();
-            assert_eq!(expect, out);
-        }
+        let u16_expected = vec!["0", "255", "65535"];
+        assert_eq!(
+            u16_expected,
+            get_cast_values::<UInt16Type>(&u16_array, &DataType::UInt16)
+        );
+
+        let u8_expected = vec!["0", "255", "null"];
+        assert_eq!(
+            u8_expected,
+            get_cast_values::<UInt8Type>(&u16_array, &DataType::UInt8)
+        );
     }
 
     #[test]
-    fn test_cast_from_f64() {
-        let f64_values: Vec<f64> = vec![
-            i64::MIN as f64,
-            i32::MIN as f64,
-            i16::MIN as f64,
-            i8::MIN as f64,
-            0_f64,
-            u8::MAX as f64,
-            u16::MAX as f64,
-            u32::MAX as f64,
-            u64::MAX as f64,
-        ];
-        let f64_array: ArrayRef = Arc::new(Float64Array::from(f64_values));
+    fn test_cast_from_uint8() {
+        let u8_values: Vec<u8> = vec![0, u8::MAX];
+        let u8_array: ArrayRef = Arc::new(UInt8Array::from(u8_values));
 
-        let f64_expected = vec![
-            -9223372036854776000.0,
-            -2147483648.0,
-            -32768.0,
-            -128.0,
-            0.0,
-            255.0,
-            65535.0,
-            4294967295.0,
-            18446744073709552000.0,
-        ];
+        let f64_expected = vec!["0.0", "255.0"];
         assert_eq!(
             f64_expected,
-            get_cast_values::<Float64Type>(&f64_array, &DataType::Float64)
-                .iter()
-                .map(|i| i.parse::<f64>().unwrap())
-                .collect::<Vec<f64>>()
+            get_cast_values::<Float64Type>(&u8_array, &DataType::Float64)
         );
 
-        let f32_expected = vec![
-            -9223372000000000000.0,
-            -2147483600.0,
-            -32768.0,
-            -128.0,
-            0.0,
-            255.0,
-            65535.0,
-            4294967300.0,
-            18446744000000000000.0,
-        ];
+        let f32_expected = vec!["0.0", "255.0"];
         assert_eq!(
             f32_expected,
-            get_cast_values::<Float32Type>(&f64_array, &DataType::Float32)
-                .iter()
-                .map(|i| i.parse::<f32>().unwrap())
-                .collect::<Vec<f32>>()
+            get_cast_values::<Float32Type>(&u8_array, &DataType::Float32)
         );
 
-        let i64_expected = vec![
-            "-9223372036854775808",
-            "-2147483648",
-            "-32768",
-            "-128",
-            "0",
-            "255",
-            "65535",
-            "4294967295",
-            "null",
-        ];
+        let i64_expected = vec!["0", "255"];
         assert_eq!(
             i64_expected,
-            get_cast_values::<Int64Type>(&f64_array, &DataType::Int64)
+            get_cast_values::<Int64Type>(&u8_array, &DataType::Int64)
         );
 
-        let i32_expected = vec![
-            "null",
-            "-2147483648",
-            "-32768",
-            "-128",
-            "0",
-            "255",
-            "65535",
-            "null",
-            "null",
-        ];
+        let i32_expected = vec!["0", "255"];
         assert_eq!(
             i32_expected,
-            get_cast_values::<Int32Type>(&f64_array, &DataType::Int32)
+            get_cast_values::<Int32Type>(&u8_array, &DataType::Int32)
         );
 
-        let i16_expected = vec![
-            "null", "null", "-32768", "-128", "0", "255", "null", "null", "null",
-        ];
+        let i16_expected = vec!["0", "255"];
         assert_eq!(
             i16_expected,
-            get_cast_values::<Int16Type>(&f64_array, &DataType::Int16)
+            get_cast_values::<Int16Type>(&u8_array, &DataType::Int16)
         );
 
-        let i8_expected = vec![
-            "null", "null", "null", "-128", "0", "null", "null", "null", "null",
-        ];
+        let i8_expected = vec!["0", "null"];
         assert_eq!(
             i8_expected,
-            get_cast_values::<Int8Type>(&f64_array, &DataType::Int8)
+            get_cast_values::<Int8Type>(&u8_array, &DataType::Int8)
         );
 
-        let u64_expected = vec![
-            "null",
-            "null",
-            "null",
-            "null",
-            "0",
-            "255",
-            "65535",
-            "4294967295",
-            "null",
-        ];
+        let u64_expected = vec!["0", "255"];
         assert_eq!(
             u64_expected,
-            get_cast_values::<UInt64Type>(&f64_array, &DataType::UInt64)
+            get_cast_values::<UInt64Type>(&u8_array, &DataType::UInt64)
         );
 
-        let u32_expected = vec![
-            "null",
-            "null",
-            "null",
-            "null",
-            "0",
-            "255",
-            "65535",
-            "4294967295",
-            "null",
-        ];
+        let u32_expected = vec!["0", "255"];
         assert_eq!(
             u32_expected,
-            get_cast_values::<UInt32Type>(&f64_array, &DataType::UInt32)
+            get_cast_values::<UInt32Type>(&u8_array, &DataType::UInt32)
         );
 
-        let u16_expected = vec![
-            "null", "null", "null", "null", "0", "255", "65535", "null", "null",
-        ];
+        let u16_expected = vec!["0", "255"];
         assert_eq!(
             u16_expected,
-            get_cast_values::<UInt16Type>(&f64_array, &DataType::UInt16)
+            get_cast_values::<UInt16Type>(&u8_array, &DataType::UInt16)
         );
 
-        let u8_expected = vec![
-            "null", "null", "null", "null", "0", "255", "null", "null", "null",
-        ];
+        let u8_expected = vec!["0", "255"];
         assert_eq!(
             u8_expected,
-            get_cast_values::<UInt8Type>(&f64_array, &DataType::UInt8)
+            get_cast_values::<UInt8Type>(&u8_array, &DataType::UInt8)
         );
     }
 
     #[test]
-    fn test_cast_from_f32() {
-        let f32_values: Vec<f32> = vec![
-            i32::MIN as f32,
-            i32::MIN as f32,
-            i16::MIN as f32,
-            i8::MIN as f32,
-            0_f32,
-            u8::MAX as f32,
-            u16::MAX as f32,
-            u32::MAX as f32,
-            u32::MAX as f32,
+    fn test_cast_from_int64() {
+        let i64_values: Vec<i64> = vec![
+            i64::MIN,
+            i32::MIN as i64,
+            i16::MIN as i64,
+            i8::MIN as i64,
+            0,
+            i8::MAX as i64,
+            i16::MAX as i64,
+            i32::MAX as i64,
+            i64::MAX,
         ];
-        let f32_array: ArrayRef = Arc::new(Float32Array::from(f32_values));
+        let i64_array: ArrayRef = Arc::new(Int64Array::from(i64_values));
 
         let f64_expected = vec![
-            "-2147483648.0",
-            "-2147483648.0",
-            "-32768.0",
-            "-128.0",
-            "0.0",
-            "255.0",
-            "65535.0",
-            "4294967296.0",
-            "4294967296.0",
+            -9223372036854776000.0,
+            -2147483648.0,
+            -32768.0,
+            -128.0,
+            0.0,
+            127.0,
+            32767.0,
+            2147483647.0,
+            9223372036854776000.0,
         ];
         assert_eq!(
             f64_expected,
-            get_cast_values::<Float64Type>(&f32_array, &DataType::Float64)
+            get_cast_values::<Float64Type>(&i64_array, &DataType::Float64)
+                .iter()
+                .map(|i| i.parse::<f64>().unwrap())
+                .collect::<Vec<f64>>()
         );
 
         let f32_expected = vec![
-            "-2147483600.0",
-            "-2147483600.0",
-            "-32768.0",
-            "-128.0",
-            "0.0",
-            "255.0",
-            "65535.0",
-            "4294967300.0",
-            "4294967300.0",
+            -9223372000000000000.0,
+            -2147483600.0,
+            -32768.0,
+            -128.0,
+            0.0,
+            127.0,
+            32767.0,
+            2147483600.0,
+            9223372000000000000.0,
         ];
         assert_eq!(
             f32_expected,
-            get_cast_values::<Float32Type>(&f32_array, &DataType::Float32)
+            get_cast_values::<Float32Type>(&i64_array, &DataType::Float32)
+                .iter()
+                .map(|i| i.parse::<f32>().unwrap())
+                .collect::<Vec<f32>>()
         );
 
         let i64_expected = vec![
-            "-2147483648",
+            "-9223372036854775808",
             "-2147483648",
             "-32768",
             "-128",
             "0",
-            "255",
-            "65535",
-            "4294967296",
-            "4294967296",
+            "127",
+            "32767",
+            "2147483647",
+            "9223372036854775807",
         ];
         assert_eq!(
             i64_expected,
-            get_cast_values::<Int64Type>(&f32_array, &DataType::Int64)
+            get_cast_values::<Int64Type>(&i64_array, &DataType::Int64)
         );
 
         let i32_expected = vec![
-            "-2147483648",
+            "null",
             "-2147483648",
             "-32768",
             "-128",
             "0",
-            "255",
-            "65535",
-            "null",
+            "127",
+            "32767",
+            "2147483647",
             "null",
         ];
         assert_eq!(
             i32_expected,
-            get_cast_values::<Int32Type>(&f32_array, &DataType::Int32)
+            get_cast_values::<Int32Type>(&i64_array, &DataType::Int32)
+        );
+
+        assert_eq!(
+            i32_expected,
+            get_cast_values::<Date32Type>(&i64_array, &DataType::Date32)
         );
 
         let i16_expected = vec![
-            "null", "null", "-32768", "-128", "0", "255", "null", "null", "null",
+            "null", "null", "-32768", "-128", "0", "127", "32767", "null", "null",
         ];
         assert_eq!(
             i16_expected,
-            get_cast_values::<Int16Type>(&f32_array, &DataType::Int16)
+            get_cast_values::<Int16Type>(&i64_array, &DataType::Int16)
         );
 
         let i8_expected = vec![
-            "null", "null", "null", "-128", "0", "null", "null", "null", "null",
+            "null", "null", "null", "-128", "0", "127", "null", "null", "null",
         ];
         assert_eq!(
             i8_expected,
-            get_cast_values::<Int8Type>(&f32_array, &DataType::Int8)
+            get_cast_values::<Int8Type>(&i64_array, &DataType::Int8)
         );
 
         let u64_expected = vec![
@@ -6364,2100 +13009,3142 @@ mod tests {
             "null",
             "null",
             "0",
-            "255",
-            "65535",
-            "4294967296",
-            "4294967296",
+            "127",
+            "32767",
+            "2147483647",
+            "9223372036854775807",
         ];
         assert_eq!(
             u64_expected,
-            get_cast_values::<UInt64Type>(&f32_array, &DataType::UInt64)
+            get_cast_values::<UInt64Type>(&i64_array, &DataType::UInt64)
         );
 
         let u32_expected = vec![
-            "null", "null", "null", "null", "0", "255", "65535", "null", "null",
+            "null",
+            "null",
+            "null",
+            "null",
+            "0",
+            "127",
+            "32767",
+            "2147483647",
+            "null",
         ];
         assert_eq!(
             u32_expected,
-            get_cast_values::<UInt32Type>(&f32_array, &DataType::UInt32)
+            get_cast_values::<UInt32Type>(&i64_array, &DataType::UInt32)
         );
 
         let u16_expected = vec![
-            "null", "null", "null", "null", "0", "255", "65535", "null", "null",
+            "null", "null", "null", "null", "0", "127", "32767", "null", "null",
         ];
         assert_eq!(
             u16_expected,
-            get_cast_values::<UInt16Type>(&f32_array, &DataType::UInt16)
+            get_cast_values::<UInt16Type>(&i64_array, &DataType::UInt16)
         );
 
         let u8_expected = vec![
-            "null", "null", "null", "null", "0", "255", "null", "null", "null",
+            "null", "null", "null", "null", "0", "127", "null", "null", "null",
         ];
         assert_eq!(
             u8_expected,
-            get_cast_values::<UInt8Type>(&f32_array, &DataType::UInt8)
+            get_cast_values::<UInt8Type>(&i64_array, &DataType::UInt8)
         );
     }
 
     #[test]
-    fn test_cast_from_uint64() {
-        let u64_values: Vec<u64> = vec![
+    fn test_cast_from_int32() {
+        let i32_values: Vec<i32> = vec![
+            i32::MIN,
+            i16::MIN as i32,
+            i8::MIN as i32,
             0,
-            u8::MAX as u64,
-            u16::MAX as u64,
-            u32::MAX as u64,
-            u64::MAX,
+            i8::MAX as i32,
+            i16::MAX as i32,
+            i32::MAX,
         ];
-        let u64_array: ArrayRef = Arc::new(UInt64Array::from(u64_values));
+        let i32_array: ArrayRef = Arc::new(Int32Array::from(i32_values));
 
-        let f64_expected =
-            vec![0.0, 255.0, 65535.0, 4294967295.0, 18446744073709552000.0];
+        let f64_expected = vec![
+            "-2147483648.0",
+            "-32768.0",
+            "-128.0",
+            "0.0",
+            "127.0",
+            "32767.0",
+            "2147483647.0",
+        ];
         assert_eq!(
             f64_expected,
-            get_cast_values::<Float64Type>(&u64_array, &DataType::Float64)
-                .iter()
-                .map(|i| i.parse::<f64>().unwrap())
-                .collect::<Vec<f64>>()
+            get_cast_values::<Float64Type>(&i32_array, &DataType::Float64)
         );
 
-        let f32_expected =
-            vec![0.0, 255.0, 65535.0, 4294967300.0, 18446744000000000000.0];
+        let f32_expected = vec![
+            "-2147483600.0",
+            "-32768.0",
+            "-128.0",
+            "0.0",
+            "127.0",
+            "32767.0",
+            "2147483600.0",
+        ];
         assert_eq!(
             f32_expected,
-            get_cast_values::<Float32Type>(&u64_array, &DataType::Float32)
-                .iter()
-                .map(|i| i.parse::<f32>().unwrap())
-                .collect::<Vec<f32>>()
-        );
-
-        let i64_expected = vec!["0", "255", "65535", "4294967295", "null"];
-        assert_eq!(
-            i64_expected,
-            get_cast_values::<Int64Type>(&u64_array, &DataType::Int64)
-        );
-
-        let i32_expected = vec!["0", "255", "65535", "null", "null"];
-        assert_eq!(
-            i32_expected,
-            get_cast_values::<Int32Type>(&u64_array, &DataType::Int32)
+            get_cast_values::<Float32Type>(&i32_array, &DataType::Float32)
         );
 
-        let i16_expected = vec!["0", "255", "null", "null", "null"];
+        let i16_expected = vec!["null", "-32768", "-128", "0", "127", "32767", "null"];
         assert_eq!(
             i16_expected,
-            get_cast_values::<Int16Type>(&u64_array, &DataType::Int16)
+            get_cast_values::<Int16Type>(&i32_array, &DataType::Int16)
         );
 
-        let i8_expected = vec!["0", "null", "null", "null", "null"];
+        let i8_expected = vec!["null", "null", "-128", "0", "127", "null", "null"];
         assert_eq!(
             i8_expected,
-            get_cast_values::<Int8Type>(&u64_array, &DataType::Int8)
+            get_cast_values::<Int8Type>(&i32_array, &DataType::Int8)
         );
 
         let u64_expected =
-            vec!["0", "255", "65535", "4294967295", "18446744073709551615"];
+            vec!["null", "null", "null", "0", "127", "32767", "2147483647"];
         assert_eq!(
             u64_expected,
-            get_cast_values::<UInt64Type>(&u64_array, &DataType::UInt64)
+            get_cast_values::<UInt64Type>(&i32_array, &DataType::UInt64)
         );
 
-        let u32_expected = vec!["0", "255", "65535", "4294967295", "null"];
+        let u32_expected =
+            vec!["null", "null", "null", "0", "127", "32767", "2147483647"];
         assert_eq!(
             u32_expected,
-            get_cast_values::<UInt32Type>(&u64_array, &DataType::UInt32)
+            get_cast_values::<UInt32Type>(&i32_array, &DataType::UInt32)
         );
 
-        let u16_expected = vec!["0", "255", "65535", "null", "null"];
+        let u16_expected = vec!["null", "null", "null", "0", "127", "32767", "null"];
         assert_eq!(
             u16_expected,
-            get_cast_values::<UInt16Type>(&u64_array, &DataType::UInt16)
+            get_cast_values::<UInt16Type>(&i32_array, &DataType::UInt16)
         );
 
-        let u8_expected = vec!["0", "255", "null", "null", "null"];
+        let u8_expected = vec!["null", "null", "null", "0", "127", "null", "null"];
         assert_eq!(
             u8_expected,
-            get_cast_values::<UInt8Type>(&u64_array, &DataType::UInt8)
-        );
-    }
-
-    #[test]
-    fn test_cast_from_uint32() {
-        let u32_values: Vec<u32> = vec![0, u8::MAX as u32, u16::MAX as u32, u32::MAX];
-        let u32_array: ArrayRef = Arc::new(UInt32Array::from(u32_values));
-
-        let f64_expected = vec!["0.0", "255.0", "65535.0", "4294967295.0"];
-        assert_eq!(
-            f64_expected,
-            get_cast_values::<Float64Type>(&u32_array, &DataType::Float64)
-        );
-
-        let f32_expected = vec!["0.0", "255.0", "65535.0", "4294967300.0"];
-        assert_eq!(
-            f32_expected,
-            get_cast_values::<Float32Type>(&u32_array, &DataType::Float32)
+            get_cast_values::<UInt8Type>(&i32_array, &DataType::UInt8)
         );
 
-        let i64_expected = vec!["0", "255", "65535", "4294967295"];
+        // The date32 to date64 cast increases the numerical values in order to keep the same dates.
+        let i64_expected = vec![
+            "-185542587187200000",
+            "-2831155200000",
+            "-11059200000",
+            "0",
+            "10972800000",
+            "2831068800000",
+            "185542587100800000",
+        ];
         assert_eq!(
             i64_expected,
-            get_cast_values::<Int64Type>(&u32_array, &DataType::Int64)
-        );
-
-        let i32_expected = vec!["0", "255", "65535", "null"];
-        assert_eq!(
-            i32_expected,
-            get_cast_values::<Int32Type>(&u32_array, &DataType::Int32)
-        );
-
-        let i16_expected = vec!["0", "255", "null", "null"];
-        assert_eq!(
-            i16_expected,
-            get_cast_values::<Int16Type>(&u32_array, &DataType::Int16)
-        );
-
-        let i8_expected = vec!["0", "null", "null", "null"];
-        assert_eq!(
-            i8_expected,
-            get_cast_values::<Int8Type>(&u32_array, &DataType::Int8)
-        );
-
-        let u64_expected = vec!["0", "255", "65535", "4294967295"];
-        assert_eq!(
-            u64_expected,
-            get_cast_values::<UInt64Type>(&u32_array, &DataType::UInt64)
-        );
-
-        let u32_expected = vec!["0", "255", "65535", "4294967295"];
-        assert_eq!(
-            u32_expected,
-            get_cast_values::<UInt32Type>(&u32_array, &DataType::UInt32)
-        );
-
-        let u16_expected = vec!["0", "255", "65535", "null"];
-        assert_eq!(
-            u16_expected,
-            get_cast_values::<UInt16Type>(&u32_array, &DataType::UInt16)
-        );
-
-        let u8_expected = vec!["0", "255", "null", "null"];
-        assert_eq!(
-            u8_expected,
-            get_cast_values::<UInt8Type>(&u32_array, &DataType::UInt8)
+            get_cast_values::<Date64Type>(&i32_array, &DataType::Date64)
         );
     }
 
     #[test]
-    fn test_cast_from_uint16() {
-        let u16_values: Vec<u16> = vec![0, u8::MAX as u16, u16::MAX];
-        let u16_array: ArrayRef = Arc::new(UInt16Array::from(u16_values));
+    fn test_cast_from_int16() {
+        let i16_values: Vec<i16> =
+            vec![i16::MIN, i8::MIN as i16, 0, i8::MAX as i16, i16::MAX];
+        let i16_array: ArrayRef = Arc::new(Int16Array::from(i16_values));
 
-        let f64_expected = vec!["0.0", "255.0", "65535.0"];
+        let f64_expected = vec!["-32768.0", "-128.0", "0.0", "127.0", "32767.0"];
         assert_eq!(
             f64_expected,
-            get_cast_values::<Float64Type>(&u16_array, &DataType::Float64)
+            get_cast_values::<Float64Type>(&i16_array, &DataType::Float64)
         );
 
-        let f32_expected = vec!["0.0", "255.0", "65535.0"];
+        let f32_expected = vec!["-32768.0", "-128.0", "0.0", "127.0", "32767.0"];
         assert_eq!(
             f32_expected,
-            get_cast_values::<Float32Type>(&u16_array, &DataType::Float32)
+            get_cast_values::<Float32Type>(&i16_array, &DataType::Float32)
         );
 
-        let i64_expected = vec!["0", "255", "65535"];
+        let i64_expected = vec!["-32768", "-128", "0", "127", "32767"];
         assert_eq!(
             i64_expected,
-            get_cast_values::<Int64Type>(&u16_array, &DataType::Int64)
+            get_cast_values::<Int64Type>(&i16_array, &DataType::Int64)
         );
 
-        let i32_expected = vec!["0", "255", "65535"];
+        let i32_expected = vec!["-32768", "-128", "0", "127", "32767"];
         assert_eq!(
             i32_expected,
-            get_cast_values::<Int32Type>(&u16_array, &DataType::Int32)
+            get_cast_values::<Int32Type>(&i16_array, &DataType::Int32)
         );
 
-        let i16_expected = vec!["0", "255", "null"];
+        let i16_expected = vec!["-32768", "-128", "0", "127", "32767"];
         assert_eq!(
             i16_expected,
-            get_cast_values::<Int16Type>(&u16_array, &DataType::Int16)
+            get_cast_values::<Int16Type>(&i16_array, &DataType::Int16)
         );
 
-        let i8_expected = vec!["0", "null", "null"];
+        let i8_expected = vec!["null", "-128", "0", "127", "null"];
         assert_eq!(
             i8_expected,
-            get_cast_values::<Int8Type>(&u16_array, &DataType::Int8)
+            get_cast_values::<Int8Type>(&i16_array, &DataType::Int8)
         );
 
-        let u64_expected = vec!["0", "255", "65535"];
+        let u64_expected = vec!["null", "null", "0", "127", "32767"];
         assert_eq!(
             u64_expected,
-            get_cast_values::<UInt64Type>(&u16_array, &DataType::UInt64)
+            get_cast_values::<UInt64Type>(&i16_array, &DataType::UInt64)
         );
 
-        let u32_expected = vec!["0", "255", "65535"];
+        let u32_expected = vec!["null", "null", "0", "127", "32767"];
         assert_eq!(
             u32_expected,
-            get_cast_values::<UInt32Type>(&u16_array, &DataType::UInt32)
+            get_cast_values::<UInt32Type>(&i16_array, &DataType::UInt32)
         );
 
-        let u16_expected = vec!["0", "255", "65535"];
+        let u16_expected = vec!["null", "null", "0", "127", "32767"];
         assert_eq!(
             u16_expected,
-            get_cast_values::<UInt16Type>(&u16_array, &DataType::UInt16)
+            get_cast_values::<UInt16Type>(&i16_array, &DataType::UInt16)
         );
 
-        let u8_expected = vec!["0", "255", "null"];
+        let u8_expected = vec!["null", "null", "0", "127", "null"];
         assert_eq!(
             u8_expected,
-            get_cast_values::<UInt8Type>(&u16_array, &DataType::UInt8)
+            get_cast_values::<UInt8Type>(&i16_array, &DataType::UInt8)
         );
     }
 
     #[test]
-    fn test_cast_from_uint8() {
-        let u8_values: Vec<u8> = vec![0, u8::MAX];
-        let u8_array: ArrayRef = Arc::new(UInt8Array::from(u8_values));
+    fn test_cast_from_date32() {
+        let i32_values: Vec<i32> = vec![
+            i32::MIN,
+            i16::MIN as i32,
+            i8::MIN as i32,
+            0,
+            i8::MAX as i32,
+            i16::MAX as i32,
+            i32::MAX,
+        ];
+        let date32_array: ArrayRef = Arc::new(Date32Array::from(i32_values));
 
-        let f64_expected = vec!["0.0", "255.0"];
+        let i64_expected = vec![
+            "-2147483648",
+            "-32768",
+            "-128",
+            "0",
+            "127",
+            "32767",
+            "2147483647",
+        ];
+        assert_eq!(
+            i64_expected,
+            get_cast_values::<Int64Type>(&date32_array, &DataType::Int64)
+        );
+    }
+
+    #[test]
+    fn test_cast_from_int8() {
+        let i8_values: Vec<i8> = vec![i8::MIN, 0, i8::MAX];
+        let i8_array = Int8Array::from(i8_values);
+
+        let f64_expected = vec!["-128.0", "0.0", "127.0"];
         assert_eq!(
             f64_expected,
-            get_cast_values::<Float64Type>(&u8_array, &DataType::Float64)
+            get_cast_values::<Float64Type>(&i8_array, &DataType::Float64)
         );
 
-        let f32_expected = vec!["0.0", "255.0"];
+        let f32_expected = vec!["-128.0", "0.0", "127.0"];
         assert_eq!(
             f32_expected,
-            get_cast_values::<Float32Type>(&u8_array, &DataType::Float32)
+            get_cast_values::<Float32Type>(&i8_array, &DataType::Float32)
         );
 
-        let i64_expected = vec!["0", "255"];
+        let i64_expected = vec!["-128", "0", "127"];
         assert_eq!(
             i64_expected,
-            get_cast_values::<Int64Type>(&u8_array, &DataType::Int64)
+            get_cast_values::<Int64Type>(&i8_array, &DataType::Int64)
         );
 
-        let i32_expected = vec!["0", "255"];
+        let i32_expected = vec!["-128", "0", "127"];
         assert_eq!(
             i32_expected,
-            get_cast_values::<Int32Type>(&u8_array, &DataType::Int32)
+            get_cast_values::<Int32Type>(&i8_array, &DataType::Int32)
         );
 
-        let i16_expected = vec!["0", "255"];
+        let i16_expected = vec!["-128", "0", "127"];
         assert_eq!(
             i16_expected,
-            get_cast_values::<Int16Type>(&u8_array, &DataType::Int16)
+            get_cast_values::<Int16Type>(&i8_array, &DataType::Int16)
         );
 
-        let i8_expected = vec!["0", "null"];
+        let i8_expected = vec!["-128", "0", "127"];
         assert_eq!(
             i8_expected,
-            get_cast_values::<Int8Type>(&u8_array, &DataType::Int8)
+            get_cast_values::<Int8Type>(&i8_array, &DataType::Int8)
         );
 
-        let u64_expected = vec!["0", "255"];
+        let u64_expected = vec!["null", "0", "127"];
         assert_eq!(
             u64_expected,
-            get_cast_values::<UInt64Type>(&u8_array, &DataType::UInt64)
+            get_cast_values::<UInt64Type>(&i8_array, &DataType::UInt64)
         );
 
-        let u32_expected = vec!["0", "255"];
+        let u32_expected = vec!["null", "0", "127"];
         assert_eq!(
             u32_expected,
-            get_cast_values::<UInt32Type>(&u8_array, &DataType::UInt32)
+            get_cast_values::<UInt32Type>(&i8_array, &DataType::UInt32)
         );
 
-        let u16_expected = vec!["0", "255"];
+        let u16_expected = vec!["null", "0", "127"];
         assert_eq!(
             u16_expected,
-            get_cast_values::<UInt16Type>(&u8_array, &DataType::UInt16)
+            get_cast_values::<UInt16Type>(&i8_array, &DataType::UInt16)
         );
 
-        let u8_expected = vec!["0", "255"];
+        let u8_expected = vec!["null", "0", "127"];
         assert_eq!(
             u8_expected,
-            get_cast_values::<UInt8Type>(&u8_array, &DataType::UInt8)
+            get_cast_values::<UInt8Type>(&i8_array, &DataType::UInt8)
         );
     }
 
+    /// Convert `array` into a vector of strings by casting to data type dt
+    fn get_cast_values<T>(array: &dyn Array, dt: &DataType) -> Vec<String>
+    where
+        T: ArrowPrimitiveType,
+    {
+        let c = cast(array, dt).unwrap();
+        let a = c.as_primitive::<T>();
+        let mut v: Vec<String> = vec![];
+        for i in 0..array.len() {
+            if a.is_null(i) {
+                v.push("null".to_string())
+            } else {
+                v.push(format!("{:?}", a.value(i)));
+            }
+        }
+        v
+    }
+
     #[test]
-    fn test_cast_from_int64() {
-        let i64_values: Vec<i64> = vec![
-            i64::MIN,
-            i32::MIN as i64,
-            i16::MIN as i64,
-            i8::MIN as i64,
-            0,
-            i8::MAX as i64,
-            i16::MAX as i64,
-            i32::MAX as i64,
-            i64::MAX,
-        ];
-        let i64_array: ArrayRef = Arc::new(Int64Array::from(i64_values));
+    fn test_cast_utf8_dict() {
+        // FROM a dictionary with of Utf8 values
+        use DataType::*;
 
-        let f64_expected = vec![
-            -9223372036854776000.0,
-            -2147483648.0,
-            -32768.0,
-            -128.0,
-            0.0,
-            127.0,
-            32767.0,
-            2147483647.0,
-            9223372036854776000.0,
-        ];
-        assert_eq!(
-            f64_expected,
-            get_cast_values::<Float64Type>(&i64_array, &DataType::Float64)
-                .iter()
-                .map(|i| i.parse::<f64>().unwrap())
-                .collect::<Vec<f64>>()
-        );
+        let mut builder = StringDictionaryBuilder::<Int8Type>::new();
+        builder.append("one").unwrap();
+        builder.append_null();
+        builder.append("three").unwrap();
+        let array: ArrayRef = Arc::new(builder.finish());
 
-        let f32_expected = vec![
-            -9223372000000000000.0,
-            -2147483600.0,
-            -32768.0,
-            -128.0,
-            0.0,
-            127.0,
-            32767.0,
-            2147483600.0,
-            9223372000000000000.0,
-        ];
-        assert_eq!(
-            f32_expected,
-            get_cast_values::<Float32Type>(&i64_array, &DataType::Float32)
-                .iter()
-                .map(|i| i.parse::<f32>().unwrap())
-                .collect::<Vec<f32>>()
-        );
+        let expected = vec!["one", "null", "three"];
 
-        let i64_expected = vec![
-            "-9223372036854775808",
-            "-2147483648",
-            "-32768",
-            "-128",
-            "0",
-            "127",
-            "32767",
-            "2147483647",
-            "9223372036854775807",
-        ];
-        assert_eq!(
-            i64_expected,
-            get_cast_values::<Int64Type>(&i64_array, &DataType::Int64)
-        );
+        // Test casting TO StringArray
+        let cast_type = Utf8;
+        let cast_array = cast(&array, &cast_type).expect("cast to UTF-8 failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
 
-        let i32_expected = vec![
-            "null",
-            "-2147483648",
-            "-32768",
-            "-128",
-            "0",
-            "127",
-            "32767",
-            "2147483647",
-            "null",
-        ];
-        assert_eq!(
-            i32_expected,
-            get_cast_values::<Int32Type>(&i64_array, &DataType::Int32)
-        );
+        // Test casting TO Dictionary (with different index sizes)
+
+        let cast_type = Dictionary(Box::new(Int16), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
+
+        let cast_type = Dictionary(Box::new(Int32), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
+
+        let cast_type = Dictionary(Box::new(Int64), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
+
+        let cast_type = Dictionary(Box::new(UInt8), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
+
+        let cast_type = Dictionary(Box::new(UInt16), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
+
+        let cast_type = Dictionary(Box::new(UInt32), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
 
-        assert_eq!(
-            i32_expected,
-            get_cast_values::<Date32Type>(&i64_array, &DataType::Date32)
-        );
+        let cast_type = Dictionary(Box::new(UInt64), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
+    }
 
-        let i16_expected = vec![
-            "null", "null", "-32768", "-128", "0", "127", "32767", "null", "null",
-        ];
+    #[test]
+    fn test_cast_dict_to_dict_same_keys() {
+        let keys = Int32Array::from(vec![Some(1), None, Some(0), Some(1)]);
+        let values = Int64Array::from(vec![5, 300]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_dictionary::<Int32Type>();
+        // The keys are not copied
+        assert_eq!(b.keys().values().as_ptr(), array.keys().values().as_ptr());
+        assert_eq!(b.keys().nulls(), array.keys().nulls());
         assert_eq!(
-            i16_expected,
-            get_cast_values::<Int16Type>(&i64_array, &DataType::Int16)
+            b.values().as_string::<i32>(),
+            &StringArray::from(vec!["5", "300"])
         );
 
-        let i8_expected = vec![
-            "null", "null", "null", "-128", "0", "127", "null", "null", "null",
-        ];
+        // Values that fail to cast are null, and so are the rows referencing them
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Int8));
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_dictionary::<Int32Type>();
+        assert_eq!(b.keys(), array.keys());
         assert_eq!(
-            i8_expected,
-            get_cast_values::<Int8Type>(&i64_array, &DataType::Int8)
+            b.values().as_primitive::<Int8Type>(),
+            &Int8Array::from(vec![Some(5), None])
         );
 
-        let u64_expected = vec![
-            "null",
-            "null",
-            "null",
-            "null",
-            "0",
-            "127",
-            "32767",
-            "2147483647",
-            "9223372036854775807",
-        ];
-        assert_eq!(
-            u64_expected,
-            get_cast_values::<UInt64Type>(&i64_array, &DataType::UInt64)
-        );
+        let options = CastOptions::new().with_safe(false);
+        assert!(cast_with_options(&array, &to_type, &options).is_err());
+    }
 
-        let u32_expected = vec![
-            "null",
-            "null",
-            "null",
-            "null",
-            "0",
-            "127",
-            "32767",
-            "2147483647",
-            "null",
-        ];
-        assert_eq!(
-            u32_expected,
-            get_cast_values::<UInt32Type>(&i64_array, &DataType::UInt32)
-        );
+    #[test]
+    fn test_cast_timestamp_dict_unit() {
+        let tz: Arc<str> = "+01:00".into();
+        let values = TimestampMillisecondArray::from(vec![1, -1500, i64::MAX])
+            .with_timezone(tz.clone());
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(0), Some(2)]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+
+        let value_type = DataType::Timestamp(TimeUnit::Nanosecond, Some(tz.clone()));
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(value_type.clone()));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_dictionary::<Int32Type>();
+        assert_eq!(b.keys().values().as_ptr(), array.keys().values().as_ptr());
+        let values = b.values().as_primitive::<TimestampNanosecondType>();
+        let expected = TimestampNanosecondArray::from(vec![
+            Some(1_000_000),
+            Some(-1_500_000_000),
+            None,
+        ])
+        .with_timezone(tz.clone());
+        assert_eq!(values, &expected);
+
+        // Only the values referenced by the keys are flattened
+        let flat = cast(b, &value_type).unwrap();
+        assert_eq!(flat.null_count(), 2);
+
+        let value_type = DataType::Timestamp(TimeUnit::Second, Some(tz));
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(value_type));
+        let b = cast(&array, &to_type).unwrap();
+        let values = b.as_dictionary::<Int32Type>().values();
+        let values = values.as_primitive::<TimestampSecondType>();
+        assert_eq!(&values.values()[..2], &[0, -1]);
+    }
 
-        let u16_expected = vec![
-            "null", "null", "null", "null", "0", "127", "32767", "null", "null",
-        ];
-        assert_eq!(
-            u16_expected,
-            get_cast_values::<UInt16Type>(&i64_array, &DataType::UInt16)
-        );
+    #[test]
+    fn test_cast_dict_to_dict_bad_index_value_primitive() {
+        use DataType::*;
+        // test converting from an array that has indexes of a type
+        // that are out of bounds for a particular other kind of
+        // index.
 
-        let u8_expected = vec![
-            "null", "null", "null", "null", "0", "127", "null", "null", "null",
-        ];
-        assert_eq!(
-            u8_expected,
-            get_cast_values::<UInt8Type>(&i64_array, &DataType::UInt8)
+        let mut builder = PrimitiveDictionaryBuilder::<Int32Type, Int64Type>::new();
+
+        // add 200 distinct values (which can be stored by a
+        // dictionary indexed by int32, but not a dictionary indexed
+        // with int8)
+        for i in 0..200 {
+            builder.append(i).unwrap();
+        }
+        let array: ArrayRef = Arc::new(builder.finish());
+
+        let cast_type = Dictionary(Box::new(Int8), Box::new(Utf8));
+        let res = cast(&array, &cast_type);
+        assert!(res.is_err());
+        let actual_error = format!("{res:?}");
+        let expected_error = "Could not convert 72 dictionary indexes from Int32 to Int8";
+        assert!(
+            actual_error.contains(expected_error),
+            "did not find expected error '{actual_error}' in actual error '{expected_error}'"
         );
     }
 
     #[test]
-    fn test_cast_from_int32() {
-        let i32_values: Vec<i32> = vec![
-            i32::MIN,
-            i16::MIN as i32,
-            i8::MIN as i32,
-            0,
-            i8::MAX as i32,
-            i16::MAX as i32,
-            i32::MAX,
-        ];
-        let i32_array: ArrayRef = Arc::new(Int32Array::from(i32_values));
+    fn test_cast_dict_to_dict_bad_index_value_utf8() {
+        use DataType::*;
+        // Same test as test_cast_dict_to_dict_bad_index_value but use
+        // string values (and encode the expected behavior here);
 
-        let f64_expected = vec![
-            "-2147483648.0",
-            "-32768.0",
-            "-128.0",
-            "0.0",
-            "127.0",
-            "32767.0",
-            "2147483647.0",
-        ];
-        assert_eq!(
-            f64_expected,
-            get_cast_values::<Float64Type>(&i32_array, &DataType::Float64)
-        );
+        let mut builder = StringDictionaryBuilder::<Int32Type>::new();
 
-        let f32_expected = vec![
-            "-2147483600.0",
-            "-32768.0",
-            "-128.0",
-            "0.0",
-            "127.0",
-            "32767.0",
-            "2147483600.0",
-        ];
-        assert_eq!(
-            f32_expected,
-            get_cast_values::<Float32Type>(&i32_array, &DataType::Float32)
-        );
+        // add 200 distinct values (which can be stored by a
+        // dictionary indexed by int32, but not a dictionary indexed
+        // with int8)
+        for i in 0..200 {
+            let val = format!("val{i}");
+            builder.append(&val).unwrap();
+        }
+        let array = builder.finish();
 
-        let i16_expected = vec!["null", "-32768", "-128", "0", "127", "32767", "null"];
-        assert_eq!(
-            i16_expected,
-            get_cast_values::<Int16Type>(&i32_array, &DataType::Int16)
+        let cast_type = Dictionary(Box::new(Int8), Box::new(Utf8));
+        let res = cast(&array, &cast_type);
+        assert!(res.is_err());
+        let actual_error = format!("{res:?}");
+        let expected_error = "Could not convert 72 dictionary indexes from Int32 to Int8";
+        assert!(
+            actual_error.contains(expected_error),
+            "did not find expected error '{actual_error}' in actual error '{expected_error}'"
         );
+    }
 
-        let i8_expected = vec!["null", "null", "-128", "0", "127", "null", "null"];
-        assert_eq!(
-            i8_expected,
-            get_cast_values::<Int8Type>(&i32_array, &DataType::Int8)
-        );
+    #[test]
+    fn test_cast_primitive_dict() {
+        // FROM a dictionary with of INT32 values
+        use DataType::*;
+
+        let mut builder = PrimitiveDictionaryBuilder::<Int8Type, Int32Type>::new();
+        builder.append(1).unwrap();
+        builder.append_null();
+        builder.append(3).unwrap();
+        let array: ArrayRef = Arc::new(builder.finish());
+
+        let expected = vec!["1", "null", "3"];
+
+        // Test casting TO PrimitiveArray, different dictionary type
+        let cast_array = cast(&array, &Utf8).expect("cast to UTF-8 failed");
+        assert_eq!(array_to_strings(&cast_array), expected);
+        assert_eq!(cast_array.data_type(), &Utf8);
+
+        let cast_array = cast(&array, &Int64).expect("cast to int64 failed");
+        assert_eq!(array_to_strings(&cast_array), expected);
+        assert_eq!(cast_array.data_type(), &Int64);
+    }
+
+    #[test]
+    fn test_cast_primitive_array_to_dict() {
+        use DataType::*;
+
+        let mut builder = PrimitiveBuilder::<Int32Type>::new();
+        builder.append_value(1);
+        builder.append_null();
+        builder.append_value(3);
+        let array: ArrayRef = Arc::new(builder.finish());
+
+        let expected = vec!["1", "null", "3"];
+
+        // Cast to a dictionary (same value type, Int32)
+        let cast_type = Dictionary(Box::new(UInt8), Box::new(Int32));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
+
+        // Cast to a dictionary (different value type, Int8)
+        let cast_type = Dictionary(Box::new(UInt8), Box::new(Int8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
+    }
+
+    #[test]
+    fn test_cast_string_array_to_dict() {
+        use DataType::*;
+
+        let array = Arc::new(StringArray::from(vec![Some("one"), None, Some("three")]))
+            as ArrayRef;
+
+        let expected = vec!["one", "null", "three"];
+
+        // Cast to a dictionary (same value type, Utf8)
+        let cast_type = Dictionary(Box::new(UInt8), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(array_to_strings(&cast_array), expected);
+    }
+
+    #[test]
+    fn test_cast_null_array_to_from_decimal_array() {
+        let data_type = DataType::Decimal128(12, 4);
+        let array = new_null_array(&DataType::Null, 4);
+        assert_eq!(array.data_type(), &DataType::Null);
+        let cast_array = cast(&array, &data_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &data_type);
+        for i in 0..4 {
+            assert!(cast_array.is_null(i));
+        }
+
+        let array = new_null_array(&data_type, 4);
+        assert_eq!(array.data_type(), &data_type);
+        let cast_array = cast(&array, &DataType::Null).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &DataType::Null);
+        for i in 0..4 {
+            assert!(cast_array.is_null(i));
+        }
+    }
+
+    #[test]
+    fn test_cast_null_array_from_and_to_primitive_array() {
+        macro_rules! typed_test {
+            ($ARR_TYPE:ident, $DATATYPE:ident, $TYPE:tt) => {{
+                {
+                    let array = Arc::new(NullArray::new(6)) as ArrayRef;
+                    let expected = $ARR_TYPE::from(vec![None; 6]);
+                    let cast_type = DataType::$DATATYPE;
+                    let cast_array = cast(&array, &cast_type).expect("cast failed");
+                    let cast_array = cast_array.as_primitive::<$TYPE>();
+                    assert_eq!(cast_array.data_type(), &cast_type);
+                    assert_eq!(cast_array, &expected);
+                }
+            }};
+        }
 
-        let u64_expected =
-            vec!["null", "null", "null", "0", "127", "32767", "2147483647"];
-        assert_eq!(
-            u64_expected,
-            get_cast_values::<UInt64Type>(&i32_array, &DataType::UInt64)
-        );
+        typed_test!(Int16Array, Int16, Int16Type);
+        typed_test!(Int32Array, Int32, Int32Type);
+        typed_test!(Int64Array, Int64, Int64Type);
 
-        let u32_expected =
-            vec!["null", "null", "null", "0", "127", "32767", "2147483647"];
-        assert_eq!(
-            u32_expected,
-            get_cast_values::<UInt32Type>(&i32_array, &DataType::UInt32)
-        );
+        typed_test!(UInt16Array, UInt16, UInt16Type);
+        typed_test!(UInt32Array, UInt32, UInt32Type);
+        typed_test!(UInt64Array, UInt64, UInt64Type);
 
-        let u16_expected = vec!["null", "null", "null", "0", "127", "32767", "null"];
-        assert_eq!(
-            u16_expected,
-            get_cast_values::<UInt16Type>(&i32_array, &DataType::UInt16)
-        );
+        typed_test!(Float32Array, Float32, Float32Type);
+        typed_test!(Float64Array, Float64, Float64Type);
 
-        let u8_expected = vec!["null", "null", "null", "0", "127", "null", "null"];
-        assert_eq!(
-            u8_expected,
-            get_cast_values::<UInt8Type>(&i32_array, &DataType::UInt8)
-        );
+        typed_test!(Date32Array, Date32, Date32Type);
+        typed_test!(Date64Array, Date64, Date64Type);
+    }
 
-        // The date32 to date64 cast increases the numerical values in order to keep the same dates.
-        let i64_expected = vec![
-            "-185542587187200000",
-            "-2831155200000",
-            "-11059200000",
-            "0",
-            "10972800000",
-            "2831068800000",
-            "185542587100800000",
-        ];
-        assert_eq!(
-            i64_expected,
-            get_cast_values::<Date64Type>(&i32_array, &DataType::Date64)
-        );
+    fn cast_from_null_to_other(data_type: &DataType) {
+        // Cast from null to data_type
+        {
+            let array = new_null_array(&DataType::Null, 4);
+            assert_eq!(array.data_type(), &DataType::Null);
+            let cast_array = cast(&array, data_type).expect("cast failed");
+            assert_eq!(cast_array.data_type(), data_type);
+            for i in 0..4 {
+                assert!(cast_array.is_null(i));
+            }
+        }
     }
 
     #[test]
-    fn test_cast_from_int16() {
-        let i16_values: Vec<i16> =
-            vec![i16::MIN, i8::MIN as i16, 0, i8::MAX as i16, i16::MAX];
-        let i16_array: ArrayRef = Arc::new(Int16Array::from(i16_values));
+    fn test_cast_null_from_and_to_variable_sized() {
+        cast_from_null_to_other(&DataType::Utf8);
+        cast_from_null_to_other(&DataType::LargeUtf8);
+        cast_from_null_to_other(&DataType::Binary);
+        cast_from_null_to_other(&DataType::LargeBinary);
+    }
 
-        let f64_expected = vec!["-32768.0", "-128.0", "0.0", "127.0", "32767.0"];
-        assert_eq!(
-            f64_expected,
-            get_cast_values::<Float64Type>(&i16_array, &DataType::Float64)
+    #[test]
+    fn test_cast_null_from_and_to_nested_type() {
+        // Cast null from and to map
+        let data_type = DataType::Map(
+            Arc::new(Field::new_struct(
+                "entry",
+                vec![
+                    Field::new("key", DataType::Utf8, false),
+                    Field::new("value", DataType::Int32, true),
+                ],
+                false,
+            )),
+            false,
         );
+        cast_from_null_to_other(&data_type);
 
-        let f32_expected = vec!["-32768.0", "-128.0", "0.0", "127.0", "32767.0"];
-        assert_eq!(
-            f32_expected,
-            get_cast_values::<Float32Type>(&i16_array, &DataType::Float32)
+        // Cast null from and to list
+        let data_type =
+            DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
+        cast_from_null_to_other(&data_type);
+        let data_type =
+            DataType::LargeList(Arc::new(Field::new("item", DataType::Int32, true)));
+        cast_from_null_to_other(&data_type);
+        let data_type = DataType::FixedSizeList(
+            Arc::new(Field::new("item", DataType::Int32, true)),
+            4,
         );
+        cast_from_null_to_other(&data_type);
 
-        let i64_expected = vec!["-32768", "-128", "0", "127", "32767"];
-        assert_eq!(
-            i64_expected,
-            get_cast_values::<Int64Type>(&i16_array, &DataType::Int64)
-        );
+        // Cast null from and to dictionary
+        let values = vec![None, None, None, None] as Vec<Option<&str>>;
+        let array: DictionaryArray<Int8Type> = values.into_iter().collect();
+        let array = Arc::new(array) as ArrayRef;
+        let data_type = array.data_type().to_owned();
+        cast_from_null_to_other(&data_type);
 
-        let i32_expected = vec!["-32768", "-128", "0", "127", "32767"];
-        assert_eq!(
-            i32_expected,
-            get_cast_values::<Int32Type>(&i16_array, &DataType::Int32)
-        );
+        // Cast null from and to struct
+        let data_type =
+            DataType::Struct(vec![Field::new("data", DataType::Int64, false)].into());
+        cast_from_null_to_other(&data_type);
+    }
 
-        let i16_expected = vec!["-32768", "-128", "0", "127", "32767"];
-        assert_eq!(
-            i16_expected,
-            get_cast_values::<Int16Type>(&i16_array, &DataType::Int16)
-        );
+    /// Print the `DictionaryArray` `array` as a vector of strings
+    fn array_to_strings(array: &ArrayRef) -> Vec<String> {
+        (0..array.len())
+            .map(|i| {
+                if array.is_null(i) {
+                    "null".to_string()
+                } else {
+                    array_value_to_string(array, i).expect("Convert array to String")
+                }
+            })
+            .collect()
+    }
 
-        let i8_expected = vec!["null", "-128", "0", "127", "null"];
-        assert_eq!(
-            i8_expected,
-            get_cast_values::<Int8Type>(&i16_array, &DataType::Int8)
-        );
+    #[test]
+    fn test_cast_utf8_to_date32() {
+        use chrono::NaiveDate;
+        let from_ymd = chrono::NaiveDate::from_ymd_opt;
+        let since = chrono::NaiveDate::signed_duration_since;
 
-        let u64_expected = vec!["null", "null", "0", "127", "32767"];
-        assert_eq!(
-            u64_expected,
-            get_cast_values::<UInt64Type>(&i16_array, &DataType::UInt64)
-        );
+        let a = StringArray::from(vec![
+            "2000-01-01",          // valid date with leading 0s
+            "2000-2-2",            // valid date without leading 0s
+            "2000-00-00",          // invalid month and day
+            "2000-01-01T12:00:00", // date + time is invalid
+            "2000",                // just a year is invalid
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Date32).unwrap();
+        let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
 
-        let u32_expected = vec!["null", "null", "0", "127", "32767"];
-        assert_eq!(
-            u32_expected,
-            get_cast_values::<UInt32Type>(&i16_array, &DataType::UInt32)
-        );
+        // test valid inputs
+        let date_value = since(
+            NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+            from_ymd(1970, 1, 1).unwrap(),
+        )
+        .num_days() as i32;
+        assert!(c.is_valid(0)); // "2000-01-01"
+        assert_eq!(date_value, c.value(0));
 
-        let u16_expected = vec!["null", "null", "0", "127", "32767"];
-        assert_eq!(
-            u16_expected,
-            get_cast_values::<UInt16Type>(&i16_array, &DataType::UInt16)
-        );
+        let date_value = since(
+            NaiveDate::from_ymd_opt(2000, 2, 2).unwrap(),
+            from_ymd(1970, 1, 1).unwrap(),
+        )
+        .num_days() as i32;
+        assert!(c.is_valid(1)); // "2000-2-2"
+        assert_eq!(date_value, c.value(1));
 
-        let u8_expected = vec!["null", "null", "0", "127", "null"];
-        assert_eq!(
-            u8_expected,
-            get_cast_values::<UInt8Type>(&i16_array, &DataType::UInt8)
-        );
+        // test invalid inputs
+        assert!(!c.is_valid(2)); // "2000-00-00"
+        assert!(!c.is_valid(3)); // "2000-01-01T12:00:00"
+        assert!(!c.is_valid(4)); // "2000"
     }
 
     #[test]
-    fn test_cast_from_date32() {
-        let i32_values: Vec<i32> = vec![
-            i32::MIN,
-            i16::MIN as i32,
-            i8::MIN as i32,
-            0,
-            i8::MAX as i32,
-            i16::MAX as i32,
-            i32::MAX,
-        ];
-        let date32_array: ArrayRef = Arc::new(Date32Array::from(i32_values));
+    fn test_cast_utf8_to_date64() {
+        let a = StringArray::from(vec![
+            "2000-01-01T12:00:00", // date + time valid
+            "2020-12-15T12:34:56", // date + time valid
+            "2020-2-2T12:34:56",   // valid date time without leading 0s
+            "2000-00-00T12:00:00", // invalid month and day
+            "2000-01-01 12:00:00", // missing the 'T'
+            "2000-01-01",          // just a date is invalid
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Date64).unwrap();
+        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
+
+        // test valid inputs
+        assert!(c.is_valid(0)); // "2000-01-01T12:00:00"
+        assert_eq!(946728000000, c.value(0));
+        assert!(c.is_valid(1)); // "2020-12-15T12:34:56"
+        assert_eq!(1608035696000, c.value(1));
+        assert!(c.is_valid(2)); // "2020-2-2T12:34:56"
+        assert_eq!(1580646896000, c.value(2));
+
+        // test invalid inputs
+        assert!(!c.is_valid(3)); // "2000-00-00T12:00:00"
+        assert!(!c.is_valid(4)); // "2000-01-01 12:00:00"
+        assert!(!c.is_valid(5)); // "2000-01-01"
+    }
+
+    #[test]
+    fn test_cast_list_containers() {
+        // large-list to list
+        let array = Arc::new(make_large_list_array()) as ArrayRef;
+        let list_array = cast(
+            &array,
+            &DataType::List(Arc::new(Field::new("", DataType::Int32, false))),
+        )
+        .unwrap();
+        let actual = list_array.as_any().downcast_ref::<ListArray>().unwrap();
+        let expected = array.as_any().downcast_ref::<LargeListArray>().unwrap();
+
+        assert_eq!(&expected.value(0), &actual.value(0));
+        assert_eq!(&expected.value(1), &actual.value(1));
+        assert_eq!(&expected.value(2), &actual.value(2));
+
+        // list to large-list
+        let array = Arc::new(make_list_array()) as ArrayRef;
+        let large_list_array = cast(
+            &array,
+            &DataType::LargeList(Arc::new(Field::new("", DataType::Int32, false))),
+        )
+        .unwrap();
+        let actual = large_list_array
+            .as_any()
+            .downcast_ref::<LargeListArray>()
+            .unwrap();
+        let expected = array.as_any().downcast_ref::<ListArray>().unwrap();
 
-        let i64_expected = vec![
-            "-2147483648",
-            "-32768",
-            "-128",
-            "0",
-            "127",
-            "32767",
-            "2147483647",
-        ];
-        assert_eq!(
-            i64_expected,
-            get_cast_values::<Int64Type>(&date32_array, &DataType::Int64)
-        );
+        assert_eq!(&expected.value(0), &actual.value(0));
+        assert_eq!(&expected.value(1), &actual.value(1));
+        assert_eq!(&expected.value(2), &actual.value(2));
     }
 
-    #[test]
-    fn test_cast_from_int8() {
-        let i8_values: Vec<i8> = vec![i8::MIN, 0, i8::MAX];
-        let i8_array = Int8Array::from(i8_values);
-
-        let f64_expected = vec!["-128.0", "0.0", "127.0"];
-        assert_eq!(
-            f64_expected,
-            get_cast_values::<Float64Type>(&i8_array, &DataType::Float64)
-        );
+    fn make_list_array() -> ListArray {
+        // Construct a value array
+        let value_data = ArrayData::builder(DataType::Int32)
+            .len(8)
+            .add_buffer(Buffer::from_slice_ref([0, 1, 2, 3, 4, 5, 6, 7]))
+            .build()
+            .unwrap();
 
-        let f32_expected = vec!["-128.0", "0.0", "127.0"];
-        assert_eq!(
-            f32_expected,
-            get_cast_values::<Float32Type>(&i8_array, &DataType::Float32)
-        );
+        // Construct a buffer for value offsets, for the nested array:
+        //  [[0, 1, 2], [3, 4, 5], [6, 7]]
+        let value_offsets = Buffer::from_slice_ref([0, 3, 6, 8]);
 
-        let i64_expected = vec!["-128", "0", "127"];
-        assert_eq!(
-            i64_expected,
-            get_cast_values::<Int64Type>(&i8_array, &DataType::Int64)
-        );
+        // Construct a list array from the above two
+        let list_data_type =
+            DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
+        let list_data = ArrayData::builder(list_data_type)
+            .len(3)
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build()
+            .unwrap();
+        ListArray::from(list_data)
+    }
 
-        let i32_expected = vec!["-128", "0", "127"];
-        assert_eq!(
-            i32_expected,
-            get_cast_values::<Int32Type>(&i8_array, &DataType::Int32)
-        );
+    fn make_large_list_array() -> LargeListArray {
+        // Construct a value array
+        let value_data = ArrayData::builder(DataType::Int32)
+            .len(8)
+            .add_buffer(Buffer::from_slice_ref([0, 1, 2, 3, 4, 5, 6, 7]))
+            .build()
+            .unwrap();
 
-        let i16_expected = vec!["-128", "0", "127"];
-        assert_eq!(
-            i16_expected,
-            get_cast_values::<Int16Type>(&i8_array, &DataType::Int16)
-        );
+        // Construct a buffer for value offsets, for the nested array:
+        //  [[0, 1, 2], [3, 4, 5], [6, 7]]
+        let value_offsets = Buffer::from_slice_ref([0i64, 3, 6, 8]);
 
-        let i8_expected = vec!["-128", "0", "127"];
-        assert_eq!(
-            i8_expected,
-            get_cast_values::<Int8Type>(&i8_array, &DataType::Int8)
-        );
+        // Construct a list array from the above two
+        let list_data_type =
+            DataType::LargeList(Arc::new(Field::new("item", DataType::Int32, true)));
+        let list_data = ArrayData::builder(list_data_type)
+            .len(3)
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build()
+            .unwrap();
+        LargeListArray::from(list_data)
+    }
 
-        let u64_expected = vec!["null", "0", "127"];
-        assert_eq!(
-            u64_expected,
-            get_cast_values::<UInt64Type>(&i8_array, &DataType::UInt64)
-        );
+    #[test]
+    fn test_utf8_cast_offsets() {
+        // test if offset of the array is taken into account during cast
+        let str_array = StringArray::from(vec!["a", "b", "c"]);
+        let str_array = str_array.slice(1, 2);
 
-        let u32_expected = vec!["null", "0", "127"];
-        assert_eq!(
-            u32_expected,
-            get_cast_values::<UInt32Type>(&i8_array, &DataType::UInt32)
-        );
+        let out = cast(&str_array, &DataType::LargeUtf8).unwrap();
 
-        let u16_expected = vec!["null", "0", "127"];
-        assert_eq!(
-            u16_expected,
-            get_cast_values::<UInt16Type>(&i8_array, &DataType::UInt16)
-        );
+        let large_str_array = out.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        let strs = large_str_array.into_iter().flatten().collect::<Vec<_>>();
+        assert_eq!(strs, &["b", "c"])
+    }
 
-        let u8_expected = vec!["null", "0", "127"];
+    #[test]
+    fn test_byte_cast_slice_memory() {
+        let array: StringArray = (0..1000)
+            .map(|i| Some(format!("{i:0>100}")).filter(|_| i % 7 != 0))
+            .collect();
+        let full_size = array.get_buffer_memory_size();
+        assert!(full_size > 100_000);
+
+        // Casting a slice only retains the values of the slice
+        let sliced = array.slice(500, 10);
+        let out = cast(&sliced, &DataType::LargeUtf8).unwrap();
+        assert!(out.get_buffer_memory_size() < 2_000);
+        assert_eq!(out.as_string::<i64>().value_offsets()[0], 0);
+        let expected: LargeStringArray = sliced.iter().collect();
+        assert_eq!(out.as_string::<i64>(), &expected);
+
+        let back = cast(&out.slice(2, 5), &DataType::Utf8).unwrap();
+        let expected: StringArray = sliced.slice(2, 5).iter().collect();
+        assert_eq!(back.as_string::<i32>(), &expected);
+
+        let binary = cast(&array, &DataType::Binary).unwrap();
+        let sliced = binary.slice(10, 3);
+        let out = cast(&sliced, &DataType::LargeBinary).unwrap();
+        assert!(out.get_buffer_memory_size() < 1_000);
         assert_eq!(
-            u8_expected,
-            get_cast_values::<UInt8Type>(&i8_array, &DataType::UInt8)
+            out.as_binary::<i64>().value(0),
+            sliced.as_binary::<i32>().value(0)
         );
-    }
 
-    /// Convert `array` into a vector of strings by casting to data type dt
-    fn get_cast_values<T>(array: &dyn Array, dt: &DataType) -> Vec<String>
-    where
-        T: ArrowPrimitiveType,
-    {
-        let c = cast(array, dt).unwrap();
-        let a = c.as_primitive::<T>();
-        let mut v: Vec<String> = vec![];
-        for i in 0..array.len() {
-            if a.is_null(i) {
-                v.push("null".to_string())
-            } else {
-                v.push(format!("{:?}", a.value(i)));
-            }
-        }
-        v
+        // Casting a whole array shares its values
+        let out = cast(&array, &DataType::LargeUtf8).unwrap();
+        let out = out.as_string::<i64>();
+        assert_eq!(out.values().as_ptr(), array.values().as_ptr());
+        assert_eq!(out.null_count(), array.null_count());
     }
 
     #[test]
-    fn test_cast_utf8_dict() {
-        // FROM a dictionary with of Utf8 values
-        use DataType::*;
-
-        let mut builder = StringDictionaryBuilder::<Int8Type>::new();
-        builder.append("one").unwrap();
-        builder.append_null();
-        builder.append("three").unwrap();
-        let array: ArrayRef = Arc::new(builder.finish());
-
-        let expected = vec!["one", "null", "three"];
-
-        // Test casting TO StringArray
-        let cast_type = Utf8;
-        let cast_array = cast(&array, &cast_type).expect("cast to UTF-8 failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+    fn test_list_cast_offsets() {
+        // test if offset of the array is taken into account during cast
+        let array1 = make_list_array().slice(1, 2);
+        let array2 = Arc::new(make_list_array()) as ArrayRef;
 
-        // Test casting TO Dictionary (with different index sizes)
+        let dt = DataType::LargeList(Arc::new(Field::new("item", DataType::Int32, true)));
+        let out1 = cast(&array1, &dt).unwrap();
+        let out2 = cast(&array2, &dt).unwrap();
 
-        let cast_type = Dictionary(Box::new(Int16), Box::new(Utf8));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+        assert_eq!(&out1, &out2.slice(1, 2))
+    }
 
-        let cast_type = Dictionary(Box::new(Int32), Box::new(Utf8));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+    #[test]
+    fn test_list_to_string() {
+        let str_array = StringArray::from(vec!["a", "b", "c", "d", "e", "f", "g", "h"]);
+        let value_offsets = Buffer::from_slice_ref([0, 3, 6, 8]);
+        let value_data = str_array.into_data();
 
-        let cast_type = Dictionary(Box::new(Int64), Box::new(Utf8));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+        let list_data_type =
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true)));
+        let list_data = ArrayData::builder(list_data_type)
+            .len(3)
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build()
+            .unwrap();
+        let array = Arc::new(ListArray::from(list_data)) as ArrayRef;
 
-        let cast_type = Dictionary(Box::new(UInt8), Box::new(Utf8));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+        let out = cast(&array, &DataType::Utf8).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        assert_eq!(&out, &vec!["[a, b, c]", "[d, e, f]", "[g, h]"]);
 
-        let cast_type = Dictionary(Box::new(UInt16), Box::new(Utf8));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+        let out = cast(&array, &DataType::LargeUtf8).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<LargeStringArray>()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        assert_eq!(&out, &vec!["[a, b, c]", "[d, e, f]", "[g, h]"]);
 
-        let cast_type = Dictionary(Box::new(UInt32), Box::new(Utf8));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+        let array = Arc::new(make_list_array()) as ArrayRef;
+        let out = cast(&array, &DataType::Utf8).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        assert_eq!(&out, &vec!["[0, 1, 2]", "[3, 4, 5]", "[6, 7]"]);
 
-        let cast_type = Dictionary(Box::new(UInt64), Box::new(Utf8));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+        let array = Arc::new(make_large_list_array()) as ArrayRef;
+        let out = cast(&array, &DataType::LargeUtf8).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<LargeStringArray>()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        assert_eq!(&out, &vec!["[0, 1, 2]", "[3, 4, 5]", "[6, 7]"]);
     }
 
     #[test]
-    fn test_cast_dict_to_dict_bad_index_value_primitive() {
-        use DataType::*;
-        // test converting from an array that has indexes of a type
-        // that are out of bounds for a particular other kind of
-        // index.
-
-        let mut builder = PrimitiveDictionaryBuilder::<Int32Type, Int64Type>::new();
+    fn test_cast_f64_to_decimal128() {
+        // to reproduce https://github.com/apache/arrow-rs/issues/2997
 
-        // add 200 distinct values (which can be stored by a
-        // dictionary indexed by int32, but not a dictionary indexed
-        // with int8)
-        for i in 0..200 {
-            builder.append(i).unwrap();
-        }
-        let array: ArrayRef = Arc::new(builder.finish());
+        let decimal_type = DataType::Decimal128(18, 2);
+        let array = Float64Array::from(vec![
+            Some(0.0699999999),
+            Some(0.0659999999),
+            Some(0.0650000000),
+            Some(0.0649999999),
+        ]);
+        let array = Arc::new(array) as ArrayRef;
+        generate_cast_test_case!(
+            &array,
+            Decimal128Array,
+            &decimal_type,
+            vec![
+                Some(7_i128), // round up
+                Some(7_i128), // round up
+                Some(7_i128), // round up
+                Some(6_i128), // round down
+            ]
+        );
 
-        let cast_type = Dictionary(Box::new(Int8), Box::new(Utf8));
-        let res = cast(&array, &cast_type);
-        assert!(res.is_err());
-        let actual_error = format!("{res:?}");
-        let expected_error = "Could not convert 72 dictionary indexes from Int32 to Int8";
-        assert!(
-            actual_error.contains(expected_error),
-            "did not find expected error '{actual_error}' in actual error '{expected_error}'"
+        let decimal_type = DataType::Decimal128(18, 3);
+        let array = Float64Array::from(vec![
+            Some(0.0699999999),
+            Some(0.0659999999),
+            Some(0.0650000000),
+            Some(0.0649999999),
+        ]);
+        let array = Arc::new(array) as ArrayRef;
+        generate_cast_test_case!(
+            &array,
+            Decimal128Array,
+            &decimal_type,
+            vec![
+                Some(70_i128), // round up
+                Some(66_i128), // round up
+                Some(65_i128), // round down
+                Some(65_i128), // round up
+            ]
         );
     }
 
     #[test]
-    fn test_cast_dict_to_dict_bad_index_value_utf8() {
-        use DataType::*;
-        // Same test as test_cast_dict_to_dict_bad_index_value but use
-        // string values (and encode the expected behavior here);
-
-        let mut builder = StringDictionaryBuilder::<Int32Type>::new();
-
-        // add 200 distinct values (which can be stored by a
-        // dictionary indexed by int32, but not a dictionary indexed
-        // with int8)
-        for i in 0..200 {
-            let val = format!("val{i}");
-            builder.append(&val).unwrap();
-        }
-        let array = builder.finish();
+    fn test_cast_numeric_to_decimal128_overflow() {
+        let array = Int64Array::from(vec![i64::MAX]);
+        let array = Arc::new(array) as ArrayRef;
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Decimal128(38, 30),
+            &CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        assert!(casted_array.is_ok());
+        assert!(casted_array.unwrap().is_null(0));
 
-        let cast_type = Dictionary(Box::new(Int8), Box::new(Utf8));
-        let res = cast(&array, &cast_type);
-        assert!(res.is_err());
-        let actual_error = format!("{res:?}");
-        let expected_error = "Could not convert 72 dictionary indexes from Int32 to Int8";
-        assert!(
-            actual_error.contains(expected_error),
-            "did not find expected error '{actual_error}' in actual error '{expected_error}'"
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Decimal128(38, 30),
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
+        assert!(casted_array.is_err());
     }
 
     #[test]
-    fn test_cast_primitive_dict() {
-        // FROM a dictionary with of INT32 values
-        use DataType::*;
-
-        let mut builder = PrimitiveDictionaryBuilder::<Int8Type, Int32Type>::new();
-        builder.append(1).unwrap();
-        builder.append_null();
-        builder.append(3).unwrap();
-        let array: ArrayRef = Arc::new(builder.finish());
-
-        let expected = vec!["1", "null", "3"];
-
-        // Test casting TO PrimitiveArray, different dictionary type
-        let cast_array = cast(&array, &Utf8).expect("cast to UTF-8 failed");
-        assert_eq!(array_to_strings(&cast_array), expected);
-        assert_eq!(cast_array.data_type(), &Utf8);
+    fn test_cast_numeric_to_decimal256_overflow() {
+        let array = Int64Array::from(vec![i64::MAX]);
+        let array = Arc::new(array) as ArrayRef;
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Decimal256(76, 76),
+            &CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        assert!(casted_array.is_ok());
+        assert!(casted_array.unwrap().is_null(0));
 
-        let cast_array = cast(&array, &Int64).expect("cast to int64 failed");
-        assert_eq!(array_to_strings(&cast_array), expected);
-        assert_eq!(cast_array.data_type(), &Int64);
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Decimal256(76, 76),
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        assert!(casted_array.is_err());
     }
 
     #[test]
-    fn test_cast_primitive_array_to_dict() {
-        use DataType::*;
-
-        let mut builder = PrimitiveBuilder::<Int32Type>::new();
-        builder.append_value(1);
-        builder.append_null();
-        builder.append_value(3);
-        let array: ArrayRef = Arc::new(builder.finish());
+    fn test_cast_floating_point_to_decimal128_overflow() {
+        let array = Float64Array::from(vec![f64::MAX]);
+        let array = Arc::new(array) as ArrayRef;
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Decimal128(38, 30),
+            &CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        assert!(casted_array.is_ok());
+        assert!(casted_array.unwrap().is_null(0));
 
-        let expected = vec!["1", "null", "3"];
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Decimal128(38, 30),
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        let err = casted_array.unwrap_err().to_string();
+        let expected_error = "Cast error: Cannot cast to Decimal128(38, 30)";
+        assert!(
+            err.contains(expected_error),
+            "did not find expected error '{expected_error}' in actual error '{err}'"
+        );
+    }
 
-        // Cast to a dictionary (same value type, Int32)
-        let cast_type = Dictionary(Box::new(UInt8), Box::new(Int32));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+    #[test]
+    fn test_cast_floating_point_to_decimal256_overflow() {
+        let array = Float64Array::from(vec![f64::MAX]);
+        let array = Arc::new(array) as ArrayRef;
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Decimal256(76, 50),
+            &CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        assert!(casted_array.is_ok());
+        assert!(casted_array.unwrap().is_null(0));
 
-        // Cast to a dictionary (different value type, Int8)
-        let cast_type = Dictionary(Box::new(UInt8), Box::new(Int8));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Decimal256(76, 50),
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
+        let err = casted_array.unwrap_err().to_string();
+        let expected_error = "Cast error: Cannot cast to Decimal256(76, 50)";
+        assert!(
+            err.contains(expected_error),
+            "did not find expected error '{expected_error}' in actual error '{err}'"
+        );
     }
 
     #[test]
-    fn test_cast_string_array_to_dict() {
-        use DataType::*;
-
-        let array = Arc::new(StringArray::from(vec![Some("one"), None, Some("three")]))
-            as ArrayRef;
+    fn test_cast_decimal128_to_decimal128_negative_scale() {
+        let input_type = DataType::Decimal128(20, 0);
+        let output_type = DataType::Decimal128(20, -1);
+        assert!(can_cast_types(&input_type, &output_type));
+        let array = vec![Some(1123450), Some(2123455), Some(3123456), None];
+        let input_decimal_array = create_decimal_array(array, 20, 0).unwrap();
+        let array = Arc::new(input_decimal_array) as ArrayRef;
+        generate_cast_test_case!(
+            &array,
+            Decimal128Array,
+            &output_type,
+            vec![
+                Some(112345_i128),
+                Some(212346_i128),
+                Some(312346_i128),
+                None
+            ]
+        );
 
-        let expected = vec!["one", "null", "three"];
+        let casted_array = cast(&array, &output_type).unwrap();
+        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
 
-        // Cast to a dictionary (same value type, Utf8)
-        let cast_type = Dictionary(Box::new(UInt8), Box::new(Utf8));
-        let cast_array = cast(&array, &cast_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &cast_type);
-        assert_eq!(array_to_strings(&cast_array), expected);
+        assert_eq!("1123450", decimal_arr.value_as_string(0));
+        assert_eq!("2123460", decimal_arr.value_as_string(1));
+        assert_eq!("3123460", decimal_arr.value_as_string(2));
     }
 
     #[test]
-    fn test_cast_null_array_to_from_decimal_array() {
-        let data_type = DataType::Decimal128(12, 4);
-        let array = new_null_array(&DataType::Null, 4);
-        assert_eq!(array.data_type(), &DataType::Null);
-        let cast_array = cast(&array, &data_type).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &data_type);
-        for i in 0..4 {
-            assert!(cast_array.is_null(i));
-        }
+    fn test_cast_numeric_to_decimal128_negative() {
+        let decimal_type = DataType::Decimal128(38, -1);
+        let array = Arc::new(Int32Array::from(vec![
+            Some(1123456),
+            Some(2123456),
+            Some(3123456),
+        ])) as ArrayRef;
+
+        let casted_array = cast(&array, &decimal_type).unwrap();
+        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
+
+        assert_eq!("1123450", decimal_arr.value_as_string(0));
+        assert_eq!("2123450", decimal_arr.value_as_string(1));
+        assert_eq!("3123450", decimal_arr.value_as_string(2));
 
-        let array = new_null_array(&data_type, 4);
-        assert_eq!(array.data_type(), &data_type);
-        let cast_array = cast(&array, &DataType::Null).expect("cast failed");
-        assert_eq!(cast_array.data_type(), &DataType::Null);
-        for i in 0..4 {
-            assert!(cast_array.is_null(i));
-        }
+        let array = Arc::new(Float32Array::from(vec![
+            Some(1123.456),
+            Some(2123.456),
+            Some(3123.456),
+        ])) as ArrayRef;
+
+        let casted_array = cast(&array, &decimal_type).unwrap();
+        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
+
+        assert_eq!("1120", decimal_arr.value_as_string(0));
+        assert_eq!("2120", decimal_arr.value_as_string(1));
+        assert_eq!("3120", decimal_arr.value_as_string(2));
     }
 
     #[test]
-    fn test_cast_null_array_from_and_to_primitive_array() {
-        macro_rules! typed_test {
-            ($ARR_TYPE:ident, $DATATYPE:ident, $TYPE:tt) => {{
-                {
-                    let array = Arc::new(NullArray::new(6)) as ArrayRef;
-                    let expected = $ARR_TYPE::from(vec![None; 6]);
-                    let cast_type = DataType::$DATATYPE;
-                    let cast_array = cast(&array, &cast_type).expect("cast failed");
-                    let cast_array = cast_array.as_primitive::<$TYPE>();
-                    assert_eq!(cast_array.data_type(), &cast_type);
-                    assert_eq!(cast_array, &expected);
-                }
-            }};
-        }
+    fn test_cast_decimal128_to_decimal128_negative() {
+        let input_type = DataType::Decimal128(10, -1);
+        let output_type = DataType::Decimal128(10, -2);
+        assert!(can_cast_types(&input_type, &output_type));
+        let array = vec![Some(123)];
+        let input_decimal_array = create_decimal_array(array, 10, -1).unwrap();
+        let array = Arc::new(input_decimal_array) as ArrayRef;
+        generate_cast_test_case!(
+            &array,
+            Decimal128Array,
+            &output_type,
+            vec![Some(12_i128),]
+        );
 
-        typed_test!(Int16Array, Int16, Int16Type);
-        typed_test!(Int32Array, Int32, Int32Type);
-        typed_test!(Int64Array, Int64, Int64Type);
+        let casted_array = cast(&array, &output_type).unwrap();
+        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
 
-        typed_test!(UInt16Array, UInt16, UInt16Type);
-        typed_test!(UInt32Array, UInt32, UInt32Type);
-        typed_test!(UInt64Array, UInt64, UInt64Type);
+        assert_eq!("1200", decimal_arr.value_as_string(0));
 
-        typed_test!(Float32Array, Float32, Float32Type);
-        typed_test!(Float64Array, Float64, Float64Type);
+        let array = vec![Some(125)];
+        let input_decimal_array = create_decimal_array(array, 10, -1).unwrap();
+        let array = Arc::new(input_decimal_array) as ArrayRef;
+        generate_cast_test_case!(
+            &array,
+            Decimal128Array,
+            &output_type,
+            vec![Some(13_i128),]
+        );
 
-        typed_test!(Date32Array, Date32, Date32Type);
-        typed_test!(Date64Array, Date64, Date64Type);
-    }
+        let casted_array = cast(&array, &output_type).unwrap();
+        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
 
-    fn cast_from_null_to_other(data_type: &DataType) {
-        // Cast from null to data_type
-        {
-            let array = new_null_array(&DataType::Null, 4);
-            assert_eq!(array.data_type(), &DataType::Null);
-            let cast_array = cast(&array, data_type).expect("cast failed");
-            assert_eq!(cast_array.data_type(), data_type);
-            for i in 0..4 {
-                assert!(cast_array.is_null(i));
-            }
-        }
+        assert_eq!("1300", decimal_arr.value_as_string(0));
     }
 
     #[test]
-    fn test_cast_null_from_and_to_variable_sized() {
-        cast_from_null_to_other(&DataType::Utf8);
-        cast_from_null_to_other(&DataType::LargeUtf8);
-        cast_from_null_to_other(&DataType::Binary);
-        cast_from_null_to_other(&DataType::LargeBinary);
+    fn test_cast_decimal128_to_decimal256_negative() {
+        let input_type = DataType::Decimal128(10, 3);
+        let output_type = DataType::Decimal256(10, 5);
+        assert!(can_cast_types(&input_type, &output_type));
+        let array = vec![Some(i128::MAX), Some(i128::MIN)];
+        let input_decimal_array = create_decimal_array(array, 10, 3).unwrap();
+        let array = Arc::new(input_decimal_array) as ArrayRef;
+
+        let hundred = i256::from_i128(100);
+        generate_cast_test_case!(
+            &array,
+            Decimal256Array,
+            &output_type,
+            vec![
+                Some(i256::from_i128(i128::MAX).mul_wrapping(hundred)),
+                Some(i256::from_i128(i128::MIN).mul_wrapping(hundred))
+            ]
+        );
     }
 
     #[test]
-    fn test_cast_null_from_and_to_nested_type() {
-        // Cast null from and to map
-        let data_type = DataType::Map(
-            Arc::new(Field::new_struct(
-                "entry",
-                vec![
-                    Field::new("key", DataType::Utf8, false),
-                    Field::new("value", DataType::Int32, true),
-                ],
-                false,
-            )),
-            false,
+    fn test_parse_string_to_decimal() {
+        assert_eq!(
+            Decimal128Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal128Type>(
+                    "123.45",
+                    2,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                2,
+            ),
+            "123.45"
+        );
+        assert_eq!(
+            Decimal128Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal128Type>(
+                    "12345",
+                    2,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                2,
+            ),
+            "12345.00"
+        );
+        assert_eq!(
+            Decimal128Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal128Type>(
+                    "0.12345",
+                    2,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                2,
+            ),
+            "0.12"
+        );
+        assert_eq!(
+            Decimal128Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal128Type>(
+                    ".12345",
+                    2,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                2,
+            ),
+            "0.12"
+        );
+        assert_eq!(
+            Decimal128Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal128Type>(
+                    ".1265",
+                    2,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                2,
+            ),
+            "0.13"
+        );
+        assert_eq!(
+            Decimal128Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal128Type>(
+                    ".1265",
+                    2,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                2,
+            ),
+            "0.13"
         );
-        cast_from_null_to_other(&data_type);
 
-        // Cast null from and to list
-        let data_type =
-            DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
-        cast_from_null_to_other(&data_type);
-        let data_type =
-            DataType::LargeList(Arc::new(Field::new("item", DataType::Int32, true)));
-        cast_from_null_to_other(&data_type);
-        let data_type = DataType::FixedSizeList(
-            Arc::new(Field::new("item", DataType::Int32, true)),
-            4,
+        assert_eq!(
+            Decimal256Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal256Type>(
+                    "123.45",
+                    3,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                3,
+            ),
+            "123.450"
         );
-        cast_from_null_to_other(&data_type);
+        assert_eq!(
+            Decimal256Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal256Type>(
+                    "12345",
+                    3,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                3,
+            ),
+            "12345.000"
+        );
+        assert_eq!(
+            Decimal256Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal256Type>(
+                    "0.12345",
+                    3,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                3,
+            ),
+            "0.123"
+        );
+        assert_eq!(
+            Decimal256Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal256Type>(
+                    ".12345",
+                    3,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                3,
+            ),
+            "0.123"
+        );
+        assert_eq!(
+            Decimal256Type::format_decimal(
+                parse_string_to_decimal_native::<Decimal256Type>(
+                    ".1265",
+                    3,
+                    RoundingMode::HalfUp
+                )
+                .unwrap(),
+                38,
+                3,
+            ),
+            "0.127"
+        );
+    }
+
+    fn test_cast_string_to_decimal(array: ArrayRef) {
+        // Decimal128
+        let output_type = DataType::Decimal128(38, 2);
+        assert!(can_cast_types(array.data_type(), &output_type));
+
+        let casted_array = cast(&array, &output_type).unwrap();
+        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
+
+        assert_eq!("123.45", decimal_arr.value_as_string(0));
+        assert_eq!("1.23", decimal_arr.value_as_string(1));
+        assert_eq!("0.12", decimal_arr.value_as_string(2));
+        assert_eq!("0.13", decimal_arr.value_as_string(3));
+        assert_eq!("1.26", decimal_arr.value_as_string(4));
+        assert_eq!("12345.00", decimal_arr.value_as_string(5));
+        assert_eq!("12345.00", decimal_arr.value_as_string(6));
+        assert_eq!("0.12", decimal_arr.value_as_string(7));
+        assert_eq!("12.23", decimal_arr.value_as_string(8));
+        assert!(decimal_arr.is_null(9));
+        assert_eq!("0.00", decimal_arr.value_as_string(10));
+        assert_eq!("0.00", decimal_arr.value_as_string(11));
+        assert!(decimal_arr.is_null(12));
+
+        // Decimal256
+        let output_type = DataType::Decimal256(76, 3);
+        assert!(can_cast_types(array.data_type(), &output_type));
+
+        let casted_array = cast(&array, &output_type).unwrap();
+        let decimal_arr = casted_array.as_primitive::<Decimal256Type>();
+
+        assert_eq!("123.450", decimal_arr.value_as_string(0));
+        assert_eq!("1.235", decimal_arr.value_as_string(1));
+        assert_eq!("0.123", decimal_arr.value_as_string(2));
+        assert_eq!("0.127", decimal_arr.value_as_string(3));
+        assert_eq!("1.263", decimal_arr.value_as_string(4));
+        assert_eq!("12345.000", decimal_arr.value_as_string(5));
+        assert_eq!("12345.000", decimal_arr.value_as_string(6));
+        assert_eq!("0.123", decimal_arr.value_as_string(7));
+        assert_eq!("12.234", decimal_arr.value_as_string(8));
+        assert!(decimal_arr.is_null(9));
+        assert_eq!("0.000", decimal_arr.value_as_string(10));
+        assert_eq!("0.000", decimal_arr.value_as_string(11));
+        assert!(decimal_arr.is_null(12));
+    }
 
-        // Cast null from and to dictionary
-        let values = vec![None, None, None, None] as Vec<Option<&str>>;
-        let array: DictionaryArray<Int8Type> = values.into_iter().collect();
-        let array = Arc::new(array) as ArrayRef;
-        let data_type = array.data_type().to_owned();
-        cast_from_null_to_other(&data_type);
+    #[test]
+    fn test_cast_utf8_to_decimal() {
+        let str_array = StringArray::from(vec![
+            Some("123.45"),
+            Some("1.2345"),
+            Some("0.12345"),
+            Some("0.1267"),
+            Some("1.263"),
+            Some("12345.0"),
+            Some("12345"),
+            Some("000.123"),
+            Some("12.234000"),
+            None,
+            Some(""),
+            Some(" "),
+            None,
+        ]);
+        let array = Arc::new(str_array) as ArrayRef;
 
-        // Cast null from and to struct
-        let data_type =
-            DataType::Struct(vec![Field::new("data", DataType::Int64, false)].into());
-        cast_from_null_to_other(&data_type);
+        test_cast_string_to_decimal(array);
     }
 
-    /// Print the `DictionaryArray` `array` as a vector of strings
-    fn array_to_strings(array: &ArrayRef) -> Vec<String> {
-        (0..array.len())
-            .map(|i| {
-                if array.is_null(i) {
-                    "null".to_string()
-                } else {
-                    array_value_to_string(array, i).expect("Convert array to String")
-                }
-            })
-            .collect()
+    #[test]
+    fn test_cast_large_utf8_to_decimal() {
+        let str_array = LargeStringArray::from(vec![
+            Some("123.45"),
+            Some("1.2345"),
+            Some("0.12345"),
+            Some("0.1267"),
+            Some("1.263"),
+            Some("12345.0"),
+            Some("12345"),
+            Some("000.123"),
+            Some("12.234000"),
+            None,
+            Some(""),
+            Some(" "),
+            None,
+        ]);
+        let array = Arc::new(str_array) as ArrayRef;
+
+        test_cast_string_to_decimal(array);
     }
 
     #[test]
-    fn test_cast_utf8_to_date32() {
-        use chrono::NaiveDate;
-        let from_ymd = chrono::NaiveDate::from_ymd_opt;
-        let since = chrono::NaiveDate::signed_duration_since;
+    fn test_cast_invalid_utf8_to_decimal() {
+        let str_array = StringArray::from(vec!["4.4.5", ". 0.123"]);
+        let array = Arc::new(str_array) as ArrayRef;
 
-        let a = StringArray::from(vec![
-            "2000-01-01",          // valid date with leading 0s
-            "2000-2-2",            // valid date without leading 0s
-            "2000-00-00",          // invalid month and day
-            "2000-01-01T12:00:00", // date + time is invalid
-            "2000",                // just a year is invalid
-        ]);
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Date32).unwrap();
-        let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
+        // Safe cast
+        let output_type = DataType::Decimal128(38, 2);
+        let casted_array = cast(&array, &output_type).unwrap();
+        assert!(casted_array.is_null(0));
+        assert!(casted_array.is_null(1));
 
-        // test valid inputs
-        let date_value = since(
-            NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
-            from_ymd(1970, 1, 1).unwrap(),
-        )
-        .num_days() as i32;
-        assert!(c.is_valid(0)); // "2000-01-01"
-        assert_eq!(date_value, c.value(0));
+        let output_type = DataType::Decimal256(76, 2);
+        let casted_array = cast(&array, &output_type).unwrap();
+        assert!(casted_array.is_null(0));
+        assert!(casted_array.is_null(1));
 
-        let date_value = since(
-            NaiveDate::from_ymd_opt(2000, 2, 2).unwrap(),
-            from_ymd(1970, 1, 1).unwrap(),
-        )
-        .num_days() as i32;
-        assert!(c.is_valid(1)); // "2000-2-2"
-        assert_eq!(date_value, c.value(1));
+        // Non-safe cast
+        let output_type = DataType::Decimal128(38, 2);
+        let str_array = StringArray::from(vec!["4.4.5"]);
+        let array = Arc::new(str_array) as ArrayRef;
+        let option = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
+        assert!(casted_err
+            .to_string()
+            .contains("Cannot cast string '4.4.5' to value of Decimal128(38, 10) type"));
 
-        // test invalid inputs
-        assert!(!c.is_valid(2)); // "2000-00-00"
-        assert!(!c.is_valid(3)); // "2000-01-01T12:00:00"
-        assert!(!c.is_valid(4)); // "2000"
+        let str_array = StringArray::from(vec![". 0.123"]);
+        let array = Arc::new(str_array) as ArrayRef;
+        let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
+        assert!(casted_err.to_string().contains(
+            "Cannot cast string '. 0.123' to value of Decimal128(38, 10) type"
+        ));
     }
 
-    #[test]
-    fn test_cast_utf8_to_date64() {
-        let a = StringArray::from(vec![
-            "2000-01-01T12:00:00", // date + time valid
-            "2020-12-15T12:34:56", // date + time valid
-            "2020-2-2T12:34:56",   // valid date time without leading 0s
-            "2000-00-00T12:00:00", // invalid month and day
-            "2000-01-01 12:00:00", // missing the 'T'
-            "2000-01-01",          // just a date is invalid
-        ]);
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Date64).unwrap();
-        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
-
-        // test valid inputs
-        assert!(c.is_valid(0)); // "2000-01-01T12:00:00"
-        assert_eq!(946728000000, c.value(0));
-        assert!(c.is_valid(1)); // "2020-12-15T12:34:56"
-        assert_eq!(1608035696000, c.value(1));
-        assert!(c.is_valid(2)); // "2020-2-2T12:34:56"
-        assert_eq!(1580646896000, c.value(2));
+    fn test_cast_string_to_decimal128_overflow(overflow_array: ArrayRef) {
+        let output_type = DataType::Decimal128(38, 2);
+        let casted_array = cast(&overflow_array, &output_type).unwrap();
+        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
 
-        // test invalid inputs
-        assert!(!c.is_valid(3)); // "2000-00-00T12:00:00"
-        assert!(!c.is_valid(4)); // "2000-01-01 12:00:00"
-        assert!(!c.is_valid(5)); // "2000-01-01"
+        assert!(decimal_arr.is_null(0));
+        assert!(decimal_arr.is_null(1));
+        assert!(decimal_arr.is_null(2));
+        assert_eq!(
+            "999999999999999999999999999999999999.99",
+            decimal_arr.value_as_string(3)
+        );
+        assert_eq!(
+            "100000000000000000000000000000000000.00",
+            decimal_arr.value_as_string(4)
+        );
     }
 
     #[test]
-    fn test_cast_list_containers() {
-        // large-list to list
-        let array = Arc::new(make_large_list_array()) as ArrayRef;
-        let list_array = cast(
-            &array,
-            &DataType::List(Arc::new(Field::new("", DataType::Int32, false))),
-        )
-        .unwrap();
-        let actual = list_array.as_any().downcast_ref::<ListArray>().unwrap();
-        let expected = array.as_any().downcast_ref::<LargeListArray>().unwrap();
+    fn test_cast_utf8_to_decimal128_overflow() {
+        let overflow_str_array = StringArray::from(vec![
+            i128::MAX.to_string(),
+            i128::MIN.to_string(),
+            "99999999999999999999999999999999999999".to_string(),
+            "999999999999999999999999999999999999.99".to_string(),
+            "99999999999999999999999999999999999.999".to_string(),
+        ]);
+        let overflow_array = Arc::new(overflow_str_array) as ArrayRef;
 
-        assert_eq!(&expected.value(0), &actual.value(0));
-        assert_eq!(&expected.value(1), &actual.value(1));
-        assert_eq!(&expected.value(2), &actual.value(2));
+        test_cast_string_to_decimal128_overflow(overflow_array);
+    }
 
-        // list to large-list
-        let array = Arc::new(make_list_array()) as ArrayRef;
-        let large_list_array = cast(
-            &array,
-            &DataType::LargeList(Arc::new(Field::new("", DataType::Int32, false))),
-        )
-        .unwrap();
-        let actual = large_list_array
-            .as_any()
-            .downcast_ref::<LargeListArray>()
-            .unwrap();
-        let expected = array.as_any().downcast_ref::<ListArray>().unwrap();
+    #[test]
+    fn test_cast_large_utf8_to_decimal128_overflow() {
+        let overflow_str_array = LargeStringArray::from(vec![
+            i128::MAX.to_string(),
+            i128::MIN.to_string(),
+            "99999999999999999999999999999999999999".to_string(),
+            "999999999999999999999999999999999999.99".to_string(),
+            "99999999999999999999999999999999999.999".to_string(),
+        ]);
+        let overflow_array = Arc::new(overflow_str_array) as ArrayRef;
 
-        assert_eq!(&expected.value(0), &actual.value(0));
-        assert_eq!(&expected.value(1), &actual.value(1));
-        assert_eq!(&expected.value(2), &actual.value(2));
+        test_cast_string_to_decimal128_overflow(overflow_array);
     }
 
-    fn make_list_array() -> ListArray {
-        // Construct a value array
-        let value_data = ArrayData::builder(DataType::Int32)
-            .len(8)
-            .add_buffer(Buffer::from_slice_ref([0, 1, 2, 3, 4, 5, 6, 7]))
-            .build()
-            .unwrap();
-
-        // Construct a buffer for value offsets, for the nested array:
-        //  [[0, 1, 2], [3, 4, 5], [6, 7]]
-        let value_offsets = Buffer::from_slice_ref([0, 3, 6, 8]);
+    fn test_cast_string_to_decimal256_overflow(overflow_array: ArrayRef) {
+        let output_type = DataType::Decimal256(76, 2);
+        let casted_array = cast(&overflow_array, &output_type).unwrap();
+        let decimal_arr = casted_array.as_primitive::<Decimal256Type>();
 
-        // Construct a list array from the above two
-        let list_data_type =
-            DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
-        let list_data = ArrayData::builder(list_data_type)
-            .len(3)
-            .add_buffer(value_offsets)
-            .add_child_data(value_data)
-            .build()
-            .unwrap();
-        ListArray::from(list_data)
+        assert_eq!(
+            "170141183460469231731687303715884105727.00",
+            decimal_arr.value_as_string(0)
+        );
+        assert_eq!(
+            "-170141183460469231731687303715884105728.00",
+            decimal_arr.value_as_string(1)
+        );
+        assert_eq!(
+            "99999999999999999999999999999999999999.00",
+            decimal_arr.value_as_string(2)
+        );
+        assert_eq!(
+            "999999999999999999999999999999999999.99",
+            decimal_arr.value_as_string(3)
+        );
+        assert_eq!(
+            "100000000000000000000000000000000000.00",
+            decimal_arr.value_as_string(4)
+        );
+        assert!(decimal_arr.is_null(5));
+        assert!(decimal_arr.is_null(6));
     }
 
-    fn make_large_list_array() -> LargeListArray {
-        // Construct a value array
-        let value_data = ArrayData::builder(DataType::Int32)
-            .len(8)
-            .add_buffer(Buffer::from_slice_ref([0, 1, 2, 3, 4, 5, 6, 7]))
-            .build()
-            .unwrap();
-
-        // Construct a buffer for value offsets, for the nested array:
-        //  [[0, 1, 2], [3, 4, 5], [6, 7]]
-        let value_offsets = Buffer::from_slice_ref([0i64, 3, 6, 8]);
+    #[test]
+    fn test_cast_utf8_to_decimal256_overflow() {
+        let overflow_str_array = StringArray::from(vec![
+            i128::MAX.to_string(),
+            i128::MIN.to_string(),
+            "99999999999999999999999999999999999999".to_string(),
+            "999999999999999999999999999999999999.99".to_string(),
+            "99999999999999999999999999999999999.999".to_string(),
+            i256::MAX.to_string(),
+            i256::MIN.to_string(),
+        ]);
+        let overflow_array = Arc::new(overflow_str_array) as ArrayRef;
 
-        // Construct a list array from the above two
-        let list_data_type =
-            DataType::LargeList(Arc::new(Field::new("item", DataType::Int32, true)));
-        let list_data = ArrayData::builder(list_data_type)
-            .len(3)
-            .add_buffer(value_offsets)
-            .add_child_data(value_data)
-            .build()
-            .unwrap();
-        LargeListArray::from(list_data)
+        test_cast_string_to_decimal256_overflow(overflow_array);
     }
 
     #[test]
-    fn test_utf8_cast_offsets() {
-        // test if offset of the array is taken into account during cast
-        let str_array = StringArray::from(vec!["a", "b", "c"]);
-        let str_array = str_array.slice(1, 2);
+    fn test_cast_large_utf8_to_decimal256_overflow() {
+        let overflow_str_array = LargeStringArray::from(vec![
+            i128::MAX.to_string(),
+            i128::MIN.to_string(),
+            "99999999999999999999999999999999999999".to_string(),
+            "999999999999999999999999999999999999.99".to_string(),
+            "99999999999999999999999999999999999.999".to_string(),
+            i256::MAX.to_string(),
+            i256::MIN.to_string(),
+        ]);
+        let overflow_array = Arc::new(overflow_str_array) as ArrayRef;
 
-        let out = cast(&str_array, &DataType::LargeUtf8).unwrap();
+        test_cast_string_to_decimal256_overflow(overflow_array);
+    }
 
-        let large_str_array = out.as_any().downcast_ref::<LargeStringArray>().unwrap();
-        let strs = large_str_array.into_iter().flatten().collect::<Vec<_>>();
-        assert_eq!(strs, &["b", "c"])
+    #[test]
+    fn test_cast_date32_to_timestamp() {
+        let a = Date32Array::from(vec![Some(18628), Some(18993), None]); // 2021-1-1, 2022-1-1
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
+        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+        assert_eq!(1609459200, c.value(0));
+        assert_eq!(1640995200, c.value(1));
+        assert!(c.is_null(2));
     }
 
     #[test]
-    fn test_list_cast_offsets() {
-        // test if offset of the array is taken into account during cast
-        let array1 = make_list_array().slice(1, 2);
-        let array2 = Arc::new(make_list_array()) as ArrayRef;
+    fn test_cast_date32_to_timestamp_ms() {
+        let a = Date32Array::from(vec![Some(18628), Some(18993), None]); // 2021-1-1, 2022-1-1
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Millisecond, None)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(1609459200000, c.value(0));
+        assert_eq!(1640995200000, c.value(1));
+        assert!(c.is_null(2));
+    }
 
-        let dt = DataType::LargeList(Arc::new(Field::new("item", DataType::Int32, true)));
-        let out1 = cast(&array1, &dt).unwrap();
-        let out2 = cast(&array2, &dt).unwrap();
+    #[test]
+    fn test_cast_date32_to_timestamp_us() {
+        let a = Date32Array::from(vec![Some(18628), Some(18993), None]); // 2021-1-1, 2022-1-1
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Microsecond, None)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        assert_eq!(1609459200000000, c.value(0));
+        assert_eq!(1640995200000000, c.value(1));
+        assert!(c.is_null(2));
+    }
 
-        assert_eq!(&out1, &out2.slice(1, 2))
+    #[test]
+    fn test_cast_date32_to_timestamp_ns() {
+        let a = Date32Array::from(vec![Some(18628), Some(18993), None]); // 2021-1-1, 2022-1-1
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Nanosecond, None)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(1609459200000000000, c.value(0));
+        assert_eq!(1640995200000000000, c.value(1));
+        assert!(c.is_null(2));
     }
 
     #[test]
-    fn test_list_to_string() {
-        let str_array = StringArray::from(vec!["a", "b", "c", "d", "e", "f", "g", "h"]);
-        let value_offsets = Buffer::from_slice_ref([0, 3, 6, 8]);
-        let value_data = str_array.into_data();
+    fn test_timezone_cast() {
+        let a = StringArray::from(vec![
+            "2000-01-01T12:00:00", // date + time valid
+            "2020-12-15T12:34:56", // date + time valid
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Nanosecond, None)).unwrap();
+        let v = b.as_primitive::<TimestampNanosecondType>();
 
-        let list_data_type =
-            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true)));
-        let list_data = ArrayData::builder(list_data_type)
-            .len(3)
-            .add_buffer(value_offsets)
-            .add_child_data(value_data)
-            .build()
-            .unwrap();
-        let array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+        assert_eq!(v.value(0), 946728000000000000);
+        assert_eq!(v.value(1), 1608035696000000000);
 
-        let out = cast(&array, &DataType::Utf8).unwrap();
-        let out = out
-            .as_any()
-            .downcast_ref::<StringArray>()
-            .unwrap()
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
-        assert_eq!(&out, &vec!["[a, b, c]", "[d, e, f]", "[g, h]"]);
+        let b = cast(
+            &b,
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into())),
+        )
+        .unwrap();
+        let v = b.as_primitive::<TimestampNanosecondType>();
 
-        let out = cast(&array, &DataType::LargeUtf8).unwrap();
-        let out = out
-            .as_any()
-            .downcast_ref::<LargeStringArray>()
-            .unwrap()
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
-        assert_eq!(&out, &vec!["[a, b, c]", "[d, e, f]", "[g, h]"]);
+        assert_eq!(v.value(0), 946728000000000000);
+        assert_eq!(v.value(1), 1608035696000000000);
 
-        let array = Arc::new(make_list_array()) as ArrayRef;
-        let out = cast(&array, &DataType::Utf8).unwrap();
-        let out = out
-            .as_any()
-            .downcast_ref::<StringArray>()
-            .unwrap()
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
-        assert_eq!(&out, &vec!["[0, 1, 2]", "[3, 4, 5]", "[6, 7]"]);
+        let b = cast(
+            &b,
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("+02:00".into())),
+        )
+        .unwrap();
+        let v = b.as_primitive::<TimestampMillisecondType>();
 
-        let array = Arc::new(make_large_list_array()) as ArrayRef;
-        let out = cast(&array, &DataType::LargeUtf8).unwrap();
-        let out = out
-            .as_any()
-            .downcast_ref::<LargeStringArray>()
-            .unwrap()
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
-        assert_eq!(&out, &vec!["[0, 1, 2]", "[3, 4, 5]", "[6, 7]"]);
+        assert_eq!(v.value(0), 946728000000);
+        assert_eq!(v.value(1), 1608035696000);
     }
 
     #[test]
-    fn test_cast_f64_to_decimal128() {
-        // to reproduce https://github.com/apache/arrow-rs/issues/2997
-
-        let decimal_type = DataType::Decimal128(18, 2);
-        let array = Float64Array::from(vec![
-            Some(0.0699999999),
-            Some(0.0659999999),
-            Some(0.0650000000),
-            Some(0.0649999999),
-        ]);
-        let array = Arc::new(array) as ArrayRef;
-        generate_cast_test_case!(
-            &array,
-            Decimal128Array,
-            &decimal_type,
-            vec![
-                Some(7_i128), // round up
-                Some(7_i128), // round up
-                Some(7_i128), // round up
-                Some(6_i128), // round down
-            ]
-        );
+    fn test_cast_utf8_to_timestamp() {
+        fn test_tz(tz: Arc<str>) {
+            let valid = StringArray::from(vec![
+                "2023-01-01 04:05:06.789000-08:00",
+                "2023-01-01 04:05:06.789000-07:00",
+                "2023-01-01 04:05:06.789 -0800",
+                "2023-01-01 04:05:06.789 -08:00",
+                "2023-01-01 040506 +0730",
+                "2023-01-01 040506 +07:30",
+                "2023-01-01 04:05:06.789",
+                "2023-01-01 04:05:06",
+                "2023-01-01",
+            ]);
 
-        let decimal_type = DataType::Decimal128(18, 3);
-        let array = Float64Array::from(vec![
-            Some(0.0699999999),
-            Some(0.0659999999),
-            Some(0.0650000000),
-            Some(0.0649999999),
-        ]);
-        let array = Arc::new(array) as ArrayRef;
-        generate_cast_test_case!(
-            &array,
-            Decimal128Array,
-            &decimal_type,
-            vec![
-                Some(70_i128), // round up
-                Some(66_i128), // round up
-                Some(65_i128), // round down
-                Some(65_i128), // round up
-            ]
-        );
-    }
+            let array = Arc::new(valid) as ArrayRef;
+            let b = cast_with_options(
+                &array,
+                &DataType::Timestamp(TimeUnit::Nanosecond, Some(tz.clone())),
+                &CastOptions {
+                    safe: false,
+                    ..DEFAULT_CAST_OPTIONS
+                },
+            )
+            .unwrap();
 
-    #[test]
-    fn test_cast_numeric_to_decimal128_overflow() {
-        let array = Int64Array::from(vec![i64::MAX]);
-        let array = Arc::new(array) as ArrayRef;
-        let casted_array = cast_with_options(
-            &array,
-            &DataType::Decimal128(38, 30),
-            &CastOptions { safe: true },
-        );
-        assert!(casted_array.is_ok());
-        assert!(casted_array.unwrap().is_null(0));
+            let tz = tz.as_ref().parse().unwrap();
 
-        let casted_array = cast_with_options(
-            &array,
-            &DataType::Decimal128(38, 30),
-            &CastOptions { safe: false },
-        );
-        assert!(casted_array.is_err());
-    }
+            let as_tz = |v: i64| {
+                as_datetime_with_timezone::<TimestampNanosecondType>(v, tz).unwrap()
+            };
 
-    #[test]
-    fn test_cast_numeric_to_decimal256_overflow() {
-        let array = Int64Array::from(vec![i64::MAX]);
-        let array = Arc::new(array) as ArrayRef;
-        let casted_array = cast_with_options(
-            &array,
-            &DataType::Decimal256(76, 76),
-            &CastOptions { safe: true },
-        );
-        assert!(casted_array.is_ok());
-        assert!(casted_array.unwrap().is_null(0));
+            let as_utc = |v: &i64| as_tz(*v).naive_utc().to_string();
+            let as_local = |v: &i64| as_tz(*v).naive_local().to_string();
 
-        let casted_array = cast_with_options(
-            &array,
-            &DataType::Decimal256(76, 76),
-            &CastOptions { safe: false },
-        );
-        assert!(casted_array.is_err());
-    }
+            let values = b.as_primitive::<TimestampNanosecondType>().values();
+            let utc_results: Vec<_> = values.iter().map(as_utc).collect();
+            let local_results: Vec<_> = values.iter().map(as_local).collect();
 
-    #[test]
-    fn test_cast_floating_point_to_decimal128_overflow() {
-        let array = Float64Array::from(vec![f64::MAX]);
-        let array = Arc::new(array) as ArrayRef;
-        let casted_array = cast_with_options(
-            &array,
-            &DataType::Decimal128(38, 30),
-            &CastOptions { safe: true },
-        );
-        assert!(casted_array.is_ok());
-        assert!(casted_array.unwrap().is_null(0));
+            // Absolute timestamps should be parsed preserving the same UTC instant
+            assert_eq!(
+                &utc_results[..6],
+                &[
+                    "2023-01-01 12:05:06.789".to_string(),
+                    "2023-01-01 11:05:06.789".to_string(),
+                    "2023-01-01 12:05:06.789".to_string(),
+                    "2023-01-01 12:05:06.789".to_string(),
+                    "2022-12-31 20:35:06".to_string(),
+                    "2022-12-31 20:35:06".to_string(),
+                ]
+            );
+            // Non-absolute timestamps should be parsed preserving the same local instant
+            assert_eq!(
+                &local_results[6..],
+                &[
+                    "2023-01-01 04:05:06.789".to_string(),
+                    "2023-01-01 04:05:06".to_string(),
+                    "2023-01-01 00:00:00".to_string()
+                ]
+            )
+        }
 
-        let casted_array = cast_with_options(
-            &array,
-            &DataType::Decimal128(38, 30),
-            &CastOptions { safe: false },
-        );
-        let err = casted_array.unwrap_err().to_string();
-        let expected_error = "Cast error: Cannot cast to Decimal128(38, 30)";
-        assert!(
-            err.contains(expected_error),
-            "did not find expected error '{expected_error}' in actual error '{err}'"
-        );
+        test_tz("+00:00".into());
+        test_tz("+02:00".into());
     }
 
     #[test]
-    fn test_cast_floating_point_to_decimal256_overflow() {
-        let array = Float64Array::from(vec![f64::MAX]);
-        let array = Arc::new(array) as ArrayRef;
-        let casted_array = cast_with_options(
-            &array,
-            &DataType::Decimal256(76, 50),
-            &CastOptions { safe: true },
-        );
-        assert!(casted_array.is_ok());
-        assert!(casted_array.unwrap().is_null(0));
+    fn test_cast_invalid_utf8() {
+        let v1: &[u8] = b"\xFF invalid";
+        let v2: &[u8] = b"\x00 Foo";
+        let s = BinaryArray::from(vec![v1, v2]);
+        let options = CastOptions {
+            safe: true,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let array = cast_with_options(&s, &DataType::Utf8, &options).unwrap();
+        let a = array.as_string::<i32>();
+        a.to_data().validate_full().unwrap();
 
-        let casted_array = cast_with_options(
-            &array,
-            &DataType::Decimal256(76, 50),
-            &CastOptions { safe: false },
-        );
-        let err = casted_array.unwrap_err().to_string();
-        let expected_error = "Cast error: Cannot cast to Decimal256(76, 50)";
-        assert!(
-            err.contains(expected_error),
-            "did not find expected error '{expected_error}' in actual error '{err}'"
-        );
+        assert_eq!(a.null_count(), 1);
+        assert_eq!(a.len(), 2);
+        assert!(a.is_null(0));
+        assert_eq!(a.value(0), "");
+        assert_eq!(a.value(1), "\x00 Foo");
     }
 
     #[test]
-    fn test_cast_decimal128_to_decimal128_negative_scale() {
-        let input_type = DataType::Decimal128(20, 0);
-        let output_type = DataType::Decimal128(20, -1);
-        assert!(can_cast_types(&input_type, &output_type));
-        let array = vec![Some(1123450), Some(2123455), Some(3123456), None];
-        let input_decimal_array = create_decimal_array(array, 20, 0).unwrap();
-        let array = Arc::new(input_decimal_array) as ArrayRef;
-        generate_cast_test_case!(
+    fn test_cast_utf8_to_timestamptz() {
+        let valid = StringArray::from(vec!["2023-01-01"]);
+
+        let array = Arc::new(valid) as ArrayRef;
+        let b = cast(
             &array,
-            Decimal128Array,
-            &output_type,
-            vec![
-                Some(112345_i128),
-                Some(212346_i128),
-                Some(312346_i128),
-                None
-            ]
-        );
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into())),
+        )
+        .unwrap();
 
-        let casted_array = cast(&array, &output_type).unwrap();
-        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
+        let expect = DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into()));
 
-        assert_eq!("1123450", decimal_arr.value_as_string(0));
-        assert_eq!("2123460", decimal_arr.value_as_string(1));
-        assert_eq!("3123460", decimal_arr.value_as_string(2));
+        assert_eq!(b.data_type(), &expect);
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(1672531200000000000, c.value(0));
     }
 
     #[test]
-    fn test_cast_numeric_to_decimal128_negative() {
-        let decimal_type = DataType::Decimal128(38, -1);
-        let array = Arc::new(Int32Array::from(vec![
-            Some(1123456),
-            Some(2123456),
-            Some(3123456),
-        ])) as ArrayRef;
-
-        let casted_array = cast(&array, &decimal_type).unwrap();
-        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
-
-        assert_eq!("1123450", decimal_arr.value_as_string(0));
-        assert_eq!("2123450", decimal_arr.value_as_string(1));
-        assert_eq!("3123450", decimal_arr.value_as_string(2));
-
-        let array = Arc::new(Float32Array::from(vec![
-            Some(1123.456),
-            Some(2123.456),
-            Some(3123.456),
-        ])) as ArrayRef;
-
-        let casted_array = cast(&array, &decimal_type).unwrap();
-        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
-
-        assert_eq!("1120", decimal_arr.value_as_string(0));
-        assert_eq!("2120", decimal_arr.value_as_string(1));
-        assert_eq!("3120", decimal_arr.value_as_string(2));
+    fn test_cast_utf8_to_timestamp_fixed_offset() {
+        let array = StringArray::from(vec![
+            Some("2023-01-01T08:00:00"),
+            Some("2023-01-01 08:00:00.000"),
+            Some("2023-01-01T00:00:00Z"),
+            Some("2023-01-01T01:00:00+01:00"),
+            Some("2023-01-02"),
+            None,
+        ]);
+        let to_type = DataType::Timestamp(TimeUnit::Second, Some("+08:00".into()));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+
+        let expected = TimestampSecondArray::from(vec![
+            Some(1672531200),
+            Some(1672531200),
+            Some(1672531200),
+            Some(1672531200),
+            Some(1672588800),
+            None,
+        ])
+        .with_timezone("+08:00");
+        assert_eq!(b.as_primitive::<TimestampSecondType>(), &expected);
     }
 
     #[test]
-    fn test_cast_decimal128_to_decimal128_negative() {
-        let input_type = DataType::Decimal128(10, -1);
-        let output_type = DataType::Decimal128(10, -2);
-        assert!(can_cast_types(&input_type, &output_type));
-        let array = vec![Some(123)];
-        let input_decimal_array = create_decimal_array(array, 10, -1).unwrap();
-        let array = Arc::new(input_decimal_array) as ArrayRef;
-        generate_cast_test_case!(
-            &array,
-            Decimal128Array,
-            &output_type,
-            vec![Some(12_i128),]
-        );
+    fn test_cast_decimal_to_utf8() {
+        fn test_decimal_to_string<IN: ArrowPrimitiveType, OffsetSize: OffsetSizeTrait>(
+            output_type: DataType,
+            array: PrimitiveArray<IN>,
+        ) {
+            let b = cast(&array, &output_type).unwrap();
 
-        let casted_array = cast(&array, &output_type).unwrap();
-        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
+            assert_eq!(b.data_type(), &output_type);
+            let c = b.as_string::<OffsetSize>();
 
-        assert_eq!("1200", decimal_arr.value_as_string(0));
+            assert_eq!("1123.454", c.value(0));
+            assert_eq!("2123.456", c.value(1));
+            assert_eq!("-3123.453", c.value(2));
+            assert_eq!("-3123.456", c.value(3));
+            assert_eq!("0.000", c.value(4));
+            assert_eq!("0.123", c.value(5));
+            assert_eq!("1234.567", c.value(6));
+            assert_eq!("-1234.567", c.value(7));
+            assert!(c.is_null(8));
+        }
+        let array128: Vec<Option<i128>> = vec![
+            Some(1123454),
+            Some(2123456),
+            Some(-3123453),
+            Some(-3123456),
+            Some(0),
+            Some(123),
+            Some(123456789),
+            Some(-123456789),
+            None,
+        ];
 
-        let array = vec![Some(125)];
-        let input_decimal_array = create_decimal_array(array, 10, -1).unwrap();
-        let array = Arc::new(input_decimal_array) as ArrayRef;
-        generate_cast_test_case!(
-            &array,
-            Decimal128Array,
-            &output_type,
-            vec![Some(13_i128),]
+        let array256: Vec<Option<i256>> =
+            array128.iter().map(|v| v.map(i256::from_i128)).collect();
+
+        test_decimal_to_string::<arrow_array::types::Decimal128Type, i32>(
+            DataType::Utf8,
+            create_decimal_array(array128.clone(), 7, 3).unwrap(),
+        );
+        test_decimal_to_string::<arrow_array::types::Decimal128Type, i64>(
+            DataType::LargeUtf8,
+            create_decimal_array(array128, 7, 3).unwrap(),
+        );
+        test_decimal_to_string::<arrow_array::types::Decimal256Type, i32>(
+            DataType::Utf8,
+            create_decimal256_array(array256.clone(), 7, 3).unwrap(),
+        );
+        test_decimal_to_string::<arrow_array::types::Decimal256Type, i64>(
+            DataType::LargeUtf8,
+            create_decimal256_array(array256, 7, 3).unwrap(),
         );
-
-        let casted_array = cast(&array, &output_type).unwrap();
-        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
-
-        assert_eq!("1300", decimal_arr.value_as_string(0));
     }
 
     #[test]
-    fn test_cast_decimal128_to_decimal256_negative() {
-        let input_type = DataType::Decimal128(10, 3);
-        let output_type = DataType::Decimal256(10, 5);
-        assert!(can_cast_types(&input_type, &output_type));
-        let array = vec![Some(i128::MAX), Some(i128::MIN)];
-        let input_decimal_array = create_decimal_array(array, 10, 3).unwrap();
-        let array = Arc::new(input_decimal_array) as ArrayRef;
+    fn test_cast_decimal_to_utf8_dict() {
+        let array = create_decimal_array(
+            vec![Some(1999), Some(-250), None, Some(1999), Some(123456789)],
+            7,
+            2,
+        )
+        .unwrap();
 
-        let hundred = i256::from_i128(100);
-        generate_cast_test_case!(
-            &array,
-            Decimal256Array,
-            &output_type,
-            vec![
-                Some(i256::from_i128(i128::MAX).mul_wrapping(hundred)),
-                Some(i256::from_i128(i128::MIN).mul_wrapping(hundred))
-            ]
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let b = cast(&array, &dict_type).unwrap();
+        assert_eq!(b.data_type(), &dict_type);
+        let dict = b.as_dictionary::<Int8Type>();
+        // Only the distinct values are formatted
+        assert_eq!(dict.values().len(), 3);
+        let keys: Vec<_> = dict.keys().iter().collect();
+        assert_eq!(keys, vec![Some(0), Some(1), None, Some(0), Some(2)]);
+        let values = dict.values().as_string::<i32>();
+        let expected = StringArray::from(vec!["19.99", "-2.50", "12345.67"]);
+        assert_eq!(values, &expected);
+
+        let dict_type = DataType::Dictionary(
+            Box::new(DataType::UInt16),
+            Box::new(DataType::LargeUtf8),
         );
+        let array256 = cast(&array, &DataType::Decimal256(7, 2)).unwrap();
+        let b = cast(&array256, &dict_type).unwrap();
+        let b = cast(&b, &DataType::LargeUtf8).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some("19.99"),
+            Some("-2.50"),
+            None,
+            Some("19.99"),
+            Some("12345.67"),
+        ]);
+        assert_eq!(b.as_string::<i64>(), &expected);
+
+        // Values are formatted as the plain cast to strings formats them
+        let format_options = FormatOptions::default().with_decimal_scientific(true);
+        let options = CastOptions::new().with_format_options(format_options);
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let b = cast_with_options(&array, &dict_type, &options).unwrap();
+        let b = cast(&b, &DataType::Utf8).unwrap();
+        let expected = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        assert_eq!(b.as_string::<i32>(), expected.as_string::<i32>());
+        assert_eq!(b.as_string::<i32>().value(0), "1.999E1");
+    }
+
+    fn map_type(key: DataType, value: DataType, sorted: bool) -> DataType {
+        let entries = Fields::from(vec![
+            Field::new("keys", key, false),
+            Field::new("values", value, true),
+        ]);
+        DataType::Map(
+            Arc::new(Field::new("entries", DataType::Struct(entries), false)),
+            sorted,
+        )
     }
 
     #[test]
-    fn test_parse_string_to_decimal() {
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>("123.45", 2).unwrap(),
-                38,
-                2,
-            ),
-            "123.45"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>("12345", 2).unwrap(),
-                38,
-                2,
-            ),
-            "12345.00"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>("0.12345", 2).unwrap(),
-                38,
-                2,
-            ),
-            "0.12"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>(".12345", 2).unwrap(),
-                38,
-                2,
-            ),
-            "0.12"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>(".1265", 2).unwrap(),
-                38,
-                2,
-            ),
-            "0.13"
-        );
-        assert_eq!(
-            Decimal128Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal128Type>(".1265", 2).unwrap(),
-                38,
-                2,
-            ),
-            "0.13"
-        );
+    fn test_cast_map_sort_keys() {
+        let values = Int32Array::from(vec![1, 2, 3, 4, 5, 6]);
+        let keys = ["b", "a", "c", "10", "9", "x"];
+        let array = MapArray::new_from_strings(keys.into_iter(), &values, &[0, 3, 5, 6])
+            .unwrap()
+            .slice(0, 2);
 
+        let to_type = map_type(DataType::Utf8, DataType::Int64, true);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let err = cast(&array, &to_type).unwrap_err();
         assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>("123.45", 3).unwrap(),
-                38,
-                3,
-            ),
-            "123.450"
-        );
-        assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>("12345", 3).unwrap(),
-                38,
-                3,
-            ),
-            "12345.000"
-        );
-        assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>("0.12345", 3).unwrap(),
-                38,
-                3,
-            ),
-            "0.123"
-        );
-        assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>(".12345", 3).unwrap(),
-                38,
-                3,
-            ),
-            "0.123"
+            err.to_string(),
+            "Cast error: Cannot cast a map with unsorted keys to a map with sorted keys unless sort_map_keys is set"
         );
+
+        let options = CastOptions {
+            sort_map_keys: true,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_map();
+        assert_eq!(b.len(), 2);
+        let keys = b.value(0).column(0).as_string::<i32>().clone();
+        assert_eq!(keys, StringArray::from(vec!["a", "b", "c"]));
+        let values = b.value(0).column(1).as_primitive::<Int64Type>().clone();
+        assert_eq!(values, Int64Array::from(vec![2, 1, 3]));
+        let keys = b.value(1).column(0).as_string::<i32>().clone();
+        assert_eq!(keys, StringArray::from(vec!["10", "9"]));
+
+        // Keys are sorted after they are cast
+        let to_type = map_type(DataType::Int32, DataType::Utf8, true);
+        let b = cast_with_options(&array.slice(1, 1), &to_type, &options).unwrap();
+        let b = b.as_map();
+        let keys = b.value(0).column(0).as_primitive::<Int32Type>().clone();
+        assert_eq!(keys, Int32Array::from(vec![9, 10]));
+        let values = b.value(0).column(1).as_string::<i32>().clone();
+        assert_eq!(values, StringArray::from(vec!["5", "4"]));
+
+        // Maps cast to unsorted maps keep their order
+        let to_type = map_type(DataType::LargeUtf8, DataType::Int32, false);
+        let b = cast(&array, &to_type).unwrap();
+        let keys = b.as_map().value(0).column(0).as_string::<i64>().clone();
+        assert_eq!(keys, LargeStringArray::from(vec!["b", "a", "c"]));
+
+        // Keys must not be null
+        let to_type = map_type(DataType::Int32, DataType::Int32, false);
+        let err = cast(&array, &to_type).unwrap_err();
         assert_eq!(
-            Decimal256Type::format_decimal(
-                parse_string_to_decimal_native::<Decimal256Type>(".1265", 3).unwrap(),
-                38,
-                3,
-            ),
-            "0.127"
+            err.to_string(),
+            "Cast error: Cannot cast map keys of type Utf8 to Int32, map keys cannot be null"
         );
+        assert!(!can_cast_types(
+            array.data_type(),
+            &map_type(DataType::Utf8, DataType::Struct(Fields::empty()), false)
+        ));
     }
 
-    fn test_cast_string_to_decimal(array: ArrayRef) {
-        // Decimal128
-        let output_type = DataType::Decimal128(38, 2);
-        assert!(can_cast_types(array.data_type(), &output_type));
-
-        let casted_array = cast(&array, &output_type).unwrap();
-        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
-
-        assert_eq!("123.45", decimal_arr.value_as_string(0));
-        assert_eq!("1.23", decimal_arr.value_as_string(1));
-        assert_eq!("0.12", decimal_arr.value_as_string(2));
-        assert_eq!("0.13", decimal_arr.value_as_string(3));
-        assert_eq!("1.26", decimal_arr.value_as_string(4));
-        assert_eq!("12345.00", decimal_arr.value_as_string(5));
-        assert_eq!("12345.00", decimal_arr.value_as_string(6));
-        assert_eq!("0.12", decimal_arr.value_as_string(7));
-        assert_eq!("12.23", decimal_arr.value_as_string(8));
-        assert!(decimal_arr.is_null(9));
-        assert_eq!("0.00", decimal_arr.value_as_string(10));
-        assert_eq!("0.00", decimal_arr.value_as_string(11));
-        assert!(decimal_arr.is_null(12));
+    #[test]
+    fn test_cast_struct_to_map() {
+        let keys = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), Some(5)]),
+        ]);
+        let values = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(10), None]),
+            Some(vec![Some(30), Some(40)]),
+            Some(vec![Some(50)]),
+            Some(vec![]),
+            Some(vec![Some(60), Some(70)]),
+        ]);
+        let array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("keys", keys.data_type().clone(), true)),
+                Arc::new(keys) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("values", values.data_type().clone(), true)),
+                Arc::new(values) as ArrayRef,
+            ),
+        ]);
 
-        // Decimal256
-        let output_type = DataType::Decimal256(76, 3);
-        assert!(can_cast_types(array.data_type(), &output_type));
+        let to_type = map_type(DataType::Utf8, DataType::Int32, false);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_map();
+        assert_eq!(b.len(), 5);
+        assert_eq!(b.null_count(), 2);
+        assert!(b.is_null(1));
+        assert!(b.is_null(2));
+        assert_eq!(b.value_length(3), 0);
+        let keys = b.value(0).column(0).as_string::<i32>().clone();
+        assert_eq!(keys, StringArray::from(vec!["1", "2"]));
+        let values = b.value(4).column(1).as_primitive::<Int32Type>().clone();
+        assert_eq!(values, Int32Array::from(vec![60, 70]));
+        let values = b.value(0).column(1).as_primitive::<Int32Type>().clone();
+        assert_eq!(values, Int32Array::from(vec![Some(10), None]));
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert!(
+            err.to_string().contains("as it has 1 keys and 2 values"),
+            "{err}"
+        );
 
-        let casted_array = cast(&array, &output_type).unwrap();
-        let decimal_arr = casted_array.as_primitive::<Decimal256Type>();
+        let to_type = map_type(DataType::Utf8, DataType::Struct(Fields::empty()), false);
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        let to_type = map_type(DataType::Int32, DataType::Int64, false);
+        assert!(!can_cast_types(
+            &DataType::Struct(vec![Field::new("keys", DataType::Int32, true)].into()),
+            &to_type
+        ));
+    }
 
-        assert_eq!("123.450", decimal_arr.value_as_string(0));
-        assert_eq!("1.235", decimal_arr.value_as_string(1));
-        assert_eq!("0.123", decimal_arr.value_as_string(2));
-        assert_eq!("0.127", decimal_arr.value_as_string(3));
-        assert_eq!("1.263", decimal_arr.value_as_string(4));
-        assert_eq!("12345.000", decimal_arr.value_as_string(5));
-        assert_eq!("12345.000", decimal_arr.value_as_string(6));
-        assert_eq!("0.123", decimal_arr.value_as_string(7));
-        assert_eq!("12.234", decimal_arr.value_as_string(8));
-        assert!(decimal_arr.is_null(9));
-        assert_eq!("0.000", decimal_arr.value_as_string(10));
-        assert_eq!("0.000", decimal_arr.value_as_string(11));
-        assert!(decimal_arr.is_null(12));
+    fn union_fields() -> Vec<Field> {
+        vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]
     }
 
     #[test]
-    fn test_cast_utf8_to_decimal() {
-        let str_array = StringArray::from(vec![
-            Some("123.45"),
-            Some("1.2345"),
-            Some("0.12345"),
-            Some("0.1267"),
-            Some("1.263"),
-            Some("12345.0"),
-            Some("12345"),
-            Some("000.123"),
-            Some("12.234000"),
-            None,
-            Some(""),
-            Some(" "),
-            None,
-        ]);
-        let array = Arc::new(str_array) as ArrayRef;
+    fn test_cast_union_to_values() {
+        // The unselected values of the sparse children fail to cast to Int64
+        let ints = Int32Array::from(vec![Some(1), Some(-1), None, Some(0)]);
+        let strings = StringArray::from(vec!["x", "2", "y", "-3"]);
+        let fields = union_fields();
+        let children: Vec<(Field, ArrayRef)> = vec![
+            (fields[0].clone(), Arc::new(ints)),
+            (fields[1].clone(), Arc::new(strings)),
+        ];
+        let type_ids = Buffer::from_slice_ref([0_i8, 1, 0, 1]);
+        let array = UnionArray::try_new(&[0, 1], type_ids, None, children).unwrap();
+        assert!(can_cast_types(array.data_type(), &DataType::Int64));
+        assert!(!can_cast_types(
+            array.data_type(),
+            &DataType::Struct(Fields::empty())
+        ));
 
-        test_cast_string_to_decimal(array);
-    }
+        let options = CastOptions::new().with_safe(false);
+        let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        let expected = Int64Array::from(vec![Some(1), Some(2), None, Some(-3)]);
+        assert_eq!(b.as_primitive::<Int64Type>(), &expected);
 
-    #[test]
-    fn test_cast_large_utf8_to_decimal() {
-        let str_array = LargeStringArray::from(vec![
-            Some("123.45"),
-            Some("1.2345"),
-            Some("0.12345"),
-            Some("0.1267"),
-            Some("1.263"),
-            Some("12345.0"),
-            Some("12345"),
-            Some("000.123"),
-            Some("12.234000"),
-            None,
-            Some(""),
-            Some(" "),
-            None,
-        ]);
-        let array = Arc::new(str_array) as ArrayRef;
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let expected = StringArray::from(vec![Some("1"), Some("2"), None, Some("-3")]);
+        assert_eq!(b.as_string::<i32>(), &expected);
+
+        let b = cast(&array.slice(1, 2), &DataType::Decimal128(10, 2)).unwrap();
+        let b = b.as_primitive::<Decimal128Type>();
+        assert_eq!(b.data_type(), &DataType::Decimal128(10, 2));
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![Some(200), None]);
+
+        // A dense union of a single child to the type of that child
+        let fields = [Field::new("a", DataType::Utf8, true)];
+        let strings = StringArray::from(vec!["a", "b", "c"]);
+        let children: Vec<(Field, ArrayRef)> =
+            vec![(fields[0].clone(), Arc::new(strings))];
+        let type_ids = Buffer::from_slice_ref([3_i8, 3]);
+        let offsets = Buffer::from_slice_ref([2_i32, 0]);
+        let array = UnionArray::try_new(&[3], type_ids, Some(offsets), children).unwrap();
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        assert_eq!(b.as_string::<i32>(), &StringArray::from(vec!["c", "a"]));
 
-        test_cast_string_to_decimal(array);
+        let options = options.with_safe(false);
+        let err = cast_with_options(&array, &DataType::Int32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string 'c' to value of Int32 type"
+        );
     }
 
     #[test]
-    fn test_cast_invalid_utf8_to_decimal() {
-        let str_array = StringArray::from(vec!["4.4.5", ". 0.123"]);
-        let array = Arc::new(str_array) as ArrayRef;
+    fn test_cast_strict_dictionary_values() {
+        let keys = Int8Array::from(vec![Some(0), None, Some(2), Some(1)]);
+        let values = StringArray::from(vec![Some("a"), None, Some("c"), None]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
 
-        // Safe cast
-        let output_type = DataType::Decimal128(38, 2);
-        let casted_array = cast(&array, &output_type).unwrap();
-        assert!(casted_array.is_null(0));
-        assert!(casted_array.is_null(1));
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let expected = StringArray::from(vec![Some("a"), None, Some("c"), None]);
+        assert_eq!(b.as_string::<i32>(), &expected);
 
-        let output_type = DataType::Decimal256(76, 2);
-        let casted_array = cast(&array, &output_type).unwrap();
-        assert!(casted_array.is_null(0));
-        assert!(casted_array.is_null(1));
+        let options = CastOptions::new().with_strict_dictionary_values(true);
+        let err = cast_with_options(&array, &DataType::Utf8, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast Dictionary(Int8, Utf8) to Utf8, as key 1 at index 3 references a null value"
+        );
 
-        // Non-safe cast
-        let output_type = DataType::Decimal128(38, 2);
-        let str_array = StringArray::from(vec!["4.4.5"]);
-        let array = Arc::new(str_array) as ArrayRef;
-        let option = CastOptions { safe: false };
-        let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
-        assert!(casted_err
-            .to_string()
-            .contains("Cannot cast string '4.4.5' to value of Decimal128(38, 10) type"));
+        // Unreferenced null values and null keys are allowed
+        let b = cast_with_options(&array.slice(0, 3), &DataType::LargeUtf8, &options)
+            .unwrap();
+        assert_eq!(b.null_count(), 1);
 
-        let str_array = StringArray::from(vec![". 0.123"]);
-        let array = Arc::new(str_array) as ArrayRef;
-        let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
-        assert!(casted_err.to_string().contains(
-            "Cannot cast string '. 0.123' to value of Decimal128(38, 10) type"
-        ));
+        // Dictionaries cast to dictionaries keep their null values
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8));
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(b.as_dictionary::<Int16Type>().values().null_count(), 2);
     }
 
-    fn test_cast_string_to_decimal128_overflow(overflow_array: ArrayRef) {
-        let output_type = DataType::Decimal128(38, 2);
-        let casted_array = cast(&overflow_array, &output_type).unwrap();
-        let decimal_arr = casted_array.as_primitive::<Decimal128Type>();
-
-        assert!(decimal_arr.is_null(0));
-        assert!(decimal_arr.is_null(1));
-        assert!(decimal_arr.is_null(2));
+    #[test]
+    fn test_cast_union_to_union() {
+        let ints = Int32Array::from(vec![Some(1), Some(-1), None]);
+        let strings = StringArray::from(vec!["x", "2", "y"]);
+        let fields = union_fields();
+        let children: Vec<(Field, ArrayRef)> = vec![
+            (fields[0].clone(), Arc::new(ints)),
+            (fields[1].clone(), Arc::new(strings)),
+        ];
+        let type_ids = Buffer::from_slice_ref([0_i8, 1, 0]);
+        let array = UnionArray::try_new(&[0, 1], type_ids, None, children).unwrap();
+
+        let to_fields = UnionFields::new(
+            [0, 1],
+            [
+                Field::new("a", DataType::Utf8, true),
+                Field::new("b", DataType::Int64, true),
+            ],
+        );
+        let to_type = DataType::Union(to_fields.clone(), UnionMode::Sparse);
+        assert!(can_cast_types(array.data_type(), &to_type));
+
+        let options = CastOptions::new().with_safe(false);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        assert_eq!(b.type_ids(), array.type_ids());
+        assert_eq!(b.child(0).as_string::<i32>().value(0), "1");
+        assert!(b.child(0).is_null(2));
+        assert_eq!(b.child(1).as_primitive::<Int64Type>().value(1), 2);
+
+        let b = cast(&array.slice(1, 2), &to_type).unwrap();
+        assert_eq!(b.len(), 2);
         assert_eq!(
-            "999999999999999999999999999999999999.99",
-            decimal_arr.value_as_string(3)
+            cast(&b, &DataType::Utf8)
+                .unwrap()
+                .as_string::<i32>()
+                .value(0),
+            "2"
         );
+
+        let to_type = DataType::Union(to_fields, UnionMode::Dense);
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        let err = cast(&array, &to_type).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("not supported, as the unions have different type ids or modes"));
+    }
+
+    #[test]
+    fn test_cast_sliced_list() {
+        let values = StringArray::from(vec![
+            "not a timestamp",
+            "2023-01-01T00:00:00",
+            "2023-01-02T00:00:00",
+            "2023-01-03T00:00:00",
+            "not a timestamp",
+        ]);
+        let field = Arc::new(Field::new("item", DataType::Utf8, true));
+        let offsets = OffsetBuffer::new(vec![0, 1, 3, 3, 4, 5].into());
+        let nulls = NullBuffer::new(BooleanBuffer::collect_bool(5, |i| i != 2));
+        let full = ListArray::new(field, offsets, Arc::new(values), Some(nulls));
+        let list = full.slice(1, 3);
+
+        // The values outside the offsets of the slice are not cast
+        let to_type = DataType::List(Arc::new(Field::new(
+            "item",
+            DataType::Timestamp(TimeUnit::Second, None),
+            false,
+        )));
+        let options = CastOptions::new().with_safe(false);
+        let b = cast_with_options(&list, &to_type, &options).unwrap();
+        let b = b.as_list::<i32>();
+        b.to_data().validate_full().unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        assert_eq!(b.value_offsets(), &[0, 2, 2, 3]);
+        assert!(b.is_null(1));
+        let values = b.values().as_primitive::<TimestampSecondType>();
+        assert_eq!(values.values(), &[1672531200, 1672617600, 1672704000]);
+
+        let err = cast_with_options(&full, &to_type, &options).unwrap_err();
         assert_eq!(
-            "100000000000000000000000000000000000.00",
-            decimal_arr.value_as_string(4)
+            err.to_string(),
+            "Parser error: Error parsing timestamp from 'not a timestamp': error parsing date"
         );
     }
 
     #[test]
-    fn test_cast_utf8_to_decimal128_overflow() {
-        let overflow_str_array = StringArray::from(vec![
-            i128::MAX.to_string(),
-            i128::MIN.to_string(),
-            "99999999999999999999999999999999999999".to_string(),
-            "999999999999999999999999999999999999.99".to_string(),
-            "99999999999999999999999999999999999.999".to_string(),
+    fn test_cast_list_to_map() {
+        let keys = StringArray::from(vec![Some("b"), Some("a"), Some("c"), None]);
+        let values = Int32Array::from(vec![Some(1), Some(2), None, Some(4)]);
+        let entries = StructArray::from(vec![
+            (
+                Arc::new(Field::new("key", DataType::Utf8, true)),
+                Arc::new(keys) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("value", DataType::Int32, true)),
+                Arc::new(values) as ArrayRef,
+            ),
         ]);
-        let overflow_array = Arc::new(overflow_str_array) as ArrayRef;
+        let field = Arc::new(Field::new("item", entries.data_type().clone(), true));
+        let offsets = OffsetBuffer::new(vec![0, 2, 2, 3, 4].into());
+        let nulls = NullBuffer::new(BooleanBuffer::collect_bool(4, |i| i != 1));
+        let array = ListArray::new(field, offsets, Arc::new(entries), Some(nulls));
+
+        let to_type = map_type(DataType::Utf8, DataType::Int64, true);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let options = CastOptions::new().with_sort_map_keys(true);
+        let b = cast_with_options(&array.slice(0, 3), &to_type, &options).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_map();
+        assert!(b.is_null(1));
+        let keys = b.value(0).column(0).as_string::<i32>().clone();
+        assert_eq!(keys, StringArray::from(vec!["a", "b"]));
+        let values = b.value(0).column(1).as_primitive::<Int64Type>().clone();
+        assert_eq!(values, Int64Array::from(vec![2, 1]));
+        assert!(b.value(2).column(1).is_null(0));
+
+        // Keys must not be null
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast map keys of type Utf8 to Utf8, map keys cannot be null"
+        );
 
-        test_cast_string_to_decimal128_overflow(overflow_array);
+        let to_type = map_type(DataType::Utf8, DataType::Int64, false);
+        let err = cast(
+            &array.slice(0, 3),
+            &map_type(DataType::Utf8, DataType::Int64, true),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("unless sort_map_keys is set"),
+            "{err}"
+        );
+        assert!(!can_cast_types(
+            &DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            &to_type
+        ));
     }
 
     #[test]
-    fn test_cast_large_utf8_to_decimal128_overflow() {
-        let overflow_str_array = LargeStringArray::from(vec![
-            i128::MAX.to_string(),
-            i128::MIN.to_string(),
-            "99999999999999999999999999999999999999".to_string(),
-            "999999999999999999999999999999999999.99".to_string(),
-            "99999999999999999999999999999999999.999".to_string(),
-        ]);
-        let overflow_array = Arc::new(overflow_str_array) as ArrayRef;
-
-        test_cast_string_to_decimal128_overflow(overflow_array);
-    }
+    fn test_cast_fixed_size_list() {
+        let array = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(0), Some(1)]),
+                None,
+                Some(vec![Some(2), None]),
+                Some(vec![Some(4), Some(5)]),
+            ],
+            2,
+        )
+        .slice(1, 3);
+
+        let item = |t: DataType| Arc::new(Field::new("item", t, true));
+        let to_type = DataType::List(item(DataType::Int64));
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_list::<i32>();
+        assert_eq!(b.value_offsets(), &[0, 2, 4, 6]);
+        assert!(b.is_null(0));
+        let values = b.value(1).as_primitive::<Int64Type>().clone();
+        assert_eq!(values, Int64Array::from(vec![Some(2), None]));
+
+        let to_type = DataType::LargeList(item(DataType::Utf8));
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_list::<i64>();
+        assert_eq!(b.value_offsets(), &[0, 2, 4, 6]);
+        let values = b.value(2).as_string::<i32>().clone();
+        assert_eq!(values, StringArray::from(vec!["4", "5"]));
+
+        let to_type = DataType::FixedSizeList(item(DataType::Float64), 2);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        assert!(b.is_null(0));
+        let values = b.value(2).as_primitive::<Float64Type>().clone();
+        assert_eq!(values, Float64Array::from(vec![4.0, 5.0]));
 
-    fn test_cast_string_to_decimal256_overflow(overflow_array: ArrayRef) {
-        let output_type = DataType::Decimal256(76, 2);
-        let casted_array = cast(&overflow_array, &output_type).unwrap();
-        let decimal_arr = casted_array.as_primitive::<Decimal256Type>();
+        let to_type = DataType::FixedSizeList(item(DataType::Int32), 3);
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        assert!(cast(&array, &to_type).is_err());
 
+        // Only lists that are not null must not contain nulls
+        let to_type = DataType::FixedSizeList(
+            Arc::new(Field::new("item", DataType::Int32, false)),
+            2,
+        );
+        let err = cast(&array, &to_type).unwrap_err();
         assert_eq!(
-            "170141183460469231731687303715884105727.00",
-            decimal_arr.value_as_string(0)
+            err.to_string(),
+            "Cast error: Cannot cast null values to the non-nullable list field 'item'"
         );
+        let b = cast(&array.slice(2, 1), &to_type).unwrap();
+        assert_eq!(b.len(), 1);
+    }
+
+    #[test]
+    fn test_cast_list_to_fixed_size_list() {
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(0)]),
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+            Some(vec![]),
+            Some(vec![None, Some(4)]),
+        ])
+        .slice(1, 5);
+
+        let item = |t: DataType| Arc::new(Field::new("item", t, true));
+        let to_type = DataType::FixedSizeList(item(DataType::Int64), 2);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        assert_eq!(b.len(), 5);
+        let valid: Vec<_> = (0..b.len()).map(|i| b.is_valid(i)).collect();
+        assert_eq!(valid, vec![true, false, false, false, true]);
+        let values = b.value(0).as_primitive::<Int64Type>().clone();
+        assert_eq!(values, Int64Array::from(vec![1, 2]));
+        let values = b.value(4).as_primitive::<Int64Type>().clone();
+        assert_eq!(values, Int64Array::from(vec![None, Some(4)]));
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
         assert_eq!(
-            "-170141183460469231731687303715884105728.00",
-            decimal_arr.value_as_string(1)
+            err.to_string(),
+            "Cast error: Cannot cast list of length 1 at row 2 to FixedSizeList(2)"
         );
+
+        let b = cast_with_options(&array.slice(0, 1), &to_type, &options).unwrap();
+        let b = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        let values = b.values().as_primitive::<Int64Type>().clone();
+        assert_eq!(values, Int64Array::from(vec![1, 2]));
+
+        let array = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(2)]),
+        ]);
+        let to_type = DataType::FixedSizeList(item(DataType::Utf8), 1);
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
         assert_eq!(
-            "99999999999999999999999999999999999999.00",
-            decimal_arr.value_as_string(2)
+            b.values().as_string::<i32>(),
+            &StringArray::from(vec!["1", "2"])
+        );
+    }
+
+    #[test]
+    fn test_cast_map_values() {
+        let values = StringArray::from(vec![Some("1"), None, Some("3"), Some("4")]);
+        let keys = ["a", "b", "c", "d"];
+        let array =
+            MapArray::new_from_strings(keys.into_iter(), &values, &[0, 2, 2, 4]).unwrap();
+
+        let entries = Fields::from(vec![
+            Field::new("k2", DataType::LargeUtf8, false),
+            Field::new("v2", DataType::Float64, true),
+        ]);
+        let to_type = DataType::Map(
+            Arc::new(Field::new("items", DataType::Struct(entries), false)),
+            false,
         );
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_map();
+        assert_eq!(b.value_offsets(), &[0, 2, 2, 4]);
+        assert_eq!(b.entries().column_names(), vec!["k2", "v2"]);
+        let keys = b.keys().as_string::<i64>();
+        assert_eq!(keys, &LargeStringArray::from(vec!["a", "b", "c", "d"]));
+        let values = b.values().as_primitive::<Float64Type>();
         assert_eq!(
-            "999999999999999999999999999999999999.99",
-            decimal_arr.value_as_string(3)
+            values,
+            &Float64Array::from(vec![Some(1.0), None, Some(3.0), Some(4.0)])
         );
+
+        // Only the values referenced by the map must satisfy the value field
+        let entries = Fields::from(vec![
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", DataType::Float64, false),
+        ]);
+        let to_type = DataType::Map(
+            Arc::new(Field::new("entries", DataType::Struct(entries), false)),
+            false,
+        );
+        let err = cast(&array, &to_type).unwrap_err();
         assert_eq!(
-            "100000000000000000000000000000000000.00",
-            decimal_arr.value_as_string(4)
+            err.to_string(),
+            "Cast error: Cannot cast null values to the non-nullable list field 'values'"
         );
-        assert!(decimal_arr.is_null(5));
-        assert!(decimal_arr.is_null(6));
+        let b = cast(&array.slice(2, 1), &to_type).unwrap();
+        let values = b.as_map().values().as_primitive::<Float64Type>().clone();
+        assert_eq!(values, Float64Array::from(vec![3.0, 4.0]));
     }
 
     #[test]
-    fn test_cast_utf8_to_decimal256_overflow() {
-        let overflow_str_array = StringArray::from(vec![
-            i128::MAX.to_string(),
-            i128::MIN.to_string(),
-            "99999999999999999999999999999999999999".to_string(),
-            "999999999999999999999999999999999999.99".to_string(),
-            "99999999999999999999999999999999999.999".to_string(),
-            i256::MAX.to_string(),
-            i256::MIN.to_string(),
+    fn test_cast_utf8_to_temporal_two_digit_year() {
+        let array = StringArray::from(vec![
+            Some("02-JAN-99"),
+            Some("2023-04-01"),
+            Some("15-mar-24 13:45:00"),
+            None,
+            Some("01-XYZ-99"),
         ]);
-        let overflow_array = Arc::new(overflow_str_array) as ArrayRef;
+        let options = CastOptions {
+            two_digit_year_pivot: Some(1950),
+            ..DEFAULT_CAST_OPTIONS
+        };
 
-        test_cast_string_to_decimal256_overflow(overflow_array);
+        // Relaxed parsing is opt-in
+        let b = cast(&array, &DataType::Date32).unwrap();
+        assert_eq!(b.null_count(), 4);
+
+        let b = cast_with_options(&array, &DataType::Date32, &options).unwrap();
+        let expected =
+            Date32Array::from(vec![Some(10593), Some(19448), None, None, None]);
+        assert_eq!(b.as_primitive::<Date32Type>(), &expected);
+
+        let b = cast_with_options(&array, &DataType::Date64, &options).unwrap();
+        let expected = Date64Array::from(vec![
+            Some(915_235_200_000),
+            None,
+            Some(1_710_510_300_000),
+            None,
+            None,
+        ]);
+        assert_eq!(b.as_primitive::<Date64Type>(), &expected);
+
+        let to_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".into()));
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<TimestampSecondType>();
+        assert_eq!(b.value(0), 915_231_600);
+        assert_eq!(b.value(1), 1_680_303_600);
+        assert_eq!(b.value(2), 1_710_506_700);
+        assert!(b.is_null(3));
+        assert!(b.is_null(4));
+
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        let err = cast_with_options(&array, &DataType::Date32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '15-mar-24 13:45:00' to value of Date32 type"
+        );
+        let err = cast_with_options(&array.slice(4, 1), &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error parsing timestamp from '01-XYZ-99': timestamp must contain at least 10 characters"
+        );
     }
 
     #[test]
-    fn test_cast_large_utf8_to_decimal256_overflow() {
-        let overflow_str_array = LargeStringArray::from(vec![
-            i128::MAX.to_string(),
-            i128::MIN.to_string(),
-            "99999999999999999999999999999999999999".to_string(),
-            "999999999999999999999999999999999999.99".to_string(),
-            "99999999999999999999999999999999999.999".to_string(),
-            i256::MAX.to_string(),
-            i256::MIN.to_string(),
-        ]);
-        let overflow_array = Arc::new(overflow_str_array) as ArrayRef;
+    fn test_cast_decimal_to_f32_rounding() {
+        // 16777217.0000000001 is just above the midpoint of 16777216 and 16777218, but
+        // is rounded to the midpoint as an f64, which then rounds to even as an f32
+        let cases = [
+            (167772170000000001_i128, 10, 16777218_f32),
+            (-167772170000000001_i128, 10, -16777218_f32),
+            (167772170000000000_i128, 10, 16777216_f32),
+            (12345, 2, 123.45_f32),
+            (-7, -3, -7000_f32),
+            (1, 38, 1e-38_f32),
+            (i128::MAX, 0, 1.7014118e38_f32),
+            (0, 5, 0_f32),
+        ];
+        for (value, scale, expected) in cases {
+            let array = Decimal128Array::from(vec![value])
+                .with_precision_and_scale(38, scale)
+                .unwrap();
+            let b = cast(&array, &DataType::Float32).unwrap();
+            let b = b.as_primitive::<Float32Type>();
+            assert_eq!(b.value(0), expected, "{value} {scale}");
 
-        test_cast_string_to_decimal256_overflow(overflow_array);
+            let array = Decimal256Array::from(vec![i256::from_i128(value)])
+                .with_precision_and_scale(76, scale)
+                .unwrap();
+            let b = cast(&array, &DataType::Float32).unwrap();
+            assert_eq!(b.as_primitive::<Float32Type>().value(0), expected);
+        }
+
+        let array = Decimal256Array::from(vec![i256::MAX])
+            .with_precision_and_scale(76, 40)
+            .unwrap();
+        let b = cast(&array, &DataType::Float32).unwrap();
+        assert_eq!(b.as_primitive::<Float32Type>().value(0), 5.789604e36_f32);
     }
 
     #[test]
-    fn test_cast_date32_to_timestamp() {
-        let a = Date32Array::from(vec![Some(18628), Some(18993), None]); // 2021-1-1, 2022-1-1
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
-        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
-        assert_eq!(1609459200, c.value(0));
-        assert_eq!(1640995200, c.value(1));
-        assert!(c.is_null(2));
+    fn test_cast_overflow_does_not_panic() {
+        let unsafe_options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+
+        let array = Date32Array::from(vec![Some(0), Some(i32::MAX)]);
+        let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_primitive::<TimestampNanosecondType>();
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![Some(0), None]);
+        let err = cast_with_options(&array, &to_type, &unsafe_options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: 2147483647 * 86400000000000"
+        );
+
+        let array = Date64Array::from(vec![i64::MIN]);
+        let to_type = DataType::Timestamp(TimeUnit::Microsecond, None);
+        assert!(cast(&array, &to_type).unwrap().is_null(0));
+
+        let array = Time32SecondArray::from(vec![i32::MAX]);
+        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
+        assert!(b.is_null(0));
+
+        let array = Time64MicrosecondArray::from(vec![i64::MIN]);
+        let to_type = DataType::Time64(TimeUnit::Nanosecond);
+        assert!(cast_with_options(&array, &to_type, &unsafe_options).is_err());
+
+        let array = Decimal256Array::from(vec![i256::MAX, i256::MIN])
+            .with_precision_and_scale(76, 10)
+            .unwrap();
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let b = b.as_primitive::<Float64Type>();
+        assert_eq!(b.value(0), 5.78960446186581e66);
+        assert_eq!(b.value(1), -5.78960446186581e66);
     }
 
     #[test]
-    fn test_cast_date32_to_timestamp_ms() {
-        let a = Date32Array::from(vec![Some(18628), Some(18993), None]); // 2021-1-1, 2022-1-1
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Millisecond, None)).unwrap();
-        let c = b
-            .as_any()
-            .downcast_ref::<TimestampMillisecondArray>()
-            .unwrap();
-        assert_eq!(1609459200000, c.value(0));
-        assert_eq!(1640995200000, c.value(1));
-        assert!(c.is_null(2));
+    fn test_cast_list_to_utf8_delimiter() {
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        builder.append_value([Some("a"), Some("b"), Some("c")]);
+        builder.append_null();
+        builder.append_value([Some("x"), None, Some("z")]);
+        builder.append_value([] as [Option<&str>; 0]);
+        builder.append_value([Some("only")]);
+        let array = builder.finish();
+
+        let options = CastOptions {
+            list_delimiter: Some("|".into()),
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let expected = StringArray::from(vec![
+            Some("a|b|c"),
+            None,
+            Some("x||z"),
+            Some(""),
+            Some("only"),
+        ]);
+        assert_eq!(b.as_string::<i32>(), &expected);
+
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        assert_eq!(b.as_string::<i32>().value(0), "[a, b, c]");
+
+        let array = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+        ])
+        .slice(1, 1);
+        let options = CastOptions {
+            list_delimiter: Some(", ".into()),
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &DataType::LargeUtf8, &options).unwrap();
+        assert_eq!(b.as_string::<i64>(), &LargeStringArray::from(vec!["3"]));
     }
 
     #[test]
-    fn test_cast_date32_to_timestamp_us() {
-        let a = Date32Array::from(vec![Some(18628), Some(18993), None]); // 2021-1-1, 2022-1-1
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Microsecond, None)).unwrap();
-        let c = b
-            .as_any()
-            .downcast_ref::<TimestampMicrosecondArray>()
-            .unwrap();
-        assert_eq!(1609459200000000, c.value(0));
-        assert_eq!(1640995200000000, c.value(1));
-        assert!(c.is_null(2));
+    fn test_cast_unsigned_to_temporal() {
+        let array = UInt32Array::from(vec![Some(0), Some(19448), None, Some(u32::MAX)]);
+        assert!(can_cast_types(array.data_type(), &DataType::Date32));
+        let b = cast(&array, &DataType::Date32).unwrap();
+        let expected = Date32Array::from(vec![Some(0), Some(19448), None, None]);
+        assert_eq!(b.as_primitive::<Date32Type>(), &expected);
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err = cast_with_options(&array, &DataType::Date32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Can't cast value 4294967295 to type Date32"
+        );
+
+        let array = UInt64Array::from(vec![Some(1680307200000), None, Some(u64::MAX)]);
+        let b = cast(&array, &DataType::Date64).unwrap();
+        let expected = Date64Array::from(vec![Some(1680307200000), None, None]);
+        assert_eq!(b.as_primitive::<Date64Type>(), &expected);
+
+        let to_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into()));
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_primitive::<TimestampMillisecondType>();
+        assert_eq!(b.value(0), 1680307200000);
+        assert!(b.is_null(1));
+        assert!(b.is_null(2));
+
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Can't cast value 18446744073709551615 to type Timestamp(Millisecond, Some(\"+01:00\"))"
+        );
     }
 
     #[test]
-    fn test_cast_date32_to_timestamp_ns() {
-        let a = Date32Array::from(vec![Some(18628), Some(18993), None]); // 2021-1-1, 2022-1-1
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Nanosecond, None)).unwrap();
-        let c = b
-            .as_any()
-            .downcast_ref::<TimestampNanosecondArray>()
-            .unwrap();
-        assert_eq!(1609459200000000000, c.value(0));
-        assert_eq!(1640995200000000000, c.value(1));
-        assert!(c.is_null(2));
+    fn test_cast_nested_dictionary() {
+        let inner = DictionaryArray::<Int16Type>::from_iter(["a", "b", "a", "c"]);
+        let keys = Int32Array::from(vec![Some(0), Some(3), None, Some(1), Some(0)]);
+        let nested = DictionaryArray::try_new(keys, Arc::new(inner)).unwrap();
+        let expected =
+            StringArray::from(vec![Some("a"), Some("c"), None, Some("b"), Some("a")]);
+
+        let inner_type =
+            DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8));
+        let nested_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(inner_type.clone()));
+        let to_types = [
+            DataType::Utf8,
+            DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::Utf8)),
+            inner_type.clone(),
+            nested_type.clone(),
+        ];
+        for to_type in to_types {
+            assert!(can_cast_types(nested.data_type(), &to_type));
+            let b = cast(&nested, &to_type).unwrap();
+            assert_eq!(b.data_type(), &to_type);
+            let b = cast(&b, &DataType::Utf8).unwrap();
+            assert_eq!(b.as_string::<i32>(), &expected, "{to_type:?}");
+        }
+
+        // Flat arrays can be cast to a nested dictionary
+        assert!(can_cast_types(&DataType::Utf8, &nested_type));
+        let b = cast(&expected, &nested_type).unwrap();
+        assert_eq!(b.data_type(), &nested_type);
+        let b = cast(&b, &DataType::Utf8).unwrap();
+        assert_eq!(b.as_string::<i32>(), &expected);
+
+        let nested_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(inner_type));
+        let array = Int64Array::from(vec![Some(1), None, Some(1), Some(2)]);
+        let b = cast(&array, &nested_type).unwrap();
+        let b = cast(&b, &DataType::Int64).unwrap();
+        assert_eq!(b.as_primitive::<Int64Type>(), &array);
     }
 
     #[test]
-    fn test_timezone_cast() {
-        let a = StringArray::from(vec![
-            "2000-01-01T12:00:00", // date + time valid
-            "2020-12-15T12:34:56", // date + time valid
+    fn test_cast_utf8_to_timestamp_strict_fractional_seconds() {
+        let array = StringArray::from(vec![
+            "2020-09-08T13:42:29.123456789123Z",
+            "2020-09-08T13:42:29.123Z",
+            "2020-09-08T13:42:29.1234",
         ]);
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Timestamp(TimeUnit::Nanosecond, None)).unwrap();
-        let v = b.as_primitive::<TimestampNanosecondType>();
+        let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_primitive::<TimestampNanosecondType>();
+        assert_eq!(b.value(0), 1599572549123456789);
+        assert_eq!(b.value(1), 1599572549123000000);
+
+        let options = CastOptions {
+            strict_fractional_seconds: true,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<TimestampNanosecondType>();
+        assert!(b.is_null(0));
+        assert_eq!(b.value(1), 1599572549123000000);
+        assert_eq!(b.value(2), 1599572549123400000);
 
-        assert_eq!(v.value(0), 946728000000000000);
-        assert_eq!(v.value(1), 1608035696000000000);
+        let to_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<TimestampMillisecondType>();
+        assert!(b.is_null(0));
+        assert_eq!(b.value(1), 1599572549123);
+        assert!(b.is_null(2));
 
-        let b = cast(
-            &b,
-            &DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into())),
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '2020-09-08T13:42:29.123456789123Z' to Millisecond without truncating its fractional seconds"
+        );
+    }
+
+    #[test]
+    fn test_cast_string_to_timestamp_with_inferred_unit() {
+        let infer = |values: Vec<Option<&str>>, options: &CastOptions| {
+            let array = LargeStringArray::from(values);
+            cast_string_to_timestamp_with_inferred_unit(&array, None, options)
+        };
+        let cases = [
+            (vec!["2023-04-01T12:00:00.123456789"], TimeUnit::Nanosecond),
+            (vec!["2262-04-11T23:47:16.854775807"], TimeUnit::Nanosecond),
+            (vec!["2262-04-11T23:47:16.854775808"], TimeUnit::Microsecond),
+            (vec!["1500-01-01", "2000-01-01"], TimeUnit::Microsecond),
+            (vec!["9999-12-31T23:59:59.999999"], TimeUnit::Microsecond),
+        ];
+        for (values, unit) in cases {
+            let values = values.into_iter().map(Some).collect();
+            let out = infer(values, &DEFAULT_CAST_OPTIONS).unwrap();
+            assert_eq!(out.data_type(), &DataType::Timestamp(unit, None));
+            assert_eq!(out.null_count(), 0);
+        }
+
+        // Values that fail to parse do not influence the unit
+        let values = vec![Some("3000-01-01"), None, Some("invalid")];
+        let out = infer(values.clone(), &DEFAULT_CAST_OPTIONS).unwrap();
+        let out = out.as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(out.value(0), 32503680000000000);
+        assert_eq!(out.null_count(), 2);
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err = infer(values, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error parsing timestamp from 'invalid': timestamp must contain at least 10 characters"
+        );
+
+        let array = StringArray::from(vec!["2023-04-01T12:00:00+01:00"]);
+        let tz = Some("+05:30".into());
+        let out = cast_string_to_timestamp_with_inferred_unit(
+            &array,
+            tz,
+            &DEFAULT_CAST_OPTIONS,
         )
         .unwrap();
-        let v = b.as_primitive::<TimestampNanosecondType>();
+        let to_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("+05:30".into()));
+        assert_eq!(out.data_type(), &to_type);
+
+        let err = cast_string_to_timestamp_with_inferred_unit(
+            &Int32Array::from(vec![1]),
+            None,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot infer the timestamp unit of Int32, expected Utf8 or LargeUtf8"
+        );
+
+        // The range of the unit is reported when casting directly
+        let array = StringArray::from(vec!["3000-01-01T00:00:00"]);
+        let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Overflow converting 3000-01-01 00:00:00 to Nanosecond, which can only represent datetimes from 1677-09-21 00:12:43.145224192 to 2262-04-11 23:47:16.854775807"
+        );
+    }
+
+    #[test]
+    fn test_cast_list_child_nullability() {
+        let nullable = Arc::new(Field::new("item", DataType::Int32, true));
+        let required = Arc::new(Field::new("item", DataType::Int32, false));
+
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3), None]),
+        ]);
+
+        for to_type in [
+            DataType::List(required.clone()),
+            DataType::LargeList(required.clone()),
+        ] {
+            let err = cast(&array, &to_type).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Cast error: Cannot cast null values to the non-nullable list field 'item'"
+            );
+
+            // Nulls outside of the sliced values are ignored
+            let sliced = array.slice(0, 2);
+            let b = cast(&sliced, &to_type).unwrap();
+            assert_eq!(b.data_type(), &to_type);
+            assert_eq!(b.len(), 2);
+            assert!(b.is_null(1));
+        }
+
+        // The target field is used, even if only the nullability differs
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+        ]);
+        let array = cast(&array, &DataType::List(required.clone())).unwrap();
+        let to_type = DataType::LargeList(nullable);
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+
+        // Failed conversions of the values are nulls
+        let array =
+            ListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![Some(
+                i64::MAX,
+            )])]);
+        let err = cast(&array, &DataType::List(required)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast null values to the non-nullable list field 'item'"
+        );
+    }
 
-        assert_eq!(v.value(0), 946728000000000000);
-        assert_eq!(v.value(1), 1608035696000000000);
+    #[test]
+    fn test_cast_utf8_to_timestamp_without_offset() {
+        let array = StringArray::from(vec![
+            "2023-01-02 10:00:00+05:30",
+            "2023-01-02 10:00:00",
+            "2023-01-02T10:00:00Z",
+        ]);
+        let to_type = DataType::Timestamp(TimeUnit::Second, Some("+02:00".into()));
+        let utc = 1672653600;
 
-        let b = cast(
-            &b,
-            &DataType::Timestamp(TimeUnit::Millisecond, Some("+02:00".into())),
-        )
-        .unwrap();
-        let v = b.as_primitive::<TimestampMillisecondType>();
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_primitive::<TimestampSecondType>();
+        assert_eq!(b.values(), &[utc - 19800, utc - 7200, utc]);
 
-        assert_eq!(v.value(0), 946728000000);
-        assert_eq!(v.value(1), 1608035696000);
+        let options = CastOptions {
+            timestamp_without_offset: TimestampWithoutOffset::Utc,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let b = b.as_primitive::<TimestampSecondType>();
+        assert_eq!(b.values(), &[utc - 19800, utc, utc]);
+
+        // Timestamps without a timezone are unaffected
+        let to_type = DataType::Timestamp(TimeUnit::Second, None);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<TimestampSecondType>();
+        assert_eq!(b.values(), &[utc - 19800, utc, utc]);
     }
 
     #[test]
-    fn test_cast_utf8_to_timestamp() {
-        fn test_tz(tz: Arc<str>) {
-            let valid = StringArray::from(vec![
-                "2023-01-01 04:05:06.789000-08:00",
-                "2023-01-01 04:05:06.789000-07:00",
-                "2023-01-01 04:05:06.789 -0800",
-                "2023-01-01 04:05:06.789 -08:00",
-                "2023-01-01 040506 +0730",
-                "2023-01-01 040506 +07:30",
-                "2023-01-01 04:05:06.789",
-                "2023-01-01 04:05:06",
-                "2023-01-01",
-            ]);
-
-            let array = Arc::new(valid) as ArrayRef;
-            let b = cast_with_options(
-                &array,
-                &DataType::Timestamp(TimeUnit::Nanosecond, Some(tz.clone())),
-                &CastOptions { safe: false },
-            )
-            .unwrap();
+    fn test_cast_utf8_to_time_with_offset() {
+        let array = StringArray::from(vec![
+            Some("10:00:00+02:00"),
+            Some("01:30:00.5-0530"),
+            Some("23:00:00 +01"),
+            Some("10:00:00Z"),
+            Some("10:00:00"),
+            Some("10:00:00+25:00"),
+            None,
+        ]);
+        let secs = |h: i64, m: i64, s: i64| (h * 3600 + m * 60 + s) * 1_000_000_000;
 
-            let tz = tz.as_ref().parse().unwrap();
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        let b = b.as_primitive::<Time64NanosecondType>();
+        let expected = Time64NanosecondArray::from(vec![
+            Some(secs(10, 0, 0)),
+            Some(secs(1, 30, 0) + 500_000_000),
+            Some(secs(23, 0, 0)),
+            Some(secs(10, 0, 0)),
+            Some(secs(10, 0, 0)),
+            None,
+            None,
+        ]);
+        assert_eq!(b, &expected);
+
+        let options = CastOptions::new().with_time_with_offset(TimeWithOffset::Utc);
+        let to_type = DataType::Time32(TimeUnit::Second);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let b = b.as_primitive::<Time32SecondType>();
+        let expected = Time32SecondArray::from(vec![
+            Some(8 * 3600),
+            Some(7 * 3600),
+            Some(22 * 3600),
+            Some(10 * 3600),
+            Some(10 * 3600),
+            None,
+            None,
+        ]);
+        assert_eq!(b, &expected);
 
-            let as_tz = |v: i64| {
-                as_datetime_with_timezone::<TimestampNanosecondType>(v, tz).unwrap()
-            };
+        let options = options.with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '10:00:00+25:00' to value of Time32(Second) type"
+        );
+    }
 
-            let as_utc = |v: &i64| as_tz(*v).naive_utc().to_string();
-            let as_local = |v: &i64| as_tz(*v).naive_local().to_string();
+    #[test]
+    fn test_cast_utf8_to_duration() {
+        let array = StringArray::from(vec![
+            Some("PT1H30M"),
+            Some("1 hour 30 minutes"),
+            Some("-P1DT0.0015S"),
+            Some("90"),
+            Some("P1M"),
+            None,
+        ]);
 
-            let values = b.as_primitive::<TimestampNanosecondType>().values();
-            let utc_results: Vec<_> = values.iter().map(as_utc).collect();
-            let local_results: Vec<_> = values.iter().map(as_local).collect();
+        let b = cast(&array, &DataType::Duration(TimeUnit::Millisecond)).unwrap();
+        let expected = DurationMillisecondArray::from(vec![
+            Some(5_400_000),
+            Some(5_400_000),
+            Some(-86_400_001),
+            Some(90_000),
+            None,
+            None,
+        ]);
+        assert_eq!(b.as_primitive::<DurationMillisecondType>(), &expected);
 
-            // Absolute timestamps should be parsed preserving the same UTC instant
-            assert_eq!(
-                &utc_results[..6],
-                &[
-                    "2023-01-01 12:05:06.789".to_string(),
-                    "2023-01-01 11:05:06.789".to_string(),
-                    "2023-01-01 12:05:06.789".to_string(),
-                    "2023-01-01 12:05:06.789".to_string(),
-                    "2022-12-31 20:35:06".to_string(),
-                    "2022-12-31 20:35:06".to_string(),
-                ]
-            );
-            // Non-absolute timestamps should be parsed preserving the same local instant
-            assert_eq!(
-                &local_results[6..],
-                &[
-                    "2023-01-01 04:05:06.789".to_string(),
-                    "2023-01-01 04:05:06".to_string(),
-                    "2023-01-01 00:00:00".to_string()
-                ]
-            )
-        }
+        let array = LargeStringArray::from(vec!["PT0.5S", "2 days"]);
+        let b = cast(&array, &DataType::Duration(TimeUnit::Second)).unwrap();
+        let expected = DurationSecondArray::from(vec![0, 172_800]);
+        assert_eq!(b.as_primitive::<DurationSecondType>(), &expected);
 
-        test_tz("+00:00".into());
-        test_tz("+02:00".into());
+        let options = CastOptions::new().with_safe(false);
+        let to_type = DataType::Duration(TimeUnit::Nanosecond);
+        let array = StringArray::from(vec!["PT1S", "PT1H1H"]);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string 'PT1H1H' to value of Duration(Nanosecond) type: Parser error: Invalid ISO 8601 duration: \"PT1H1H\""
+        );
+        assert!(can_cast_types(&DataType::LargeUtf8, &to_type));
     }
 
     #[test]
-    fn test_cast_invalid_utf8() {
-        let v1: &[u8] = b"\xFF invalid";
-        let v2: &[u8] = b"\x00 Foo";
-        let s = BinaryArray::from(vec![v1, v2]);
-        let options = CastOptions { safe: true };
-        let array = cast_with_options(&s, &DataType::Utf8, &options).unwrap();
-        let a = array.as_string::<i32>();
-        a.to_data().validate_full().unwrap();
+    fn test_cast_duration_interval_to_utf8() {
+        let duration = DurationMillisecondArray::from(vec![Some(5_400_500), None]);
+        let interval = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNanoType::make_value(14, 1, 1_500_000_000)),
+            None,
+        ]);
+        for to_type in [DataType::Utf8, DataType::LargeUtf8] {
+            assert!(can_cast_types(duration.data_type(), &to_type));
+            assert!(can_cast_types(interval.data_type(), &to_type));
+        }
 
-        assert_eq!(a.null_count(), 1);
-        assert_eq!(a.len(), 2);
-        assert!(a.is_null(0));
-        assert_eq!(a.value(0), "");
-        assert_eq!(a.value(1), "\x00 Foo");
+        let b = cast(&duration, &DataType::Utf8).unwrap();
+        assert_eq!(
+            b.as_string::<i32>(),
+            &StringArray::from(vec![Some("PT5400.500S"), None])
+        );
+        let b = cast(&interval, &DataType::LargeUtf8).unwrap();
+        let expected = "0 years 14 mons 1 days 0 hours 0 mins 1.500000000 secs";
+        assert_eq!(b.as_string::<i64>().value(0), expected);
+
+        let format_options = FormatOptions::default()
+            .with_duration_format(DurationFormat::Pretty)
+            .with_interval_format(DurationFormat::Iso8601);
+        let options = CastOptions::new().with_format_options(format_options);
+        let b = cast_with_options(&duration, &DataType::Utf8, &options).unwrap();
+        let expected =
+            StringArray::from(vec![Some("0 days 1 hours 30 mins 0.500 secs"), None]);
+        assert_eq!(b.as_string::<i32>(), &expected);
+        let b = cast_with_options(&interval, &DataType::Utf8, &options).unwrap();
+        let expected = StringArray::from(vec![Some("P1Y2M1DT1.5S"), None]);
+        assert_eq!(b.as_string::<i32>(), &expected);
     }
 
     #[test]
-    fn test_cast_utf8_to_timestamptz() {
-        let valid = StringArray::from(vec!["2023-01-01"]);
+    fn test_cast_decimal_rounding_mode() {
+        use RoundingMode::*;
+        let cases: [(RoundingMode, [i128; 6]); 5] = [
+            (HalfUp, [3, -3, 2, -2, 4, -4]),
+            (HalfEven, [2, -2, 2, -2, 4, -4]),
+            (Truncate, [2, -2, 2, -2, 3, -3]),
+            (Ceil, [3, -2, 3, -2, 4, -3]),
+            (Floor, [2, -3, 2, -3, 3, -4]),
+        ];
 
-        let array = Arc::new(valid) as ArrayRef;
-        let b = cast(
-            &array,
-            &DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into())),
+        let decimals = create_decimal_array(
+            vec![
+                Some(250),
+                Some(-250),
+                Some(210),
+                Some(-210),
+                Some(350),
+                Some(-350),
+            ],
+            10,
+            2,
         )
         .unwrap();
+        let floats = Float64Array::from(vec![2.5, -2.5, 2.1, -2.1, 3.5, -3.5]);
+        let strings =
+            StringArray::from(vec!["0.25", "-0.25", "0.21", "-0.21", "0.35", "-0.35"]);
 
-        let expect = DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into()));
+        for (rounding, expected) in cases {
+            let options = CastOptions::new().with_rounding(rounding);
 
-        assert_eq!(b.data_type(), &expect);
-        let c = b
-            .as_any()
-            .downcast_ref::<TimestampNanosecondArray>()
-            .unwrap();
-        assert_eq!(1672531200000000000, c.value(0));
+            let b = cast_with_options(&decimals, &DataType::Decimal128(10, 0), &options)
+                .unwrap();
+            let values: Vec<_> = b.as_primitive::<Decimal128Type>().values().to_vec();
+            assert_eq!(values, expected, "{rounding:?} decimal");
+
+            let b = cast_with_options(&floats, &DataType::Decimal256(10, 0), &options)
+                .unwrap();
+            let values: Vec<_> = b.as_primitive::<Decimal256Type>().values().to_vec();
+            assert_eq!(values, expected.map(i256::from_i128), "{rounding:?} float");
+
+            let to_type = DataType::Decimal128(10, 1);
+            let b = cast_with_options(&strings, &to_type, &options).unwrap();
+            let values: Vec<_> = b.as_primitive::<Decimal128Type>().values().to_vec();
+            assert_eq!(values, expected, "{rounding:?} string");
+        }
     }
 
     #[test]
-    fn test_cast_decimal_to_utf8() {
-        fn test_decimal_to_string<IN: ArrowPrimitiveType, OffsetSize: OffsetSizeTrait>(
-            output_type: DataType,
-            array: PrimitiveArray<IN>,
-        ) {
-            let b = cast(&array, &output_type).unwrap();
+    fn test_cast_utf8_to_decimal_scientific_roundtrip() {
+        let array = create_decimal_array(
+            vec![
+                Some(12345),
+                Some(-1500),
+                Some(0),
+                None,
+                Some(i64::MAX as i128),
+            ],
+            38,
+            6,
+        )
+        .unwrap();
 
-            assert_eq!(b.data_type(), &output_type);
-            let c = b.as_string::<OffsetSize>();
+        let options = FormatOptions::default().with_decimal_scientific(true);
+        let formatter = ArrayFormatter::try_new(&array, &options).unwrap();
+        let strings: StringArray = (0..array.len())
+            .map(|i| array.is_valid(i).then(|| formatter.value(i).to_string()))
+            .collect();
+        assert_eq!(strings.value(0), "1.2345E-2");
 
-            assert_eq!("1123.454", c.value(0));
-            assert_eq!("2123.456", c.value(1));
-            assert_eq!("-3123.453", c.value(2));
-            assert_eq!("-3123.456", c.value(3));
-            assert_eq!("0.000", c.value(4));
-            assert_eq!("0.123", c.value(5));
-            assert_eq!("1234.567", c.value(6));
-            assert_eq!("-1234.567", c.value(7));
-            assert!(c.is_null(8));
-        }
-        let array128: Vec<Option<i128>> = vec![
-            Some(1123454),
-            Some(2123456),
-            Some(-3123453),
-            Some(-3123456),
-            Some(0),
-            Some(123),
-            Some(123456789),
-            Some(-123456789),
-            None,
-        ];
+        let b = cast(&strings, array.data_type()).unwrap();
+        assert_eq!(b.as_primitive::<Decimal128Type>(), &array);
 
-        let array256: Vec<Option<i256>> =
-            array128.iter().map(|v| v.map(i256::from_i128)).collect();
+        let array = cast(&array, &DataType::Decimal256(50, 6)).unwrap();
+        let b = cast(&strings, array.data_type()).unwrap();
+        assert_eq!(b.as_primitive::<Decimal256Type>(), array.as_primitive());
+    }
 
-        test_decimal_to_string::<arrow_array::types::Decimal128Type, i32>(
-            DataType::Utf8,
-            create_decimal_array(array128.clone(), 7, 3).unwrap(),
-        );
-        test_decimal_to_string::<arrow_array::types::Decimal128Type, i64>(
-            DataType::LargeUtf8,
-            create_decimal_array(array128, 7, 3).unwrap(),
-        );
-        test_decimal_to_string::<arrow_array::types::Decimal256Type, i32>(
-            DataType::Utf8,
-            create_decimal256_array(array256.clone(), 7, 3).unwrap(),
+    #[test]
+    fn test_cast_timestamp_to_utf8_dict() {
+        let array = TimestampMillisecondArray::from(vec![
+            Some(1_000),
+            None,
+            Some(86_400_500),
+            Some(1_000),
+        ])
+        .with_timezone("+01:00");
+
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let b = cast(&array, &dict_type).unwrap();
+        let dict = b.as_dictionary::<Int32Type>();
+        assert_eq!(
+            dict.keys(),
+            &Int32Array::from(vec![Some(0), None, Some(1), Some(0)])
         );
-        test_decimal_to_string::<arrow_array::types::Decimal256Type, i64>(
-            DataType::LargeUtf8,
-            create_decimal256_array(array256, 7, 3).unwrap(),
+        let expected = StringArray::from(vec![
+            "1970-01-01T01:00:01+01:00",
+            "1970-01-02T01:00:00.500+01:00",
+        ]);
+        assert_eq!(dict.values().as_string::<i32>(), &expected);
+
+        let dict_type = DataType::Dictionary(
+            Box::new(DataType::UInt8),
+            Box::new(DataType::LargeUtf8),
         );
+        let array = TimestampSecondArray::from(vec![Some(0), Some(0), None]);
+        let b = cast(&array, &dict_type).unwrap();
+        let b = cast(&b, &DataType::LargeUtf8).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some("1970-01-01T00:00:00"),
+            Some("1970-01-01T00:00:00"),
+            None,
+        ]);
+        assert_eq!(b.as_string::<i64>(), &expected);
     }
 
     #[test]
@@ -8467,7 +16154,10 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal128(7, 3),
-            &CastOptions { safe: true },
+            &CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
@@ -8475,7 +16165,10 @@ mod tests {
         let err = cast_with_options(
             &array,
             &DataType::Decimal128(7, 3),
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal128 of precision 7. Max is 9999999", err.unwrap_err().to_string());
     }
@@ -8487,7 +16180,10 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal256(7, 3),
-            &CastOptions { safe: true },
+            &CastOptions {
+                safe: true,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
@@ -8495,7 +16191,10 @@ mod tests {
         let err = cast_with_options(
             &array,
             &DataType::Decimal256(7, 3),
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal256 of precision 7. Max is 9999999", err.unwrap_err().to_string());
     }
@@ -8551,7 +16250,10 @@ mod tests {
 
         let casted_array = cast_from_duration_to_interval::<DurationSecondType>(
             array,
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert!(casted_array.is_err());
 
@@ -8578,7 +16280,10 @@ mod tests {
 
         let casted_array = cast_from_duration_to_interval::<DurationMillisecondType>(
             array,
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert!(casted_array.is_err());
 
@@ -8605,7 +16310,10 @@ mod tests {
 
         let casted_array = cast_from_duration_to_interval::<DurationMicrosecondType>(
             array,
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert!(casted_array.is_err());
 
@@ -8625,7 +16333,10 @@ mod tests {
         let array = vec![i64::MAX];
         let casted_array = cast_from_duration_to_interval::<DurationNanosecondType>(
             array,
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         )
         .unwrap();
         assert_eq!(casted_array.value(0), 9223372036854775807);
@@ -8676,7 +16387,10 @@ mod tests {
 
         let casted_array = cast_from_interval_to_duration::<DurationSecondType>(
             array,
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert!(casted_array.is_err());
 
@@ -8699,7 +16413,10 @@ mod tests {
 
         let casted_array = cast_from_interval_to_duration::<DurationMillisecondType>(
             array,
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert!(casted_array.is_err());
 
@@ -8726,7 +16443,10 @@ mod tests {
 
         let casted_array = cast_from_interval_to_duration::<DurationMicrosecondType>(
             array,
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert!(casted_array.is_err());
 
@@ -8757,8 +16477,229 @@ mod tests {
 
         let casted_array = cast_from_interval_to_duration::<DurationNanosecondType>(
             array,
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
         );
         assert!(casted_array.is_err());
     }
+
+    #[test]
+    fn test_cast_options_builder() {
+        assert_eq!(CastOptions::new(), DEFAULT_CAST_OPTIONS);
+        assert_eq!(CastOptions::default(), DEFAULT_CAST_OPTIONS);
+
+        let options = CastOptions::new()
+            .with_safe(false)
+            .with_compat(CastCompat::ArrowCpp)
+            .with_list_delimiter(Some("|".into()))
+            .with_reinterpret(true);
+        assert_eq!(
+            options,
+            CastOptions {
+                safe: false,
+                compat: CastCompat::ArrowCpp,
+                list_delimiter: Some("|".into()),
+                reinterpret: true,
+                ..DEFAULT_CAST_OPTIONS
+            }
+        );
+
+        let array = Int64Array::from(vec![(1 << 32) + 1]);
+        let to_type = DataType::Interval(IntervalUnit::DayTime);
+        let c = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = c.as_primitive::<IntervalDayTimeType>();
+        assert_eq!(c.value(0), IntervalDayTimeType::make_value(1, 1));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_cast_options_serde() {
+        let format_options = FormatOptions::new()
+            .with_null("NULL")
+            .with_date_format(Some("%d/%m/%Y"));
+        let options = CastOptions::new()
+            .with_safe(false)
+            .with_list_delimiter(Some("|".into()))
+            .with_base64(Some(Base64Alphabet::UrlSafe))
+            .with_number_format(Some(NumberFormat::new(',', Some('.'))))
+            .with_format_options(format_options)
+            .with_rounding(RoundingMode::HalfEven)
+            .with_timezone_conversion(TimezoneConversion::WallClock)
+            .with_local_time_resolution(LocalTimeResolution::Latest);
+
+        let json = serde_json::to_string(&options).unwrap();
+        let actual: CastOptions = serde_json::from_str(&json).unwrap();
+        assert_eq!(actual, options);
+
+        // Fields that cannot be serialized are an error when set
+        let with_registry = options
+            .clone()
+            .with_registry(Some(Arc::new(CastRegistry::new())));
+        let err = serde_json::to_string(&with_registry).unwrap_err();
+        assert!(err.to_string().contains("cannot be serialized"), "{err}");
+
+        let with_formats = options.clone().with_timestamp_formats(&["%m/%d/%Y"]);
+        assert!(serde_json::to_string(&with_formats).is_err());
+
+        // And are ignored when deserializing
+        let json = r#"{"safe":false,"registry":null,"timestamp_formats":["%Y"]}"#;
+        let actual: CastOptions = serde_json::from_str(json).unwrap();
+        assert_eq!(actual, CastOptions::new().with_safe(false));
+
+        // Missing fields are set to their default
+        let actual: CastOptions =
+            serde_json::from_str(r#"{"safe":false,"rounding":"Truncate"}"#).unwrap();
+        let expected = CastOptions::new()
+            .with_safe(false)
+            .with_rounding(RoundingMode::Truncate);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_cast_utf8_to_fixed_size_float_list() {
+        let array = StringArray::from(vec![
+            Some("[0.1, -2, 3e-4]"),
+            Some(" [1,2,3] "),
+            Some("[1, 2]"),
+            Some("[1, x, 3]"),
+            Some("1, 2, 3"),
+            None,
+        ]);
+        let item = |t: DataType| Arc::new(Field::new("item", t, true));
+        let to_type = DataType::FixedSizeList(item(DataType::Float32), 3);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        assert!(can_cast_types(&DataType::LargeUtf8, &to_type));
+
+        let b = cast(&array, &to_type).unwrap();
+        let b = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        let valid: Vec<_> = (0..b.len()).map(|i| b.is_valid(i)).collect();
+        assert_eq!(valid, [true, true, false, false, false, false]);
+        assert_eq!(
+            b.values().as_primitive::<Float32Type>().values()[..6],
+            [0.1, -2., 3e-4, 1., 2., 3.]
+        );
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '[1, 2]' at row 2 to FixedSizeList(3), a list of length 2"
+        );
+        let array = LargeStringArray::from(vec!["[]", "[1, x]"]);
+        let to_type = DataType::FixedSizeList(item(DataType::Float64), 0);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '[1, x]' at row 1 to FixedSizeList(0), not a list of Float64"
+        );
+
+        let to_type = DataType::FixedSizeList(item(DataType::Int32), 2);
+        assert!(!can_cast_types(&DataType::Utf8, &to_type));
+    }
+
+    #[test]
+    fn test_cast_decimal_boolean() {
+        let array = create_decimal_array(vec![Some(0), Some(1), Some(-250), None], 10, 2)
+            .unwrap();
+        let b = cast(&array, &DataType::Boolean).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(false), Some(true), Some(true), None]);
+        assert_eq!(b.as_boolean(), &expected);
+
+        let array = Decimal256Array::from(vec![Some(i256::ZERO), Some(i256::MINUS_ONE)])
+            .with_precision_and_scale(40, 0)
+            .unwrap();
+        let b = cast(&array, &DataType::Boolean).unwrap();
+        let expected = BooleanArray::from(vec![false, true]);
+        assert_eq!(b.as_boolean(), &expected);
+
+        let array = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let b = cast(&array, &DataType::Decimal128(5, 2)).unwrap();
+        let b = b.as_primitive::<Decimal128Type>();
+        assert_eq!(
+            b,
+            &create_decimal_array(vec![Some(100), Some(0), None], 5, 2).unwrap()
+        );
+
+        let b = cast(&array, &DataType::Decimal256(40, 3)).unwrap();
+        let b = b.as_primitive::<Decimal256Type>();
+        let values: Vec<_> = b.iter().collect();
+        assert_eq!(
+            values,
+            [Some(i256::from_i128(1000)), Some(i256::ZERO), None]
+        );
+
+        // 1 exceeds the precision of Decimal128(2, 2)
+        let b = cast(&array, &DataType::Decimal128(2, 2)).unwrap();
+        assert_eq!(b.null_count(), 2);
+        let options = CastOptions::new().with_safe(false);
+        cast_with_options(&array, &DataType::Decimal128(2, 2), &options).unwrap_err();
+
+        for t in [DataType::Decimal128(5, 2), DataType::Decimal256(5, 2)] {
+            assert!(can_cast_types(&t, &DataType::Boolean));
+            assert!(can_cast_types(&DataType::Boolean, &t));
+        }
+    }
+
+    #[test]
+    fn test_cast_precision_loss() {
+        let loss = Arc::new(PrecisionLoss::new());
+        let options = CastOptions::new().with_precision_loss(Some(loss.clone()));
+
+        let array = TimestampNanosecondArray::from(vec![
+            Some(1_000_000_000),
+            Some(1_000_000_001),
+            None,
+            Some(-1),
+        ]);
+        let to_type = DataType::Timestamp(TimeUnit::Second, None);
+        cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(loss.take(), 2);
+        // Increasing the unit is lossless
+        let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(loss.count(), 0);
+
+        let array = Decimal128Array::from(vec![Some(12345), Some(12300), None, Some(-5)])
+            .with_precision_and_scale(10, 3)
+            .unwrap();
+        cast_with_options(&array, &DataType::Decimal128(10, 1), &options).unwrap();
+        assert_eq!(loss.take(), 2);
+        cast_with_options(&array, &DataType::Decimal256(10, 1), &options).unwrap();
+        assert_eq!(loss.take(), 2);
+        cast_with_options(&array, &DataType::Decimal128(10, 4), &options).unwrap();
+        assert_eq!(loss.take(), 0);
+
+        let array = Float64Array::from(vec![
+            Some(0.5),
+            Some(0.1),
+            None,
+            Some(f64::NAN),
+            Some(f64::INFINITY),
+            Some(1e300),
+        ]);
+        cast_with_options(&array, &DataType::Float32, &options).unwrap();
+        assert_eq!(loss.take(), 2);
+
+        // Nested values are counted
+        let array = Arc::new(array) as ArrayRef;
+        let list = ListArray::new(
+            Arc::new(Field::new("item", DataType::Float64, true)),
+            OffsetBuffer::new(vec![0, 2, 6].into()),
+            array,
+            None,
+        );
+        let to_type =
+            DataType::List(Arc::new(Field::new("item", DataType::Float32, true)));
+        cast_with_options(&list, &to_type, &options).unwrap();
+        assert_eq!(loss.count(), 2);
+
+        // Options are equal if they share a counter
+        assert_eq!(options, options.clone());
+        let other =
+            CastOptions::new().with_precision_loss(Some(Arc::new(PrecisionLoss::new())));
+        assert_ne!(options, other);
+    }
 }
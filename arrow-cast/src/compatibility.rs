@@ -0,0 +1,407 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Whether casts are supported, and whether they may lose information
+//!
+//! [`can_cast_types`] only returns whether a cast is supported, while
+//! [`cast_compatibility`] also returns whether it may lose information, or why it is
+//! not supported, and [`is_infallible_cast`] whether it may return null or an error
+
+use crate::cast::{can_cast_types, time_unit_multiple};
+use crate::decimal::is_infallible_decimal_rescale;
+use arrow_schema::DataType;
+
+/// Whether a cast is supported, and whether it may lose information, see
+/// [`cast_compatibility`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CastCompatibility {
+    /// Every value can be cast without losing information
+    Supported,
+    /// Some values may lose precision, e.g. when casting `Float64` to `Float32`, or fail
+    /// to cast, returning null or an error depending on [`CastOptions::safe`], see
+    /// [`is_infallible_cast`]
+    ///
+    /// [`CastOptions::safe`]: crate::cast::CastOptions::safe
+    SupportedLossy,
+    /// The cast is not supported by [`cast_with_options`]
+    ///
+    /// [`cast_with_options`]: crate::cast::cast_with_options
+    Unsupported {
+        /// Why the cast is not supported
+        reason: String,
+    },
+}
+
+/// Returns whether [`cast_with_options`] can cast from `from_type` to `to_type`, and if
+/// so whether values may lose information, or else the reason it cannot
+///
+/// A cast is [`CastCompatibility::Supported`] only if it is known to preserve every
+/// value, e.g. widening integers and decimals, and is otherwise considered lossy.
+///
+/// ```
+/// # use arrow_cast::{cast_compatibility, CastCompatibility};
+/// # use arrow_schema::{DataType, TimeUnit};
+/// let c = cast_compatibility(&DataType::Int32, &DataType::Int64);
+/// assert_eq!(c, CastCompatibility::Supported);
+///
+/// let c = cast_compatibility(&DataType::Int64, &DataType::Float64);
+/// assert_eq!(c, CastCompatibility::SupportedLossy);
+///
+/// let from = DataType::Duration(TimeUnit::Second);
+/// let to = DataType::Timestamp(TimeUnit::Second, None);
+/// match cast_compatibility(&from, &to) {
+///     CastCompatibility::Unsupported { reason } => {
+///         assert!(reason.contains("a duration is not an instant in time"))
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// [`cast_with_options`]: crate::cast::cast_with_options
+pub fn cast_compatibility(from_type: &DataType, to_type: &DataType) -> CastCompatibility {
+    if !can_cast_types(from_type, to_type) {
+        return CastCompatibility::Unsupported {
+            reason: unsupported_cast_reason(from_type, to_type),
+        };
+    }
+    match is_lossless_cast(from_type, to_type) {
+        true => CastCompatibility::Supported,
+        false => CastCompatibility::SupportedLossy,
+    }
+}
+
+/// Returns the reason that a cast from `from_type` to `to_type` is not supported,
+/// describing the innermost unsupported cast of nested types
+fn unsupported_cast_reason(from_type: &DataType, to_type: &DataType) -> String {
+    use DataType::*;
+    let detail = match (from_type, to_type) {
+        (
+            List(from) | LargeList(from) | FixedSizeList(from, _),
+            List(to) | LargeList(to) | FixedSizeList(to, _),
+        ) if !can_cast_types(from.data_type(), to.data_type()) => {
+            return unsupported_cast_reason(from.data_type(), to.data_type())
+        }
+        (Dictionary(_, from), Dictionary(_, to)) if !can_cast_types(from, to) => {
+            return unsupported_cast_reason(from, to)
+        }
+        (Dictionary(_, from), _) if !can_cast_types(from, to_type) => {
+            return unsupported_cast_reason(from, to_type)
+        }
+        (Duration(_), Timestamp(_, _)) | (Timestamp(_, _), Duration(_)) => {
+            Some("a duration is not an instant in time")
+        }
+        (Interval(_), Duration(_)) | (Duration(_), Interval(_)) => {
+            Some("only Interval(MonthDayNano) has a fixed number of nanoseconds")
+        }
+        (Struct(_), _) => Some("structs can only be cast to structs and maps"),
+        (_, Struct(_)) => Some("only structs and timestamps can be cast to structs"),
+        (List(_) | LargeList(_) | FixedSizeList(_, _), _) => {
+            Some("lists can only be cast to lists and strings")
+        }
+        _ => None,
+    };
+    match detail {
+        Some(detail) => {
+            format!("Casting from {from_type:?} to {to_type:?} not supported, {detail}")
+        }
+        None => format!("Casting from {from_type:?} to {to_type:?} not supported"),
+    }
+}
+
+/// Returns true if casting from `from_type` to `to_type`, which must be supported,
+/// preserves every value
+fn is_lossless_cast(from_type: &DataType, to_type: &DataType) -> bool {
+    use DataType::*;
+    if from_type == to_type {
+        return true;
+    }
+    match (from_type, to_type) {
+        (Null, _) => true,
+        (_, Utf8 | LargeUtf8) if from_type.is_numeric() || from_type == &Boolean => true,
+        (Boolean, Decimal128(p, s) | Decimal256(p, s)) => *p as i16 - *s as i16 >= 1,
+        (Boolean, _) => to_type.is_numeric(),
+        (Utf8 | LargeUtf8, Utf8 | LargeUtf8 | Binary | LargeBinary) => true,
+        (Binary | LargeBinary, Binary | LargeBinary) => true,
+        (Float16, Float32 | Float64) | (Float32, Float64) => true,
+        (Date32, Date64) => true,
+        (Time32(from), Time32(to) | Time64(to)) | (Time64(from), Time64(to)) => {
+            time_unit_multiple(from) <= time_unit_multiple(to)
+        }
+        (
+            List(from) | LargeList(from) | FixedSizeList(from, _),
+            List(to) | LargeList(to),
+        ) => is_lossless_cast(from.data_type(), to.data_type()),
+        (FixedSizeList(from, from_size), FixedSizeList(to, to_size)) => {
+            from_size == to_size && is_lossless_cast(from.data_type(), to.data_type())
+        }
+        (Dictionary(from_key, from), Dictionary(to_key, to)) => {
+            is_lossless_cast(from_key, to_key) && is_lossless_cast(from, to)
+        }
+        (Dictionary(_, from), _) => is_lossless_cast(from, to_type),
+        (
+            Decimal128(p1, s1) | Decimal256(p1, s1),
+            Decimal128(p2, s2) | Decimal256(p2, s2),
+        ) => is_infallible_decimal_rescale(*p1, *s1, *p2, *s2),
+        (_, Decimal128(p, s) | Decimal256(p, s)) => match integer_digits(from_type) {
+            Some(digits) => digits <= *p as i16 - *s as i16,
+            None => false,
+        },
+        (_, Float16 | Float32 | Float64) => {
+            // Integers are exact if they fit in the mantissa of the float
+            let mantissa_bits = match to_type {
+                Float16 => 11,
+                Float32 => 24,
+                _ => 53,
+            };
+            match integer_bits(from_type) {
+                Some(bits) => bits <= mantissa_bits,
+                None => false,
+            }
+        }
+        (_, Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64) => {
+            match (integer_bits(from_type), integer_bits(to_type)) {
+                // An unsigned integer needs an additional bit as a signed integer
+                (Some(from), Some(to)) => {
+                    let signed = |t: &DataType| matches!(t, Int8 | Int16 | Int32 | Int64);
+                    match (signed(from_type), signed(to_type)) {
+                        (true, false) => false,
+                        _ => from <= to,
+                    }
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if [`cast_with_options`] casts every non-null value from `from_type` to
+/// `to_type` to a non-null value, and so never returns null or an error for it,
+/// regardless of [`CastOptions::safe`]
+///
+/// Casts that preserve every value, see [`cast_compatibility`], are infallible, as are
+/// some lossy casts, e.g. integers to floats, and decimals to decimals with fewer
+/// fractional digits and more integer digits, to which values are rounded. Otherwise
+/// casts are considered fallible, even if they only fail for some options. Casts of
+/// byte arrays and lists may still fail if the offsets of the result overflow, e.g.
+/// `LargeUtf8` to `Utf8`.
+///
+/// ```
+/// # use arrow_cast::is_infallible_cast;
+/// # use arrow_schema::DataType;
+/// // Every value with 5 integer digits has at most 8 digits with 3 more fractional digits
+/// let from = DataType::Decimal128(7, 2);
+/// assert!(is_infallible_cast(&from, &DataType::Decimal128(10, 5)));
+/// assert!(!is_infallible_cast(&from, &DataType::Decimal128(9, 5)));
+///
+/// // Lossy, as values are rounded, but infallible
+/// assert!(is_infallible_cast(&from, &DataType::Decimal128(7, 1)));
+/// assert!(is_infallible_cast(&DataType::Int64, &DataType::Float32));
+/// assert!(!is_infallible_cast(&DataType::Int64, &DataType::Int32));
+/// ```
+///
+/// [`cast_with_options`]: crate::cast::cast_with_options
+/// [`CastOptions::safe`]: crate::cast::CastOptions::safe
+pub fn is_infallible_cast(from_type: &DataType, to_type: &DataType) -> bool {
+    use DataType::*;
+    match (from_type, to_type) {
+        (
+            Decimal128(p1, s1) | Decimal256(p1, s1),
+            Decimal128(p2, s2) | Decimal256(p2, s2),
+        ) => {
+            // Rounding may carry into an additional integer digit
+            let integer_digits = |p: u8, s: i8| p as i16 - s as i16;
+            is_infallible_decimal_rescale(*p1, *s1, *p2, *s2)
+                || (s2 < s1 && integer_digits(*p2, *s2) > integer_digits(*p1, *s1))
+        }
+        (_, Float32 | Float64) if integer_bits(from_type).is_some() => true,
+        _ => can_cast_types(from_type, to_type) && is_lossless_cast(from_type, to_type),
+    }
+}
+
+/// Returns the number of magnitude bits of an integer type, excluding the sign bit
+fn integer_bits(data_type: &DataType) -> Option<u8> {
+    use DataType::*;
+    match data_type {
+        Int8 => Some(7),
+        Int16 => Some(15),
+        Int32 => Some(31),
+        Int64 => Some(63),
+        UInt8 => Some(8),
+        UInt16 => Some(16),
+        UInt32 => Some(32),
+        UInt64 => Some(64),
+        _ => None,
+    }
+}
+
+/// Returns the number of decimal digits of the largest value of an integer type
+fn integer_digits(data_type: &DataType) -> Option<i16> {
+    use DataType::*;
+    match data_type {
+        Int8 | UInt8 => Some(3),
+        Int16 | UInt16 => Some(5),
+        Int32 | UInt32 => Some(10),
+        Int64 => Some(19),
+        UInt64 => Some(20),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use arrow_schema::{Field, Fields, IntervalUnit, TimeUnit};
+
+    #[test]
+    fn test_cast_compatibility() {
+        use DataType::*;
+        let list = |t: DataType| List(Arc::new(Field::new("item", t, true)));
+        let dict = |k: DataType, v: DataType| Dictionary(Box::new(k), Box::new(v));
+
+        let supported = [
+            (Int32, Int32),
+            (Null, Int8),
+            (Int8, Int64),
+            (UInt32, Int64),
+            (UInt8, UInt16),
+            (Int16, Float32),
+            (Int32, Float64),
+            (Float16, Float64),
+            (Boolean, Int8),
+            (Boolean, Decimal128(1, 0)),
+            (Int32, Utf8),
+            (Float64, LargeUtf8),
+            (Utf8, LargeBinary),
+            (Date32, Date64),
+            (Time32(TimeUnit::Second), Time64(TimeUnit::Nanosecond)),
+            (Int32, Decimal128(12, 2)),
+            (Decimal128(10, 2), Decimal256(12, 4)),
+            (list(Int32), list(Int64)),
+            (dict(Int8, Utf8), dict(Int16, LargeUtf8)),
+            (dict(Int8, Int16), Int32),
+        ];
+        for (from, to) in supported {
+            assert_eq!(
+                cast_compatibility(&from, &to),
+                CastCompatibility::Supported,
+                "{from:?} to {to:?}"
+            );
+        }
+
+        let lossy = [
+            (Int64, Int32),
+            (Int8, UInt64),
+            (UInt32, Int32),
+            (Int64, Float64),
+            (Int32, Float32),
+            (Float64, Float32),
+            (Float32, Int64),
+            (Utf8, Int32),
+            (Boolean, Decimal128(2, 2)),
+            (Date64, Date32),
+            (Time64(TimeUnit::Nanosecond), Time64(TimeUnit::Microsecond)),
+            (
+                Timestamp(TimeUnit::Second, None),
+                Timestamp(TimeUnit::Nanosecond, None),
+            ),
+            (Int64, Decimal128(20, 2)),
+            (Decimal128(10, 2), Decimal128(10, 1)),
+            (Decimal128(10, 2), Decimal128(10, 3)),
+            (list(Int64), list(Int32)),
+            (dict(Int16, Utf8), dict(Int8, Utf8)),
+            (Utf8, dict(Int8, Utf8)),
+        ];
+        for (from, to) in lossy {
+            assert_eq!(
+                cast_compatibility(&from, &to),
+                CastCompatibility::SupportedLossy,
+                "{from:?} to {to:?}"
+            );
+        }
+
+        let unsupported = [
+            (
+                Duration(TimeUnit::Second),
+                Timestamp(TimeUnit::Second, None),
+                "Casting from Duration(Second) to Timestamp(Second, None) not supported, a duration is not an instant in time",
+            ),
+            (
+                list(Interval(IntervalUnit::DayTime)),
+                list(Duration(TimeUnit::Second)),
+                "Casting from Interval(DayTime) to Duration(Second) not supported, only Interval(MonthDayNano) has a fixed number of nanoseconds",
+            ),
+            (
+                list(Int32),
+                Int32,
+                "Casting from List(Field { name: \"item\", data_type: Int32, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} }) to Int32 not supported, lists can only be cast to lists and strings",
+            ),
+            (
+                Int32,
+                Struct(Fields::empty()),
+                "Casting from Int32 to Struct([]) not supported, only structs and timestamps can be cast to structs",
+            ),
+            (
+                dict(Int8, Binary),
+                Date32,
+                "Casting from Binary to Date32 not supported",
+            ),
+        ];
+        for (from, to, reason) in unsupported {
+            assert!(!can_cast_types(&from, &to));
+            assert_eq!(
+                cast_compatibility(&from, &to),
+                CastCompatibility::Unsupported {
+                    reason: reason.to_string()
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_infallible_cast() {
+        use DataType::*;
+        let infallible = [
+            (Int8, Int64),
+            (Int64, Float64),
+            (UInt64, Float32),
+            (Int32, Utf8),
+            (Decimal128(5, 2), Decimal128(8, 5)),
+            (Decimal128(10, 2), Decimal256(40, 32)),
+            (Decimal256(20, 0), Decimal128(38, 18)),
+            (Decimal128(10, 4), Decimal128(10, 3)),
+        ];
+        for (from, to) in infallible {
+            assert!(is_infallible_cast(&from, &to), "{from:?} to {to:?}");
+        }
+
+        let fallible = [
+            (Int64, Int32),
+            (Float64, Int64),
+            (Utf8, Int32),
+            (Decimal128(5, 2), Decimal128(7, 5)),
+            (Decimal256(40, 0), Decimal128(38, 0)),
+            (Decimal128(10, 4), Decimal128(9, 3)),
+            (Int32, Struct(Fields::empty())),
+        ];
+        for (from, to) in fallible {
+            assert!(!is_infallible_cast(&from, &to), "{from:?} to {to:?}");
+        }
+    }
+}
@@ -0,0 +1,581 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels rescaling decimal arrays, as when casting between decimal types
+
+use crate::cast::{count_precision_loss, CastOptions};
+use arrow_array::types::DecimalType;
+use arrow_array::{ArrowNativeTypeOp, ArrowPrimitiveType, PrimitiveArray};
+use arrow_buffer::i256;
+use arrow_schema::ArrowError;
+use num::ToPrimitive;
+
+/// A utility trait that provides checked conversions between
+/// decimal types inspired by [`NumCast`](num::NumCast)
+///
+/// Implemented for the native types of [`Decimal128Type`] and [`Decimal256Type`]
+///
+/// [`Decimal128Type`]: arrow_array::types::Decimal128Type
+/// [`Decimal256Type`]: arrow_array::types::Decimal256Type
+pub trait DecimalCast: Sized {
+    /// Converts to an `i128`, returning `None` if it does not fit
+    fn to_i128(self) -> Option<i128>;
+
+    /// Converts to an [`i256`], returning `None` if it does not fit
+    fn to_i256(self) -> Option<i256>;
+
+    /// Converts from another decimal native type, returning `None` if it does not fit
+    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self>;
+
+    /// Converts from an `f64`, truncating any fractional part, returning `None` if it
+    /// does not fit or is not finite
+    fn from_f64(n: f64) -> Option<Self>;
+}
+
+impl DecimalCast for i128 {
+    fn to_i128(self) -> Option<i128> {
+        Some(self)
+    }
+
+    fn to_i256(self) -> Option<i256> {
+        Some(i256::from_i128(self))
+    }
+
+    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
+        n.to_i128()
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        ToPrimitive::to_i128(&n)
+    }
+}
+
+impl DecimalCast for i256 {
+    fn to_i128(self) -> Option<i128> {
+        self.to_i128()
+    }
+
+    fn to_i256(self) -> Option<i256> {
+        Some(self)
+    }
+
+    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
+        n.to_i256()
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        i256::from_f64(n)
+    }
+}
+
+fn cast_decimal_to_decimal_error<I, O>(
+    output_precision: u8,
+    output_scale: i8,
+) -> impl Fn(<I as ArrowPrimitiveType>::Native) -> ArrowError
+where
+    I: DecimalType,
+    O: DecimalType,
+    I::Native: DecimalCast + ArrowNativeTypeOp,
+    O::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    move |x: I::Native| {
+        ArrowError::CastError(format!(
+            "Cannot cast to {}({}, {}). Overflowing on {:?}",
+            O::PREFIX,
+            output_precision,
+            output_scale,
+            x
+        ))
+    }
+}
+
+fn convert_to_smaller_scale_decimal<I, O>(
+    array: &PrimitiveArray<I>,
+    input_scale: i8,
+    output_precision: u8,
+    output_scale: i8,
+    cast_options: &CastOptions,
+) -> Result<PrimitiveArray<O>, ArrowError>
+where
+    I: DecimalType,
+    O: DecimalType,
+    I::Native: DecimalCast + ArrowNativeTypeOp,
+    O::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    let error = cast_decimal_to_decimal_error::<I, O>(output_precision, output_scale);
+    let div = I::Native::from_decimal(10_i128)
+        .unwrap()
+        .pow_checked((input_scale - output_scale) as u32)?;
+
+    count_precision_loss(array, cast_options, |x| {
+        x.mod_wrapping(div) != I::Native::ZERO
+    });
+
+    let f = |x: I::Native| {
+        // div is >= 10 and so this cannot overflow
+        let d = x.div_wrapping(div);
+        let r = x.mod_wrapping(div);
+        O::Native::from_decimal(cast_options.rounding.round(d, r, div))
+    };
+
+    Ok(match cast_options.safe {
+        true => array.unary_opt(f),
+        false => array.try_unary(|x| f(x).ok_or_else(|| error(x)))?,
+    })
+}
+
+/// Returns true if every one of `values`, including those of null slots, has at most
+/// `precision` digits
+fn values_within_precision<T>(values: &[T], precision: u8) -> bool
+where
+    T: DecimalCast + ArrowNativeTypeOp,
+{
+    let max = match T::from_decimal(10_i128)
+        .unwrap()
+        .pow_checked(precision as u32)
+    {
+        Ok(max) => max,
+        Err(_) => return false,
+    };
+    let min = max.neg_wrapping();
+    values
+        .iter()
+        .fold(true, |valid, x| valid & x.is_lt(max) & x.is_gt(min))
+}
+
+/// Returns true if every decimal value with `input_precision` and `input_scale` can be
+/// rescaled to `output_precision` and `output_scale` without losing digits, i.e. if the
+/// output has at least as many fractional and integer digits
+pub(crate) fn is_infallible_decimal_rescale(
+    input_precision: u8,
+    input_scale: i8,
+    output_precision: u8,
+    output_scale: i8,
+) -> bool {
+    let integer_digits = |p: u8, s: i8| p as i16 - s as i16;
+    output_scale >= input_scale
+        && integer_digits(output_precision, output_scale)
+            >= integer_digits(input_precision, input_scale)
+}
+
+fn convert_to_bigger_or_equal_scale_decimal<I, O>(
+    array: &PrimitiveArray<I>,
+    input_scale: i8,
+    output_precision: u8,
+    output_scale: i8,
+    cast_options: &CastOptions,
+) -> Result<PrimitiveArray<O>, ArrowError>
+where
+    I: DecimalType,
+    O: DecimalType,
+    I::Native: DecimalCast + ArrowNativeTypeOp,
+    O::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    let error = cast_decimal_to_decimal_error::<I, O>(output_precision, output_scale);
+    let mul = O::Native::from_decimal(10_i128)
+        .unwrap()
+        .pow_checked((output_scale - input_scale) as u32)?;
+
+    // Values with at most the input precision digits are at most the output precision
+    // digits when rescaled, and so cannot overflow. The precision of arrays is not
+    // enforced when they are built, and so the values are validated first, in a single
+    // pass that can be vectorized, falling back to checking each value if any exceed it
+    let input_precision = array.precision();
+    if output_precision <= O::MAX_PRECISION
+        && is_infallible_decimal_rescale(
+            input_precision,
+            input_scale,
+            output_precision,
+            output_scale,
+        )
+        && values_within_precision(array.values(), input_precision)
+    {
+        return Ok(array.unary(|x| {
+            // Every value has at most `input_precision` digits, and so fits in O
+            let x = O::Native::from_decimal(x).unwrap();
+            x.mul_wrapping(mul)
+        }));
+    }
+
+    let f = |x| O::Native::from_decimal(x).and_then(|x| x.mul_checked(mul).ok());
+
+    Ok(match cast_options.safe {
+        true => array.unary_opt(f),
+        false => array.try_unary(|x| f(x).ok_or_else(|| error(x)))?,
+    })
+}
+
+/// Rescales the decimal `array` with scale `input_scale` to the decimal type `O` with
+/// `output_precision` and `output_scale`, as when casting between decimal types
+///
+/// Reducing the scale rounds half away from zero. Values that overflow the native type
+/// of `O` are returned as null, or as an error if [`CastOptions::safe`] is false. The
+/// values are not validated against `output_precision`, see
+/// [`PrimitiveArray::validate_decimal_precision`]
+///
+/// ```
+/// # use arrow_array::Decimal128Array;
+/// # use arrow_array::types::{Decimal128Type, Decimal256Type};
+/// # use arrow_buffer::i256;
+/// # use arrow_cast::{rescale_decimal, DEFAULT_CAST_OPTIONS};
+/// // 1.2345 and 2.5
+/// let array = Decimal128Array::from(vec![12345, 25000])
+///     .with_precision_and_scale(10, 4)
+///     .unwrap();
+///
+/// let out = rescale_decimal::<Decimal128Type, Decimal128Type>(
+///     &array, 4, 10, 1, &DEFAULT_CAST_OPTIONS,
+/// )
+/// .unwrap();
+/// assert_eq!(out.values(), &[12, 25]);
+/// assert_eq!(out.scale(), 1);
+///
+/// let out = rescale_decimal::<Decimal128Type, Decimal256Type>(
+///     &array, 4, 40, 6, &DEFAULT_CAST_OPTIONS,
+/// )
+/// .unwrap();
+/// assert_eq!(out.value(0), i256::from_i128(1234500));
+/// ```
+pub fn rescale_decimal<I, O>(
+    array: &PrimitiveArray<I>,
+    input_scale: i8,
+    output_precision: u8,
+    output_scale: i8,
+    cast_options: &CastOptions,
+) -> Result<PrimitiveArray<O>, ArrowError>
+where
+    I: DecimalType,
+    O: DecimalType,
+    I::Native: DecimalCast + ArrowNativeTypeOp,
+    O::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    let array: PrimitiveArray<O> = if input_scale > output_scale {
+        convert_to_smaller_scale_decimal::<I, O>(
+            array,
+            input_scale,
+            output_precision,
+            output_scale,
+            cast_options,
+        )?
+    } else {
+        convert_to_bigger_or_equal_scale_decimal::<I, O>(
+            array,
+            input_scale,
+            output_precision,
+            output_scale,
+            cast_options,
+        )?
+    };
+
+    array.with_precision_and_scale(output_precision, output_scale)
+}
+
+/// The values of a decimal array that could not be represented after rescaling, see
+/// [`rescale_decimal_with_stats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DecimalOverflowStats {
+    /// the number of non-null values that overflowed, and were returned as null
+    pub overflow_count: usize,
+    /// the smallest absolute value that overflowed, unscaled at the input scale
+    pub min_magnitude: Option<i256>,
+    /// the largest absolute value that overflowed, unscaled at the input scale
+    pub max_magnitude: Option<i256>,
+}
+
+impl DecimalOverflowStats {
+    fn record(&mut self, value: i256) {
+        let magnitude = value.checked_abs().unwrap_or(i256::MAX);
+        self.overflow_count += 1;
+        self.min_magnitude =
+            Some(self.min_magnitude.map_or(magnitude, |m| m.min(magnitude)));
+        self.max_magnitude =
+            Some(self.max_magnitude.map_or(magnitude, |m| m.max(magnitude)));
+    }
+}
+
+/// Rescales the decimal `array` as [`rescale_decimal`], additionally returning
+/// statistics of the values that overflowed
+///
+/// Unlike [`rescale_decimal`], values that do not fit `output_precision` are treated
+/// as overflowing, and are returned as null, or as an error if [`CastOptions::safe`]
+/// is false. The magnitudes of the overflowing values can be used to choose a
+/// precision that fits them.
+///
+/// ```
+/// # use arrow_array::{Array, Decimal128Array};
+/// # use arrow_array::types::Decimal128Type;
+/// # use arrow_buffer::i256;
+/// # use arrow_cast::{rescale_decimal_with_stats, DEFAULT_CAST_OPTIONS};
+/// // 1.5, 123.45 and -9999.99
+/// let array = Decimal128Array::from(vec![150, 12345, -999999])
+///     .with_precision_and_scale(6, 2)
+///     .unwrap();
+///
+/// let (out, stats) = rescale_decimal_with_stats::<Decimal128Type, Decimal128Type>(
+///     &array, 2, 3, 1, &DEFAULT_CAST_OPTIONS,
+/// )
+/// .unwrap();
+/// assert_eq!(out.value(0), 15);
+/// assert_eq!(out.null_count(), 2);
+/// assert_eq!(stats.overflow_count, 2);
+/// assert_eq!(stats.min_magnitude, Some(i256::from_i128(12345)));
+/// assert_eq!(stats.max_magnitude, Some(i256::from_i128(999999)));
+/// ```
+pub fn rescale_decimal_with_stats<I, O>(
+    array: &PrimitiveArray<I>,
+    input_scale: i8,
+    output_precision: u8,
+    output_scale: i8,
+    cast_options: &CastOptions,
+) -> Result<(PrimitiveArray<O>, DecimalOverflowStats), ArrowError>
+where
+    I: DecimalType,
+    O: DecimalType,
+    I::Native: DecimalCast + ArrowNativeTypeOp,
+    O::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    let rescaled = rescale_decimal::<I, O>(
+        array,
+        input_scale,
+        output_precision,
+        output_scale,
+        cast_options,
+    )?;
+
+    let mut stats = DecimalOverflowStats::default();
+    let out: PrimitiveArray<O> = array
+        .iter()
+        .zip(rescaled.iter())
+        .map(|(v, r)| {
+            let v = match v {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+            // Only null when safe, otherwise rescale_decimal errors
+            if let Some(r) = r {
+                match O::validate_decimal_precision(r, output_precision) {
+                    Ok(()) => return Ok(Some(r)),
+                    Err(e) if !cast_options.safe => return Err(e),
+                    Err(_) => {}
+                }
+            }
+            // Decimal natives always fit in an i256
+            stats.record(v.to_i256().unwrap());
+            Ok(None)
+        })
+        .collect::<Result<_, ArrowError>>()?;
+
+    Ok((
+        out.with_precision_and_scale(output_precision, output_scale)?,
+        stats,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast::DEFAULT_CAST_OPTIONS;
+    use arrow_array::types::{Decimal128Type, Decimal256Type};
+    use arrow_array::{Array, Decimal128Array, Decimal256Array};
+    use arrow_schema::DataType;
+
+    #[test]
+    fn test_rescale_decimal() {
+        let array = Decimal128Array::from(vec![Some(-15), Some(14), None, Some(25)])
+            .with_precision_and_scale(5, 1)
+            .unwrap();
+        let out = rescale_decimal::<Decimal128Type, Decimal128Type>(
+            &array,
+            1,
+            5,
+            0,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap();
+        assert_eq!(out.data_type(), &DataType::Decimal128(5, 0));
+        let values: Vec<_> = out.iter().collect();
+        assert_eq!(values, vec![Some(-2), Some(1), None, Some(3)]);
+
+        let array = Decimal256Array::from(vec![i256::MAX, i256::ONE])
+            .with_precision_and_scale(76, 0)
+            .unwrap();
+        let out = rescale_decimal::<Decimal256Type, Decimal128Type>(
+            &array,
+            0,
+            38,
+            2,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap();
+        assert!(out.is_null(0));
+        assert_eq!(out.value(1), 100);
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err =
+            rescale_decimal::<Decimal256Type, Decimal128Type>(&array, 0, 38, 2, &options)
+                .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Cast error: Cannot cast to Decimal128(38, 2). Overflowing on"));
+    }
+
+    #[test]
+    fn test_rescale_decimal_infallible() {
+        let values = vec![Some(99_999), Some(-99_999), None, Some(0), Some(12)];
+        let array = Decimal128Array::from(values)
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+
+        // 3 integer digits and at least 2 fractional digits cannot overflow
+        let options = CastOptions::new().with_safe(false);
+        let out =
+            rescale_decimal::<Decimal128Type, Decimal128Type>(&array, 2, 8, 5, &options)
+                .unwrap();
+        let values: Vec<_> = out.iter().collect();
+        assert_eq!(
+            values,
+            vec![
+                Some(99_999_000),
+                Some(-99_999_000),
+                None,
+                Some(0),
+                Some(12_000)
+            ]
+        );
+
+        let out = rescale_decimal::<Decimal128Type, Decimal256Type>(
+            &array, 2, 38, 35, &options,
+        )
+        .unwrap();
+        assert_eq!(out.value(1), i256::from_i128(-99_999 * 10_i128.pow(33)));
+        assert!(out.is_null(2));
+
+        let array = Decimal256Array::from(vec![i256::from_i128(-123), i256::ONE])
+            .with_precision_and_scale(20, 0)
+            .unwrap();
+        let out = rescale_decimal::<Decimal256Type, Decimal128Type>(
+            &array, 0, 38, 18, &options,
+        )
+        .unwrap();
+        assert_eq!(out.values(), &[-123 * 10_i128.pow(18), 10_i128.pow(18)]);
+
+        // Values that exceed the precision of the array are checked
+        let array = Decimal128Array::from(vec![Some(10_i128.pow(37)), Some(1)])
+            .with_precision_and_scale(5, 0)
+            .unwrap();
+        let out = rescale_decimal::<Decimal128Type, Decimal128Type>(
+            &array,
+            0,
+            38,
+            33,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap();
+        assert!(out.is_null(0));
+        assert_eq!(out.value(1), 10_i128.pow(33));
+        let err = rescale_decimal::<Decimal128Type, Decimal128Type>(
+            &array, 0, 38, 33, &options,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Overflowing on"), "{err}");
+
+        let array = Decimal256Array::from(vec![i256::MAX, i256::ONE])
+            .with_precision_and_scale(20, 0)
+            .unwrap();
+        let err = rescale_decimal::<Decimal256Type, Decimal128Type>(
+            &array, 0, 38, 18, &options,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Overflowing on"), "{err}");
+
+        // Otherwise values are checked, as they may overflow
+        let array = Decimal128Array::from(vec![10_i128.pow(37)])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        let out = rescale_decimal::<Decimal128Type, Decimal128Type>(
+            &array,
+            0,
+            38,
+            2,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap();
+        assert!(out.is_null(0));
+    }
+
+    #[test]
+    fn test_rescale_decimal_with_stats() {
+        let array = Decimal256Array::from(vec![
+            Some(i256::from_i128(-1234)),
+            None,
+            Some(i256::from_i128(99)),
+            Some(i256::MIN),
+            Some(i256::from_i128(150)),
+        ])
+        .with_precision_and_scale(76, 1)
+        .unwrap();
+        let (out, stats) = rescale_decimal_with_stats::<Decimal256Type, Decimal128Type>(
+            &array,
+            1,
+            3,
+            2,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap();
+        assert_eq!(out.data_type(), &DataType::Decimal128(3, 2));
+        let values: Vec<_> = out.iter().collect();
+        assert_eq!(values, vec![None, None, Some(990), None, None]);
+        assert_eq!(
+            stats,
+            DecimalOverflowStats {
+                overflow_count: 3,
+                min_magnitude: Some(i256::from_i128(150)),
+                max_magnitude: Some(i256::MAX),
+            }
+        );
+
+        let array = Decimal128Array::from(vec![Some(1), None])
+            .with_precision_and_scale(3, 0)
+            .unwrap();
+        let (out, stats) = rescale_decimal_with_stats::<Decimal128Type, Decimal128Type>(
+            &array,
+            0,
+            3,
+            0,
+            &DEFAULT_CAST_OPTIONS,
+        )
+        .unwrap();
+        assert_eq!(out, array);
+        assert_eq!(stats, DecimalOverflowStats::default());
+
+        let options = CastOptions::new().with_safe(false);
+        let array = Decimal128Array::from(vec![99, 100])
+            .with_precision_and_scale(3, 0)
+            .unwrap();
+        let err = rescale_decimal_with_stats::<Decimal128Type, Decimal128Type>(
+            &array, 0, 3, 1, &options,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: 1000 is too large to store in a Decimal128 of precision 3. Max is 999"
+        );
+    }
+}
@@ -29,7 +29,8 @@ use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
 use arrow_schema::*;
-use chrono::{NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+use chrono::format::{Fixed, Item, Numeric, Pad, StrftimeItems};
+use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
 use lexical_core::FormattedSize;
 
 type TimeFormat<'a> = Option<&'a str>;
@@ -39,7 +40,12 @@ type TimeFormat<'a> = Option<&'a str>;
 /// By default nulls are formatted as `""` and temporal types formatted
 /// according to RFC3339
 ///
-#[derive(Debug, Clone)]
+/// With the `serde` feature, options can be serialized and deserialized, borrowing
+/// their strings from the input
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct FormatOptions<'a> {
     /// If set to `true` any formatting errors will be written to the output
     /// instead of being converted into a [`std::fmt::Error`]
@@ -47,19 +53,37 @@ pub struct FormatOptions<'a> {
     /// Format string for nulls
     null: &'a str,
     /// Date format for date arrays
+    #[cfg_attr(feature = "serde", serde(borrow))]
     date_format: TimeFormat<'a>,
     /// Format for DateTime arrays
+    #[cfg_attr(feature = "serde", serde(borrow))]
     datetime_format: TimeFormat<'a>,
     /// Timestamp format for timestamp arrays
+    #[cfg_attr(feature = "serde", serde(borrow))]
     timestamp_format: TimeFormat<'a>,
     /// Timestamp format for timestamp with timezone arrays
+    #[cfg_attr(feature = "serde", serde(borrow))]
     timestamp_tz_format: TimeFormat<'a>,
     /// Time format for time arrays
+    #[cfg_attr(feature = "serde", serde(borrow))]
     time_format: TimeFormat<'a>,
+    /// Format decimals in scientific notation
+    decimal_scientific: bool,
+    /// Format for duration arrays
+    duration_format: DurationFormat,
+    /// Format for interval arrays
+    interval_format: DurationFormat,
 }
 
 impl<'a> Default for FormatOptions<'a> {
     fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> FormatOptions<'a> {
+    /// Creates a new set of format options
+    pub const fn new() -> Self {
         Self {
             safe: true,
             null: "",
@@ -68,11 +92,12 @@ impl<'a> Default for FormatOptions<'a> {
             timestamp_format: None,
             timestamp_tz_format: None,
             time_format: None,
+            decimal_scientific: false,
+            duration_format: DurationFormat::Iso8601,
+            interval_format: DurationFormat::Pretty,
         }
     }
-}
 
-impl<'a> FormatOptions<'a> {
     /// If set to `true` any formatting errors will be written to the output
     /// instead of being converted into a [`std::fmt::Error`]
     pub fn with_display_error(mut self, safe: bool) -> Self {
@@ -126,6 +151,50 @@ impl<'a> FormatOptions<'a> {
             ..self
         }
     }
+
+    /// If set to `true` decimals are formatted in scientific notation, with a single
+    /// integer digit and no trailing zeros, e.g. `1.5E-3` instead of `0.00150`
+    ///
+    /// Defaults to `false`
+    pub fn with_decimal_scientific(self, decimal_scientific: bool) -> Self {
+        Self {
+            decimal_scientific,
+            ..self
+        }
+    }
+
+    /// Overrides the format used for [`DataType::Duration`] columns
+    ///
+    /// Defaults to [`DurationFormat::Iso8601`]
+    pub fn with_duration_format(self, duration_format: DurationFormat) -> Self {
+        Self {
+            duration_format,
+            ..self
+        }
+    }
+
+    /// Overrides the format used for [`DataType::Interval`] columns
+    ///
+    /// Defaults to [`DurationFormat::Pretty`]
+    pub fn with_interval_format(self, interval_format: DurationFormat) -> Self {
+        Self {
+            interval_format,
+            ..self
+        }
+    }
+}
+
+/// The format of durations and intervals, see [`FormatOptions::with_duration_format`]
+/// and [`FormatOptions::with_interval_format`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DurationFormat {
+    /// ISO 8601, e.g. `P1DT3600S` for durations, and `P1Y2M3DT4H5M6.5S` for
+    /// intervals, with a sign on each component of mixed sign intervals
+    Iso8601,
+    /// Each component written out, e.g. `1 days 1 hours 0 mins 0 secs` for durations,
+    /// and `1 years 2 mons 3 days 4 hours 5 mins 6.500000000 secs` for intervals
+    Pretty,
 }
 
 /// Implements [`Display`] for a specific array value
@@ -408,14 +477,18 @@ impl<'a> DisplayIndex for &'a PrimitiveArray<Float16Type> {
 macro_rules! decimal_display {
     ($($t:ty),+) => {
         $(impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<$t> {
-            type State = (u8, i8);
+            type State = (u8, i8, bool);
 
-            fn prepare(&self, _options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
-                Ok((self.precision(), self.scale()))
+            fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+                Ok((self.precision(), self.scale(), options.decimal_scientific))
             }
 
             fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
-                write!(f, "{}", <$t>::format_decimal(self.values()[idx], s.0, s.1))?;
+                let value = self.values()[idx];
+                match s.2 {
+                    true => write_decimal_scientific::<$t>(f, value, s.1)?,
+                    false => write_decimal::<$t>(f, value, s.0, s.1)?,
+                }
                 Ok(())
             }
         })+
@@ -424,24 +497,148 @@ macro_rules! decimal_display {
 
 decimal_display!(Decimal128Type, Decimal256Type);
 
+/// Room for the digits of any unscaled decimal, the 77 digits of `i256::MIN` being the most
+const MAX_DECIMAL_DIGITS: usize = 80;
+
+/// Writes the decimal digits of the unscaled `value` to the end of `buf`, returning
+/// whether it is negative and the digits, without a sign
+fn decimal_digits<T: DecimalType>(
+    value: T::Native,
+    buf: &mut [u8; MAX_DECIMAL_DIGITS],
+) -> (bool, &str) {
+    // Work with non-positive values, so that the minimum value does not overflow
+    let negative = value.is_lt(T::Native::ZERO);
+    let mut value = match negative {
+        true => value,
+        false => value.neg_wrapping(),
+    };
+    let chunk = T::Native::usize_as(1_000_000_000);
+
+    let mut start = buf.len();
+    loop {
+        let mut remainder = value.mod_wrapping(chunk).neg_wrapping().as_usize();
+        value = value.div_wrapping(chunk);
+        let last = value.is_zero();
+        // Every chunk but the most significant is padded to nine digits
+        let mut digits = 0;
+        while digits < 9 && !(last && remainder == 0 && digits > 0) {
+            start -= 1;
+            buf[start] = b'0' + (remainder % 10) as u8;
+            remainder /= 10;
+            digits += 1;
+        }
+        if last {
+            break;
+        }
+    }
+    // The buffer only contains ASCII digits
+    (negative, std::str::from_utf8(&buf[start..]).unwrap())
+}
+
+/// Writes the decimal with the unscaled `value`, truncated to `precision` digits, and
+/// `scale`, as [`DecimalType::format_decimal`] formats it, without allocating a string
+///
+/// This writes the digits of decimals cast to strings, including the distinct values of
+/// decimals cast to string dictionaries
+///
+/// ```
+/// # use arrow_array::types::Decimal128Type;
+/// # use arrow_cast::display::write_decimal;
+/// let mut s = String::new();
+/// write_decimal::<Decimal128Type>(&mut s, -12345, 10, 2).unwrap();
+/// assert_eq!(s, "-123.45");
+/// ```
+pub fn write_decimal<T: DecimalType>(
+    f: &mut dyn Write,
+    value: T::Native,
+    precision: u8,
+    scale: i8,
+) -> std::fmt::Result {
+    let mut buf = [0; MAX_DECIMAL_DIGITS];
+    let (negative, rest) = decimal_digits::<T>(value, &mut buf);
+    let sign = if negative { "-" } else { "" };
+    let rest = &rest[..rest.len().min(precision as usize)];
+
+    if scale <= 0 {
+        write!(f, "{sign}{rest}")?;
+        for _ in 0..scale.unsigned_abs() {
+            f.write_char('0')?;
+        }
+    } else if rest.len() > scale as usize {
+        // Decimal separator is in the middle of the digits
+        let (whole, fraction) = rest.split_at(rest.len() - scale as usize);
+        write!(f, "{sign}{whole}.{fraction}")?;
+    } else {
+        write!(f, "{sign}0.{rest:0>width$}", width = scale as usize)?;
+    }
+    Ok(())
+}
+
+/// Writes the decimal with the unscaled `value` and `scale` in scientific notation
+fn write_decimal_scientific<T: DecimalType>(
+    f: &mut dyn Write,
+    value: T::Native,
+    scale: i8,
+) -> FormatResult {
+    let mut buf = [0; MAX_DECIMAL_DIGITS];
+    let (negative, digits) = decimal_digits::<T>(value, &mut buf);
+    let sign = if negative { "-" } else { "" };
+    let significant = digits.trim_end_matches('0');
+    if significant.is_empty() {
+        f.write_str("0E0")?;
+        return Ok(());
+    }
+
+    let exponent = digits.len() as i64 - 1 - scale as i64;
+    let (first, rest) = significant.split_at(1);
+    match rest.is_empty() {
+        true => write!(f, "{sign}{first}E{exponent}")?,
+        false => write!(f, "{sign}{first}.{rest}E{exponent}")?,
+    }
+    Ok(())
+}
+
+/// The items of [`chrono::DateTime::to_rfc3339_opts`] with [`chrono::SecondsFormat::AutoSi`]
+/// and `use_z`, which are written directly to avoid allocating a string per value
+const RFC3339_ITEMS: &[Item<'static>] = &[
+    Item::Numeric(Numeric::Year, Pad::Zero),
+    Item::Literal("-"),
+    Item::Numeric(Numeric::Month, Pad::Zero),
+    Item::Literal("-"),
+    Item::Numeric(Numeric::Day, Pad::Zero),
+    Item::Literal("T"),
+    Item::Numeric(Numeric::Hour, Pad::Zero),
+    Item::Literal(":"),
+    Item::Numeric(Numeric::Minute, Pad::Zero),
+    Item::Literal(":"),
+    Item::Numeric(Numeric::Second, Pad::Zero),
+    Item::Fixed(Fixed::Nanosecond),
+    Item::Fixed(Fixed::TimezoneOffsetColonZ),
+];
+
+/// A timestamp format, parsed once per array
+type TimestampFormat<'a> = Option<Vec<Item<'a>>>;
+
+fn parse_format(format: TimeFormat<'_>) -> TimestampFormat<'_> {
+    format.map(|s| StrftimeItems::new(s).collect())
+}
+
 fn write_timestamp(
     f: &mut dyn Write,
     naive: NaiveDateTime,
     timezone: Option<Tz>,
-    format: Option<&str>,
+    format: &TimestampFormat<'_>,
 ) -> FormatResult {
     match timezone {
         Some(tz) => {
             let date = Utc.from_utc_datetime(&naive).with_timezone(&tz);
             match format {
-                Some(s) => write!(f, "{}", date.format(s))?,
-                None => {
-                    write!(f, "{}", date.to_rfc3339_opts(SecondsFormat::AutoSi, true))?
-                }
+                Some(items) => write!(f, "{}", date.format_with_items(items.iter()))?,
+                None => write!(f, "{}", date.format_with_items(RFC3339_ITEMS.iter()))?,
             }
         }
         None => match format {
-            Some(s) => write!(f, "{}", naive.format(s))?,
+            Some(items) => write!(f, "{}", naive.format_with_items(items.iter()))?,
             None => write!(f, "{naive:?}")?,
         },
     }
@@ -451,12 +648,12 @@ fn write_timestamp(
 macro_rules! timestamp_display {
     ($($t:ty),+) => {
         $(impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<$t> {
-            type State = (Option<Tz>, TimeFormat<'a>);
+            type State = (Option<Tz>, TimestampFormat<'a>);
 
             fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
                 match self.data_type() {
-                    DataType::Timestamp(_, Some(tz)) => Ok((Some(tz.parse()?), options.timestamp_tz_format)),
-                    DataType::Timestamp(_, None) => Ok((None, options.timestamp_format)),
+                    DataType::Timestamp(_, Some(tz)) => Ok((Some(tz.parse()?), parse_format(options.timestamp_tz_format))),
+                    DataType::Timestamp(_, None) => Ok((None, parse_format(options.timestamp_format))),
                     _ => unreachable!(),
                 }
             }
@@ -471,7 +668,7 @@ macro_rules! timestamp_display {
                     ))
                 })?;
 
-                write_timestamp(f, naive, s.0, s.1.clone())
+                write_timestamp(f, naive, s.0, &s.1)
             }
         })+
     };
@@ -534,24 +731,158 @@ temporal_display!(time64us_to_time, time_format, Time64MicrosecondType);
 temporal_display!(time64ns_to_time, time_format, Time64NanosecondType);
 
 macro_rules! duration_display {
-    ($convert:ident, $t:ty) => {
-        impl<'a> DisplayIndex for &'a PrimitiveArray<$t> {
-            fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
-                write!(f, "{}", $convert(self.value(idx)))?;
+    ($t:ty, $unit:expr, $digits:expr) => {
+        impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<$t> {
+            type State = DurationFormat;
+
+            fn prepare(
+                &self,
+                options: &FormatOptions<'a>,
+            ) -> Result<Self::State, ArrowError> {
+                Ok(options.duration_format)
+            }
+
+            fn write(
+                &self,
+                fmt: &Self::State,
+                idx: usize,
+                f: &mut dyn Write,
+            ) -> FormatResult {
+                let v = self.value(idx);
+                match fmt {
+                    DurationFormat::Iso8601 => write_iso_duration(f, v, $unit)?,
+                    DurationFormat::Pretty => {
+                        write_pretty_duration(f, v, $unit, $digits)?
+                    }
+                }
                 Ok(())
             }
         }
     };
 }
 
-duration_display!(duration_s_to_duration, DurationSecondType);
-duration_display!(duration_ms_to_duration, DurationMillisecondType);
-duration_display!(duration_us_to_duration, DurationMicrosecondType);
-duration_display!(duration_ns_to_duration, DurationNanosecondType);
+duration_display!(DurationSecondType, 1, 0);
+duration_display!(DurationMillisecondType, 1_000, 3);
+duration_display!(DurationMicrosecondType, 1_000_000, 6);
+duration_display!(DurationNanosecondType, 1_000_000_000, 9);
 
-impl<'a> DisplayIndex for &'a PrimitiveArray<IntervalYearMonthType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
-        let interval = self.value(idx) as f64;
+/// Writes a duration of `value` units, with `unit` units per second, in ISO 8601 form,
+/// e.g. `P1DT5400.500S`, with the fractional seconds as milliseconds, microseconds or
+/// nanoseconds, whichever is exact
+///
+/// This is written here rather than with the `Display` of [`chrono::Duration`], whose
+/// output differs between versions of chrono, and which cannot represent all durations
+fn write_iso_duration(f: &mut dyn Write, value: i64, unit: i64) -> FormatResult {
+    let (abs, unit) = (value.unsigned_abs(), unit as u64);
+    let (secs, nanos) = (abs / unit, abs % unit * (1_000_000_000 / unit));
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+
+    let sign = if value < 0 { "-" } else { "" };
+    write!(f, "{sign}P")?;
+    if days != 0 {
+        write!(f, "{days}D")?;
+    }
+    if days == 0 || secs != 0 || nanos != 0 {
+        write!(f, "T{secs}")?;
+        match nanos {
+            0 => {}
+            n if n % 1_000_000 == 0 => write!(f, ".{:03}", n / 1_000_000)?,
+            n if n % 1_000 == 0 => write!(f, ".{:06}", n / 1_000)?,
+            n => write!(f, ".{n:09}")?,
+        }
+        write!(f, "S")?;
+    }
+    Ok(())
+}
+
+/// Writes a duration of `value` units, with `unit` units per second, as its days, hours,
+/// minutes and seconds, the seconds with `digits` fractional digits
+fn write_pretty_duration(
+    f: &mut dyn Write,
+    value: i64,
+    unit: i64,
+    digits: usize,
+) -> FormatResult {
+    let (secs, fraction) = (value / unit, value % unit);
+    let mins = secs / 60;
+    let hours = mins / 60;
+    let days = hours / 24;
+    let (secs, mins, hours) = (secs % 60, mins % 60, hours % 24);
+
+    let sign = if secs < 0 || fraction < 0 { "-" } else { "" };
+    write!(
+        f,
+        "{days} days {hours} hours {mins} mins {sign}{}",
+        secs.abs()
+    )?;
+    if digits > 0 {
+        write!(f, ".{:0digits$}", fraction.abs())?;
+    }
+    write!(f, " secs")?;
+    Ok(())
+}
+
+/// Writes an interval in ISO 8601 form, e.g. `P1Y2M3DT4H5M6.5S`, where each non-zero
+/// component has its own sign, and a zero interval is `PT0S`
+fn write_iso_interval(
+    f: &mut dyn Write,
+    months: i32,
+    days: i32,
+    nanos: i64,
+) -> FormatResult {
+    const NANOS_PER_SEC: i64 = 1_000_000_000;
+    if months == 0 && days == 0 && nanos == 0 {
+        write!(f, "PT0S")?;
+        return Ok(());
+    }
+
+    write!(f, "P")?;
+    for (value, designator) in [(months / 12, 'Y'), (months % 12, 'M'), (days, 'D')] {
+        if value != 0 {
+            write!(f, "{value}{designator}")?;
+        }
+    }
+    if nanos == 0 {
+        return Ok(());
+    }
+
+    write!(f, "T")?;
+    let (hours, mins) = (
+        nanos / (3600 * NANOS_PER_SEC),
+        nanos / (60 * NANOS_PER_SEC) % 60,
+    );
+    for (value, designator) in [(hours, 'H'), (mins, 'M')] {
+        if value != 0 {
+            write!(f, "{value}{designator}")?;
+        }
+    }
+    let nanos = nanos % (60 * NANOS_PER_SEC);
+    if nanos != 0 {
+        let sign = if nanos < 0 { "-" } else { "" };
+        let (secs, fraction) = (nanos.abs() / NANOS_PER_SEC, nanos.abs() % NANOS_PER_SEC);
+        write!(f, "{sign}{secs}")?;
+        if fraction != 0 {
+            let fraction = format!("{fraction:09}");
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        write!(f, "S")?;
+    }
+    Ok(())
+}
+
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalYearMonthType> {
+    type State = DurationFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
+        let value = self.value(idx);
+        if *fmt == DurationFormat::Iso8601 {
+            return write_iso_interval(f, value, 0, 0);
+        }
+        let interval = value as f64;
         let years = (interval / 12_f64).floor();
         let month = interval - (years * 12_f64);
 
@@ -563,8 +894,18 @@ impl<'a> DisplayIndex for &'a PrimitiveArray<IntervalYearMonthType> {
     }
 }
 
-impl<'a> DisplayIndex for &'a PrimitiveArray<IntervalDayTimeType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalDayTimeType> {
+    type State = DurationFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
+        if *fmt == DurationFormat::Iso8601 {
+            let (days, millis) = IntervalDayTimeType::to_parts(self.value(idx));
+            return write_iso_interval(f, 0, days, millis as i64 * 1_000_000);
+        }
         let value: u64 = self.value(idx) as u64;
 
         let days_parts: i32 = ((value & 0xFFFFFFFF00000000) >> 32) as i32;
@@ -599,8 +940,19 @@ impl<'a> DisplayIndex for &'a PrimitiveArray<IntervalDayTimeType> {
     }
 }
 
-impl<'a> DisplayIndex for &'a PrimitiveArray<IntervalMonthDayNanoType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalMonthDayNanoType> {
+    type State = DurationFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
+        if *fmt == DurationFormat::Iso8601 {
+            let (months, days, nanos) =
+                IntervalMonthDayNanoType::to_parts(self.value(idx));
+            return write_iso_interval(f, months, days, nanos);
+        }
         let value: u128 = self.value(idx) as u128;
 
         let months_part: i32 =
@@ -866,6 +1218,82 @@ pub fn lexical_to_string<N: lexical_core::ToLexical>(n: N) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_decimal_scientific() {
+        let array = Decimal128Array::from(vec![
+            Some(12345),
+            Some(-1500),
+            Some(0),
+            Some(7),
+            Some(100),
+            None,
+        ])
+        .with_precision_and_scale(10, 6)
+        .unwrap();
+
+        let options = FormatOptions::default().with_decimal_scientific(true);
+        let formatter = ArrayFormatter::try_new(&array, &options).unwrap();
+        let formatted: Vec<_> = (0..array.len())
+            .map(|i| formatter.value(i).to_string())
+            .collect();
+        assert_eq!(
+            formatted,
+            vec!["1.2345E-2", "-1.5E-3", "0E0", "7E-6", "1E-4", ""]
+        );
+
+        let array = Decimal256Array::from(vec![arrow_buffer::i256::from_i128(-25)])
+            .with_precision_and_scale(10, -3)
+            .unwrap();
+        let formatter = ArrayFormatter::try_new(&array, &options).unwrap();
+        assert_eq!(formatter.value(0).to_string(), "-2.5E4");
+    }
+
+    #[test]
+    fn test_write_decimal() {
+        use arrow_buffer::i256;
+
+        let cases = [
+            (12345, 10, 2),
+            (-12345, 10, 2),
+            (5, 10, 3),
+            (-5, 10, 3),
+            (0, 10, 2),
+            (-25, 10, -3),
+            (0, 10, -2),
+            (123456789, 7, 2),
+            (-123456789, 7, 0),
+            (1_000_000_000, 38, 0),
+            (-1_000_000_007, 38, 3),
+            (i128::MIN, 38, 10),
+            (i128::MAX, 38, 10),
+        ];
+        for (value, precision, scale) in cases {
+            let mut actual = String::new();
+            write_decimal::<Decimal128Type>(&mut actual, value, precision, scale)
+                .unwrap();
+            let expected = Decimal128Type::format_decimal(value, precision, scale);
+            assert_eq!(actual, expected, "{value} {precision} {scale}");
+        }
+
+        let cases = [
+            (i256::from_i128(-25), 76, 1),
+            (
+                i256::from_i128(i128::MIN).wrapping_mul(i256::from_i128(10)),
+                76,
+                5,
+            ),
+            (i256::MIN, 76, 20),
+            (i256::MAX, 76, 20),
+        ];
+        for (value, precision, scale) in cases {
+            let mut actual = String::new();
+            write_decimal::<Decimal256Type>(&mut actual, value, precision, scale)
+                .unwrap();
+            let expected = Decimal256Type::format_decimal(value, precision, scale);
+            assert_eq!(actual, expected, "{value} {precision} {scale}");
+        }
+    }
+
     #[test]
     fn test_map_arry_to_string() {
         let keys = vec!["a", "b", "c", "d", "e", "f", "g", "h"];
@@ -907,5 +1335,104 @@ mod tests {
         let s_array = DurationSecondArray::from(vec![Some(1), None]);
         assert_eq!(array_value_to_string(&s_array, 0).unwrap(), "PT1S");
         assert_eq!(array_value_to_string(&s_array, 1).unwrap(), "");
+
+        // Beyond the range of chrono::Duration, formatted in the same form
+        let s_array = DurationSecondArray::from(vec![i64::MAX, i64::MIN, 86_400 << 40]);
+        let formatted: Vec<_> = (0..3)
+            .map(|i| array_value_to_string(&s_array, i).unwrap())
+            .collect();
+        assert_eq!(
+            formatted,
+            [
+                "P106751991167300DT55807S",
+                "-P106751991167300DT55808S",
+                "P1099511627776D"
+            ]
+        );
+
+        let ms_array =
+            DurationMillisecondArray::from(vec![5_400_500, -500, 0, 86_401_000]);
+        let formatted: Vec<_> = (0..4)
+            .map(|i| array_value_to_string(&ms_array, i).unwrap())
+            .collect();
+        assert_eq!(formatted, ["PT5400.500S", "-PT0.500S", "PT0S", "P1DT1S"]);
+
+        let us_array = DurationMicrosecondArray::from(vec![1_500]);
+        assert_eq!(array_value_to_string(&us_array, 0).unwrap(), "PT0.001500S");
+    }
+
+    fn format_all(array: &dyn Array, options: &FormatOptions) -> Vec<String> {
+        let formatter = ArrayFormatter::try_new(array, options).unwrap();
+        (0..array.len())
+            .map(|i| formatter.value(i).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_duration_format() {
+        let options =
+            FormatOptions::default().with_duration_format(DurationFormat::Pretty);
+
+        let array = DurationMillisecondArray::from(vec![
+            Some(93_784_005),
+            Some(-1_500),
+            Some(0),
+            None,
+        ]);
+        assert_eq!(
+            format_all(&array, &options),
+            [
+                "1 days 2 hours 3 mins 4.005 secs",
+                "0 days 0 hours 0 mins -1.500 secs",
+                "0 days 0 hours 0 mins 0.000 secs",
+                ""
+            ]
+        );
+
+        let array = DurationSecondArray::from(vec![-3_600, i64::MIN]);
+        assert_eq!(
+            format_all(&array, &options),
+            [
+                "0 days -1 hours 0 mins 0 secs",
+                "-106751991167300 days -15 hours -30 mins -8 secs"
+            ]
+        );
+
+        let array = DurationNanosecondArray::from(vec![1]);
+        assert_eq!(
+            format_all(&array, &options),
+            ["0 days 0 hours 0 mins 0.000000001 secs"]
+        );
+    }
+
+    #[test]
+    fn test_interval_format() {
+        let options =
+            FormatOptions::default().with_interval_format(DurationFormat::Iso8601);
+
+        let array = IntervalYearMonthArray::from(vec![14, -3, 0]);
+        assert_eq!(format_all(&array, &options), ["P1Y2M", "P-3M", "PT0S"]);
+
+        let array = IntervalDayTimeArray::from(vec![
+            IntervalDayTimeType::make_value(3, 3_723_500),
+            IntervalDayTimeType::make_value(-1, -500),
+        ]);
+        assert_eq!(format_all(&array, &options), ["P3DT1H2M3.5S", "P-1DT-0.5S"]);
+
+        let array = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNanoType::make_value(-13, 2, 60_000_000_001),
+            IntervalMonthDayNanoType::make_value(0, 0, 7_200_000_000_000),
+        ]);
+        assert_eq!(
+            format_all(&array, &options),
+            ["P-1Y-1M2DT1M0.000000001S", "PT2H"]
+        );
+
+        // Defaults to the pretty format
+        let options = FormatOptions::default();
+        assert_eq!(
+            format_all(&array, &options)[1],
+            "0 years 0 mons 0 days 2 hours 0 mins 0.000000000 secs"
+        );
     }
 }
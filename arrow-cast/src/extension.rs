@@ -0,0 +1,566 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Cast kernels for the [canonical extension types]
+//!
+//! Extension types are identified by the [`EXTENSION_TYPE_NAME_KEY`] metadata of a
+//! [`Field`], and so are cast with [`cast_to_field`], which is given the source and
+//! target fields, instead of [`cast_with_options`], which only sees their storage types.
+//!
+//! [canonical extension types]: https://arrow.apache.org/docs/format/CanonicalExtensions.html
+
+use crate::cast::{can_cast_types, cast_with_options, CastOptions};
+use crate::registry::CastType;
+use arrow_array::builder::{FixedSizeBinaryBuilder, GenericStringBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, FixedSizeBinaryArray, OffsetSizeTrait};
+use arrow_schema::{ArrowError, DataType, Field};
+use std::sync::Arc;
+
+/// The [`Field`] metadata key containing the name of its extension type
+pub const EXTENSION_TYPE_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The [`Field`] metadata key containing the serialized parameters of its extension type
+pub const EXTENSION_TYPE_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// A canonical extension type, see the [module docs](self)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CanonicalExtensionType {
+    /// `arrow.uuid`, stored as `FixedSizeBinary(16)`
+    Uuid,
+    /// `arrow.json`, stored as `Utf8` or `LargeUtf8`
+    Json,
+    /// `arrow.fixed_shape_tensor`, stored as a `FixedSizeList` with the product of
+    /// `shape` elements
+    FixedShapeTensor {
+        /// The dimensions of each tensor
+        shape: Vec<usize>,
+    },
+}
+
+impl CanonicalExtensionType {
+    /// Returns the canonical extension type of `field`, if any
+    ///
+    /// Returns an error if the extension metadata of `field` is invalid for its
+    /// canonical extension type, or the type of `field` is not a valid storage type
+    pub fn try_from_field(field: &Field) -> Result<Option<Self>, ArrowError> {
+        let name = match field.metadata().get(EXTENSION_TYPE_NAME_KEY) {
+            Some(name) => name.as_str(),
+            None => return Ok(None),
+        };
+        let metadata = field.metadata().get(EXTENSION_TYPE_METADATA_KEY);
+        let extension = match name {
+            "arrow.uuid" => Self::Uuid,
+            "arrow.json" => Self::Json,
+            "arrow.fixed_shape_tensor" => {
+                let shape = metadata.and_then(|m| parse_tensor_shape(m));
+                let shape = shape.ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Invalid metadata for extension type {name}: {metadata:?}"
+                    ))
+                })?;
+                Self::FixedShapeTensor { shape }
+            }
+            // Not a canonical extension type
+            _ => return Ok(None),
+        };
+        extension.validate_storage(field.data_type())?;
+        Ok(Some(extension))
+    }
+
+    /// Returns the name of this extension type
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Uuid => "arrow.uuid",
+            Self::Json => "arrow.json",
+            Self::FixedShapeTensor { .. } => "arrow.fixed_shape_tensor",
+        }
+    }
+
+    fn validate_storage(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        let valid = match self {
+            Self::Uuid => data_type == &DataType::FixedSizeBinary(16),
+            Self::Json => matches!(data_type, DataType::Utf8 | DataType::LargeUtf8),
+            Self::FixedShapeTensor { shape } => match data_type {
+                DataType::FixedSizeList(_, size) => tensor_size(shape) == Some(*size),
+                _ => false,
+            },
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid storage type {data_type:?} for extension type {}",
+                self.name()
+            ))),
+        }
+    }
+}
+
+/// Parses the `shape` of the JSON metadata of a fixed shape tensor, e.g.
+/// `{"shape": [2, 5]}`
+///
+/// Only the `shape` key of the top-level object is matched, and not e.g. a string
+/// `"shape"` within the `dim_names` of the tensor
+fn parse_tensor_shape(metadata: &str) -> Option<Vec<usize>> {
+    let bytes = metadata.as_bytes();
+    // The nesting of objects and arrays, and whether the next string is a key
+    let mut depth = 0;
+    let mut is_key = false;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'"' => {
+                let end = json_string_end(bytes, idx)?;
+                if depth == 1 && is_key && &metadata[idx + 1..end] == "shape" {
+                    let rest = metadata[end + 1..].trim_start().strip_prefix(':')?;
+                    return parse_shape_list(rest.trim_start());
+                }
+                is_key = false;
+                idx = end;
+            }
+            b'{' | b'[' => {
+                depth += 1;
+                is_key = depth == 1 && bytes[idx] == b'{';
+            }
+            b'}' | b']' => depth -= 1,
+            b',' => is_key = depth == 1,
+            _ => {}
+        }
+        idx += 1;
+    }
+    None
+}
+
+/// Returns the index of the quote closing the JSON string starting at `start`
+fn json_string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut idx = start + 1;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' => idx += 2,
+            b'"' => return Some(idx),
+            _ => idx += 1,
+        }
+    }
+    None
+}
+
+/// Parses a JSON list of dimensions, e.g. `[2, 5]`
+fn parse_shape_list(json: &str) -> Option<Vec<usize>> {
+    let (list, _) = json.strip_prefix('[')?.split_once(']')?;
+    if list.trim().is_empty() {
+        return Some(vec![]);
+    }
+    list.split(',').map(|x| x.trim().parse().ok()).collect()
+}
+
+/// Returns the number of elements in a tensor of `shape`, if it fits in an `i32`
+fn tensor_size(shape: &[usize]) -> Option<i32> {
+    let size = shape
+        .iter()
+        .try_fold(1_usize, |acc, x| acc.checked_mul(*x))?;
+    size.try_into().ok()
+}
+
+/// Return true if [`cast_to_field`] can cast from `from` to `to`
+pub fn can_cast_to_field(from: &Field, to: &Field) -> bool {
+    let from_extension = CanonicalExtensionType::try_from_field(from);
+    let to_extension = CanonicalExtensionType::try_from_field(to);
+    let (from_extension, to_extension) = match (from_extension, to_extension) {
+        (Ok(from), Ok(to)) => (from, to),
+        _ => return false,
+    };
+
+    use CanonicalExtensionType::*;
+    use DataType::*;
+    match (
+        from_extension,
+        to_extension,
+        from.data_type(),
+        to.data_type(),
+    ) {
+        (Some(a), Some(b), _, _) if a.name() != b.name() => false,
+        (Some(Uuid), None, _, Utf8 | LargeUtf8) => true,
+        (None, Some(Uuid), Utf8 | LargeUtf8, _) => true,
+        (_, _, from_type, to_type) => can_cast_types(from_type, to_type),
+    }
+}
+
+/// Cast `array`, described by the field `from`, to the field `to`
+///
+/// In addition to the casts supported by [`cast_with_options`], which are used for
+/// fields that are not canonical extension types, this supports:
+///
+/// * `arrow.uuid` to and from `Utf8` and `LargeUtf8`, formatted as hyphenated
+///   lowercase hex, e.g. `"67e55044-10b1-426f-9247-bb680e5fe0c8"`, and parsed from
+///   hyphenated or simple hex of either case
+/// * `arrow.uuid` to and from `FixedSizeBinary(16)`
+/// * `arrow.json` to and from `Utf8` and `LargeUtf8`, without validating the JSON
+/// * `arrow.fixed_shape_tensor` to and from a `FixedSizeList` with the same number of
+///   elements, casting the elements
+///
+/// Casts between different extension types are not supported, unless registered in
+/// [`CastOptions::registry`], which are used instead of the above for fields where
+/// either has an extension type.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use arrow_array::{Array, FixedSizeBinaryArray, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_cast::DEFAULT_CAST_OPTIONS;
+/// # use arrow_cast::extension::{cast_to_field, EXTENSION_TYPE_NAME_KEY};
+/// # use arrow_schema::{DataType, Field};
+/// let metadata = HashMap::from([(
+///     EXTENSION_TYPE_NAME_KEY.to_string(),
+///     "arrow.uuid".to_string(),
+/// )]);
+/// let uuid = Field::new("id", DataType::FixedSizeBinary(16), true).with_metadata(metadata);
+/// let string = Field::new("id", DataType::Utf8, true);
+///
+/// let array = StringArray::from(vec!["67E55044-10B1-426F-9247-BB680E5FE0C8"]);
+/// let uuids = cast_to_field(&array, &string, &uuid, &DEFAULT_CAST_OPTIONS).unwrap();
+/// let binary = uuids.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+/// assert_eq!(binary.value(0)[..2], [0x67, 0xe5]);
+///
+/// let strings = cast_to_field(&uuids, &uuid, &string, &DEFAULT_CAST_OPTIONS).unwrap();
+/// assert_eq!(
+///     strings.as_string::<i32>().value(0),
+///     "67e55044-10b1-426f-9247-bb680e5fe0c8"
+/// );
+/// ```
+pub fn cast_to_field(
+    array: &dyn Array,
+    from: &Field,
+    to: &Field,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    if let Some(registry) = &cast_options.registry {
+        let (from_type, to_type) = (CastType::from_field(from), CastType::from_field(to));
+        if from_type.extension().is_some() || to_type.extension().is_some() {
+            if let Some(cast) = registry.get(&from_type, &to_type) {
+                return cast(array, to.data_type(), cast_options);
+            }
+        }
+    }
+
+    let from_extension = CanonicalExtensionType::try_from_field(from)?;
+    let to_extension = CanonicalExtensionType::try_from_field(to)?;
+
+    use CanonicalExtensionType::*;
+    use DataType::*;
+    match (
+        &from_extension,
+        &to_extension,
+        array.data_type(),
+        to.data_type(),
+    ) {
+        (Some(a), Some(b), _, _) if a.name() != b.name() => {
+            Err(ArrowError::CastError(format!(
+                "Casting from extension type {} to {} not supported",
+                a.name(),
+                b.name()
+            )))
+        }
+        (Some(Uuid), None, _, Utf8) => format_uuids::<i32>(array),
+        (Some(Uuid), None, _, LargeUtf8) => format_uuids::<i64>(array),
+        (None, Some(Uuid), Utf8, _) => parse_uuids::<i32>(array, cast_options),
+        (None, Some(Uuid), LargeUtf8, _) => parse_uuids::<i64>(array, cast_options),
+        (
+            Some(FixedShapeTensor { .. }),
+            _,
+            FixedSizeList(_, a_size),
+            FixedSizeList(_, b_size),
+        )
+        | (
+            _,
+            Some(FixedShapeTensor { .. }),
+            FixedSizeList(_, a_size),
+            FixedSizeList(_, b_size),
+        ) if a_size != b_size => Err(ArrowError::CastError(format!(
+            "Cannot cast {:?} to {:?} as they have different sizes",
+            array.data_type(),
+            to.data_type()
+        ))),
+        _ => cast_with_options(array, to.data_type(), cast_options),
+    }
+}
+
+/// Formats each UUID in `array` as hyphenated lowercase hex
+fn format_uuids<O: OffsetSizeTrait>(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    let array = match array.data_type() {
+        DataType::FixedSizeBinary(16) => array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap(),
+        data_type => {
+            return Err(ArrowError::CastError(format!(
+                "Cannot cast {data_type:?} as arrow.uuid, expected FixedSizeBinary(16)"
+            )))
+        }
+    };
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    let mut builder =
+        GenericStringBuilder::<O>::with_capacity(array.len(), array.len() * 36);
+    let mut buf = [0_u8; 36];
+    for value in array.iter() {
+        match value {
+            Some(value) => {
+                let mut idx = 0;
+                for (i, byte) in value.iter().enumerate() {
+                    if matches!(i, 4 | 6 | 8 | 10) {
+                        buf[idx] = b'-';
+                        idx += 1;
+                    }
+                    buf[idx] = HEX[(byte >> 4) as usize];
+                    buf[idx + 1] = HEX[(byte & 0xf) as usize];
+                    idx += 2;
+                }
+                // Safety: buf only contains ASCII characters
+                builder.append_value(unsafe { std::str::from_utf8_unchecked(&buf) })
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Parses each string in `array` as a UUID
+fn parse_uuids<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_string::<O>();
+    let mut builder = FixedSizeBinaryBuilder::with_capacity(array.len(), 16);
+    for value in array.iter() {
+        match value {
+            Some(value) => match parse_uuid(value) {
+                Some(uuid) => builder.append_value(uuid)?,
+                None if cast_options.safe => builder.append_null(),
+                None => {
+                    return Err(ArrowError::CastError(format!(
+                        "Cannot cast string '{value}' to value of arrow.uuid type"
+                    )))
+                }
+            },
+            None => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Parses a UUID from 32 hex digits, optionally hyphenated as 8-4-4-4-12
+pub(crate) fn parse_uuid(s: &str) -> Option<[u8; 16]> {
+    let bytes = s.as_bytes();
+    let digit = |i: usize| (bytes[i] as char).to_digit(16).map(|d| d as u8);
+
+    let mut out = [0_u8; 16];
+    match bytes.len() {
+        32 => {
+            for (i, b) in out.iter_mut().enumerate() {
+                *b = digit(2 * i)? << 4 | digit(2 * i + 1)?;
+            }
+        }
+        36 => {
+            if [8, 13, 18, 23].iter().any(|i| bytes[*i] != b'-') {
+                return None;
+            }
+            let mut idx = 0;
+            for (i, b) in out.iter_mut().enumerate() {
+                if matches!(i, 4 | 6 | 8 | 10) {
+                    idx += 1;
+                }
+                *b = digit(idx)? << 4 | digit(idx + 1)?;
+                idx += 2;
+            }
+        }
+        _ => return None,
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_CAST_OPTIONS;
+    use arrow_array::types::{Float32Type, Int32Type};
+    use arrow_array::{FixedSizeListArray, StringArray};
+    use std::collections::HashMap;
+
+    fn extension_field(data_type: DataType, name: &str, metadata: Option<&str>) -> Field {
+        let mut m =
+            HashMap::from([(EXTENSION_TYPE_NAME_KEY.to_string(), name.to_string())]);
+        if let Some(metadata) = metadata {
+            m.insert(
+                EXTENSION_TYPE_METADATA_KEY.to_string(),
+                metadata.to_string(),
+            );
+        }
+        Field::new("a", data_type, true).with_metadata(m)
+    }
+
+    #[test]
+    fn test_cast_uuid() {
+        let uuid = extension_field(DataType::FixedSizeBinary(16), "arrow.uuid", None);
+        let string = Field::new("a", DataType::LargeUtf8, true);
+        assert!(can_cast_to_field(&string, &uuid));
+        assert!(can_cast_to_field(&uuid, &string));
+
+        let array = arrow_array::LargeStringArray::from(vec![
+            Some("00112233-4455-6677-8899-aabbccddeeff"),
+            Some("00112233445566778899AABBCCDDEEFF"),
+            None,
+            Some("00112233-4455-6677-8899-aabbccddeef"),
+            Some("0011223344-55-6677-8899-aabbccddeeff"),
+            Some("g0112233445566778899aabbccddeeff"),
+        ]);
+        let b = cast_to_field(&array, &string, &uuid, &DEFAULT_CAST_OPTIONS).unwrap();
+        let b = b.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+        let expected: Vec<u8> = (0..16).map(|x| x * 0x11).collect();
+        assert_eq!(b.value(0), expected);
+        assert_eq!(b.value(1), expected);
+        assert_eq!(b.null_count(), 4);
+
+        let c = cast_to_field(b, &uuid, &string, &DEFAULT_CAST_OPTIONS).unwrap();
+        let c = c.as_string::<i64>();
+        assert_eq!(c.value(0), "00112233-4455-6677-8899-aabbccddeeff");
+        assert_eq!(c.value(1), "00112233-4455-6677-8899-aabbccddeeff");
+        assert_eq!(c.null_count(), 4);
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err = cast_to_field(&array, &string, &uuid, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '00112233-4455-6677-8899-aabbccddeef' to value of arrow.uuid type"
+        );
+
+        // Storage casts are unchanged
+        let binary = Field::new("a", DataType::FixedSizeBinary(16), true);
+        let d = cast_to_field(b, &uuid, &binary, &DEFAULT_CAST_OPTIONS).unwrap();
+        assert_eq!(d.as_any().downcast_ref::<FixedSizeBinaryArray>(), Some(b));
+
+        // Array does not match the storage of the field
+        let err =
+            cast_to_field(&array, &uuid, &string, &DEFAULT_CAST_OPTIONS).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast LargeUtf8 as arrow.uuid, expected FixedSizeBinary(16)"
+        );
+    }
+
+    #[test]
+    fn test_cast_json() {
+        let json = extension_field(DataType::Utf8, "arrow.json", None);
+        let string = Field::new("a", DataType::LargeUtf8, true);
+        let uuid = extension_field(DataType::FixedSizeBinary(16), "arrow.uuid", None);
+
+        let array = StringArray::from(vec![Some("{\"a\": 1}"), None]);
+        let b = cast_to_field(&array, &json, &string, &DEFAULT_CAST_OPTIONS).unwrap();
+        let b = b.as_string::<i64>();
+        assert_eq!(b.value(0), "{\"a\": 1}");
+        assert!(b.is_null(1));
+
+        let c = cast_to_field(b, &string, &json, &DEFAULT_CAST_OPTIONS).unwrap();
+        assert_eq!(c.as_string::<i32>(), &array);
+
+        assert!(!can_cast_to_field(&json, &uuid));
+        let err = cast_to_field(&array, &json, &uuid, &DEFAULT_CAST_OPTIONS).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Casting from extension type arrow.json to arrow.uuid not supported"
+        );
+    }
+
+    #[test]
+    fn test_cast_fixed_shape_tensor() {
+        let item = Arc::new(Field::new("item", DataType::Int32, true));
+        let tensor = extension_field(
+            DataType::FixedSizeList(item.clone(), 6),
+            "arrow.fixed_shape_tensor",
+            Some("{\"shape\": [2, 3], \"dim_names\": [\"x\", \"y\"]}"),
+        );
+        let float_item = Arc::new(Field::new("item", DataType::Float32, true));
+        let list = Field::new("a", DataType::FixedSizeList(float_item, 6), true);
+        assert!(can_cast_to_field(&tensor, &list));
+
+        let array = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![Some((0..6).map(Some).collect::<Vec<_>>()), None],
+            6,
+        );
+        let b = cast_to_field(&array, &tensor, &list, &DEFAULT_CAST_OPTIONS).unwrap();
+        let b = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        assert_eq!(b.data_type(), list.data_type());
+        assert!(b.is_null(1));
+        let values = b.value(0);
+        let values = values.as_primitive::<Float32Type>();
+        assert_eq!(values.values(), &[0., 1., 2., 3., 4., 5.]);
+
+        let c = cast_to_field(b, &list, &tensor, &DEFAULT_CAST_OPTIONS).unwrap();
+        assert_eq!(
+            c.as_any().downcast_ref::<FixedSizeListArray>(),
+            Some(&array)
+        );
+
+        let other = Field::new("a", DataType::FixedSizeList(item.clone(), 4), true);
+        assert!(!can_cast_to_field(&tensor, &other));
+        assert!(cast_to_field(&array, &tensor, &other, &DEFAULT_CAST_OPTIONS).is_err());
+
+        // Casts of the elements follow the options of the kernel
+        let float_array = FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(
+            vec![Some(vec![Some(f32::MAX); 6])],
+            6,
+        );
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err = cast_to_field(&float_array, &list, &tensor, &options).unwrap_err();
+        assert!(err.to_string().starts_with("Cast error"), "{err}");
+
+        // Storage does not match the shape
+        let invalid = extension_field(
+            DataType::FixedSizeList(item, 6),
+            "arrow.fixed_shape_tensor",
+            Some("{\"shape\": [2, 2]}"),
+        );
+        let err =
+            cast_to_field(&array, &list, &invalid, &DEFAULT_CAST_OPTIONS).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid storage type FixedSizeList(Field { name: \"item\", data_type: Int32, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} }, 6) for extension type arrow.fixed_shape_tensor"
+        );
+    }
+
+    #[test]
+    fn test_parse_tensor_shape() {
+        assert_eq!(parse_tensor_shape("{\"shape\":[2,5]}"), Some(vec![2, 5]));
+        assert_eq!(parse_tensor_shape("{ \"shape\" : [ 3 ] }"), Some(vec![3]));
+        assert_eq!(parse_tensor_shape("{\"shape\": []}"), Some(vec![]));
+        assert_eq!(parse_tensor_shape("{\"shape\": [2, -1]}"), None);
+        assert_eq!(parse_tensor_shape("{}"), None);
+
+        let metadata = r#"{"dim_names": ["shape", "y"], "shape": [2, 3]}"#;
+        assert_eq!(parse_tensor_shape(metadata), Some(vec![2, 3]));
+        let metadata =
+            r#"{"dim_names": ["x", "y"], "permutation": [1, 0], "shape": [4, 1]}"#;
+        assert_eq!(parse_tensor_shape(metadata), Some(vec![4, 1]));
+        let metadata = r#"{"other": {"shape": [1]}, "dim_names": ["a\"shape"]}"#;
+        assert_eq!(parse_tensor_shape(metadata), None);
+    }
+}
@@ -0,0 +1,247 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels producing intervals from dates, and decomposing intervals into their parts
+
+use arrow_array::cast::AsArray;
+use arrow_array::temporal_conversions::as_date;
+use arrow_array::types::{
+    Date32Type, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalYearMonthType,
+};
+use arrow_array::{
+    Array, Date32Array, Int32Array, Int64Array, IntervalDayTimeArray,
+    IntervalMonthDayNanoArray,
+};
+use arrow_schema::{ArrowError, DataType, IntervalUnit};
+use chrono::{Datelike, Months, NaiveDate};
+
+/// Returns the calendar interval between each pair of dates in `start` and `end`
+///
+/// The interval is decomposed into the whole number of months from `start`, followed
+/// by the remaining days, such that adding the months and then the days to `start`
+/// yields `end`. Where `end` is before `start` the interval is the negation of the
+/// interval from `end` to `start`. The nanoseconds component is always zero.
+///
+/// Returns null where either date is null, and an error if the arrays have different
+/// lengths
+///
+/// ```
+/// # use arrow_array::Date32Array;
+/// # use arrow_array::types::IntervalMonthDayNanoType;
+/// # use arrow_cast::interval::dates_to_interval;
+/// // 2023-01-31 to 2023-03-01
+/// let start = Date32Array::from(vec![19388]);
+/// let end = Date32Array::from(vec![19417]);
+/// let interval = dates_to_interval(&start, &end).unwrap();
+/// assert_eq!(interval.value(0), IntervalMonthDayNanoType::make_value(1, 1, 0));
+/// ```
+pub fn dates_to_interval(
+    start: &Date32Array,
+    end: &Date32Array,
+) -> Result<IntervalMonthDayNanoArray, ArrowError> {
+    if start.len() != end.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compute interval between date arrays of different lengths, got {} and {}",
+            start.len(),
+            end.len()
+        )));
+    }
+
+    start
+        .iter()
+        .zip(end.iter())
+        .map(|(start, end)| match (start, end) {
+            (Some(start), Some(end)) => {
+                let (months, days) = date_diff(to_date(start)?, to_date(end)?)?;
+                Ok(Some(IntervalMonthDayNanoType::make_value(months, days, 0)))
+            }
+            _ => Ok(None),
+        })
+        .collect()
+}
+
+fn to_date(v: i32) -> Result<NaiveDate, ArrowError> {
+    as_date::<Date32Type>(v as i64).ok_or_else(|| {
+        ArrowError::ComputeError(format!("Date32 value {v} is out of range"))
+    })
+}
+
+/// Returns the months and days from `start` to `end`
+fn date_diff(start: NaiveDate, end: NaiveDate) -> Result<(i32, i32), ArrowError> {
+    if end < start {
+        let (months, days) = date_diff(end, start)?;
+        return Ok((-months, -days));
+    }
+
+    let mut months =
+        (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+    if end.day() < start.day() {
+        months -= 1;
+    }
+
+    // Adding months clamps to the end of the month, e.g. 31st January + 1 month is
+    // the 28th or 29th February, and so never overshoots end
+    let anchor = start
+        .checked_add_months(Months::new(months as u32))
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Overflow adding {months} months to {start}"
+            ))
+        })?;
+    let days = (end - anchor).num_days();
+    Ok((months, days as i32))
+}
+
+/// Returns the number of months in each interval of `array`
+///
+/// Supports `Interval(YearMonth)`, and `Interval(MonthDayNano)` for which the days and
+/// nanoseconds are ignored. Returns an error for other types, including
+/// `Interval(DayTime)` which has no months component.
+///
+/// ```
+/// # use arrow_array::{Array, IntervalYearMonthArray};
+/// # use arrow_cast::interval::interval_to_months;
+/// let array = IntervalYearMonthArray::from(vec![Some(14), None]);
+/// let months = interval_to_months(&array).unwrap();
+/// assert_eq!(months.value(0), 14);
+/// assert!(months.is_null(1));
+/// ```
+pub fn interval_to_months(array: &dyn Array) -> Result<Int32Array, ArrowError> {
+    match array.data_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            Ok(array.as_primitive::<IntervalYearMonthType>().unary(|v| v))
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => Ok(array
+            .as_primitive::<IntervalMonthDayNanoType>()
+            .unary(|v| IntervalMonthDayNanoType::to_parts(v).0)),
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot get the months of {d:?}"
+        ))),
+    }
+}
+
+/// Returns the days and milliseconds of each interval in `array`
+///
+/// Casting `Interval(DayTime)` to `Int64` instead returns the total number of
+/// milliseconds, or if [`CastOptions::reinterpret`](crate::CastOptions::reinterpret) is
+/// set, the days in the upper and the milliseconds in the lower 32 bits
+pub fn interval_daytime_to_parts(
+    array: &IntervalDayTimeArray,
+) -> (Int32Array, Int32Array) {
+    let days = array.unary(|v| IntervalDayTimeType::to_parts(v).0);
+    let millis = array.unary(|v| IntervalDayTimeType::to_parts(v).1);
+    (days, millis)
+}
+
+/// Returns the months, days and nanoseconds of each interval in `array`
+pub fn interval_month_day_nano_to_parts(
+    array: &IntervalMonthDayNanoArray,
+) -> (Int32Array, Int32Array, Int64Array) {
+    let months = array.unary(|v| IntervalMonthDayNanoType::to_parts(v).0);
+    let days = array.unary(|v| IntervalMonthDayNanoType::to_parts(v).1);
+    let nanos = array.unary(|v| IntervalMonthDayNanoType::to_parts(v).2);
+    (months, days, nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::IntervalYearMonthArray;
+
+    fn days(date: &str) -> i32 {
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+        (date - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32
+    }
+
+    #[test]
+    fn test_dates_to_interval() {
+        let cases = [
+            ("2023-01-15", "2023-01-15", 0, 0),
+            ("2023-01-15", "2023-01-20", 0, 5),
+            ("2023-01-15", "2023-02-15", 1, 0),
+            ("2023-01-15", "2024-03-14", 13, 28),
+            ("2023-01-31", "2023-02-28", 0, 28),
+            ("2023-01-31", "2023-03-01", 1, 1),
+            ("2024-01-31", "2024-02-29", 0, 29),
+            ("2020-02-29", "2021-02-28", 11, 30),
+            ("2023-03-01", "2023-01-31", -1, -1),
+            ("1969-12-31", "1970-01-01", 0, 1),
+        ];
+
+        let start = Date32Array::from_iter_values(cases.iter().map(|c| days(c.0)));
+        let end = Date32Array::from_iter_values(cases.iter().map(|c| days(c.1)));
+        let interval = dates_to_interval(&start, &end).unwrap();
+
+        for (i, (s, e, months, d)) in cases.iter().enumerate() {
+            let expected = IntervalMonthDayNanoType::make_value(*months, *d, 0);
+            assert_eq!(interval.value(i), expected, "{s} to {e}");
+        }
+    }
+
+    #[test]
+    fn test_dates_to_interval_nulls() {
+        let start = Date32Array::from(vec![Some(0), None, Some(31)]);
+        let end = Date32Array::from(vec![None, Some(0), Some(0)]);
+        let interval = dates_to_interval(&start, &end).unwrap();
+        assert!(interval.is_null(0));
+        assert!(interval.is_null(1));
+        assert_eq!(
+            interval.value(2),
+            IntervalMonthDayNanoType::make_value(-1, 0, 0)
+        );
+
+        let err = dates_to_interval(&start, &Date32Array::from(vec![0])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compute interval between date arrays of different lengths, got 3 and 1"
+        );
+    }
+
+    #[test]
+    fn test_interval_to_parts() {
+        let array = IntervalYearMonthArray::from(vec![Some(-3), None]);
+        let months = interval_to_months(&array).unwrap();
+        assert_eq!(months, Int32Array::from(vec![Some(-3), None]));
+
+        let array = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNanoType::make_value(1, -2, 3)),
+            Some(IntervalMonthDayNanoType::make_value(-1, 2, i64::MIN)),
+            None,
+        ]);
+        let months = interval_to_months(&array).unwrap();
+        assert_eq!(months, Int32Array::from(vec![Some(1), Some(-1), None]));
+
+        let (months, days, nanos) = interval_month_day_nano_to_parts(&array);
+        assert_eq!(months, Int32Array::from(vec![Some(1), Some(-1), None]));
+        assert_eq!(days, Int32Array::from(vec![Some(-2), Some(2), None]));
+        assert_eq!(nanos, Int64Array::from(vec![Some(3), Some(i64::MIN), None]));
+
+        let array = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTimeType::make_value(-1, 500)),
+            None,
+        ]);
+        let (days, millis) = interval_daytime_to_parts(&array);
+        assert_eq!(days, Int32Array::from(vec![Some(-1), None]));
+        assert_eq!(millis, Int32Array::from(vec![Some(500), None]));
+
+        let err = interval_to_months(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot get the months of Interval(DayTime)"
+        );
+    }
+}
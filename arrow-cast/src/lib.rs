@@ -17,10 +17,26 @@
 
 //! Cast kernel for [Apache Arrow](https://docs.rs/arrow)
 
+pub mod base64;
+pub mod batch;
+pub mod bucket;
 pub mod cast;
 pub use cast::*;
+pub mod compatibility;
+pub mod decimal;
 pub mod display;
+pub mod extension;
+pub mod interval;
+pub mod offsets;
+#[cfg(feature = "rayon")]
+pub mod parallel;
 pub mod parse;
+pub mod registry;
+pub mod sort_key;
+pub mod temporal;
+#[cfg(any(test, feature = "test_utils"))]
+pub mod test_util;
+pub mod truncate;
 
 #[cfg(feature = "prettyprint")]
 pub mod pretty;
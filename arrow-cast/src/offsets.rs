@@ -0,0 +1,162 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels converting the offsets of variable length arrays
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{
+    make_array, Array, ArrayRef, LargeBinaryArray, LargeListArray, LargeStringArray,
+};
+use arrow_buffer::OffsetBuffer;
+use arrow_schema::{ArrowError, DataType};
+
+/// Converts a `Utf8`, `Binary`, `List` or `Map` array to the equivalent container with
+/// 64-bit offsets, such as when appending it to another array would overflow `i32`
+///
+/// Only the offsets are converted, the values and child arrays are shared with `array`.
+/// As there is no `Map` type with 64-bit offsets, `Map` is converted to a `LargeList`
+/// of its entries. Arrays that already have 64-bit offsets are returned unchanged.
+///
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_cast::promote_offsets_to_large;
+/// # use arrow_schema::DataType;
+/// let array = StringArray::from(vec![Some("arrow"), None, Some("rs")]);
+/// let large = promote_offsets_to_large(&array).unwrap();
+/// assert_eq!(large.data_type(), &DataType::LargeUtf8);
+/// assert_eq!(large.as_string::<i64>().value(2), "rs");
+/// ```
+pub fn promote_offsets_to_large(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_string::<i32>();
+            let offsets = widen_offsets(array.offsets());
+            let values = array.values().clone();
+            Ok(Arc::new(LargeStringArray::try_new(
+                offsets,
+                values,
+                array.nulls().cloned(),
+            )?))
+        }
+        DataType::Binary => {
+            let array = array.as_binary::<i32>();
+            let offsets = widen_offsets(array.offsets());
+            let values = array.values().clone();
+            Ok(Arc::new(LargeBinaryArray::try_new(
+                offsets,
+                values,
+                array.nulls().cloned(),
+            )?))
+        }
+        DataType::List(field) => {
+            let array = array.as_list::<i32>();
+            Ok(Arc::new(LargeListArray::try_new(
+                field.clone(),
+                widen_offsets(array.offsets()),
+                array.values().clone(),
+                array.nulls().cloned(),
+            )?))
+        }
+        DataType::Map(field, _) => {
+            let array = array.as_map();
+            Ok(Arc::new(LargeListArray::try_new(
+                field.clone(),
+                widen_offsets(array.offsets()),
+                Arc::new(array.entries().clone()),
+                array.nulls().cloned(),
+            )?))
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary | DataType::LargeList(_) => {
+            Ok(make_array(array.to_data()))
+        }
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot promote the offsets of {d:?} to 64-bit"
+        ))),
+    }
+}
+
+fn widen_offsets(offsets: &OffsetBuffer<i32>) -> OffsetBuffer<i64> {
+    let widened: Vec<i64> = offsets.iter().map(|o| *o as i64).collect();
+    // SAFETY: widening preserves that the offsets are non-negative and increasing
+    unsafe { OffsetBuffer::new_unchecked(widened.into()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{BinaryArray, Int32Array, ListArray, MapArray, StringArray};
+    use arrow_schema::Field;
+
+    #[test]
+    fn test_promote_offsets_to_large() {
+        let array = StringArray::from(vec![Some("a"), None, Some("bc"), Some("def")]);
+        let sliced = array.slice(1, 3);
+        let b = promote_offsets_to_large(&sliced).unwrap();
+        let b = b.as_string::<i64>();
+        assert_eq!(
+            b,
+            &LargeStringArray::from(vec![None, Some("bc"), Some("def")])
+        );
+        // The values are shared with the input
+        assert_eq!(b.values().as_ptr(), array.values().as_ptr());
+
+        let array = BinaryArray::from(vec![b"ab".as_ref(), b"".as_ref()]);
+        let b = promote_offsets_to_large(&array).unwrap();
+        assert_eq!(b.as_binary::<i64>().value_offsets(), &[0, 2, 2]);
+
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+        ]);
+        let b = promote_offsets_to_large(&array).unwrap();
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        assert_eq!(b.data_type(), &DataType::LargeList(field));
+        let b = b.as_list::<i64>();
+        assert_eq!(b.value_offsets(), &[0, 2, 2, 3]);
+        assert!(Arc::ptr_eq(b.values(), array.values()));
+        assert!(b.is_null(1));
+
+        let values = StringArray::from(vec![Some("1"), None, Some("3")]);
+        let array =
+            MapArray::new_from_strings(["a", "b", "c"].into_iter(), &values, &[0, 2, 3])
+                .unwrap();
+        let b = promote_offsets_to_large(&array).unwrap();
+        let field = match array.data_type() {
+            DataType::Map(field, _) => field.clone(),
+            _ => unreachable!(),
+        };
+        assert_eq!(b.data_type(), &DataType::LargeList(field));
+        let b = b.as_list::<i64>();
+        assert_eq!(b.value_offsets(), &[0, 2, 3]);
+        assert_eq!(b.values().as_struct(), array.entries());
+
+        let array = LargeStringArray::from(vec!["a"]);
+        let b = promote_offsets_to_large(&array).unwrap();
+        assert_eq!(b.as_string::<i64>(), &array);
+
+        let err = promote_offsets_to_large(&Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot promote the offsets of Int32 to 64-bit"
+        );
+    }
+}
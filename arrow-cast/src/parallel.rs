@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Cast kernel casting chunks of large arrays concurrently, enabled by the `rayon`
+//! feature
+
+use crate::cast::{cast_with_options, CastOptions};
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::concat::concat;
+use rayon::prelude::*;
+
+/// Cast `array` to `to_type` as [`cast_with_options`], casting chunks of `chunk_size`
+/// rows concurrently on the rayon thread pool, and concatenating the results
+///
+/// Arrays of at most `chunk_size` rows are cast on the current thread, as are casts to
+/// `Dictionary` and `RunEndEncoded` types, whose values would otherwise be repeated in
+/// each chunk.
+///
+/// Returns the same result as [`cast_with_options`], including the error if
+/// [`CastOptions::safe`] is false. If a chunk fails to cast, the rows up to the end of
+/// the first chunk that failed are cast again on the current thread, so that the error
+/// is for the first row that failed, and describes its position in `array`.
+///
+/// Returns an error if `chunk_size` is zero
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_cast::DEFAULT_CAST_OPTIONS;
+/// # use arrow_cast::parallel::cast_with_options_parallel;
+/// # use arrow_schema::DataType;
+/// let array = Int32Array::from_iter_values(0..10_000);
+/// let b = cast_with_options_parallel(&array, &DataType::Int64, &DEFAULT_CAST_OPTIONS, 1024)
+///     .unwrap();
+/// assert_eq!(b.len(), 10_000);
+/// assert_eq!(b.as_primitive::<Int64Type>().value(9_999), 9_999);
+/// ```
+pub fn cast_with_options_parallel(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+    chunk_size: usize,
+) -> Result<ArrayRef, ArrowError> {
+    if chunk_size == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot cast in chunks of zero rows".to_string(),
+        ));
+    }
+    if array.len() <= chunk_size
+        || matches!(
+            to_type,
+            DataType::Dictionary(_, _) | DataType::RunEndEncoded(_, _)
+        )
+    {
+        return cast_with_options(array, to_type, cast_options);
+    }
+
+    let offsets: Vec<usize> = (0..array.len()).step_by(chunk_size).collect();
+    let chunks: Vec<Result<ArrayRef, ArrowError>> = offsets
+        .par_iter()
+        .map(|offset| {
+            let len = chunk_size.min(array.len() - offset);
+            cast_with_options(&array.slice(*offset, len), to_type, cast_options)
+        })
+        .collect();
+
+    let mut casts = Vec::with_capacity(chunks.len());
+    for (chunk, offset) in chunks.into_iter().zip(offsets) {
+        match chunk {
+            Ok(chunk) => casts.push(chunk),
+            Err(e) => {
+                let end = array.len().min(offset + chunk_size);
+                return cast_with_options(&array.slice(0, end), to_type, cast_options)
+                    .and(Err(e));
+            }
+        }
+    }
+    let casts: Vec<&dyn Array> = casts.iter().map(|a| a.as_ref()).collect();
+    concat(&casts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_CAST_OPTIONS;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, Int8Array, StringArray};
+
+    #[test]
+    fn test_cast_parallel() {
+        let values = (0..1000).map(|i| match i % 7 {
+            0 => None,
+            3 => Some("x".to_string()),
+            _ => Some(i.to_string()),
+        });
+        let array = StringArray::from_iter(values);
+        let expected =
+            cast_with_options(&array, &DataType::Int32, &DEFAULT_CAST_OPTIONS).unwrap();
+        for chunk_size in [1, 7, 100, 999, 1000, 2000] {
+            let b = cast_with_options_parallel(
+                &array,
+                &DataType::Int32,
+                &DEFAULT_CAST_OPTIONS,
+                chunk_size,
+            )
+            .unwrap();
+            assert_eq!(b.as_primitive::<Int32Type>(), expected.as_primitive());
+        }
+
+        // Dictionaries are packed on the current thread
+        let to_type =
+            DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8));
+        let expected =
+            cast_with_options(&array, &to_type, &DEFAULT_CAST_OPTIONS).unwrap();
+        let b = cast_with_options_parallel(&array, &to_type, &DEFAULT_CAST_OPTIONS, 10)
+            .unwrap();
+        assert_eq!(b.as_ref(), expected.as_ref());
+
+        let err = cast_with_options_parallel(
+            &array,
+            &DataType::Int32,
+            &DEFAULT_CAST_OPTIONS,
+            0,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot cast in chunks of zero rows"
+        );
+    }
+
+    #[test]
+    fn test_cast_parallel_error() {
+        let array = Int32Array::from_iter_values((0..1000).map(|i| i % 200));
+        let options = CastOptions::new().with_safe(false);
+        let expected = cast_with_options(&array, &DataType::Int8, &options).unwrap_err();
+        let err = cast_with_options_parallel(&array, &DataType::Int8, &options, 64)
+            .unwrap_err();
+        assert_eq!(err.to_string(), expected.to_string());
+
+        let b = cast_with_options_parallel(
+            &array,
+            &DataType::Int8,
+            &DEFAULT_CAST_OPTIONS,
+            64,
+        )
+        .unwrap();
+        let b = b.as_any().downcast_ref::<Int8Array>().unwrap();
+        assert_eq!(b.null_count(), 5 * 72);
+        assert_eq!(b.value(127), 127);
+        assert!(b.is_null(128));
+    }
+}
@@ -21,6 +21,9 @@ use arrow_array::{ArrowNativeTypeOp, ArrowPrimitiveType};
 use arrow_buffer::ArrowNativeType;
 use arrow_schema::ArrowError;
 use chrono::prelude::*;
+use chrono::LocalResult;
+use half::f16;
+use std::borrow::Cow;
 use std::str::FromStr;
 
 /// Parse nanoseconds from the first `N` values in digits, subtracting the offset `O`
@@ -162,7 +165,10 @@ impl TimestampParser {
 /// * `2023-01-01 040506 America/Los_Angeles`
 ///
 /// If a timestamp is ambiguous, for example as a result of daylight-savings time, an error
-/// will be returned
+/// will be returned, see [`string_to_datetime_with_resolution`] to resolve it instead
+///
+/// Fractional seconds beyond nanosecond precision are truncated, e.g.
+/// `1997-01-31T09:26:56.123456789123` is parsed as `1997-01-31T09:26:56.123456789`
 ///
 /// Some formats supported by PostgresSql <https://www.postgresql.org/docs/current/datatype-datetime.html#DATATYPE-DATETIME-TIME-TABLE>
 /// are not supported, like
@@ -175,6 +181,73 @@ impl TimestampParser {
 pub fn string_to_datetime<T: TimeZone>(
     timezone: &T,
     s: &str,
+) -> Result<DateTime<T>, ArrowError> {
+    string_to_datetime_with_resolution(timezone, s, LocalTimeResolution::Error)
+}
+
+/// Specifies how local datetimes that are ambiguous, or do not exist, in a timezone as
+/// a result of daylight saving time transitions are resolved
+///
+/// For example, in `America/New_York` clocks move forward from 02:00 to 03:00 on
+/// 2023-03-12, and so `2023-03-12 02:30` does not exist, and move back from 02:00 to
+/// 01:00 on 2023-11-05, and so `2023-11-05 01:30` occurs twice.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LocalTimeResolution {
+    /// Ambiguous and non-existent local datetimes are an error
+    #[default]
+    Error,
+    /// Ambiguous local datetimes are the earliest of their instants, and non-existent
+    /// local datetimes are an error
+    Earliest,
+    /// Ambiguous local datetimes are the latest of their instants, and non-existent
+    /// local datetimes are an error
+    Latest,
+    /// Ambiguous local datetimes are the earliest of their instants, and non-existent
+    /// local datetimes are shifted forward by the length of the gap, i.e. use the UTC
+    /// offset before the transition, e.g. `2023-03-12 02:30` in `America/New_York` is
+    /// `2023-03-12 03:30-04:00`
+    ShiftForward,
+}
+
+impl LocalTimeResolution {
+    /// Returns the datetime of `local` in `tz`, or `None` if it cannot be resolved
+    pub(crate) fn resolve<T: TimeZone>(
+        &self,
+        tz: &T,
+        local: &NaiveDateTime,
+    ) -> Option<DateTime<T>> {
+        match tz.from_local_datetime(local) {
+            LocalResult::Single(datetime) => Some(datetime),
+            LocalResult::Ambiguous(earliest, latest) => match self {
+                Self::Error => None,
+                Self::Earliest | Self::ShiftForward => Some(earliest),
+                Self::Latest => Some(latest),
+            },
+            LocalResult::None => match self {
+                Self::ShiftForward => {
+                    // Transitions are months apart, and so the offset a day earlier is
+                    // the offset before the transition
+                    let before = local.checked_sub_signed(chrono::Duration::days(1))?;
+                    let offset = tz.offset_from_utc_datetime(&before).fix();
+                    let utc = local.checked_sub_signed(chrono::Duration::seconds(
+                        offset.local_minus_utc() as i64,
+                    ))?;
+                    Some(tz.from_utc_datetime(&utc))
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Accepts a string and parses it relative to the provided `timezone`, as
+/// [`string_to_datetime`], resolving local datetimes that are ambiguous or do not exist
+/// in `timezone`, or in the timezone of the string, according to `resolution`
+pub fn string_to_datetime_with_resolution<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+    resolution: LocalTimeResolution,
 ) -> Result<DateTime<T>, ArrowError> {
     let err = |ctx: &str| {
         ArrowError::ParseError(format!("Error parsing timestamp from '{s}': {ctx}"))
@@ -188,13 +261,10 @@ pub fn string_to_datetime<T: TimeZone>(
     let parser = TimestampParser::new(bytes);
     let date = parser.date().ok_or_else(|| err("error parsing date"))?;
     if bytes.len() == 10 {
-        let offset = timezone.offset_from_local_date(&date);
-        let offset = offset
-            .single()
-            .ok_or_else(|| err("error computing timezone offset"))?;
-
         let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
-        return Ok(DateTime::from_local(date.and_time(time), offset));
+        return resolution
+            .resolve(timezone, &date.and_time(time))
+            .ok_or_else(|| err("error computing timezone offset"));
     }
 
     if !parser.test(10, b'T') && !parser.test(10, b't') && !parser.test(10, b' ') {
@@ -212,28 +282,31 @@ pub fn string_to_datetime<T: TimeZone>(
     }
 
     if bytes.len() <= tz_offset {
-        let offset = timezone.offset_from_local_datetime(&datetime);
-        let offset = offset
-            .single()
-            .ok_or_else(|| err("error computing timezone offset"))?;
-        return Ok(DateTime::from_local(datetime, offset));
+        return resolution
+            .resolve(timezone, &datetime)
+            .ok_or_else(|| err("error computing timezone offset"));
     }
 
     if bytes[tz_offset] == b'z' || bytes[tz_offset] == b'Z' {
-        let offset = timezone.offset_from_local_datetime(&datetime);
-        let offset = offset
-            .single()
-            .ok_or_else(|| err("error computing timezone offset"))?;
-        return Ok(DateTime::from_utc(datetime, offset));
+        // A UTC datetime is never ambiguous
+        return Ok(timezone.from_utc_datetime(&datetime));
     }
 
     // Parse remainder of string as timezone
     let parsed_tz: Tz = s[tz_offset..].trim_start().parse()?;
-    let offset = parsed_tz.offset_from_local_datetime(&datetime);
-    let offset = offset
-        .single()
+    let datetime = resolution
+        .resolve(&parsed_tz, &datetime)
         .ok_or_else(|| err("error computing timezone offset"))?;
-    Ok(DateTime::<Tz>::from_local(datetime, offset).with_timezone(timezone))
+    Ok(datetime.with_timezone(timezone))
+}
+
+/// Returns the number of fractional second digits of a timestamp accepted by
+/// [`string_to_datetime`], e.g. 12 for `1997-01-31T09:26:56.123456789123`
+pub(crate) fn fractional_second_digits(s: &str) -> usize {
+    match s.as_bytes().get(19) {
+        Some(b'.') => s.bytes().skip(20).take_while(u8::is_ascii_digit).count(),
+        _ => 0,
+    }
 }
 
 /// Accepts a string in RFC3339 / ISO8601 standard format and some
@@ -426,6 +499,324 @@ fn string_to_time(s: &str) -> Option<NaiveTime> {
     )
 }
 
+/// Splits a trailing UTC offset from a time of day, e.g. `"10:00:00+02:00"`, returning
+/// the time and the offset
+///
+/// The offset is either `Z`, or a sign followed by hours and optionally minutes, in the
+/// form `±HH`, `±HHMM` or `±HH:MM`. Returns `None` if the string has no such offset, the
+/// time itself is not validated.
+///
+/// ```
+/// # use arrow_cast::parse::split_time_offset;
+/// # use chrono::FixedOffset;
+/// let (time, offset) = split_time_offset("10:00:00-05:30").unwrap();
+/// assert_eq!(time, "10:00:00");
+/// assert_eq!(offset, FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap());
+///
+/// assert!(split_time_offset("10:00:00").is_none());
+/// ```
+pub fn split_time_offset(s: &str) -> Option<(&str, FixedOffset)> {
+    if let Some(time) = s.strip_suffix(['Z', 'z']) {
+        return Some((time.trim_end(), FixedOffset::east_opt(0)?));
+    }
+
+    let idx = s.rfind(['+', '-'])?;
+    let (time, offset) = (s[..idx].trim_end(), &s[idx + 1..]);
+    let digits = offset.as_bytes();
+    let (hours, minutes) = match digits.len() {
+        2 => (offset, "0"),
+        4 => (&offset[..2], &offset[2..]),
+        5 if digits[2] == b':' => (&offset[..2], &offset[3..]),
+        _ => return None,
+    };
+    if !hours
+        .bytes()
+        .chain(minutes.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let (hours, minutes) = (hours.parse::<i32>().ok()?, minutes.parse::<i32>().ok()?);
+    if minutes >= 60 {
+        return None;
+    }
+    let seconds = hours * 3600 + minutes * 60;
+    let offset = match &s[idx..idx + 1] {
+        "+" => FixedOffset::east_opt(seconds)?,
+        _ => FixedOffset::west_opt(seconds)?,
+    };
+    Some((time, offset))
+}
+
+/// Parses an integer that may be prefixed with `0x`, `0o` or `0b` to indicate a
+/// hexadecimal, octal or binary radix, e.g. `"0x1F"`, `"-0b1010"`
+///
+/// Strings without a prefix are parsed as decimal integers. Returns `None` if the
+/// string is not a valid integer or does not fit in `N`
+pub fn parse_radix_prefixed_integer<N>(s: &str) -> Option<N>
+where
+    N: lexical_core::FromLexical + num::NumCast,
+{
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let radix = match digits.get(..2) {
+        Some("0x" | "0X") => 16,
+        Some("0o" | "0O") => 8,
+        Some("0b" | "0B") => 2,
+        _ => return lexical_core::parse(s.as_bytes()).ok(),
+    };
+
+    // from_str_radix accepts a leading sign which must not follow the prefix
+    let digits = &digits[2..];
+    if !digits.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    let value = i128::from_str_radix(digits, radix).ok()?;
+    num::cast(if negative { -value } else { value })
+}
+
+/// The decimal and grouping separators of numbers formatted for a locale, e.g.
+/// `"1.234,56"` with a `,` decimal separator and `.` grouping separator
+///
+/// Numbers may additionally be written with a currency symbol, e.g. `"$1,234.56"` or
+/// `"1.234,56 €"`, and negative numbers in parentheses, e.g. `"(45.00)"`, as is common
+/// in financial data. These are only accepted if enabled with
+/// [`Self::with_currency_symbols`] and [`Self::with_parentheses_negative`].
+///
+/// ```
+/// # use arrow_cast::parse::NumberFormat;
+/// let format = NumberFormat::new(',', Some('.'));
+/// assert_eq!(format.normalize("-1.234,56").as_deref(), Some("-1234.56"));
+/// assert_eq!(format.normalize("1,234.56"), None);
+///
+/// let format = NumberFormat::new('.', Some(','))
+///     .with_currency_symbols(["$"])
+///     .with_parentheses_negative(true);
+/// assert_eq!(format.normalize("$1,234.56").as_deref(), Some("1234.56"));
+/// assert_eq!(format.normalize("($45.00)").as_deref(), Some("-45.00"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumberFormat {
+    /// The separator between the integer and fractional digits
+    pub decimal_separator: char,
+    /// The separator between groups of integer digits, if any
+    pub grouping_separator: Option<char>,
+    /// The currency symbols that may precede or follow a number, after its sign
+    pub currency_symbols: Vec<String>,
+    /// Whether a number enclosed in parentheses is negative
+    pub parentheses_negative: bool,
+}
+
+impl NumberFormat {
+    /// Creates a new [`NumberFormat`]
+    pub const fn new(decimal_separator: char, grouping_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            grouping_separator,
+            currency_symbols: Vec::new(),
+            parentheses_negative: false,
+        }
+    }
+
+    /// Sets the currency symbols that may precede or follow a number, see
+    /// [`Self::currency_symbols`]
+    pub fn with_currency_symbols<I, S>(mut self, currency_symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.currency_symbols = currency_symbols.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets whether a number enclosed in parentheses is negative, see
+    /// [`Self::parentheses_negative`]
+    pub fn with_parentheses_negative(mut self, parentheses_negative: bool) -> Self {
+        self.parentheses_negative = parentheses_negative;
+        self
+    }
+
+    /// Rewrites `s` to use `.` as the decimal separator, and removes the grouping
+    /// separators, so that it can be parsed as a float or decimal
+    ///
+    /// Grouping separators must be between two digits of the integer part. Returns
+    /// `None` if they are not, if `s` contains more than one decimal separator, or
+    /// if it contains a `.` that is neither the decimal nor the grouping separator.
+    ///
+    /// If currency symbols or parentheses are enabled, they are removed along with the
+    /// whitespace surrounding them, and `None` is returned for a number in parentheses
+    /// that also has a sign
+    pub fn normalize<'a>(&self, s: &'a str) -> Option<Cow<'a, str>> {
+        if self.currency_symbols.is_empty() && !self.parentheses_negative {
+            return self.normalize_separators(s);
+        }
+
+        let (negative, s) = self.strip_affixes(s)?;
+        let normalized = self.normalize_separators(s)?;
+        Some(match negative {
+            true => Cow::Owned(format!("-{normalized}")),
+            false => normalized,
+        })
+    }
+
+    /// Removes the parentheses, sign and currency symbol of `s`, returning whether it
+    /// is negative, and the remaining number
+    fn strip_affixes<'a>(&self, s: &'a str) -> Option<(bool, &'a str)> {
+        let mut s = s.trim();
+        let mut parentheses = false;
+        if self.parentheses_negative {
+            if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+                parentheses = true;
+                s = inner.trim();
+            }
+        }
+
+        let (mut sign, rest) = split_sign(s);
+        s = rest;
+        let currency = self.currency_symbols.iter().find_map(|symbol| {
+            let symbol = symbol.as_str();
+            match s.strip_prefix(symbol) {
+                Some(rest) => Some(rest.trim_start()),
+                None => s.strip_suffix(symbol).map(str::trim_end),
+            }
+        });
+        if let Some(rest) = currency {
+            s = rest;
+            // The sign may also follow a leading currency symbol, e.g. `$-1.5`
+            if sign.is_none() {
+                (sign, s) = split_sign(s);
+            }
+        }
+
+        match (parentheses, sign) {
+            (true, Some(_)) => None,
+            (true, None) | (false, Some('-')) => Some((true, s)),
+            (false, _) => Some((false, s)),
+        }
+    }
+
+    fn normalize_separators<'a>(&self, s: &'a str) -> Option<Cow<'a, str>> {
+        let grouping = self.grouping_separator;
+        if self.decimal_separator == '.' && !grouping.map_or(false, |g| s.contains(g)) {
+            return Some(Cow::Borrowed(s));
+        }
+
+        let mut out = String::with_capacity(s.len());
+        let mut seen_decimal = false;
+        let mut integer_part = true;
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            if Some(c) == grouping {
+                let between_digits = out.ends_with(|c: char| c.is_ascii_digit())
+                    && chars.peek().map_or(false, char::is_ascii_digit);
+                if !integer_part || !between_digits {
+                    return None;
+                }
+            } else if c == self.decimal_separator {
+                if seen_decimal {
+                    return None;
+                }
+                seen_decimal = true;
+                integer_part = false;
+                out.push('.');
+            } else {
+                match c {
+                    '.' => return None,
+                    'e' | 'E' => integer_part = false,
+                    _ => {}
+                }
+                out.push(c);
+            }
+        }
+        Some(Cow::Owned(out))
+    }
+}
+
+/// Splits the leading `+` or `-` sign from `s`
+fn split_sign(s: &str) -> (Option<char>, &str) {
+    match s.chars().next() {
+        Some(c @ ('+' | '-')) => (Some(c), &s[1..]),
+        _ => (None, s),
+    }
+}
+
+/// The English month names, and their abbreviations, accepted by [`parse_relaxed_date`]
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Parses a date with an English month name and a two or four digit year, as written
+/// by legacy systems, e.g. `"02-JAN-99"`, `"2/January/1999"`
+///
+/// The day, month and year are separated by `-` or `/`, and the month may be a full
+/// name or a three letter abbreviation in any case. A two digit year `YY` is resolved
+/// to the year ending in `YY` in the range `pivot..pivot + 100`, e.g. with a pivot of
+/// 1950, `"49"` is 2049 and `"50"` is 1950.
+///
+/// Returns `None` if the string is not such a date
+pub fn parse_relaxed_date(s: &str, pivot: i32) -> Option<NaiveDate> {
+    let mut parts = s.split(['-', '/']);
+    let (day, month, year) = (parts.next()?, parts.next()?, parts.next()?);
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if parts.next().is_some()
+        || !(1..=2).contains(&day.len())
+        || !is_digits(day)
+        || !is_digits(year)
+    {
+        return None;
+    }
+
+    let day: u32 = day.parse().ok()?;
+    let month = MONTH_NAMES.iter().position(|name| match month.len() {
+        3 => name[..3].eq_ignore_ascii_case(month),
+        _ => name.eq_ignore_ascii_case(month),
+    })? as u32
+        + 1;
+    let year: i32 = match year.len() {
+        2 => {
+            let mut year = pivot - pivot.rem_euclid(100) + year.parse::<i32>().ok()?;
+            if year < pivot {
+                year += 100;
+            }
+            year
+        }
+        4 => year.parse().ok()?,
+        _ => return None,
+    };
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Parses a date as accepted by [`parse_relaxed_date`], optionally followed by a space
+/// and a time of day, e.g. `"02-JAN-99 13:45:00"`, `"02-JAN-99 01:45:00.5 PM"`
+///
+/// Returns `None` if the string is not such a datetime
+pub fn parse_relaxed_datetime(s: &str, pivot: i32) -> Option<NaiveDateTime> {
+    match s.split_once(' ') {
+        Some((date, time)) => {
+            let time = string_to_time(time.trim_start())?;
+            Some(parse_relaxed_date(date, pivot)?.and_time(time))
+        }
+        None => Some(parse_relaxed_date(s, pivot)?.and_hms_opt(0, 0, 0).unwrap()),
+    }
+}
+
 /// Specialized parsing implementations
 /// used by csv and json reader
 pub trait Parser: ArrowPrimitiveType {
@@ -436,6 +827,15 @@ pub trait Parser: ArrowPrimitiveType {
     }
 }
 
+impl Parser for Float16Type {
+    fn parse(string: &str) -> Option<f16> {
+        // Parse as f64 to avoid double rounding through f32
+        lexical_core::parse(string.as_bytes())
+            .ok()
+            .map(f16::from_f64)
+    }
+}
+
 impl Parser for Float32Type {
     fn parse(string: &str) -> Option<f32> {
         lexical_core::parse(string.as_bytes()).ok()
@@ -466,6 +866,184 @@ parser_primitive!(Int32Type);
 parser_primitive!(Int16Type);
 parser_primitive!(Int8Type);
 
+/// The strings that [`parse_bool`] parses as `true`, ignoring ASCII case and
+/// surrounding whitespace
+pub const BOOLEAN_TRUE_TOKENS: &[&str] =
+    &["t", "tr", "tru", "true", "y", "ye", "yes", "on", "1"];
+
+/// The strings that [`parse_bool`] parses as `false`, ignoring ASCII case and
+/// surrounding whitespace
+pub const BOOLEAN_FALSE_TOKENS: &[&str] = &[
+    "f", "fa", "fal", "fals", "false", "n", "no", "of", "off", "0",
+];
+
+/// Parses `string` as a boolean if it is one of [`BOOLEAN_TRUE_TOKENS`] or
+/// [`BOOLEAN_FALSE_TOKENS`], ignoring ASCII case and surrounding whitespace
+///
+/// This is the parsing used when casting strings to booleans, see [`BooleanParser`] to
+/// accept other tokens
+pub fn parse_bool(string: &str) -> Option<bool> {
+    let bytes = trim_whitespace(string).as_bytes();
+
+    // Lowercase into a stack buffer large enough for the longest token
+    let mut buf = [0_u8; 5];
+    let lower = buf.get_mut(..bytes.len())?;
+    lower.copy_from_slice(bytes);
+    lower.make_ascii_lowercase();
+
+    let matches = |tokens: &[&str]| tokens.iter().any(|t| t.as_bytes() == lower);
+    if matches(BOOLEAN_TRUE_TOKENS) {
+        Some(true)
+    } else if matches(BOOLEAN_FALSE_TOKENS) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Trims leading and trailing whitespace from `s`
+///
+/// Equivalent to [`str::trim`], but scans for ASCII whitespace byte-wise, only
+/// decoding characters if the result starts or ends with a non-ASCII byte
+pub(crate) fn trim_whitespace(s: &str) -> &str {
+    let bytes = s.as_bytes();
+    let start = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    let end = bytes
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(start, |i| i + 1);
+
+    // ASCII whitespace bytes are always character boundaries
+    let trimmed = &s[start..end];
+    // Vertical tab is whitespace but not ASCII whitespace, as defined by WHATWG
+    let unicode = |b: &u8| !b.is_ascii() || *b == b'\x0B';
+    let bytes = trimmed.as_bytes();
+    match bytes.first().map_or(false, unicode) || bytes.last().map_or(false, unicode) {
+        true => trimmed.trim(),
+        false => trimmed,
+    }
+}
+
+/// Parses booleans from the canonical tokens accepted by [`parse_bool`], along with
+/// any additional tokens registered with [`Self::with_true_token`] and
+/// [`Self::with_false_token`]
+///
+/// ```
+/// # use arrow_cast::parse::BooleanParser;
+/// let parser = BooleanParser::new().with_true_token("si");
+/// assert_eq!(parser.parse(" Yes "), Some(true));
+/// assert_eq!(parser.parse("SI"), Some(true));
+/// assert_eq!(parser.parse("off"), Some(false));
+/// assert_eq!(parser.parse("maybe"), None);
+///
+/// // Only the registered tokens, matching case
+/// let parser = BooleanParser::new()
+///     .with_canonical_tokens(false)
+///     .with_case_sensitive(true)
+///     .with_true_token("Y")
+///     .with_false_token("N");
+/// assert_eq!(parser.parse("Y"), Some(true));
+/// assert_eq!(parser.parse("y"), None);
+/// assert_eq!(parser.parse("true"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BooleanParser {
+    true_tokens: Vec<String>,
+    false_tokens: Vec<String>,
+    canonical_tokens: bool,
+    case_sensitive: bool,
+}
+
+impl Default for BooleanParser {
+    fn default() -> Self {
+        Self {
+            true_tokens: Vec::new(),
+            false_tokens: Vec::new(),
+            canonical_tokens: true,
+            case_sensitive: false,
+        }
+    }
+}
+
+impl BooleanParser {
+    /// Create a [`BooleanParser`] accepting only the canonical tokens
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Additionally parse `token` as `true`
+    pub fn with_true_token(mut self, token: impl Into<String>) -> Self {
+        self.true_tokens.push(token.into());
+        self
+    }
+
+    /// Additionally parse `token` as `false`
+    pub fn with_false_token(mut self, token: impl Into<String>) -> Self {
+        self.false_tokens.push(token.into());
+        self
+    }
+
+    /// Sets whether the canonical tokens, [`BOOLEAN_TRUE_TOKENS`] and
+    /// [`BOOLEAN_FALSE_TOKENS`], are parsed, defaults to `true`
+    pub fn with_canonical_tokens(mut self, canonical_tokens: bool) -> Self {
+        self.canonical_tokens = canonical_tokens;
+        self
+    }
+
+    /// Sets whether tokens only match strings of the same case, defaults to `false`,
+    /// ignoring ASCII case. The canonical tokens are lowercase
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Parse `string`, ignoring surrounding whitespace, and ASCII case unless
+    /// case sensitive
+    pub fn parse(&self, string: &str) -> Option<bool> {
+        let string = trim_whitespace(string);
+        let canonical = match (self.canonical_tokens, self.case_sensitive) {
+            (false, _) => None,
+            (true, false) => parse_bool(string),
+            (true, true) => {
+                parse_bool_token(string, BOOLEAN_TRUE_TOKENS, BOOLEAN_FALSE_TOKENS, true)
+            }
+        };
+        canonical.or_else(|| {
+            parse_bool_token(
+                string,
+                &self.true_tokens,
+                &self.false_tokens,
+                self.case_sensitive,
+            )
+        })
+    }
+}
+
+fn parse_bool_token<S: AsRef<str>>(
+    string: &str,
+    true_tokens: &[S],
+    false_tokens: &[S],
+    case_sensitive: bool,
+) -> Option<bool> {
+    let matches = |tokens: &[S]| {
+        tokens.iter().any(|t| match case_sensitive {
+            true => t.as_ref() == string,
+            false => t.as_ref().eq_ignore_ascii_case(string),
+        })
+    };
+    if matches(true_tokens) {
+        Some(true)
+    } else if matches(false_tokens) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
 impl Parser for TimestampNanosecondType {
     fn parse(string: &str) -> Option<i64> {
         string_to_timestamp_nanos(string).ok()
@@ -616,6 +1194,50 @@ impl Parser for Date64Type {
     }
 }
 
+/// The largest magnitude of exponent accepted by [`expand_decimal_exponent`]
+const MAX_DECIMAL_EXPONENT: u32 = 1000;
+
+/// Rewrites a decimal in scientific notation, e.g. `1.5E-3`, as a plain decimal, e.g.
+/// `0.0015`, returning `s` unchanged if it has no exponent
+///
+/// Returns `None` if the mantissa or exponent are invalid
+pub(crate) fn expand_decimal_exponent(s: &str) -> Option<Cow<'_, str>> {
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => return Some(Cow::Borrowed(s)),
+    };
+    let exponent: i32 = exponent.parse().ok()?;
+    if exponent.unsigned_abs() > MAX_DECIMAL_EXPONENT {
+        return None;
+    }
+
+    let (sign, mantissa) = match mantissa.as_bytes().first() {
+        Some(b'-') => ("-", &mantissa[1..]),
+        Some(b'+') => ("", &mantissa[1..]),
+        _ => ("", mantissa),
+    };
+    let (integers, fractionals) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{integers}{fractionals}");
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // The position of the decimal point within digits
+    let point = integers.len() as i64 + exponent as i64;
+    let expanded = if point <= 0 {
+        format!("{sign}0.{}{digits}", "0".repeat(-point as usize))
+    } else if point as usize >= digits.len() {
+        format!(
+            "{sign}{digits}{}",
+            "0".repeat(point as usize - digits.len())
+        )
+    } else {
+        let (integers, fractionals) = digits.split_at(point as usize);
+        format!("{sign}{integers}.{fractionals}")
+    };
+    Some(Cow::Owned(expanded))
+}
+
 /// Parse the string format decimal value to i128/i256 format and checking the precision and scale.
 /// The result value can't be out of bounds.
 pub fn parse_decimal<T: DecimalType>(
@@ -628,7 +1250,10 @@ pub fn parse_decimal<T: DecimalType>(
     let mut digits = 0;
     let base = T::Native::usize_as(10);
 
-    let bs = s.as_bytes();
+    let expanded = expand_decimal_exponent(s).ok_or_else(|| {
+        ArrowError::ParseError(format!("can't parse the string value {s} to decimal"))
+    })?;
+    let bs = expanded.as_bytes();
     let (bs, negative) = match bs.first() {
         Some(b'-') => (&bs[1..], true),
         Some(b'+') => (&bs[1..], false),
@@ -747,6 +1372,113 @@ pub fn parse_interval_month_day_nano(
     ))
 }
 
+const NANOS_PER_DAY_I64: i64 = 86_400_000_000_000;
+
+/// Parses a duration to a number of nanoseconds, either as an ISO 8601 duration, e.g.
+/// `"PT1H30M"`, `"P1DT0.5S"`, `"-PT10M"`, or as an interval expression, e.g.
+/// `"1 hour 30 minutes"`, with the units accepted by [`parse_interval_month_day_nano`]
+/// and a number without a unit being a number of seconds
+///
+/// Days are 24 hours and weeks are 7 days. Years and months have no fixed length, and
+/// are rejected, as are durations that overflow 64-bit nanoseconds.
+///
+/// ```
+/// # use arrow_cast::parse::parse_duration;
+/// let nanos = 5_400_000_000_000;
+/// assert_eq!(parse_duration("PT1H30M").unwrap(), nanos);
+/// assert_eq!(parse_duration("1 hour 30 minutes").unwrap(), nanos);
+/// assert_eq!(parse_duration("-PT0.5S").unwrap(), -500_000_000);
+/// assert!(parse_duration("P1M").is_err());
+/// ```
+pub fn parse_duration(value: &str) -> Result<i64, ArrowError> {
+    let s = value.trim();
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    if let Some(iso) = unsigned.strip_prefix(['P', 'p']) {
+        let nanos = parse_iso8601_duration(iso).ok_or_else(|| {
+            ArrowError::ParseError(format!("Invalid ISO 8601 duration: {value:?}"))
+        })?;
+        return Ok(if negative { -nanos } else { nanos });
+    }
+
+    let (months, days, nanos) = parse_interval("seconds", s)?;
+    if months != 0 {
+        return Err(ArrowError::ParseError(format!(
+            "Cannot parse {value:?} as a duration, years and months have no fixed length"
+        )));
+    }
+    (days as i64)
+        .checked_mul(NANOS_PER_DAY_I64)
+        .and_then(|days| days.checked_add(nanos))
+        .ok_or_else(|| {
+            ArrowError::ParseError(format!("Duration out of range: {value:?}"))
+        })
+}
+
+/// Parses the designators of an ISO 8601 duration after the leading `P`, returning
+/// `None` if it is invalid, contains years or months, or overflows
+fn parse_iso8601_duration(s: &str) -> Option<i64> {
+    const DATE: [(u8, i64); 2] =
+        [(b'W', 7 * NANOS_PER_DAY_I64), (b'D', NANOS_PER_DAY_I64)];
+    const TIME: [(u8, i64); 3] = [
+        (b'H', 3_600_000_000_000),
+        (b'M', 60_000_000_000),
+        (b'S', 1_000_000_000),
+    ];
+
+    let (date, time) = match s.split_once(['T', 't']) {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, time),
+        None => (s, ""),
+    };
+    if date.is_empty() && time.is_empty() {
+        return None;
+    }
+
+    let mut nanos: i128 = 0;
+    for (part, units) in [(date, &DATE[..]), (time, &TIME[..])] {
+        // Designators must appear at most once, and in order
+        let mut units = units.iter();
+        let mut rest = part;
+        while !rest.is_empty() {
+            let end = rest.find(|c: char| c.is_ascii_alphabetic())?;
+            let designator = rest.as_bytes()[end].to_ascii_uppercase();
+            let (_, unit) = units.by_ref().find(|(d, _)| *d == designator)?;
+            nanos += parse_duration_component(&rest[..end], *unit)?;
+            rest = &rest[end + 1..];
+        }
+    }
+    nanos.try_into().ok()
+}
+
+/// Parses a number of `unit` nanoseconds, with an optional fraction truncated to a
+/// whole number of nanoseconds
+fn parse_duration_component(number: &str, unit: i64) -> Option<i128> {
+    let (whole, fraction) = match number.split_once(['.', ',']) {
+        Some((_, "")) => return None,
+        Some((whole, fraction)) => (whole, fraction),
+        None => (number, ""),
+    };
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+
+    let whole = whole.parse::<i64>().ok()? as i128 * unit as i128;
+    let fraction = match fraction.len() {
+        0 => 0,
+        len => {
+            let digits = &fraction[..len.min(9)];
+            let scaled =
+                digits.parse::<i128>().ok()? * 10_i128.pow(9 - digits.len() as u32);
+            scaled * unit as i128 / 1_000_000_000
+        }
+    };
+    Some(whole + fraction)
+}
+
 const SECONDS_PER_HOUR: f64 = 3_600_f64;
 const NANOS_PER_MILLIS: f64 = 1_000_000_f64;
 const NANOS_PER_SECOND: f64 = 1_000_f64 * NANOS_PER_MILLIS;
@@ -962,6 +1694,198 @@ mod tests {
         assert_eq!(parse_nanos::<6, b'0'>(b"123456"), 123_456_000);
     }
 
+    #[test]
+    fn test_parse_relaxed_date() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        let cases = [
+            ("02-JAN-99", 1950, date(1999, 1, 2)),
+            ("02-jan-49", 1950, date(2049, 1, 2)),
+            ("2-Jan-50", 1950, date(1950, 1, 2)),
+            ("31/December/1899", 1950, date(1899, 12, 31)),
+            ("29-FEB-00", 1970, date(2000, 2, 29)),
+            ("29-FEB-00", 1900, None),
+            ("15-Sept-20", 1950, None),
+            ("15-SEP-20", 1950, date(2020, 9, 15)),
+            ("15-SEP-20", -50, date(20, 9, 15)),
+            ("15-SEP-2", 1950, None),
+            ("15-SE-20", 1950, None),
+            ("15-SEP-20-1", 1950, None),
+            ("32-JAN-20", 1950, None),
+            ("+1-JAN-20", 1950, None),
+            ("1-may-20", 1950, date(2020, 5, 1)),
+            ("1-JAN-+2", 1950, None),
+            ("2020-01-15", 1950, None),
+        ];
+        for (s, pivot, expected) in cases {
+            assert_eq!(parse_relaxed_date(s, pivot), expected, "{s}");
+        }
+
+        let datetime =
+            |h, m, s, n| date(1999, 1, 2).unwrap().and_hms_nano_opt(h, m, s, n);
+        let cases = [
+            ("02-JAN-99", datetime(0, 0, 0, 0)),
+            ("02-JAN-99 13:45:10", datetime(13, 45, 10, 0)),
+            (
+                "02-JAN-99  01:45:10.5 PM",
+                datetime(13, 45, 10, 500_000_000),
+            ),
+            ("02-JAN-99 25:00:00", None),
+            ("02-JAN-99 ", None),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(parse_relaxed_datetime(s, 1950), expected, "{s}");
+        }
+    }
+
+    #[test]
+    fn test_trim_whitespace() {
+        let cases = [
+            "",
+            " ",
+            "true",
+            "  true\t\n",
+            "\x0Btrue\x0C",
+            "\u{00A0}true\u{2003}",
+            " \u{00A0} t r u e \u{3000}",
+            "é ",
+            " \x0B",
+        ];
+        for case in cases {
+            assert_eq!(trim_whitespace(case), case.trim(), "{case:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        for s in ["t", "TRUE", " yes ", "On", "1", "tRu"] {
+            assert_eq!(parse_bool(s), Some(true), "{s}");
+        }
+        for s in ["f", "FALSE", " no", "Off", "0", "fals"] {
+            assert_eq!(parse_bool(s), Some(false), "{s}");
+        }
+        for s in ["", "2", "truee", "oui", "o"] {
+            assert_eq!(parse_bool(s), None, "{s}");
+        }
+
+        let parser = BooleanParser::new()
+            .with_true_token("oui")
+            .with_false_token("Non");
+        assert_eq!(parser.parse("OUI"), Some(true));
+        assert_eq!(parser.parse(" non "), Some(false));
+        assert_eq!(parser.parse("yes"), Some(true));
+        assert_eq!(parser.parse("si"), None);
+
+        let parser = parser.with_canonical_tokens(false);
+        assert_eq!(parser.parse("OUI"), Some(true));
+        assert_eq!(parser.parse("yes"), None);
+
+        let parser = BooleanParser::new()
+            .with_case_sensitive(true)
+            .with_false_token("Non");
+        assert_eq!(parser.parse(" Non"), Some(false));
+        assert_eq!(parser.parse("non"), None);
+        assert_eq!(parser.parse("yes"), Some(true));
+        assert_eq!(parser.parse("YES"), None);
+    }
+
+    #[test]
+    fn test_parse_radix_prefixed_integer() {
+        let cases = [
+            ("0x1F", Some(31)),
+            ("0X1f", Some(31)),
+            ("0b1010", Some(10)),
+            ("0o17", Some(15)),
+            ("-0x10", Some(-16)),
+            ("+0b1", Some(1)),
+            ("42", Some(42)),
+            ("-42", Some(-42)),
+            ("0x7FFFFFFF", Some(i32::MAX)),
+            ("-0x80000000", Some(i32::MIN)),
+            ("0x80000000", None),
+            ("0x", None),
+            ("0x-1", None),
+            ("0b102", None),
+            ("0xG", None),
+            ("abc", None),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(parse_radix_prefixed_integer::<i32>(s), expected, "{s}");
+        }
+
+        assert_eq!(parse_radix_prefixed_integer::<u8>("0xFF"), Some(255));
+        assert_eq!(parse_radix_prefixed_integer::<u8>("0x100"), None);
+        assert_eq!(parse_radix_prefixed_integer::<u8>("-0x1"), None);
+        assert_eq!(parse_radix_prefixed_integer::<u64>("-0x0"), Some(0));
+    }
+
+    #[test]
+    fn test_number_format_normalize() {
+        let format = NumberFormat::new(',', Some('.'));
+        let cases = [
+            ("1.234,56", Some("1234.56")),
+            ("-1.234.567", Some("-1234567")),
+            ("+12,5e-3", Some("+12.5e-3")),
+            (",5", Some(".5")),
+            ("1,", Some("1.")),
+            ("inf", Some("inf")),
+            ("1.5", Some("15")),
+            ("1,2,3", None),
+            ("1,234.5", None),
+            ("1..234", None),
+            (".123", None),
+            ("123.", None),
+            ("-.1", None),
+            ("1e1.000", None),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(format.normalize(s).as_deref(), expected, "{s}");
+        }
+
+        let format = NumberFormat::new(',', Some(' '));
+        assert_eq!(format.normalize("1 234,5").as_deref(), Some("1234.5"));
+        assert_eq!(format.normalize("1.5"), None);
+        assert_eq!(format.normalize(" 1"), None);
+
+        let format = NumberFormat::new('.', Some(','));
+        assert!(matches!(
+            format.normalize("1.5"),
+            Some(Cow::Borrowed("1.5"))
+        ));
+        assert_eq!(format.normalize("1,234.5").as_deref(), Some("1234.5"));
+        assert_eq!(format.normalize("1.234,5"), None);
+
+        let format = NumberFormat::new(',', None);
+        assert_eq!(format.normalize("1234,5").as_deref(), Some("1234.5"));
+        assert_eq!(format.normalize("1.234,5"), None);
+    }
+
+    #[test]
+    fn test_number_format_currency() {
+        let format = NumberFormat::new(',', Some('.'))
+            .with_currency_symbols(["€", "EUR"])
+            .with_parentheses_negative(true);
+        let cases = [
+            ("1.234,56 €", Some("1234.56")),
+            ("€1.234,56", Some("1234.56")),
+            ("-€ 1,5", Some("-1.5")),
+            ("€-1,5", Some("-1.5")),
+            ("+1,5EUR", Some("1.5")),
+            ("(1.234,56 €)", Some("-1234.56")),
+            ("( 45 )", Some("-45")),
+            ("1,5", Some("1.5")),
+            ("(-1,5)", None),
+            ("1.5 €", Some("15")),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(format.normalize(s).as_deref(), expected, "{s}");
+        }
+
+        // Parentheses are only accepted if enabled
+        let format = NumberFormat::new('.', None).with_currency_symbols(["$"]);
+        assert_eq!(format.normalize("($1)").as_deref(), Some("($1)"));
+        assert_eq!(format.normalize("$1").as_deref(), Some("1"));
+    }
+
     #[test]
     fn string_to_timestamp_timezone() {
         // Explicit timezone
@@ -1114,6 +2038,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn string_to_timestamp_beyond_nanoseconds() {
+        let cases = [
+            (
+                "2020-09-08T13:42:29.123456789123",
+                "2020-09-08T13:42:29.123456789",
+            ),
+            (
+                "2020-09-08T13:42:29.123456789999Z",
+                "2020-09-08T13:42:29.123456789",
+            ),
+            (
+                "2020-09-08 13:42:29.12345678912345678+01:00",
+                "2020-09-08T12:42:29.123456789",
+            ),
+        ];
+        for (s, expected) in cases {
+            let expected = expected.parse::<NaiveDateTime>().unwrap();
+            let parsed = string_to_datetime(&Utc, s).unwrap();
+            assert_eq!(parsed.naive_utc(), expected, "{s}");
+        }
+
+        assert_eq!(
+            fractional_second_digits("2020-09-08T13:42:29.123456789123"),
+            12
+        );
+        assert_eq!(fractional_second_digits("2020-09-08T13:42:29.12Z"), 2);
+        assert_eq!(fractional_second_digits("2020-09-08T13:42:29"), 0);
+        assert_eq!(fractional_second_digits("2020-09-08"), 0);
+    }
+
     #[test]
     fn string_to_timestamp_invalid() {
         // Test parsing invalid formats
@@ -1544,6 +2499,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_split_time_offset() {
+        let east = |s| FixedOffset::east_opt(s).unwrap();
+        let cases = [
+            ("10:00:00+02:00", "10:00:00", 7200),
+            ("10:00:00.5-0130", "10:00:00.5", -5400),
+            ("10:00 +05", "10:00", 18000),
+            ("10:00:00Z", "10:00:00", 0),
+            ("10:00:00 z", "10:00:00", 0),
+            ("10:00:00-00:00", "10:00:00", 0),
+        ];
+        for (s, time, offset) in cases {
+            assert_eq!(split_time_offset(s), Some((time, east(offset))), "{s}");
+        }
+
+        let cases = [
+            "10:00:00",
+            "10:00:00+2",
+            "10:00:00+02:0",
+            "10:00:00+02:60",
+            "10:00:00+0a:00",
+            "10:00:00+24:00",
+            "10:00:00+02:00:00",
+        ];
+        for s in cases {
+            assert_eq!(split_time_offset(s), None, "{s}");
+        }
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        let cases = [
+            ("PT1H30M", 5_400_000_000_000),
+            ("pt1h30m", 5_400_000_000_000),
+            ("P1W", 7 * NANOS_PER_DAY_I64),
+            ("P1DT12H", 3 * NANOS_PER_DAY_I64 / 2),
+            ("PT0.000000001S", 1),
+            ("PT1,5M", 90_000_000_000),
+            ("PT0.0000000019S", 1),
+            ("-PT10S", -10_000_000_000),
+            ("P0D", 0),
+            ("1 hour 30 minutes", 5_400_000_000_000),
+            ("1.5", 1_500_000_000),
+            ("-1 day", -NANOS_PER_DAY_I64),
+            ("1 week 2 milliseconds", 7 * NANOS_PER_DAY_I64 + 2_000_000),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(parse_duration(s).unwrap(), expected, "{s}");
+        }
+
+        let cases = [
+            "P", "PT", "P1Y", "P1M", "P1DT", "PT1S1M", "PT1H1H", "P1H", "PT1.S", "PT.5S",
+            "PT-1S", "P1D2", "P106752D", "1 month", "1 parsec",
+        ];
+        for s in cases {
+            assert!(parse_duration(s).is_err(), "{s}");
+        }
+    }
+
     #[test]
     fn test_parse_interval() {
         assert_eq!(
@@ -1714,7 +2728,35 @@ mod tests {
             let result_256 = parse_decimal::<Decimal256Type>(s, 20, 3);
             assert_eq!(i256::from_i128(i), result_256.unwrap());
         }
-        let can_not_parse_tests = ["123,123", ".", "123.123.123", "", "+", "-"];
+        let exponent_tests = [
+            ("1.23123E2", 123123i128),
+            ("1.23123e+2", 123123i128),
+            ("-1.231E2", -123100i128),
+            ("1E-3", 1i128),
+            ("1.5E-4", 0i128),
+            ("12E1", 120000i128),
+            (".5e1", 5000i128),
+            ("0E0", 0i128),
+        ];
+        for (s, i) in exponent_tests {
+            let result_128 = parse_decimal::<Decimal128Type>(s, 20, 3);
+            assert_eq!(i, result_128.unwrap(), "{s}");
+            let result_256 = parse_decimal::<Decimal256Type>(s, 20, 3);
+            assert_eq!(i256::from_i128(i), result_256.unwrap(), "{s}");
+        }
+
+        let can_not_parse_tests = [
+            "123,123",
+            ".",
+            "123.123.123",
+            "",
+            "+",
+            "-",
+            "1E",
+            "E2",
+            "1E2.5",
+            "1E99999",
+        ];
         for s in can_not_parse_tests {
             let result_128 = parse_decimal::<Decimal128Type>(s, 20, 3);
             assert_eq!(
@@ -0,0 +1,297 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A registry of user-defined casts, see [`CastRegistry`]
+
+use crate::cast::CastOptions;
+use crate::extension::EXTENSION_TYPE_NAME_KEY;
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{ArrowError, DataType, Field};
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// A user-defined cast of an array to the given type
+pub type CastFunction = Arc<
+    dyn Fn(&dyn Array, &DataType, &CastOptions) -> Result<ArrayRef, ArrowError>
+        + Send
+        + Sync,
+>;
+
+/// The source or target type of a cast registered in a [`CastRegistry`], a [`DataType`]
+/// and the name of its extension type, if any
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CastType {
+    data_type: DataType,
+    extension: Option<String>,
+}
+
+impl CastType {
+    /// Create a [`CastType`] of `data_type`, without an extension type
+    pub fn new(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            extension: None,
+        }
+    }
+
+    /// Create a [`CastType`] of the type of `field`, and the name of its extension type
+    /// in the [`EXTENSION_TYPE_NAME_KEY`] metadata, if any
+    pub fn from_field(field: &Field) -> Self {
+        Self {
+            data_type: field.data_type().clone(),
+            extension: field.metadata().get(EXTENSION_TYPE_NAME_KEY).cloned(),
+        }
+    }
+
+    /// Sets the name of the extension type, e.g. `arrow.uuid`
+    pub fn with_extension(mut self, name: impl Into<String>) -> Self {
+        self.extension = Some(name.into());
+        self
+    }
+
+    /// Returns the [`DataType`]
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns the name of the extension type, if any
+    pub fn extension(&self) -> Option<&str> {
+        self.extension.as_deref()
+    }
+}
+
+/// Casts registered by users, for types that are not otherwise supported, set with
+/// [`CastOptions::registry`]
+///
+/// Casts between types without an extension type are used by
+/// [`cast_with_options`](crate::cast_with_options) if it does not support the cast,
+/// including for the elements of nested types, e.g. lists, and those where either type
+/// has an extension type are used by [`cast_to_field`](crate::extension::cast_to_field)
+/// in preference to its own casts of extension types.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StructArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_cast::registry::{CastRegistry, CastType};
+/// # use arrow_cast::{cast_with_options, CastOptions};
+/// # use arrow_schema::{DataType, Field, Fields};
+/// let fields = Fields::from(vec![Field::new("a", DataType::Int32, true)]);
+/// let struct_type = DataType::Struct(fields.clone());
+///
+/// // Cast a struct with a single field to the value of that field
+/// let registry = CastRegistry::new().with_cast(
+///     CastType::new(struct_type),
+///     CastType::new(DataType::Int32),
+///     |array, _to_type, _options| Ok(array.as_struct().column(0).clone()),
+/// );
+/// let options = CastOptions::new().with_registry(Some(Arc::new(registry)));
+///
+/// let array = StructArray::new(fields, vec![Arc::new(Int32Array::from(vec![1, 2]))], None);
+/// let b = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+/// assert_eq!(b.as_primitive::<Int32Type>().values(), &[1, 2]);
+/// ```
+#[derive(Clone, Default)]
+pub struct CastRegistry {
+    casts: HashMap<(CastType, CastType), CastFunction>,
+}
+
+impl CastRegistry {
+    /// Create an empty [`CastRegistry`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `cast` as the cast from `from` to `to`, replacing any existing cast
+    ///
+    /// `cast` is given the array, the [`DataType`] of `to`, and the [`CastOptions`]
+    pub fn with_cast<F>(mut self, from: CastType, to: CastType, cast: F) -> Self
+    where
+        F: Fn(&dyn Array, &DataType, &CastOptions) -> Result<ArrayRef, ArrowError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.casts.insert((from, to), Arc::new(cast));
+        self
+    }
+
+    /// Returns the cast registered from `from` to `to`, if any
+    pub fn get(&self, from: &CastType, to: &CastType) -> Option<&CastFunction> {
+        self.casts.get(&(from.clone(), to.clone()))
+    }
+
+    /// Returns true if no casts have been registered
+    pub fn is_empty(&self) -> bool {
+        self.casts.is_empty()
+    }
+}
+
+impl Debug for CastRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.casts.keys()).finish()
+    }
+}
+
+/// Registries are equal if they contain the same casts, i.e. the same functions
+/// registered for the same types
+impl PartialEq for CastRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        self.casts.len() == other.casts.len()
+            && self.casts.iter().all(|(key, cast)| {
+                other
+                    .casts
+                    .get(key)
+                    .map_or(false, |other| Arc::ptr_eq(cast, other))
+            })
+    }
+}
+
+impl Eq for CastRegistry {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast::{can_cast_types, cast, cast_with_options};
+    use crate::extension::cast_to_field;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{FixedSizeBinaryArray, Int64Array, ListArray, StringArray};
+    use arrow_buffer::OffsetBuffer;
+    use arrow_schema::FieldRef;
+
+    /// Casts a FixedSizeBinary(8) to the big-endian Int64 of each value
+    fn binary_to_int64(
+        array: &dyn Array,
+        _: &DataType,
+        _: &CastOptions,
+    ) -> Result<ArrayRef, ArrowError> {
+        let array = array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        let values = array
+            .iter()
+            .map(|v| v.map(|v| i64::from_be_bytes(v.try_into().unwrap())));
+        Ok(Arc::new(values.collect::<Int64Array>()))
+    }
+
+    fn registry() -> Arc<CastRegistry> {
+        let registry = CastRegistry::new().with_cast(
+            CastType::new(DataType::FixedSizeBinary(8)),
+            CastType::new(DataType::Int64),
+            binary_to_int64,
+        );
+        Arc::new(registry)
+    }
+
+    #[test]
+    fn test_registry_cast() {
+        let from_type = DataType::FixedSizeBinary(8);
+        assert!(!can_cast_types(&from_type, &DataType::Int64));
+
+        let array = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            [Some(258_i64.to_be_bytes()), None].into_iter(),
+            8,
+        )
+        .unwrap();
+        let err = cast(&array, &DataType::Int64).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Casting from FixedSizeBinary(8) to Int64 not supported"
+        );
+
+        let options = CastOptions::new().with_registry(Some(registry()));
+        let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        assert_eq!(
+            b.as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![Some(258), None])
+        );
+
+        // Registered casts are used for the elements of nested types
+        let field: FieldRef = Arc::new(Field::new("item", from_type, true));
+        let list = ListArray::new(
+            field,
+            OffsetBuffer::new(vec![0, 2].into()),
+            Arc::new(array),
+            None,
+        );
+        let to_type = DataType::List(Arc::new(Field::new("item", DataType::Int64, true)));
+        let b = cast_with_options(&list, &to_type, &options).unwrap();
+        let values = b.as_list::<i32>().values();
+        assert_eq!(values.as_primitive::<Int64Type>().value(0), 258);
+
+        // Supported casts are not replaced
+        let registry = CastRegistry::new().with_cast(
+            CastType::new(DataType::Int64),
+            CastType::new(DataType::Utf8),
+            |_, _, _| Err(ArrowError::CastError("unused".to_string())),
+        );
+        let options = CastOptions::new().with_registry(Some(Arc::new(registry)));
+        let b = cast_with_options(&Int64Array::from(vec![1]), &DataType::Utf8, &options);
+        assert_eq!(b.unwrap().as_string::<i32>().value(0), "1");
+    }
+
+    #[test]
+    fn test_registry_extension_cast() {
+        let metadata = std::collections::HashMap::from([(
+            EXTENSION_TYPE_NAME_KEY.to_string(),
+            "example.shout".to_string(),
+        )]);
+        let shout = Field::new("a", DataType::Utf8, true).with_metadata(metadata);
+        let plain = Field::new("a", DataType::Utf8, true);
+
+        let registry = CastRegistry::new().with_cast(
+            CastType::new(DataType::Utf8),
+            CastType::new(DataType::Utf8).with_extension("example.shout"),
+            |array, _, _| {
+                let array = array.as_string::<i32>();
+                let upper: StringArray =
+                    array.iter().map(|v| v.map(|v| v.to_uppercase())).collect();
+                Ok(Arc::new(upper))
+            },
+        );
+        assert_eq!(
+            CastType::from_field(&shout),
+            CastType::new(DataType::Utf8).with_extension("example.shout")
+        );
+
+        let array = StringArray::from(vec![Some("a"), None]);
+        let options = CastOptions::new().with_registry(Some(Arc::new(registry)));
+        let b = cast_to_field(&array, &plain, &shout, &options).unwrap();
+        assert_eq!(
+            b.as_string::<i32>(),
+            &StringArray::from(vec![Some("A"), None])
+        );
+
+        // Without an extension type the types are the same, and so the cast is a copy
+        let b = cast_to_field(&array, &plain, &plain, &options).unwrap();
+        assert_eq!(b.as_string::<i32>(), &array);
+
+        // Without a registered cast the extension type is ignored
+        let b = cast_to_field(&array, &shout, &plain, &options).unwrap();
+        assert_eq!(b.as_string::<i32>(), &array);
+
+        assert_eq!(options.clone(), options);
+        assert_ne!(
+            options,
+            CastOptions::new().with_registry(Some(Arc::new(CastRegistry::new())))
+        );
+    }
+}
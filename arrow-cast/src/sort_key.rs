@@ -0,0 +1,320 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels encoding values as bytes that compare in the same order as the values
+//!
+//! The encoding of a single column is not self-delimiting, and so the keys of
+//! several columns cannot be concatenated. To sort by several columns, use the row
+//! format of `arrow-row` instead.
+//!
+//! ```
+//! # use arrow_array::{Int32Array, StringArray};
+//! # use arrow_cast::sort_key::to_sort_key_bytes;
+//! let array = Int32Array::from(vec![5, -1, 300]);
+//! let keys = to_sort_key_bytes(&array).unwrap();
+//! assert!(keys.value(1) < keys.value(0));
+//! assert!(keys.value(0) < keys.value(2));
+//!
+//! let array = StringArray::from(vec!["b", "ab"]);
+//! let keys = to_sort_key_bytes(&array).unwrap();
+//! assert!(keys.value(1) < keys.value(0));
+//! ```
+
+use arrow_array::builder::BinaryBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::ByteArrayType;
+use arrow_array::*;
+use arrow_buffer::{i256, ArrowNativeType, Buffer, NullBuffer, OffsetBuffer};
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+use half::f16;
+
+/// Returns a [`BinaryArray`] of byte strings, the lexicographic order of which is the
+/// ascending order of the values of `array`
+///
+/// * Integers, and types represented by them, such as dates, times, timestamps and
+///   decimals, are encoded big-endian with the sign bit inverted
+/// * Floats are ordered as [`f64::total_cmp`], i.e. `-NaN < -Inf < -0 < 0 < Inf < NaN`
+/// * Booleans are encoded as a single `0` or `1` byte
+/// * Strings and binary values are their own bytes, and so strings are ordered by
+///   their UTF-8 bytes, which is the order of their code points
+/// * Dictionaries are encoded by their values
+///
+/// Null values are null in the returned array. Returns an error for other types, and
+/// if the encoded values are too large for a [`BinaryArray`].
+pub fn to_sort_key_bytes(array: &dyn Array) -> Result<BinaryArray, ArrowError> {
+    downcast_primitive_array!(
+        array => primitive_sort_key(array),
+        DataType::Null => Ok(BinaryArray::new_null(array.len())),
+        DataType::Boolean => {
+            let array = array.as_boolean();
+            let values: Vec<u8> = array.values().iter().map(u8::from).collect();
+            fixed_width_sort_key(values.into(), 1, array.nulls().cloned())
+        }
+        DataType::Utf8 => byte_sort_key(array.as_string::<i32>()),
+        DataType::LargeUtf8 => byte_sort_key(array.as_string::<i64>()),
+        DataType::Binary => byte_sort_key(array.as_binary::<i32>()),
+        DataType::LargeBinary => byte_sort_key(array.as_binary::<i64>()),
+        DataType::FixedSizeBinary(size) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            check_length(array.len() * *size as usize)?;
+            let mut builder = BinaryBuilder::with_capacity(
+                array.len(),
+                array.len() * *size as usize,
+            );
+            array.iter().for_each(|v| builder.append_option(v));
+            Ok(builder.finish())
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array!(
+            array => {
+                let values = to_sort_key_bytes(array.values().as_ref())?;
+                Ok(take(&values, array.keys(), None)?.as_binary::<i32>().clone())
+            },
+            _ => unreachable!()
+        ),
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot produce sort key bytes for {d:?}"
+        )))
+    )
+}
+
+/// A native type with an order preserving fixed width byte encoding
+trait SortKeyNative: ArrowNativeType {
+    const WIDTH: usize;
+
+    fn encode(self, out: &mut Vec<u8>);
+}
+
+macro_rules! signed_sort_key {
+    ($($t:ty),+) => {
+        $(impl SortKeyNative for $t {
+            const WIDTH: usize = std::mem::size_of::<$t>();
+
+            fn encode(self, out: &mut Vec<u8>) {
+                let mut bytes = self.to_be_bytes();
+                bytes[0] ^= 0x80;
+                out.extend_from_slice(&bytes);
+            }
+        })+
+    };
+}
+
+macro_rules! unsigned_sort_key {
+    ($($t:ty),+) => {
+        $(impl SortKeyNative for $t {
+            const WIDTH: usize = std::mem::size_of::<$t>();
+
+            fn encode(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_be_bytes());
+            }
+        })+
+    };
+}
+
+macro_rules! float_sort_key {
+    ($($t:ty => $signed:ty, $unsigned:ty),+) => {
+        $(impl SortKeyNative for $t {
+            const WIDTH: usize = std::mem::size_of::<$t>();
+
+            fn encode(self, out: &mut Vec<u8>) {
+                // Inverting all but the sign bit of negative values orders the bits
+                // as signed integers, as in `total_cmp`
+                let bits = self.to_bits() as $signed;
+                let shift = <$signed>::BITS - 1;
+                let bits = bits ^ (((bits >> shift) as $unsigned) >> 1) as $signed;
+                bits.encode(out)
+            }
+        })+
+    };
+}
+
+signed_sort_key!(i8, i16, i32, i64, i128, i256);
+unsigned_sort_key!(u8, u16, u32, u64);
+float_sort_key!(f16 => i16, u16, f32 => i32, u32, f64 => i64, u64);
+
+fn primitive_sort_key<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+) -> Result<BinaryArray, ArrowError>
+where
+    T::Native: SortKeyNative,
+{
+    let mut values = Vec::with_capacity(array.len() * T::Native::WIDTH);
+    array.values().iter().for_each(|v| v.encode(&mut values));
+    fixed_width_sort_key(values.into(), T::Native::WIDTH, array.nulls().cloned())
+}
+
+/// Returns a [`BinaryArray`] of the consecutive `width` byte values in `values`
+fn fixed_width_sort_key(
+    values: Buffer,
+    width: usize,
+    nulls: Option<NullBuffer>,
+) -> Result<BinaryArray, ArrowError> {
+    check_length(values.len())?;
+    let len = values.len() / width;
+    let offsets: Vec<i32> = (0..=len).map(|i| (i * width) as i32).collect();
+    Ok(BinaryArray::new(
+        OffsetBuffer::new(offsets.into()),
+        values,
+        nulls,
+    ))
+}
+
+fn byte_sort_key<T: ByteArrayType>(
+    array: &GenericByteArray<T>,
+) -> Result<BinaryArray, ArrowError> {
+    let offsets = array.value_offsets();
+    let bytes = offsets[array.len()].as_usize() - offsets[0].as_usize();
+    check_length(bytes)?;
+
+    let mut builder = BinaryBuilder::with_capacity(array.len(), bytes);
+    for i in 0..array.len() {
+        match array.is_valid(i) {
+            true => builder.append_value(AsRef::<[u8]>::as_ref(array.value(i))),
+            false => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+fn check_length(bytes: usize) -> Result<(), ArrowError> {
+    match i32::from_usize(bytes) {
+        Some(_) => Ok(()),
+        None => Err(ArrowError::ComputeError(format!(
+            "Sort key bytes of length {bytes} too large for BinaryArray"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int8Type;
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    /// Asserts the sort keys of `array` are strictly increasing
+    fn assert_increasing(array: &dyn Array) {
+        let keys = to_sort_key_bytes(array).unwrap();
+        assert_eq!(keys.len(), array.len());
+        for i in 1..keys.len() {
+            assert!(
+                keys.value(i - 1) < keys.value(i),
+                "{:?} not less than {:?} at {i}",
+                keys.value(i - 1),
+                keys.value(i)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sort_key_primitives() {
+        assert_increasing(&Int8Array::from(vec![i8::MIN, -1, 0, 1, i8::MAX]));
+        assert_increasing(&Int64Array::from(vec![
+            i64::MIN,
+            -256,
+            -1,
+            0,
+            255,
+            i64::MAX,
+        ]));
+        assert_increasing(&UInt16Array::from(vec![0, 1, 255, 256, u16::MAX]));
+        assert_increasing(&Float64Array::from(vec![
+            -f64::NAN,
+            f64::NEG_INFINITY,
+            -1.5,
+            -f64::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE,
+            1.5,
+            f64::INFINITY,
+            f64::NAN,
+        ]));
+        assert_increasing(&Float16Array::from(vec![
+            f16::NEG_INFINITY,
+            f16::from_f32(-2.0),
+            f16::ZERO,
+            f16::from_f32(0.5),
+        ]));
+        assert_increasing(&Float32Array::from(vec![-3.0, -2.5, 1e-10, 7.0]));
+        assert_increasing(
+            &Decimal128Array::from(vec![i128::MIN, -10, 0, 10, i128::MAX])
+                .with_precision_and_scale(38, 2)
+                .unwrap(),
+        );
+        assert_increasing(&Decimal256Array::from(vec![
+            i256::MIN,
+            i256::from_i128(-1),
+            i256::ZERO,
+            i256::from_parts(0, 1),
+            i256::MAX,
+        ]));
+        assert_increasing(
+            &TimestampNanosecondArray::from(vec![-86_400_000_000_000, 0, 1])
+                .with_timezone("+05:00"),
+        );
+        assert_increasing(&Date32Array::from(vec![-1, 0, 19000]));
+        assert_increasing(&BooleanArray::from(vec![false, true]));
+    }
+
+    #[test]
+    fn test_sort_key_bytes() {
+        assert_increasing(&StringArray::from(vec!["", "a", "ab", "b", "é", "😀"]));
+        assert_increasing(&LargeStringArray::from(vec!["A", "a", "aa"]));
+        assert_increasing(&BinaryArray::from(vec![
+            b"".as_slice(),
+            &[0],
+            &[0, 0],
+            &[1],
+            &[255],
+        ]));
+        assert_increasing(
+            &FixedSizeBinaryArray::try_from_iter([[0, 9], [1, 0], [255, 0]].into_iter())
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_sort_key_nulls_and_dictionaries() {
+        let array = Int32Array::from(vec![Some(1), None, Some(-1)]);
+        let keys = to_sort_key_bytes(&array).unwrap();
+        assert!(keys.is_null(1));
+        assert!(keys.value(2) < keys.value(0));
+        assert_eq!(keys.value(0), &[0x80, 0, 0, 1]);
+
+        let array: DictionaryArray<Int8Type> =
+            vec![Some("b"), None, Some("a"), Some("b")]
+                .into_iter()
+                .collect();
+        let keys = to_sort_key_bytes(&array).unwrap();
+        let expected =
+            BinaryArray::from(vec![Some(b"b".as_slice()), None, Some(b"a"), Some(b"b")]);
+        assert_eq!(keys, expected);
+
+        let keys = to_sort_key_bytes(&NullArray::new(2)).unwrap();
+        assert_eq!(keys.null_count(), 2);
+
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let err = to_sort_key_bytes(&ListArray::new_null(field, 1)).unwrap_err();
+        assert!(err.to_string().starts_with(
+            "Invalid argument error: Cannot produce sort key bytes for List("
+        ));
+    }
+}
@@ -0,0 +1,560 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversions between temporal arrays and [`chrono`] types, and from the components
+//! of datetimes
+//!
+//! ```
+//! # use chrono::NaiveDate;
+//! # use arrow_cast::temporal::{from_naive_dates, to_naive_dates};
+//! let date = NaiveDate::from_ymd_opt(2023, 4, 1).unwrap();
+//! let array = from_naive_dates([Some(date), None]);
+//! assert_eq!(array.value(0), 19448);
+//!
+//! let dates: Vec<_> = to_naive_dates(&array).collect::<Result<_, _>>().unwrap();
+//! assert_eq!(dates, vec![Some(date), None]);
+//! ```
+
+use crate::cast::cast;
+use arrow_array::cast::AsArray;
+use arrow_array::temporal_conversions::{as_date, as_datetime};
+use arrow_array::timezone::Tz;
+use arrow_array::types::{
+    ArrowTemporalType, ArrowTimestampType, Date32Type, Date64Type, Int64Type,
+};
+use arrow_array::{
+    Array, Date32Array, Date64Array, Int64Array, PrimitiveArray, StructArray,
+};
+use arrow_schema::{ArrowError, DataType};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+/// Creates a [`Date32Array`] from an iterator of dates
+pub fn from_naive_dates<I>(iter: I) -> Date32Array
+where
+    I: IntoIterator<Item = Option<NaiveDate>>,
+{
+    iter.into_iter()
+        .map(|d| d.map(Date32Type::from_naive_date))
+        .collect()
+}
+
+/// Creates a [`Date64Array`] from an iterator of dates
+pub fn from_naive_dates_date64<I>(iter: I) -> Date64Array
+where
+    I: IntoIterator<Item = Option<NaiveDate>>,
+{
+    iter.into_iter()
+        .map(|d| d.map(Date64Type::from_naive_date))
+        .collect()
+}
+
+/// Creates a timestamp array without a timezone from an iterator of datetimes
+///
+/// Returns an error if a datetime cannot be represented by `T`
+pub fn from_naive_datetimes<T, I>(iter: I) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowTimestampType,
+    I: IntoIterator<Item = Option<NaiveDateTime>>,
+{
+    iter.into_iter()
+        .map(|d| d.map(make_timestamp::<T>).transpose())
+        .collect()
+}
+
+/// Creates a timestamp array with the UTC timezone from an iterator of datetimes
+///
+/// The timezone of the result can be changed, without altering the instants it
+/// represents, with [`PrimitiveArray::with_timezone`]. Returns an error if a datetime
+/// cannot be represented by `T`
+pub fn from_datetimes<T, Z, I>(iter: I) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowTimestampType,
+    Z: TimeZone,
+    I: IntoIterator<Item = Option<DateTime<Z>>>,
+{
+    let array: PrimitiveArray<T> = iter
+        .into_iter()
+        .map(|d| d.map(|d| make_timestamp::<T>(d.naive_utc())).transpose())
+        .collect::<Result<_, _>>()?;
+    Ok(array.with_timezone("+00:00"))
+}
+
+fn make_timestamp<T: ArrowTimestampType>(
+    naive: NaiveDateTime,
+) -> Result<i64, ArrowError> {
+    T::make_value(naive).ok_or_else(|| {
+        ArrowError::ComputeError(format!(
+            "Cannot represent {naive} as {:?}",
+            T::DATA_TYPE
+        ))
+    })
+}
+
+/// Returns an iterator of the dates in a date or timestamp array
+///
+/// The dates of timestamps are those in UTC, regardless of their timezone. Yields an
+/// error for values that are out of the range of [`NaiveDate`]
+pub fn to_naive_dates<T>(
+    array: &PrimitiveArray<T>,
+) -> impl Iterator<Item = Result<Option<NaiveDate>, ArrowError>> + '_
+where
+    T: ArrowTemporalType,
+    T::Native: Into<i64>,
+{
+    array.iter().map(|v| {
+        v.map(|v| {
+            let v = v.into();
+            as_date::<T>(v).ok_or_else(|| out_of_range(v, array.data_type()))
+        })
+        .transpose()
+    })
+}
+
+/// Returns an iterator of the datetimes in a date or timestamp array
+///
+/// The datetimes of timestamps are those in UTC, regardless of their timezone, see
+/// [`to_datetimes`] for the local datetimes. Yields an error for values that are out
+/// of the range of [`NaiveDateTime`]
+pub fn to_naive_datetimes<T>(
+    array: &PrimitiveArray<T>,
+) -> impl Iterator<Item = Result<Option<NaiveDateTime>, ArrowError>> + '_
+where
+    T: ArrowTemporalType,
+    T::Native: Into<i64>,
+{
+    array.iter().map(|v| {
+        v.map(|v| {
+            let v = v.into();
+            as_datetime::<T>(v).ok_or_else(|| out_of_range(v, array.data_type()))
+        })
+        .transpose()
+    })
+}
+
+/// Returns an iterator of the datetimes in a timestamp array, in its timezone
+///
+/// Timestamps without a timezone are interpreted as UTC. Returns an error if the
+/// timezone is invalid, and yields an error for values that are out of the range of
+/// [`DateTime`]
+pub fn to_datetimes<T: ArrowTimestampType>(
+    array: &PrimitiveArray<T>,
+) -> Result<impl Iterator<Item = Result<Option<DateTime<Tz>>, ArrowError>> + '_, ArrowError>
+{
+    let tz: Tz = match array.data_type() {
+        DataType::Timestamp(_, Some(tz)) => tz.parse()?,
+        _ => "+00:00".parse()?,
+    };
+    Ok(array.iter().map(move |v| {
+        v.map(|v| {
+            let naive =
+                as_datetime::<T>(v).ok_or_else(|| out_of_range(v, array.data_type()))?;
+            Ok(Utc.from_utc_datetime(&naive).with_timezone(&tz))
+        })
+        .transpose()
+    }))
+}
+
+/// The names of the children of a [`StructArray`] of datetime components, and whether
+/// each is required
+const DATETIME_COMPONENTS: [(&str, bool); 7] = [
+    ("year", true),
+    ("month", true),
+    ("day", true),
+    ("hour", false),
+    ("minute", false),
+    ("second", false),
+    ("nanosecond", false),
+];
+
+/// Creates a [`Date32Array`] from a [`StructArray`] with integer `year`, `month` and
+/// `day` children
+///
+/// Rows that are null, or where any component is null, are null. Returns an error if
+/// the struct has other children, or a row is not a valid calendar date
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int16Array, Int8Array, StructArray};
+/// # use arrow_cast::temporal::components_to_date;
+/// let array = StructArray::try_from(vec![
+///     ("year", Arc::new(Int16Array::from(vec![2023, 2024])) as ArrayRef),
+///     ("month", Arc::new(Int8Array::from(vec![4, 2]))),
+///     ("day", Arc::new(Int8Array::from(vec![1, 29]))),
+/// ])
+/// .unwrap();
+/// let dates = components_to_date(&array).unwrap();
+/// assert_eq!(dates.values(), &[19448, 19782]);
+/// ```
+pub fn components_to_date(array: &StructArray) -> Result<Date32Array, ArrowError> {
+    let components = datetime_components(array, &DATETIME_COMPONENTS[..3])?;
+    (0..array.len())
+        .map(|i| {
+            let values = match row_components(array, &components, i) {
+                Some(values) => values,
+                None => return Ok(None),
+            };
+            let date = make_date(values[0], values[1], values[2], i)?;
+            Ok(Some(Date32Type::from_naive_date(date)))
+        })
+        .collect()
+}
+
+/// Creates a timestamp array without a timezone from a [`StructArray`] with integer
+/// `year`, `month` and `day` children, and optional `hour`, `minute`, `second` and
+/// `nanosecond` children, that are zero if absent
+///
+/// Rows that are null, or where any component is null, are null. Returns an error if
+/// the struct has other children, a row is not a valid calendar datetime, or it cannot
+/// be represented by `T`. Leap seconds are not supported
+pub fn components_to_timestamp<T: ArrowTimestampType>(
+    array: &StructArray,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    let components = datetime_components(array, &DATETIME_COMPONENTS)?;
+    (0..array.len())
+        .map(|i| {
+            let values = match row_components(array, &components, i) {
+                Some(values) => values,
+                None => return Ok(None),
+            };
+            let date = make_date(values[0], values[1], values[2], i)?;
+            let time = u32_components(&values[3..])
+                .and_then(|t| NaiveTime::from_hms_nano_opt(t[0], t[1], t[2], t[3]))
+                .filter(|_| values[6] < 1_000_000_000)
+                .ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "Invalid time {:02}:{:02}:{:02}.{:09} at row {i}",
+                        values[3], values[4], values[5], values[6]
+                    ))
+                })?;
+            make_timestamp::<T>(date.and_time(time)).map(Some)
+        })
+        .collect()
+}
+
+/// Returns the children of `array` for each of `names`, cast to Int64
+fn datetime_components(
+    array: &StructArray,
+    names: &[(&str, bool)],
+) -> Result<Vec<Option<Int64Array>>, ArrowError> {
+    if let Some(field) = array
+        .fields()
+        .iter()
+        .find(|f| !names.iter().any(|(name, _)| f.name() == name))
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Unexpected datetime component '{}', expected one of {:?}",
+            field.name(),
+            names.iter().map(|(name, _)| *name).collect::<Vec<_>>()
+        )));
+    }
+
+    names
+        .iter()
+        .map(|(name, required)| match array.column_by_name(name) {
+            Some(column) if column.data_type().is_integer() => {
+                let column = cast(column, &DataType::Int64)?;
+                Ok(Some(column.as_primitive::<Int64Type>().clone()))
+            }
+            Some(column) => Err(ArrowError::InvalidArgumentError(format!(
+                "Datetime component '{name}' must be an integer, got {:?}",
+                column.data_type()
+            ))),
+            None if *required => Err(ArrowError::InvalidArgumentError(format!(
+                "Missing datetime component '{name}'"
+            ))),
+            None => Ok(None),
+        })
+        .collect()
+}
+
+/// Returns the components of row `i`, with absent components zero, or `None` if the
+/// row or any of its components is null
+fn row_components(
+    array: &StructArray,
+    components: &[Option<Int64Array>],
+    i: usize,
+) -> Option<Vec<i64>> {
+    if array.is_null(i) {
+        return None;
+    }
+    components
+        .iter()
+        .map(|c| match c {
+            Some(c) => c.is_valid(i).then(|| c.value(i)),
+            None => Some(0),
+        })
+        .collect()
+}
+
+fn u32_components(values: &[i64]) -> Option<Vec<u32>> {
+    values.iter().map(|v| u32::try_from(*v).ok()).collect()
+}
+
+fn make_date(
+    year: i64,
+    month: i64,
+    day: i64,
+    row: usize,
+) -> Result<NaiveDate, ArrowError> {
+    let date = i32::try_from(year).ok().zip(u32_components(&[month, day]));
+    date.and_then(|(year, md)| NaiveDate::from_ymd_opt(year, md[0], md[1]))
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Invalid date {year}-{month:02}-{day:02} at row {row}"
+            ))
+        })
+}
+
+fn out_of_range(v: i64, data_type: &DataType) -> ArrowError {
+    ArrowError::ComputeError(format!(
+        "Cannot convert {v} of {data_type:?} to a chrono value as it is out of range"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::{
+        ArrowPrimitiveType, TimestampMillisecondType, TimestampNanosecondType,
+        TimestampSecondType,
+    };
+    use arrow_array::{
+        ArrayRef, Float64Array, Int32Array, Int8Array, TimestampMillisecondArray,
+        TimestampNanosecondArray, UInt8Array,
+    };
+    use chrono::FixedOffset;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_dates() {
+        let dates = vec![
+            Some(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()),
+            None,
+            Some(NaiveDate::from_ymd_opt(1969, 12, 31).unwrap()),
+            Some(NaiveDate::from_ymd_opt(-4000, 2, 29).unwrap()),
+        ];
+
+        let array = from_naive_dates(dates.clone());
+        assert_eq!(array.value(0), 0);
+        assert_eq!(array.value(2), -1);
+        let out: Vec<_> = to_naive_dates(&array).collect::<Result<_, _>>().unwrap();
+        assert_eq!(out, dates);
+
+        let array = from_naive_dates_date64(dates.clone());
+        assert_eq!(array.value(2), -86_400_000);
+        let out: Vec<_> = to_naive_dates(&array).collect::<Result<_, _>>().unwrap();
+        assert_eq!(out, dates);
+
+        let datetimes: Vec<_> = to_naive_datetimes(&array)
+            .map(|d| d.unwrap().map(|d| d.date()))
+            .collect();
+        assert_eq!(datetimes, dates);
+
+        let array = Date64Array::from(vec![i64::MAX]);
+        let err = to_naive_dates(&array).next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot convert 9223372036854775807 of Date64 to a chrono value as it is out of range"
+        );
+    }
+
+    #[test]
+    fn test_timestamps() {
+        let datetime = NaiveDate::from_ymd_opt(2023, 4, 1)
+            .unwrap()
+            .and_hms_milli_opt(12, 30, 0, 250)
+            .unwrap();
+
+        let array =
+            from_naive_datetimes::<TimestampMillisecondType, _>([Some(datetime), None])
+                .unwrap();
+        assert_eq!(array.data_type(), &TimestampMillisecondType::DATA_TYPE);
+        assert_eq!(array.value(0), 1680352200250);
+        assert!(array.is_null(1));
+        let out: Vec<_> = to_naive_datetimes(&array)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(out, vec![Some(datetime), None]);
+
+        let far = NaiveDate::from_ymd_opt(3000, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let err =
+            from_naive_datetimes::<TimestampNanosecondType, _>([Some(far)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot represent 3000-01-01 00:00:00 as Timestamp(Nanosecond, None)"
+        );
+    }
+
+    #[test]
+    fn test_datetimes() {
+        let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+        let local = offset.with_ymd_and_hms(2023, 4, 1, 8, 0, 0).unwrap();
+
+        let array: TimestampNanosecondArray =
+            from_datetimes([Some(local), None]).unwrap();
+        assert_eq!(
+            array.data_type(),
+            &DataType::Timestamp(
+                arrow_schema::TimeUnit::Nanosecond,
+                Some("+00:00".into())
+            )
+        );
+        assert_eq!(array.value(0), 1_680_318_000_000_000_000);
+
+        let array = array.with_timezone("+05:00");
+        let out: Vec<_> = to_datetimes(&array)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(out[0].unwrap().naive_local(), local.naive_local());
+        assert_eq!(out[0].unwrap().to_rfc3339(), "2023-04-01T08:00:00+05:00");
+        assert_eq!(out[1], None);
+
+        // Timestamps without a timezone are UTC
+        let array = TimestampMillisecondArray::from(vec![0]);
+        let out = to_datetimes(&array).unwrap().next().unwrap().unwrap();
+        assert_eq!(out.unwrap().to_rfc3339(), "1970-01-01T00:00:00+00:00");
+
+        let array = array.with_timezone("Invalid");
+        assert!(to_datetimes(&array).is_err());
+    }
+
+    #[test]
+    fn test_components_to_timestamp() {
+        let components = |extra: Vec<(&str, ArrayRef)>| {
+            let mut columns: Vec<(&str, ArrayRef)> = vec![
+                (
+                    "year",
+                    Arc::new(Int32Array::from(vec![Some(2023), Some(1969), None])),
+                ),
+                ("month", Arc::new(UInt8Array::from(vec![4, 12, 1]))),
+                ("day", Arc::new(Int64Array::from(vec![1, 31, 1]))),
+            ];
+            columns.extend(extra);
+            StructArray::try_from(columns).unwrap()
+        };
+
+        let array = components(vec![]);
+        let dates = components_to_date(&array).unwrap();
+        assert_eq!(dates, Date32Array::from(vec![Some(19448), Some(-1), None]));
+        let out = components_to_timestamp::<TimestampMillisecondType>(&array).unwrap();
+        let expected = vec![Some(1680307200000), Some(-86400000), None];
+        assert_eq!(out, TimestampMillisecondArray::from(expected));
+
+        let array = components(vec![
+            ("hour", Arc::new(Int8Array::from(vec![12, 23, 0]))),
+            (
+                "minute",
+                Arc::new(Int8Array::from(vec![Some(30), None, Some(0)])),
+            ),
+            ("second", Arc::new(Int8Array::from(vec![15, 59, 0]))),
+            ("nanosecond", Arc::new(Int32Array::from(vec![5, 0, 0]))),
+        ]);
+        let out = components_to_timestamp::<TimestampNanosecondType>(&array).unwrap();
+        assert_eq!(out.value(0), 1680352215000000005);
+        assert!(out.is_null(1));
+        assert!(out.is_null(2));
+
+        // The hour, minute, second and nanosecond are not components of a date
+        let err = components_to_date(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Unexpected datetime component 'hour', expected one of [\"year\", \"month\", \"day\"]"
+        );
+    }
+
+    #[test]
+    fn test_components_invalid() {
+        let array = StructArray::try_from(vec![
+            (
+                "year",
+                Arc::new(Int32Array::from(vec![2023, 2023])) as ArrayRef,
+            ),
+            ("month", Arc::new(Int32Array::from(vec![2, 2]))),
+            ("day", Arc::new(Int32Array::from(vec![28, 29]))),
+        ])
+        .unwrap();
+        let err = components_to_date(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Invalid date 2023-02-29 at row 1"
+        );
+
+        let array = StructArray::try_from(vec![
+            ("year", Arc::new(Int32Array::from(vec![2023])) as ArrayRef),
+            ("month", Arc::new(Int32Array::from(vec![-1]))),
+            ("day", Arc::new(Int32Array::from(vec![1]))),
+        ])
+        .unwrap();
+        let err = components_to_date(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Invalid date 2023--1-01 at row 0"
+        );
+
+        let array = StructArray::try_from(vec![
+            ("year", Arc::new(Int32Array::from(vec![2023])) as ArrayRef),
+            ("month", Arc::new(Int32Array::from(vec![1]))),
+            ("day", Arc::new(Int32Array::from(vec![1]))),
+            ("hour", Arc::new(Int32Array::from(vec![24]))),
+        ])
+        .unwrap();
+        let err = components_to_timestamp::<TimestampSecondType>(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Invalid time 24:00:00.000000000 at row 0"
+        );
+
+        let array = StructArray::try_from(vec![
+            ("year", Arc::new(Int32Array::from(vec![3000])) as ArrayRef),
+            ("month", Arc::new(Int32Array::from(vec![1]))),
+            ("day", Arc::new(Int32Array::from(vec![1]))),
+        ])
+        .unwrap();
+        let err = components_to_timestamp::<TimestampNanosecondType>(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot represent 3000-01-01 00:00:00 as Timestamp(Nanosecond, None)"
+        );
+
+        let array = StructArray::try_from(vec![
+            ("year", Arc::new(Int32Array::from(vec![2023])) as ArrayRef),
+            ("day", Arc::new(Int32Array::from(vec![1]))),
+        ])
+        .unwrap();
+        let err = components_to_date(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Missing datetime component 'month'"
+        );
+
+        let array = StructArray::try_from(vec![
+            ("year", Arc::new(Int32Array::from(vec![2023])) as ArrayRef),
+            ("month", Arc::new(Float64Array::from(vec![1.]))),
+            ("day", Arc::new(Int32Array::from(vec![1]))),
+        ])
+        .unwrap();
+        let err = components_to_date(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Datetime component 'month' must be an integer, got Float64"
+        );
+    }
+}
@@ -0,0 +1,280 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Utilities for testing casts, enabled by the `test_utils` feature
+//!
+//! ```
+//! # use arrow_array::Int32Array;
+//! # use arrow_cast::test_util::{assert_cast_roundtrip, assert_cast_roundtrip_lossy};
+//! # use arrow_schema::DataType;
+//! let array = Int32Array::from(vec![Some(1), None, Some(-3)]);
+//! assert_cast_roundtrip(&array, &DataType::Utf8);
+//! assert_cast_roundtrip_lossy(&array, &DataType::Int8);
+//! ```
+
+use crate::display::{ArrayFormatter, FormatOptions};
+use crate::{cast_with_options, CastOptions};
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::DataType;
+
+/// Asserts that casting `array` to `to_type`, and back to its own type, yields `array`
+///
+/// Arrays are compared row by row, with rows that are null in both equal, regardless
+/// of the values masked by the nulls. Values that cannot be represented in `to_type`
+/// are treated as a failed cast.
+///
+/// # Panics
+///
+/// Panics if either cast fails, or with the first row that differs
+pub fn assert_cast_roundtrip(array: &dyn Array, to_type: &DataType) {
+    let back = roundtrip(array, to_type);
+    assert_rows_eq(array, &back, to_type);
+}
+
+/// Asserts that casting `array` to `to_type`, and back to its own type, is stable,
+/// allowing for conversions that are lossy by design
+///
+/// Such conversions, e.g. casting floats to integers, which truncates their fractional
+/// part, or timestamps to a coarser unit, do not yield `array`. Instead the result of
+/// the round trip must be unchanged by a second round trip, and be null only where
+/// `array` is null. Values that cannot be represented in `to_type` are treated as a
+/// failed cast.
+///
+/// # Panics
+///
+/// Panics if any cast fails, or with the first row that differs
+pub fn assert_cast_roundtrip_lossy(array: &dyn Array, to_type: &DataType) {
+    let back = roundtrip(array, to_type);
+    if let Some(i) = (0..array.len()).find(|i| array.is_valid(*i) && back.is_null(*i)) {
+        panic!(
+            "Round trip of {:?} through {to_type:?} produced a null at row {i}",
+            array.data_type()
+        );
+    }
+    let again = roundtrip(&back, to_type);
+    assert_rows_eq(&back, &again, to_type);
+}
+
+fn roundtrip(array: &dyn Array, to_type: &DataType) -> ArrayRef {
+    let from_type = array.data_type();
+    // Treat values which cannot be cast as an error, instead of null
+    let options = CastOptions::new().with_safe(false);
+    let cast = |array: &dyn Array, to_type: &DataType| {
+        cast_with_options(array, to_type, &options).unwrap_or_else(|e| {
+            panic!(
+                "Failed to cast {:?} to {to_type:?} in round trip of {from_type:?} through {to_type:?}: {e}",
+                array.data_type(),
+            )
+        })
+    };
+    let out = cast(array, to_type);
+    cast(&out, from_type)
+}
+
+fn assert_rows_eq(expected: &dyn Array, actual: &dyn Array, to_type: &DataType) {
+    assert_eq!(expected.data_type(), actual.data_type());
+    assert_eq!(expected.len(), actual.len());
+    let diff = (0..expected.len())
+        .find(|i| expected.slice(*i, 1).as_ref() != actual.slice(*i, 1).as_ref());
+
+    if let Some(i) = diff {
+        let options = FormatOptions::default().with_null("null");
+        let format = |array: &dyn Array| match ArrayFormatter::try_new(array, &options) {
+            Ok(formatter) => formatter.value(i).to_string(),
+            Err(_) => format!("{:?}", array.slice(i, 1)),
+        };
+        panic!(
+            "Round trip of {:?} through {to_type:?} differs at row {i}, expected {} got {}",
+            expected.data_type(),
+            format(expected),
+            format(actual)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{ListBuilder, StringBuilder};
+    use arrow_array::types::Int32Type;
+    use arrow_array::*;
+    use arrow_schema::{Field, TimeUnit};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_roundtrip_matrix() {
+        let dictionary: DictionaryArray<Int32Type> =
+            vec![Some("a"), None, Some("b"), Some("a")]
+                .into_iter()
+                .collect();
+        let mut list = ListBuilder::new(StringBuilder::new());
+        list.append_value([Some("a"), None]);
+        list.append_null();
+        list.append_value(Vec::<Option<&str>>::new());
+        let list = list.finish();
+
+        let decimal = Decimal128Array::from(vec![Some(12345), None, Some(-1)])
+            .with_precision_and_scale(10, 3)
+            .unwrap();
+
+        let cases: Vec<(ArrayRef, Vec<DataType>)> = vec![
+            (
+                Arc::new(Int32Array::from(vec![Some(i32::MIN), None, Some(7)])),
+                vec![
+                    DataType::Int64,
+                    DataType::Float64,
+                    DataType::Decimal128(10, 0),
+                    DataType::Decimal256(20, 2),
+                    DataType::Utf8,
+                    DataType::LargeUtf8,
+                    DataType::Dictionary(
+                        Box::new(DataType::Int8),
+                        Box::new(DataType::Int32),
+                    ),
+                ],
+            ),
+            (
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("")])),
+                vec![
+                    DataType::LargeUtf8,
+                    DataType::Binary,
+                    DataType::LargeBinary,
+                    DataType::Dictionary(
+                        Box::new(DataType::UInt16),
+                        Box::new(DataType::Utf8),
+                    ),
+                ],
+            ),
+            (
+                Arc::new(dictionary),
+                vec![DataType::Utf8, DataType::LargeUtf8],
+            ),
+            (
+                Arc::new(list),
+                vec![DataType::LargeList(Arc::new(Field::new(
+                    "item",
+                    DataType::Utf8,
+                    true,
+                )))],
+            ),
+            (
+                Arc::new(decimal),
+                vec![
+                    DataType::Decimal128(12, 5),
+                    DataType::Decimal256(40, 3),
+                    DataType::Utf8,
+                ],
+            ),
+            (
+                Arc::new(Date32Array::from(vec![Some(-1), None, Some(19448)])),
+                vec![
+                    DataType::Date64,
+                    DataType::Int32,
+                    DataType::Utf8,
+                    DataType::Timestamp(TimeUnit::Second, None),
+                ],
+            ),
+            (
+                Arc::new(TimestampSecondArray::from(vec![
+                    Some(1),
+                    None,
+                    Some(-86401),
+                ])),
+                vec![
+                    DataType::Timestamp(TimeUnit::Nanosecond, None),
+                    DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into())),
+                    DataType::Int64,
+                    DataType::Utf8,
+                ],
+            ),
+            (
+                Arc::new(Time32MillisecondArray::from(vec![Some(1), None])),
+                vec![
+                    DataType::Time64(TimeUnit::Nanosecond),
+                    DataType::Time64(TimeUnit::Microsecond),
+                ],
+            ),
+            (
+                Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)])),
+                vec![DataType::Int8, DataType::Float32, DataType::Utf8],
+            ),
+        ];
+
+        for (array, to_types) in cases {
+            for to_type in to_types {
+                assert_cast_roundtrip(&array, &to_type);
+                assert_cast_roundtrip_lossy(&array, &to_type);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lossy_roundtrip_matrix() {
+        let decimal = Decimal128Array::from(vec![Some(12345), None, Some(-1)])
+            .with_precision_and_scale(10, 3)
+            .unwrap();
+        let cases: Vec<(ArrayRef, Vec<DataType>)> = vec![
+            (
+                Arc::new(Float64Array::from(vec![Some(1.5), None, Some(-2.7)])),
+                vec![
+                    DataType::Int32,
+                    DataType::Float32,
+                    DataType::Decimal128(5, 0),
+                ],
+            ),
+            (
+                Arc::new(TimestampNanosecondArray::from(vec![
+                    Some(1_500_000_001),
+                    None,
+                ])),
+                vec![
+                    DataType::Timestamp(TimeUnit::Second, None),
+                    DataType::Date32,
+                ],
+            ),
+            (Arc::new(decimal), vec![DataType::Decimal128(10, 1)]),
+            (
+                Arc::new(Date64Array::from(vec![Some(86_400_001), None])),
+                vec![DataType::Date32],
+            ),
+        ];
+
+        for (array, to_types) in cases {
+            for to_type in to_types {
+                assert_cast_roundtrip_lossy(&array, &to_type);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Round trip of Float64 through Int32 differs at row 0, expected 1.5 got 1.0"
+    )]
+    fn test_roundtrip_differs() {
+        let array = Float64Array::from(vec![1.5]);
+        assert_cast_roundtrip(&array, &DataType::Int32);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Failed to cast Int32 to Int8 in round trip of Int32 through Int8"
+    )]
+    fn test_roundtrip_overflow() {
+        let array = Int32Array::from(vec![1000]);
+        assert_cast_roundtrip_lossy(&array, &DataType::Int8);
+    }
+}
@@ -0,0 +1,348 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels truncating timestamps to a calendar or clock granularity
+//!
+//! Truncation floors each timestamp to the start of the enclosing period, e.g. the start
+//! of its hour or day, and is distinct from casting a timestamp to a coarser type, such
+//! as `Date32`, which changes the type of the array
+//!
+//! ```
+//! # use arrow_array::TimestampSecondArray;
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::TimestampSecondType;
+//! # use arrow_cast::truncate::{truncate_timestamp, TruncateGranularity};
+//! // 2023-04-01T20:00:00Z
+//! let array = TimestampSecondArray::from(vec![1680379200]).with_timezone("+05:30");
+//!
+//! // 2023-04-01T00:00:00Z
+//! let utc = truncate_timestamp(&array, TruncateGranularity::Day, false).unwrap();
+//! assert_eq!(utc.as_primitive::<TimestampSecondType>().value(0), 1680307200);
+//!
+//! // 2023-04-02T00:00:00+05:30
+//! let local = truncate_timestamp(&array, TruncateGranularity::Day, true).unwrap();
+//! assert_eq!(local.as_primitive::<TimestampSecondType>().value(0), 1680373800);
+//! ```
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::temporal_conversions::as_datetime;
+use arrow_array::timezone::Tz;
+use arrow_array::types::{
+    ArrowTimestampType, TimestampMicrosecondType, TimestampMillisecondType,
+    TimestampNanosecondType, TimestampSecondType,
+};
+use arrow_array::{Array, ArrayRef, PrimitiveArray};
+use arrow_schema::{ArrowError, DataType, TimeUnit};
+use chrono::{
+    Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+};
+
+/// The period a timestamp is truncated to by [`truncate_timestamp`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TruncateGranularity {
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// Weeks start on Monday
+    Week,
+    Month,
+    /// Quarters start in January, April, July and October
+    Quarter,
+    Year,
+}
+
+impl TruncateGranularity {
+    /// Returns the length of this granularity in nanoseconds, if it is fixed
+    fn nanoseconds(&self) -> Option<i64> {
+        match self {
+            Self::Microsecond => Some(1_000),
+            Self::Millisecond => Some(1_000_000),
+            Self::Second => Some(1_000_000_000),
+            Self::Minute => Some(60 * 1_000_000_000),
+            Self::Hour => Some(3_600 * 1_000_000_000),
+            Self::Day => Some(NANOSECONDS_IN_DAY),
+            Self::Week => Some(7 * NANOSECONDS_IN_DAY),
+            Self::Month | Self::Quarter | Self::Year => None,
+        }
+    }
+}
+
+const NANOSECONDS_IN_DAY: i64 = 86_400 * 1_000_000_000;
+
+/// Truncates each timestamp in `array` to the start of its enclosing `granularity`
+///
+/// If `tz_aware` is true, and `array` has a timezone, periods are those of the local time
+/// in that timezone, e.g. [`TruncateGranularity::Day`] truncates to local midnight.
+/// Otherwise periods are those of UTC. The result has the same type as `array`.
+///
+/// Where the start of a local period is ambiguous, e.g. as clocks are set back, the
+/// latest instant that is not after the timestamp is chosen. Returns an error if `array`
+/// is not a timestamp array, or if the start of a local period does not exist, e.g.
+/// because clocks skip over midnight.
+pub fn truncate_timestamp(
+    array: &dyn Array,
+    granularity: TruncateGranularity,
+    tz_aware: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let tz: Option<Tz> = match array.data_type() {
+        DataType::Timestamp(_, Some(tz)) if tz_aware => Some(tz.parse()?),
+        DataType::Timestamp(_, _) => None,
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot truncate {d:?}, expected a timestamp"
+            )))
+        }
+    };
+
+    Ok(match array.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            Arc::new(truncate::<TimestampSecondType>(
+                array.as_primitive(),
+                granularity,
+                tz,
+            )?)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            Arc::new(truncate::<TimestampMillisecondType>(
+                array.as_primitive(),
+                granularity,
+                tz,
+            )?)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            Arc::new(truncate::<TimestampMicrosecondType>(
+                array.as_primitive(),
+                granularity,
+                tz,
+            )?)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            Arc::new(truncate::<TimestampNanosecondType>(
+                array.as_primitive(),
+                granularity,
+                tz,
+            )?)
+        }
+        _ => unreachable!(),
+    })
+}
+
+fn truncate<T: ArrowTimestampType>(
+    array: &PrimitiveArray<T>,
+    granularity: TruncateGranularity,
+    tz: Option<Tz>,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    if let (None, Some(nanoseconds)) = (&tz, granularity.nanoseconds()) {
+        // Periods of a fixed length can be floored arithmetically in UTC
+        let step = nanoseconds / unit_nanoseconds(T::UNIT);
+        if step <= 1 {
+            return Ok(array.clone());
+        }
+        // The epoch is a Thursday, and so 3 days after the start of its week
+        let offset = match granularity {
+            TruncateGranularity::Week => step / 7 * 3,
+            _ => 0,
+        };
+        let truncated = array.unary::<_, T>(|v| v - (v + offset).rem_euclid(step));
+        return Ok(truncated.with_data_type(array.data_type().clone()));
+    }
+
+    let truncated = array.try_unary::<_, T, _>(|v| {
+        let utc = as_datetime::<T>(v).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Cannot truncate {v} of {:?} as it is out of range",
+                T::DATA_TYPE
+            ))
+        })?;
+        let truncated = match &tz {
+            None => truncate_naive(utc, granularity),
+            Some(tz) => {
+                let local = truncate_naive(tz.from_utc_datetime(&utc).naive_local(), granularity);
+                match tz.from_local_datetime(&local) {
+                    LocalResult::Single(d) => d.naive_utc(),
+                    LocalResult::Ambiguous(earliest, latest) => {
+                        match latest.naive_utc() <= utc {
+                            true => latest.naive_utc(),
+                            false => earliest.naive_utc(),
+                        }
+                    }
+                    LocalResult::None => {
+                        return Err(ArrowError::ComputeError(format!(
+                            "Cannot truncate {utc} to {granularity:?} as local time {local} does not exist in {:?}",
+                            array.data_type()
+                        )))
+                    }
+                }
+            }
+        };
+        T::make_value(truncated).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Cannot represent {truncated} as {:?}",
+                T::DATA_TYPE
+            ))
+        })
+    })?;
+    Ok(truncated.with_data_type(array.data_type().clone()))
+}
+
+fn unit_nanoseconds(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Returns the start of the `granularity` period containing `d`
+fn truncate_naive(d: NaiveDateTime, granularity: TruncateGranularity) -> NaiveDateTime {
+    let date = d.date();
+    let midnight = |date: NaiveDate| date.and_hms_opt(0, 0, 0).unwrap();
+    match granularity {
+        TruncateGranularity::Microsecond => {
+            d.with_nanosecond(d.nanosecond() / 1_000 * 1_000).unwrap()
+        }
+        TruncateGranularity::Millisecond => d
+            .with_nanosecond(d.nanosecond() / 1_000_000 * 1_000_000)
+            .unwrap(),
+        TruncateGranularity::Second => d.with_nanosecond(0).unwrap(),
+        TruncateGranularity::Minute => date.and_hms_opt(d.hour(), d.minute(), 0).unwrap(),
+        TruncateGranularity::Hour => date.and_hms_opt(d.hour(), 0, 0).unwrap(),
+        TruncateGranularity::Day => midnight(date),
+        TruncateGranularity::Week => {
+            let days = date.weekday().num_days_from_monday();
+            midnight(date - Duration::days(days as i64))
+        }
+        TruncateGranularity::Month => midnight(date.with_day(1).unwrap()),
+        TruncateGranularity::Quarter => {
+            let month = (date.month() - 1) / 3 * 3 + 1;
+            midnight(NaiveDate::from_ymd_opt(date.year(), month, 1).unwrap())
+        }
+        TruncateGranularity::Year => {
+            midnight(NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{TimestampMillisecondArray, TimestampSecondArray};
+
+    fn timestamp(s: &str) -> i64 {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+            .unwrap()
+            .timestamp_millis()
+    }
+
+    #[test]
+    fn test_truncate_timestamp() {
+        let cases = [
+            (TruncateGranularity::Millisecond, "2023-04-05T13:47:21.123"),
+            (TruncateGranularity::Second, "2023-04-05T13:47:21"),
+            (TruncateGranularity::Minute, "2023-04-05T13:47:00"),
+            (TruncateGranularity::Hour, "2023-04-05T13:00:00"),
+            (TruncateGranularity::Day, "2023-04-05T00:00:00"),
+            (TruncateGranularity::Week, "2023-04-03T00:00:00"),
+            (TruncateGranularity::Month, "2023-04-01T00:00:00"),
+            (TruncateGranularity::Quarter, "2023-04-01T00:00:00"),
+            (TruncateGranularity::Year, "2023-01-01T00:00:00"),
+        ];
+        let array = TimestampMillisecondArray::from(vec![
+            Some(timestamp("2023-04-05T13:47:21.123")),
+            None,
+            Some(timestamp("1969-12-31T23:59:59.999")),
+        ])
+        .with_timezone("+05:30");
+
+        for (granularity, expected) in cases {
+            let out = truncate_timestamp(&array, granularity, false).unwrap();
+            assert_eq!(out.data_type(), array.data_type());
+            let out = out.as_primitive::<TimestampMillisecondType>();
+            assert_eq!(out.value(0), timestamp(expected), "{granularity:?}");
+            assert!(out.is_null(1));
+
+            // Both paths agree for UTC
+            let utc = array.clone().with_timezone("+00:00");
+            let local = truncate_timestamp(&utc, granularity, true).unwrap();
+            let local = local.as_primitive::<TimestampMillisecondType>();
+            assert_eq!(
+                local.iter().collect::<Vec<_>>(),
+                out.iter().collect::<Vec<_>>()
+            );
+        }
+
+        let pre_epoch = [
+            (TruncateGranularity::Second, "1969-12-31T23:59:59"),
+            (TruncateGranularity::Day, "1969-12-31T00:00:00"),
+            (TruncateGranularity::Week, "1969-12-29T00:00:00"),
+            (TruncateGranularity::Year, "1969-01-01T00:00:00"),
+        ];
+        for (granularity, expected) in pre_epoch {
+            let out = truncate_timestamp(&array, granularity, false).unwrap();
+            let out = out.as_primitive::<TimestampMillisecondType>();
+            assert_eq!(out.value(2), timestamp(expected), "{granularity:?}");
+        }
+    }
+
+    #[test]
+    fn test_truncate_timestamp_tz_aware() {
+        // 2023-04-01T20:00:00Z is 2023-04-02T01:30:00+05:30
+        let array = TimestampSecondArray::from(vec![1680379200]).with_timezone("+05:30");
+
+        let cases = [
+            (TruncateGranularity::Hour, "2023-04-01T19:30:00"),
+            (TruncateGranularity::Day, "2023-04-01T18:30:00"),
+            (TruncateGranularity::Month, "2023-03-31T18:30:00"),
+            (TruncateGranularity::Microsecond, "2023-04-01T20:00:00"),
+        ];
+        for (granularity, expected) in cases {
+            let out = truncate_timestamp(&array, granularity, true).unwrap();
+            let out = out.as_primitive::<TimestampSecondType>();
+            assert_eq!(out.value(0), timestamp(expected) / 1000, "{granularity:?}");
+        }
+
+        // Timestamps without a timezone are truncated in UTC
+        let array = TimestampSecondArray::from(vec![1680379200]);
+        let out = truncate_timestamp(&array, TruncateGranularity::Day, true).unwrap();
+        let out = out.as_primitive::<TimestampSecondType>();
+        assert_eq!(out.value(0), timestamp("2023-04-01T00:00:00") / 1000);
+
+        let err = truncate_timestamp(
+            &out.clone().with_timezone("Invalid"),
+            TruncateGranularity::Day,
+            true,
+        );
+        assert!(err.is_err());
+
+        let err = truncate_timestamp(
+            &arrow_array::Int64Array::from(vec![0]),
+            TruncateGranularity::Day,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot truncate Int64, expected a timestamp"
+        );
+    }
+}
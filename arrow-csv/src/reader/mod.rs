@@ -128,7 +128,7 @@ mod records;
 use arrow_array::builder::PrimitiveBuilder;
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_cast::parse::{parse_decimal, string_to_datetime, Parser};
+use arrow_cast::parse::{parse_bool, parse_decimal, string_to_datetime, Parser};
 use arrow_schema::*;
 use chrono::{TimeZone, Utc};
 use lazy_static::lazy_static;
@@ -879,16 +879,6 @@ fn parse_formatted<T: Parser>(string: &str, format: &str) -> Option<T::Native> {
     T::parse_formatted(string, format)
 }
 
-fn parse_bool(string: &str) -> Option<bool> {
-    if string.eq_ignore_ascii_case("false") {
-        Some(false)
-    } else if string.eq_ignore_ascii_case("true") {
-        Some(true)
-    } else {
-        None
-    }
-}
-
 // parse the column string to an Arrow Array
 fn build_decimal_array<T: DecimalType>(
     _line_number: usize,
@@ -1997,17 +1987,17 @@ mod tests {
         assert_eq!(Some(true), parse_bool("tRUe"));
         assert_eq!(Some(true), parse_bool("True"));
         assert_eq!(Some(true), parse_bool("TRUE"));
-        assert_eq!(None, parse_bool("t"));
-        assert_eq!(None, parse_bool("T"));
+        assert_eq!(Some(true), parse_bool("t"));
+        assert_eq!(Some(true), parse_bool("1"));
         assert_eq!(None, parse_bool(""));
 
         assert_eq!(Some(false), parse_bool("false"));
         assert_eq!(Some(false), parse_bool("fALse"));
         assert_eq!(Some(false), parse_bool("False"));
         assert_eq!(Some(false), parse_bool("FALSE"));
-        assert_eq!(None, parse_bool("f"));
-        assert_eq!(None, parse_bool("F"));
-        assert_eq!(None, parse_bool(""));
+        assert_eq!(Some(false), parse_bool("f"));
+        assert_eq!(Some(false), parse_bool("no"));
+        assert_eq!(None, parse_bool("2"));
     }
 
     #[test]
@@ -643,6 +643,15 @@ impl ArrayData {
 
                     (buffers, children, false)
                 }
+                // An empty array has no runs
+                DataType::RunEndEncoded(r, v) if len == 0 => (
+                    vec![],
+                    vec![
+                        Self::new_empty(r.data_type()),
+                        Self::new_empty(v.data_type()),
+                    ],
+                    false,
+                ),
                 DataType::RunEndEncoded(r, v) => {
                     let runs = match r.data_type() {
                         DataType::Int16 => {
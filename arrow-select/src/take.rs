@@ -705,10 +705,12 @@ where
 {
     let nulls = values.nulls();
     let array_iter = indices
-        .values()
         .iter()
         .map(|idx| {
-            let idx = maybe_usize::<IndexType::Native>(*idx)?;
+            let idx = match idx {
+                Some(idx) => maybe_usize::<IndexType::Native>(idx)?,
+                None => return Ok(None),
+            };
             if nulls.map(|n| n.is_valid(idx)).unwrap_or(true) {
                 Ok(Some(values.value(idx)))
             } else {
@@ -1786,6 +1788,28 @@ mod tests {
         test_take_list_with_nulls!(i64, LargeList, LargeListArray);
     }
 
+    #[test]
+    fn test_take_fixed_size_binary_null_indices() {
+        let values = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some(b"ab"), None, Some(b"cd")].into_iter(),
+            2,
+        )
+        .unwrap();
+        let indices = UInt32Array::from(vec![Some(2), None, Some(1), Some(0)]);
+
+        let taken = take(&values, &indices, None).unwrap();
+        let taken = taken
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        let expected = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some(b"cd"), None, None, Some(b"ab")].into_iter(),
+            2,
+        )
+        .unwrap();
+        assert_eq!(taken, &expected);
+    }
+
     #[test]
     fn test_take_fixed_size_list() {
         do_take_fixed_size_list_test::<Int32Type>(
@@ -84,6 +84,22 @@ fn build_utf8_date_time_array(size: usize, with_nulls: bool) -> ArrayRef {
     Arc::new(builder.finish())
 }
 
+fn build_utf8_bool_array(size: usize, dirty: bool) -> ArrayRef {
+    let mut rng = seedable_rng();
+    let tokens = ["true", "false", "Yes", "no", "T", "f", "on", "OFF", "1", "0"];
+    let range = Uniform::new(0, tokens.len());
+
+    let mut builder = StringBuilder::new();
+    for _ in 0..size {
+        let token = tokens[rng.sample(range)];
+        match dirty {
+            true => builder.append_value(format!("  {token}\t")),
+            false => builder.append_value(token),
+        }
+    }
+    Arc::new(builder.finish())
+}
+
 fn build_decimal128_array(size: usize, precision: u8, scale: i8) -> ArrayRef {
     let mut rng = seedable_rng();
     let mut builder = Decimal128Builder::with_capacity(size);
@@ -134,8 +150,15 @@ fn add_benchmark(c: &mut Criterion) {
     let time64ns_array = build_array::<Time64NanosecondType>(512);
     let time_ns_array = build_array::<TimestampNanosecondType>(512);
     let time_ms_array = build_array::<TimestampMillisecondType>(512);
+    let time_ms_tz_array = cast(
+        &time_ms_array,
+        &DataType::Timestamp(TimeUnit::Millisecond, Some("+05:00".into())),
+    )
+    .unwrap();
     let utf8_date_array = build_utf8_date_array(512, true);
     let utf8_date_time_array = build_utf8_date_time_array(512, true);
+    let utf8_bool_array = build_utf8_bool_array(512, false);
+    let utf8_dirty_bool_array = build_utf8_bool_array(512, true);
 
     let decimal128_array = build_decimal128_array(512, 10, 3);
     let decimal256_array = build_decimal256_array(512, 50, 3);
@@ -217,6 +240,36 @@ fn add_benchmark(c: &mut Criterion) {
     c.bench_function("cast utf8 to date64 512", |b| {
         b.iter(|| cast_array(&utf8_date_time_array, DataType::Date64))
     });
+    c.bench_function("cast utf8 to timestamp_ns 512", |b| {
+        b.iter(|| {
+            cast_array(
+                &utf8_date_time_array,
+                DataType::Timestamp(TimeUnit::Nanosecond, None),
+            )
+        })
+    });
+    c.bench_function("cast utf8 to timestamp_ns with fixed offset 512", |b| {
+        b.iter(|| {
+            cast_array(
+                &utf8_date_time_array,
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("+08:00".into())),
+            )
+        })
+    });
+
+    c.bench_function("cast timestamp_ms to utf8 512", |b| {
+        b.iter(|| cast_array(&time_ms_array, DataType::Utf8))
+    });
+    c.bench_function("cast timestamp_ms with timezone to utf8 512", |b| {
+        b.iter(|| cast_array(&time_ms_tz_array, DataType::Utf8))
+    });
+
+    c.bench_function("cast utf8 to bool 512", |b| {
+        b.iter(|| cast_array(&utf8_bool_array, DataType::Boolean))
+    });
+    c.bench_function("cast utf8 to bool with whitespace 512", |b| {
+        b.iter(|| cast_array(&utf8_dirty_bool_array, DataType::Boolean))
+    });
 
     c.bench_function("cast decimal128 to decimal128 512", |b| {
         b.iter(|| cast_array(&decimal128_array, DataType::Decimal128(30, 5)))
@@ -24,13 +24,13 @@ use arrow_array::types::{
     UInt8Type,
 };
 use arrow_array::{
-    Array, ArrayRef, ArrowPrimitiveType, BinaryArray, BooleanArray, Date32Array,
-    Date64Array, Decimal128Array, DurationMicrosecondArray, DurationMillisecondArray,
-    DurationNanosecondArray, DurationSecondArray, FixedSizeBinaryArray,
-    FixedSizeListArray, Float16Array, Float32Array, Float64Array, Int16Array, Int32Array,
-    Int64Array, Int8Array, IntervalDayTimeArray, IntervalMonthDayNanoArray,
-    IntervalYearMonthArray, LargeBinaryArray, LargeListArray, LargeStringArray,
-    ListArray, NullArray, PrimitiveArray, StringArray, StructArray,
+    new_empty_array, Array, ArrayRef, ArrowPrimitiveType, BinaryArray, BooleanArray,
+    Date32Array, Date64Array, Decimal128Array, DurationMicrosecondArray,
+    DurationMillisecondArray, DurationNanosecondArray, DurationSecondArray,
+    FixedSizeBinaryArray, FixedSizeListArray, Float16Array, Float32Array, Float64Array,
+    Int16Array, Int32Array, Int64Array, Int8Array, IntervalDayTimeArray,
+    IntervalMonthDayNanoArray, IntervalYearMonthArray, LargeBinaryArray, LargeListArray,
+    LargeStringArray, ListArray, NullArray, PrimitiveArray, StringArray, StructArray,
     Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray,
     Time64NanosecondArray, TimestampMicrosecondArray, TimestampMillisecondArray,
     TimestampNanosecondArray, TimestampSecondArray, UInt16Array, UInt32Array,
@@ -98,6 +98,40 @@ fn test_can_cast_types() {
     }
 }
 
+#[test]
+#[cfg_attr(miri, ignore)] // running forever
+fn test_cast_empty_arrays() {
+    // every supported cast of an empty array, including a zero-length slice of a
+    // non-empty array, returns an empty array of the target type
+    let mut all_types = get_all_types();
+    all_types.extend([
+        DataType::Decimal256(76, 10),
+        DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int32, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        ),
+    ]);
+
+    for array in get_arrays_of_all_types() {
+        for empty in [new_empty_array(array.data_type()), array.slice(1, 0)] {
+            for to_type in &all_types {
+                if !can_cast_types(empty.data_type(), to_type) {
+                    continue;
+                }
+                let cast_result = cast(&empty, to_type).unwrap_or_else(|e| {
+                    panic!(
+                        "Failed to cast empty {:?} to {to_type:?}: {e}",
+                        empty.data_type()
+                    )
+                });
+                assert_eq!(cast_result.len(), 0);
+                assert_eq!(cast_result.data_type(), to_type);
+                cast_result.to_data().validate_full().unwrap();
+            }
+        }
+    }
+}
+
 /// Create instances of arrays with varying types for cast tests
 fn get_arrays_of_all_types() -> Vec<ArrayRef> {
     let tz_name = String::from("+08:00");
@@ -471,6 +505,21 @@ fn test_timestamp_cast_utf8() {
     );
 }
 
+#[test]
+fn test_timestamp_cast_time_named_timezone() {
+    // 2021-01-01T06:30:00Z and 2021-07-01T06:30:00Z, outside and during DST
+    let array =
+        TimestampSecondArray::from(vec![Some(1609482600), None, Some(1625121000)])
+            .with_timezone("America/New_York".to_string());
+    let out = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
+
+    let expected = Time32SecondArray::from(vec![Some(5400), None, Some(9000)]);
+    assert_eq!(
+        out.as_any().downcast_ref::<Time32SecondArray>().unwrap(),
+        &expected
+    );
+}
+
 fn format_timezone(tz: &str) -> Result<String, ArrowError> {
     let array = Arc::new(
         TimestampSecondArray::from(vec![Some(11111111), None]).with_timezone(tz),
@@ -526,3 +575,387 @@ fn test_pretty_format_timestamp_second_with_unknown_timezone() {
         "Parser error: Invalid timezone \"unknown\": 'unknown' is not a valid timezone"
     );
 }
+
+#[test]
+fn test_truncate_timestamp_named_timezone() {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::TimestampSecondType;
+    use arrow_cast::truncate::{truncate_timestamp, TruncateGranularity};
+
+    // 2023-03-12T16:00:00Z, after clocks are set forward at 02:00 local time, and
+    // 2023-11-05T06:30:00Z, 01:30 local time after clocks are set back at 02:00
+    let array = TimestampSecondArray::from(vec![1678636800, 1699165800])
+        .with_timezone("America/New_York".to_string());
+
+    let out = truncate_timestamp(&array, TruncateGranularity::Day, true).unwrap();
+    let out = out.as_primitive::<TimestampSecondType>();
+    // 2023-03-12T05:00:00Z and 2023-11-05T04:00:00Z
+    assert_eq!(out.values(), &[1678597200, 1699156800]);
+
+    let out = truncate_timestamp(&array, TruncateGranularity::Hour, true).unwrap();
+    let out = out.as_primitive::<TimestampSecondType>();
+    // 2023-03-12T16:00:00Z and 2023-11-05T06:00:00Z
+    assert_eq!(out.values(), &[1678636800, 1699164000]);
+}
+
+#[test]
+fn test_cast_timestamp_to_struct_named_timezone() {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Date32Type, Time64MicrosecondType};
+
+    let fields = Fields::from(vec![
+        Field::new("date", DataType::Date32, false),
+        Field::new("time", DataType::Time64(TimeUnit::Microsecond), false),
+    ]);
+    // 2023-03-26T00:30:00Z and 2023-03-26T01:30:00Z, either side of the change to
+    // daylight saving time in London
+    let array = TimestampSecondArray::from(vec![1679790600, 1679794200])
+        .with_timezone("Europe/London");
+    let out = cast(&array, &DataType::Struct(fields)).unwrap();
+    let out = out.as_struct();
+    let dates = out.column(0).as_primitive::<Date32Type>();
+    assert_eq!(dates.values(), &[19442, 19442]);
+    let times = out.column(1).as_primitive::<Time64MicrosecondType>();
+    assert_eq!(times.values(), &[1_800_000_000, 9_000_000_000]);
+}
+
+#[test]
+fn test_cast_timestamp_to_date_named_timezone() {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Date32Type, Date64Type};
+
+    // Either side of local midnight around the changes to and from daylight saving
+    // time in New York in 2021, 2021-03-14T04:30:00Z and 2021-03-14T05:00:00Z, and
+    // 2021-11-07T03:59:00Z, 2021-11-07T04:30:00Z and 2021-11-07T05:30:00Z
+    let seconds = vec![
+        Some(1615696200),
+        Some(1615698000),
+        Some(1636257540),
+        Some(1636259400),
+        Some(1636263000),
+        None,
+    ];
+    let expected = vec![
+        Some(18699),
+        Some(18700),
+        Some(18937),
+        Some(18938),
+        Some(18938),
+        None,
+    ];
+
+    let arrays: Vec<ArrayRef> = vec![
+        Arc::new(
+            TimestampSecondArray::from(seconds.clone()).with_timezone("America/New_York"),
+        ),
+        Arc::new(
+            TimestampNanosecondArray::from_iter(
+                seconds.iter().map(|v| v.map(|v| v * 1_000_000_000 + 1)),
+            )
+            .with_timezone("America/New_York"),
+        ),
+    ];
+    for array in arrays {
+        let out = cast(&array, &DataType::Date32).unwrap();
+        let days: Vec<_> = out.as_primitive::<Date32Type>().iter().collect();
+        assert_eq!(days, expected);
+
+        let out = cast(&array, &DataType::Date64).unwrap();
+        let millis: Vec<_> = out.as_primitive::<Date64Type>().iter().collect();
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|d| d.map(|d| d as i64 * 86_400_000))
+            .collect();
+        assert_eq!(millis, expected);
+    }
+}
+
+#[test]
+fn test_cast_timestamp_wall_clock_named_timezone() {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::TimestampSecondType;
+    use arrow_cast::{cast_with_options, CastOptions, TimezoneConversion};
+
+    // 2023-03-12T01:30:00Z, 2023-03-12T02:30:00Z and 2023-11-05T01:30:00Z
+    let array = TimestampSecondArray::from(vec![1678584600, 1678588200, 1699147800])
+        .with_timezone("+00:00");
+    let to_type = DataType::Timestamp(TimeUnit::Second, Some("America/New_York".into()));
+    let options =
+        CastOptions::new().with_timezone_conversion(TimezoneConversion::WallClock);
+
+    // 02:30 does not exist in New York on 2023-03-12, and 01:30 on 2023-11-05 is
+    // ambiguous, resolving to the earliest instant 2023-11-05T01:30:00-04:00
+    let out = cast_with_options(&array, &to_type, &options).unwrap();
+    let values: Vec<_> = out.as_primitive::<TimestampSecondType>().iter().collect();
+    assert_eq!(values, vec![Some(1678602600), None, Some(1699162200)]);
+
+    let err = cast_with_options(&array, &to_type, &options.with_safe(false)).unwrap_err();
+    assert!(err.to_string().contains("Cannot cast 1678588200"), "{err}");
+}
+
+#[test]
+fn test_cast_string_to_timestamp_local_time_resolution() {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::TimestampSecondType;
+    use arrow_cast::parse::LocalTimeResolution;
+    use arrow_cast::{cast_with_options, CastOptions};
+
+    // In both timezones, the first datetime is in the gap of clocks moving forward,
+    // and the second in the overlap of clocks moving back
+    let cases = [
+        (
+            "America/New_York",
+            ["2023-03-12 02:30:00", "2023-11-05 01:30:00"],
+            // 2023-03-12T07:30:00Z, 2023-11-05T05:30:00Z and 2023-11-05T06:30:00Z
+            (1678606200, 1699162200, 1699165800),
+        ),
+        (
+            "Europe/Berlin",
+            ["2023-03-26 02:30:00", "2023-10-29 02:30:00"],
+            // 2023-03-26T01:30:00Z, 2023-10-29T00:30:00Z and 2023-10-29T01:30:00Z
+            (1679794200, 1698539400, 1698543000),
+        ),
+    ];
+
+    for (tz, strings, (shifted, earliest, latest)) in cases {
+        let array = StringArray::from(strings.to_vec());
+        let to_type = DataType::Timestamp(TimeUnit::Second, Some(tz.into()));
+        let expected = [
+            (LocalTimeResolution::Error, [None, None]),
+            (LocalTimeResolution::Earliest, [None, Some(earliest)]),
+            (LocalTimeResolution::Latest, [None, Some(latest)]),
+            (
+                LocalTimeResolution::ShiftForward,
+                [Some(shifted), Some(earliest)],
+            ),
+        ];
+        for (resolution, expected) in expected {
+            let options = CastOptions::new().with_local_time_resolution(resolution);
+            let out = cast_with_options(&array, &to_type, &options).unwrap();
+            let values: Vec<_> =
+                out.as_primitive::<TimestampSecondType>().iter().collect();
+            assert_eq!(values, expected, "{tz} {resolution:?}");
+        }
+
+        // The same instants are parsed with the strftime patterns
+        let options = CastOptions::new()
+            .with_timestamp_formats(&["%Y-%m-%d %H:%M:%S"])
+            .with_local_time_resolution(LocalTimeResolution::Latest);
+        let out = cast_with_options(&array, &to_type, &options).unwrap();
+        let values: Vec<_> = out.as_primitive::<TimestampSecondType>().iter().collect();
+        assert_eq!(values, [None, Some(latest)], "{tz}");
+
+        let options = CastOptions::new().with_safe(false);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert!(
+            err.to_string().contains("error computing timezone offset"),
+            "{err}"
+        );
+    }
+}
+
+/// Create arrays of random values, including the extremes of each type
+fn get_random_arrays(rng: &mut rand::rngs::StdRng, len: usize) -> Vec<ArrayRef> {
+    use arrow_array::types::*;
+    use rand::Rng;
+
+    fn values<T, F>(
+        rng: &mut rand::rngs::StdRng,
+        len: usize,
+        extremes: &[T::Native],
+        f: F,
+    ) -> PrimitiveArray<T>
+    where
+        T: ArrowPrimitiveType,
+        F: Fn(&mut rand::rngs::StdRng) -> T::Native,
+    {
+        (0..len)
+            .map(|i| match rng.gen_range(0..8) {
+                0 => None,
+                1 if !extremes.is_empty() => Some(extremes[i % extremes.len()]),
+                _ => Some(f(rng)),
+            })
+            .collect()
+    }
+
+    macro_rules! random {
+        ($t:ty, $($e:expr),*) => {
+            Arc::new(values::<$t, _>(rng, len, &[$($e),*], |r| r.gen())) as ArrayRef
+        };
+    }
+
+    let decimal128 = |rng: &mut rand::rngs::StdRng, p: u8, s: i8| -> ArrayRef {
+        let max = 10_i128.pow(p as u32);
+        let a = values::<Decimal128Type, _>(rng, len, &[max - 1, 1 - max, 0], |r| {
+            r.gen_range(1 - max..max)
+        });
+        Arc::new(a.with_precision_and_scale(p, s).unwrap())
+    };
+    let decimal256 = |rng: &mut rand::rngs::StdRng, s: i8| -> ArrayRef {
+        let max = i256::from_i128(10).wrapping_pow(76);
+        let a = values::<Decimal256Type, _>(
+            rng,
+            len,
+            &[max.wrapping_sub(i256::ONE), i256::ONE.wrapping_sub(max)],
+            |r| i256::from_parts(r.gen(), r.gen::<i128>() % (i128::MAX / 1_000_000_000)),
+        );
+        Arc::new(a.with_precision_and_scale(76, s).unwrap())
+    };
+
+    let strings = [
+        "",
+        "0",
+        "-1",
+        "1.5",
+        "1e400",
+        "NaN",
+        "-inf",
+        "true",
+        "FALSE",
+        "0x1F",
+        "  42  ",
+        "99999999999999999999999999999999999999999",
+        "1970-01-01",
+        "+262143-12-31",
+        "2023-04-01T12:00:00.123456789123+01:00",
+        "9999-12-31T23:59:59Z",
+        "23:59:60.999",
+        "-9223372036854775808",
+        "1 year 2 days",
+        "02-JAN-99",
+        "é",
+    ];
+    let strings: Vec<Option<&str>> = (0..len)
+        .map(|_| match rng.gen_range(0..strings.len() + 1) {
+            0 => None,
+            i => Some(strings[i - 1]),
+        })
+        .collect();
+
+    vec![
+        random!(Int8Type, i8::MIN, i8::MAX),
+        random!(Int16Type, i16::MIN, i16::MAX),
+        random!(Int32Type, i32::MIN, i32::MAX),
+        random!(Int64Type, i64::MIN, i64::MAX),
+        random!(UInt8Type, u8::MAX),
+        random!(UInt16Type, u16::MAX),
+        random!(UInt32Type, u32::MAX),
+        random!(UInt64Type, u64::MAX),
+        Arc::new(values::<Float16Type, _>(
+            rng,
+            len,
+            &[f16::MAX, f16::MIN, f16::NAN, f16::INFINITY],
+            |r| f16::from_f32(r.gen::<f32>() * 1e4),
+        )),
+        random!(
+            Float32Type,
+            f32::MAX,
+            f32::MIN,
+            f32::NAN,
+            f32::NEG_INFINITY,
+            1e-40
+        ),
+        Arc::new(values::<Float64Type, _>(
+            rng,
+            len,
+            &[f64::MAX, f64::MIN, f64::NAN, f64::INFINITY, 1e300],
+            |r| (r.gen::<f64>() - 0.5) * 1e20,
+        )),
+        random!(Date32Type, i32::MIN, i32::MAX),
+        random!(Date64Type, i64::MIN, i64::MAX),
+        random!(Time32SecondType, i32::MIN, i32::MAX, 86_399),
+        random!(Time32MillisecondType, i32::MIN, i32::MAX),
+        random!(Time64MicrosecondType, i64::MIN, i64::MAX),
+        random!(Time64NanosecondType, i64::MIN, i64::MAX),
+        random!(TimestampSecondType, i64::MIN, i64::MAX),
+        random!(TimestampMillisecondType, i64::MIN, i64::MAX),
+        random!(TimestampMicrosecondType, i64::MIN, i64::MAX),
+        random!(TimestampNanosecondType, i64::MIN, i64::MAX),
+        Arc::new(
+            values::<TimestampSecondType, _>(rng, len, &[i64::MIN, i64::MAX], |r| {
+                r.gen()
+            })
+            .with_timezone("+08:00"),
+        ),
+        Arc::new(
+            values::<TimestampNanosecondType, _>(rng, len, &[i64::MIN, i64::MAX], |r| {
+                r.gen()
+            })
+            .with_timezone("-05:30"),
+        ),
+        random!(DurationSecondType, i64::MIN, i64::MAX),
+        random!(DurationMillisecondType, i64::MIN, i64::MAX),
+        random!(DurationMicrosecondType, i64::MIN, i64::MAX),
+        random!(DurationNanosecondType, i64::MIN, i64::MAX),
+        random!(IntervalYearMonthType, i32::MIN, i32::MAX),
+        random!(IntervalDayTimeType, i64::MIN, i64::MAX),
+        random!(IntervalMonthDayNanoType, i128::MIN, i128::MAX),
+        decimal128(rng, 38, 0),
+        decimal128(rng, 38, 38),
+        decimal128(rng, 10, -5),
+        decimal128(rng, 5, 2),
+        decimal256(rng, 0),
+        decimal256(rng, 76),
+        decimal256(rng, -20),
+        Arc::new(StringArray::from(strings.clone())),
+        Arc::new(LargeStringArray::from(strings.clone())),
+        Arc::new(BinaryArray::from_iter(
+            strings.iter().map(|s| s.map(|s| s.as_bytes())),
+        )),
+        Arc::new(BooleanArray::from_iter(
+            (0..len).map(|_| rng.gen::<Option<bool>>()),
+        )),
+    ]
+}
+
+/// Returns the types to cast the random arrays to
+fn get_random_cast_types() -> Vec<DataType> {
+    use DataType::*;
+    let mut types = get_all_types();
+    types.extend([
+        Int64,
+        Decimal128(38, 10),
+        Decimal128(5, 2),
+        Decimal128(10, -5),
+        Decimal256(76, 0),
+        Decimal256(76, 40),
+        Decimal256(20, -10),
+        Timestamp(TimeUnit::Nanosecond, Some("-05:30".into())),
+        FixedSizeBinary(16),
+    ]);
+    types
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // running forever
+fn test_cast_random_arrays_never_panics() {
+    // a smoke test rather than a fuzz test, as only the values are random, and not the
+    // types or the options
+    use arrow_cast::{cast_with_options, CastOptions, DEFAULT_CAST_OPTIONS};
+    use rand::SeedableRng;
+    use std::panic::AssertUnwindSafe;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    let types = get_random_cast_types();
+    let unsafe_options = CastOptions::new().with_safe(false);
+    let mut failures = vec![];
+    for _ in 0..3 {
+        for array in get_random_arrays(&mut rng, 64) {
+            for to_type in &types {
+                for options in [&DEFAULT_CAST_OPTIONS, &unsafe_options] {
+                    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                        let _ = cast_with_options(&array, to_type, options);
+                    }));
+                    if result.is_err() {
+                        failures.push(format!(
+                            "{:?} -> {to_type:?} (safe: {})",
+                            array.data_type(),
+                            options.safe
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{failures:#?}");
+}
@@ -0,0 +1,196 @@
+#!/usr/bin/env python3
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+"""Generates the golden files read by `tests/cast_golden.rs`
+
+Each case is cast with the `cast` compute function of pyarrow, and written to
+`<output dir>/<case>.arrow` as an IPC file with an `input` and an `expected` column.
+
+    python3 arrow/tests/cast_golden.py /tmp/cast_golden
+    ARROW_CAST_GOLDEN_DIR=/tmp/cast_golden cargo test -p arrow --test cast_golden --features cast_golden
+"""
+
+import decimal
+import os
+import sys
+
+import pyarrow as pa
+import pyarrow.compute as pc
+
+D = decimal.Decimal
+
+# (name, input, target type, safe)
+CASES = [
+    # Timestamps around the DST transitions of America/New_York in 2021
+    (
+        "timestamp_dst_to_string",
+        pa.array(
+            [1615705200, 1615708800, 1636264800, 1636268400, None],
+            pa.timestamp("s", "America/New_York"),
+        ),
+        pa.utf8(),
+        True,
+    ),
+    (
+        "timestamp_dst_to_date32",
+        pa.array(
+            [1615705200, 1615708800, 1636264800, 1636268400, None],
+            pa.timestamp("s", "America/New_York"),
+        ),
+        pa.date32(),
+        True,
+    ),
+    (
+        "string_to_timestamp_offset",
+        pa.array(["2021-03-14T07:30:00Z", "2021-11-07T01:30:00-04:00", None]),
+        pa.timestamp("s", "America/New_York"),
+        True,
+    ),
+    (
+        "timestamp_tz_to_utc_unit",
+        pa.array(
+            [1615705200123, -1, None], pa.timestamp("ms", "America/New_York")
+        ),
+        pa.timestamp("s", "UTC"),
+        False,
+    ),
+    # Decimal rescaling and rounding
+    (
+        "decimal_increase_scale",
+        pa.array([D("1.234"), D("-0.005"), None], pa.decimal128(10, 3)),
+        pa.decimal128(12, 5),
+        True,
+    ),
+    (
+        "decimal_truncate_scale",
+        pa.array([D("1.234"), D("-0.005"), None], pa.decimal128(10, 3)),
+        pa.decimal128(10, 1),
+        False,
+    ),
+    (
+        "decimal_to_decimal256",
+        pa.array([D("99999.99"), D("-0.01"), None], pa.decimal128(7, 2)),
+        pa.decimal256(40, 2),
+        True,
+    ),
+    (
+        "decimal_precision_overflow",
+        pa.array([D("99999.99")], pa.decimal128(7, 2)),
+        pa.decimal128(5, 2),
+        True,
+    ),
+    (
+        "decimal_to_float64",
+        pa.array([D("0.1"), D("-12345.678"), None], pa.decimal128(10, 3)),
+        pa.float64(),
+        True,
+    ),
+    (
+        "decimal_to_int32_fraction",
+        pa.array([D("1.5"), D("-2.5")], pa.decimal128(5, 1)),
+        pa.int32(),
+        True,
+    ),
+    # Float boundaries
+    (
+        "float64_to_int32_in_range",
+        pa.array([2147483647.0, -2147483648.0, 0.0, -0.0, None], pa.float64()),
+        pa.int32(),
+        True,
+    ),
+    (
+        "float64_to_int32_overflow",
+        pa.array([2147483648.0], pa.float64()),
+        pa.int32(),
+        True,
+    ),
+    (
+        "float64_to_int32_fraction",
+        pa.array([1.5], pa.float64()),
+        pa.int32(),
+        True,
+    ),
+    (
+        "float64_nan_to_int64",
+        pa.array([float("nan")], pa.float64()),
+        pa.int64(),
+        True,
+    ),
+    (
+        "float64_to_float32",
+        pa.array(
+            [1e300, -1e-300, float("inf"), float("nan"), 3.4028234663852886e38],
+            pa.float64(),
+        ),
+        pa.float32(),
+        True,
+    ),
+    (
+        "int64_to_float64_inexact",
+        pa.array([2**53 + 1], pa.int64()),
+        pa.float64(),
+        True,
+    ),
+    (
+        "float64_to_decimal",
+        pa.array([0.1, 1.005, -2.5, None], pa.float64()),
+        pa.decimal128(10, 2),
+        True,
+    ),
+    (
+        "string_to_float64",
+        pa.array(["1e308", "1e309", "-0", "0x10", " 1", "nan", None]),
+        pa.float64(),
+        False,
+    ),
+]
+
+
+def write_case(path, name, array, target, safe):
+    metadata = {b"safe": str(safe).lower().encode()}
+    try:
+        expected = pc.cast(array, target, safe=safe)
+    except pa.ArrowNotImplementedError:
+        print(f"skipping {name}, cast not supported by pyarrow")
+        return
+    except pa.ArrowInvalid:
+        metadata[b"error"] = b"true"
+        expected = pa.nulls(len(array), target)
+
+    schema = pa.schema(
+        [pa.field("input", array.type), pa.field("expected", target)],
+        metadata=metadata,
+    )
+    batch = pa.record_batch([array, expected], schema=schema)
+    with pa.OSFile(os.path.join(path, f"{name}.arrow"), "wb") as sink:
+        with pa.ipc.new_file(sink, schema) as writer:
+            writer.write_batch(batch)
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(f"usage: {sys.argv[0]} <output dir>")
+    path = sys.argv[1]
+    os.makedirs(path, exist_ok=True)
+    for name, array, target, safe in CASES:
+        write_case(path, name, array, target, safe)
+    print(f"wrote golden files for pyarrow {pa.__version__} to {path}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compares casts against the results of the Arrow C++ implementation
+//!
+//! The test requires the `cast_golden` feature, which CI enables. The golden files are
+//! generated with pyarrow by `tests/cast_golden.py`, and are read from the directory
+//! in the `ARROW_CAST_GOLDEN_DIR` environment variable, which must be set.
+//!
+//! Each file is an IPC file of a single cast, with an `input` and an `expected`
+//! column. The cast is performed with [`CastCompat::ArrowCpp`], and the `safe`
+//! schema metadata. If the `error` metadata is `true`, the C++ cast failed, and the
+//! `expected` column only holds the target type. Otherwise the buffers of the result
+//! must match those of the `expected` column byte for byte, including the values of
+//! null slots.
+
+use arrow_array::{Array, RecordBatch};
+use arrow_buffer::bit_util;
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_cast::{cast_with_options, CastCompat, CastOptions};
+use arrow_data::{layout, ArrayData, BufferSpec};
+use arrow_ipc::reader::FileReader;
+use arrow_schema::DataType;
+use std::fs::File;
+use std::path::Path;
+
+#[test]
+fn test_cast_golden() {
+    let dir = std::env::var("ARROW_CAST_GOLDEN_DIR").expect(
+        "ARROW_CAST_GOLDEN_DIR must be set to the output directory of tests/cast_golden.py",
+    );
+
+    let mut paths: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("Failed to read {dir}: {e}"))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(false, |e| e == "arrow"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "No golden files in {dir}");
+
+    let failures: Vec<String> = paths
+        .iter()
+        .flat_map(|path| {
+            read_batches(path)
+                .iter()
+                .filter_map(|batch| check_batch(batch).err())
+                .map(|e| format!("{}: {e}", path.display()))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} golden casts differ:\n{}",
+        failures.len(),
+        paths.len(),
+        failures.join("\n")
+    );
+}
+
+fn read_batches(path: &Path) -> Vec<RecordBatch> {
+    let file = File::open(path).unwrap();
+    FileReader::try_new(file, None)
+        .and_then(|reader| reader.collect())
+        .unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()))
+}
+
+/// Casts the `input` column of `batch` to the type of its `expected` column, returning
+/// a description of the difference to the C++ result, if any
+fn check_batch(batch: &RecordBatch) -> Result<(), String> {
+    let schema = batch.schema();
+    let metadata = |key: &str| schema.metadata().get(key).map(String::as_str);
+    let input = batch.column(schema.index_of("input").unwrap());
+    let expected = batch.column(schema.index_of("expected").unwrap());
+    let to_type = expected.data_type();
+
+    let options = CastOptions::new()
+        .with_safe(metadata("safe") != Some("false"))
+        .with_compat(CastCompat::ArrowCpp);
+    let cast = cast_with_options(input, to_type, &options);
+    let describe = format!("{:?} to {to_type:?}", input.data_type());
+
+    match (cast, metadata("error") == Some("true")) {
+        (Ok(_), true) => Err(format!("cast of {describe} succeeded, expected an error")),
+        (Err(e), false) => Err(format!("cast of {describe} failed: {e}")),
+        (Err(_), true) => Ok(()),
+        (Ok(actual), false) => compare_bytes(&expected.to_data(), &actual.to_data())
+            .map_err(|e| {
+                format!(
+                    "cast of {describe} expected {} got {}: {e}",
+                    format_array(expected.as_ref()),
+                    format_array(actual.as_ref())
+                )
+            }),
+    }
+}
+
+/// Compares the buffers of `actual` with those of `expected` byte for byte, unlike
+/// [`ArrayData`] equality, which ignores the values of null slots
+fn compare_bytes(expected: &ArrayData, actual: &ArrayData) -> Result<(), String> {
+    if expected.data_type() != actual.data_type() || expected.len() != actual.len() {
+        return Err(format!(
+            "{:?} of length {} differs from {:?} of length {}",
+            actual.data_type(),
+            actual.len(),
+            expected.data_type(),
+            expected.len()
+        ));
+    }
+
+    let validity = |data: &ArrayData| -> Vec<bool> {
+        (0..data.len()).map(|i| data.is_valid(i)).collect()
+    };
+    if validity(expected) != validity(actual) {
+        return Err("validity differs".to_string());
+    }
+
+    let specs = layout(expected.data_type()).buffers;
+    let buffers = specs
+        .iter()
+        .zip(expected.buffers().iter().zip(actual.buffers()));
+    for (idx, (spec, (e, a))) in buffers.enumerate() {
+        let equal = match spec {
+            BufferSpec::FixedWidth { byte_width } => {
+                // The offsets of variable sized types hold one more value than the length
+                let count = match expected.data_type() {
+                    DataType::Utf8
+                    | DataType::LargeUtf8
+                    | DataType::Binary
+                    | DataType::LargeBinary
+                    | DataType::List(_)
+                    | DataType::LargeList(_)
+                    | DataType::Map(_, _) => expected.len() + 1,
+                    _ => expected.len(),
+                };
+                let range =
+                    |offset: usize| offset * byte_width..(offset + count) * byte_width;
+                e.get(range(expected.offset())) == a.get(range(actual.offset()))
+            }
+            BufferSpec::BitMap => (0..expected.len()).all(|i| {
+                bit_util::get_bit(e, expected.offset() + i)
+                    == bit_util::get_bit(a, actual.offset() + i)
+            }),
+            BufferSpec::VariableWidth | BufferSpec::AlwaysNull => {
+                e.as_slice() == a.as_slice()
+            }
+        };
+        if !equal {
+            return Err(format!("buffer {idx} differs"));
+        }
+    }
+
+    let children = expected.child_data().iter().zip(actual.child_data());
+    for (idx, (e, a)) in children.enumerate() {
+        compare_bytes(e, a).map_err(|err| format!("child {idx}: {err}"))?;
+    }
+    Ok(())
+}
+
+fn format_array(array: &dyn Array) -> String {
+    let options = FormatOptions::default().with_null("null");
+    match ArrayFormatter::try_new(array, &options) {
+        Ok(formatter) => {
+            let values: Vec<_> = (0..array.len())
+                .map(|i| formatter.value(i).to_string())
+                .collect();
+            format!("[{}]", values.join(", "))
+        }
+        Err(_) => format!("{array:?}"),
+    }
+}
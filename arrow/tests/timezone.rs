@@ -15,7 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow_cast::parse::string_to_datetime;
+use arrow_array::timezone::Tz;
+use arrow_cast::parse::{
+    string_to_datetime, string_to_datetime_with_resolution, LocalTimeResolution,
+};
 use chrono::Utc;
 
 #[test]
@@ -79,3 +82,59 @@ fn test_parse_timezone_invalid() {
         assert_eq!(actual, expected)
     }
 }
+
+#[test]
+fn test_parse_timezone_local_time_resolution() {
+    use LocalTimeResolution::*;
+
+    // The gap of clocks moving forward, and the overlap of clocks moving back, in the
+    // US and the EU
+    let gap = [
+        "2023-03-12 02:30:00 America/New_York",
+        "2023-03-26 02:30:00 Europe/Berlin",
+    ];
+    let overlap = [
+        "2023-11-05 01:30:00 America/New_York",
+        "2023-10-29 02:30:00 Europe/Berlin",
+    ];
+    let cases = [
+        (gap[0], Error, None),
+        (gap[0], Earliest, None),
+        (gap[0], Latest, None),
+        (gap[0], ShiftForward, Some("2023-03-12T07:30:00+00:00")),
+        (gap[1], Earliest, None),
+        (gap[1], ShiftForward, Some("2023-03-26T01:30:00+00:00")),
+        (overlap[0], Error, None),
+        (overlap[0], Earliest, Some("2023-11-05T05:30:00+00:00")),
+        (overlap[0], Latest, Some("2023-11-05T06:30:00+00:00")),
+        (overlap[0], ShiftForward, Some("2023-11-05T05:30:00+00:00")),
+        (overlap[1], Error, None),
+        (overlap[1], Earliest, Some("2023-10-29T00:30:00+00:00")),
+        (overlap[1], Latest, Some("2023-10-29T01:30:00+00:00")),
+        // Datetimes in UTC are never ambiguous
+        (
+            "2023-11-05 01:30:00Z",
+            Error,
+            Some("2023-11-05T01:30:00+00:00"),
+        ),
+    ];
+
+    for (s, resolution, expected) in cases {
+        let actual = string_to_datetime_with_resolution(&Utc, s, resolution)
+            .ok()
+            .map(|d| d.to_rfc3339());
+        assert_eq!(actual.as_deref(), expected, "{s} {resolution:?}");
+    }
+
+    // Local datetimes are resolved in the timezone they are parsed relative to
+    let tz: Tz = "Europe/Berlin".parse().unwrap();
+    let actual = string_to_datetime_with_resolution(&tz, "2023-10-29T02:30:00", Latest);
+    assert_eq!(actual.unwrap().to_rfc3339(), "2023-10-29T02:30:00+01:00");
+    let actual = string_to_datetime_with_resolution(&tz, "2023-03-26", ShiftForward);
+    assert_eq!(actual.unwrap().to_rfc3339(), "2023-03-26T00:00:00+01:00");
+    let err = string_to_datetime(&tz, "2023-10-29 02:30:00").unwrap_err();
+    assert!(
+        err.to_string().ends_with("error computing timezone offset"),
+        "{err}"
+    );
+}